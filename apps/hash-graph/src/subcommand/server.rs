@@ -2,20 +2,26 @@ use alloc::sync::Arc;
 use core::{
     fmt,
     net::{AddrParseError, SocketAddr},
+    num::NonZero,
     str::FromStr as _,
     time::Duration,
 };
 use std::path::PathBuf;
 
+use axum::{Router, http::header, routing::get};
 use clap::Parser;
 use error_stack::{Report, ResultExt as _};
 use futures::{StreamExt as _, channel::mpsc};
 use harpc_codec::json::JsonCodec;
-use harpc_server::Server;
+use harpc_server::{
+    IdentityConfig, Server,
+    limit::{Quota, Rate},
+    metrics::MetricsHandle,
+};
 use hash_codec::bytes::JsonLinesEncoder;
 use hash_graph_api::{
-    rest::{QueryLogger, RestRouterDependencies, rest_api_router},
-    rpc::Dependencies,
+    rest::{InstanceAdministrators, QueryLogger, RestRouterDependencies, rest_api_router},
+    rpc::{Dependencies, Limits},
 };
 use hash_graph_authorization::{
     AuthorizationApi as _, AuthorizationApiPool, NoAuthorization,
@@ -28,13 +34,15 @@ use hash_graph_postgres_store::store::{
 use hash_graph_store::pool::StorePool;
 use hash_graph_type_fetcher::FetchingPool;
 use hash_temporal_client::TemporalClientConfig;
+use libp2p_identity::PeerId;
 use multiaddr::{Multiaddr, Protocol};
 use regex::Regex;
 use reqwest::{Client, Url};
 use tokio::{io, net::TcpListener, time::timeout};
 use tokio_postgres::NoTls;
 use tokio_util::codec::FramedWrite;
-use type_system::ontology::json_schema::DomainValidator;
+use type_system::{ontology::json_schema::DomainValidator, web::ActorGroupId};
+use uuid::Uuid;
 
 use crate::{
     error::{GraphError, HealthcheckError},
@@ -110,6 +118,33 @@ pub struct ServerArgs {
     #[clap(flatten)]
     pub rpc_address: RpcAddress,
 
+    /// Path to the key file holding the identity of the RPC server.
+    ///
+    /// If the key file does not exist, a new identity is generated and written to it. If not
+    /// set, a new identity is generated on every start.
+    #[clap(long, env = "HASH_GRAPH_RPC_KEY_FILE")]
+    pub rpc_key_file: Option<PathBuf>,
+
+    /// Comma-separated list of peer IDs that are allowed to connect to the RPC server.
+    ///
+    /// If not set, any peer is allowed to connect.
+    #[clap(long, env = "HASH_GRAPH_RPC_ALLOWED_PEERS", value_delimiter = ',')]
+    pub rpc_allowed_peers: Option<Vec<PeerId>>,
+
+    /// Maximum number of RPC requests per second for every peer and every authenticated actor.
+    ///
+    /// Short bursts of up to the same number of requests are allowed. If not set, requests are
+    /// not rate limited.
+    #[clap(long, env = "HASH_GRAPH_RPC_RATE_LIMIT")]
+    pub rpc_rate_limit: Option<NonZero<u32>>,
+
+    /// Maximum number of concurrent RPC requests for every peer and every authenticated actor.
+    ///
+    /// If not set, the number of concurrent requests is only limited by the session
+    /// configuration.
+    #[clap(long, env = "HASH_GRAPH_RPC_MAX_IN_FLIGHT")]
+    pub rpc_max_in_flight: Option<NonZero<usize>>,
+
     /// The address for the type fetcher RPC server is listening at.
     #[clap(flatten)]
     pub type_fetcher_address: TypeFetcherAddress,
@@ -181,24 +216,40 @@ pub struct ServerArgs {
     /// Outputs the queries made to the graph to the specified file.
     #[clap(long)]
     pub log_queries: Option<PathBuf>,
+
+    /// The account group whose members are allowed to use administrative endpoints, e.g. to
+    /// explain queries.
+    ///
+    /// If not specified, administrative endpoints are unavailable.
+    #[clap(long, env = "HASH_GRAPH_INSTANCE_ADMINISTRATORS")]
+    pub instance_administrators: Option<Uuid>,
 }
 
 fn server_rpc<S, A>(
     address: RpcAddress,
+    identity: IdentityConfig,
     dependencies: Dependencies<S, A, ()>,
-) -> Result<(), Report<GraphError>>
+) -> Result<MetricsHandle, Report<GraphError>>
 where
     S: StorePool + Send + Sync + 'static,
     A: AuthorizationApiPool + Send + Sync + 'static,
 {
-    let server = Server::new(harpc_server::ServerConfig::default()).change_context(GraphError)?;
+    let mut config = harpc_server::ServerConfig::default();
+    config.transport.identity = identity;
+
+    let server = Server::new(config).change_context(GraphError)?;
+    tracing::info!(peer_id = %server.peer_id(), "RPC server identity");
+    let metrics = server.metrics();
 
     let (router, task) = hash_graph_api::rpc::rpc_router(
         Dependencies {
             store: dependencies.store,
             authorization_api: dependencies.authorization_api,
             temporal_client: dependencies.temporal_client,
+            domain_validator: dependencies.domain_validator,
+            query_logger: dependencies.query_logger,
             codec: JsonCodec,
+            limits: dependencies.limits,
         },
         server.events(),
     );
@@ -228,7 +279,24 @@ where
         harpc_server::serve::serve(stream, router).await;
     });
 
-    Ok(())
+    Ok(metrics)
+}
+
+/// Exposes the metrics of the RPC server in the Prometheus text format.
+fn rpc_metrics_router(metrics: MetricsHandle) -> Router {
+    Router::new().route(
+        "/rpc/metrics",
+        get(move || {
+            let body = metrics.render();
+
+            async move {
+                (
+                    [(header::CONTENT_TYPE, harpc_server::metrics::CONTENT_TYPE)],
+                    body,
+                )
+            }
+        }),
+    )
 }
 
 #[expect(
@@ -328,24 +396,61 @@ pub async fn server(args: ServerArgs) -> Result<(), Report<GraphError>> {
             temporal_client: temporal_client_fn(args.temporal_host.clone(), args.temporal_port)
                 .await?,
             query_logger: query_logger.map(QueryLogger::new),
+            instance_administrators: args.instance_administrators.map(|account_group_id| {
+                InstanceAdministrators(ActorGroupId::new(account_group_id))
+            }),
         };
 
-        if args.rpc_enabled {
+        let rpc_metrics = if args.rpc_enabled {
             tracing::info!("Starting RPC server...");
 
-            server_rpc(
+            let mut identity = IdentityConfig::default();
+            if let Some(key_file) = &args.rpc_key_file {
+                identity = identity.with_keypair(
+                    harpc_server::load_or_generate_keypair(key_file).change_context(GraphError)?,
+                );
+            }
+            if let Some(peers) = args.rpc_allowed_peers {
+                identity = identity.with_allowlist(peers);
+            }
+
+            let quota = Quota {
+                rate: args
+                    .rpc_rate_limit
+                    .map(|burst| Rate::new(burst, Duration::from_secs(1))),
+                in_flight: args.rpc_max_in_flight,
+            };
+
+            let metrics = server_rpc(
                 args.rpc_address,
+                identity,
                 Dependencies {
                     store: Arc::clone(&dependencies.store),
                     authorization_api: Arc::clone(&dependencies.authorization_api),
                     temporal_client: temporal_client_fn(args.temporal_host, args.temporal_port)
                         .await?,
+                    domain_validator: dependencies.domain_regex.clone(),
+                    query_logger: dependencies.query_logger.clone(),
                     codec: (),
+                    limits: Limits {
+                        peer: quota,
+                        actor: quota,
+                    },
                 },
             )?;
-        }
 
-        rest_api_router(dependencies)
+            Some(metrics)
+        } else {
+            None
+        };
+
+        let router = rest_api_router(dependencies);
+
+        if let Some(metrics) = rpc_metrics {
+            router.merge(rpc_metrics_router(metrics))
+        } else {
+            router
+        }
     };
 
     tracing::info!("Listening on {}", args.http_address);
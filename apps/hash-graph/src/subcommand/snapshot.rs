@@ -1,5 +1,11 @@
+use core::error::Error;
+use std::{env, io::Cursor};
+
 use clap::Parser;
 use error_stack::{Report, ResultExt as _};
+use futures::{
+    Sink, SinkExt as _, StreamExt as _, TryStreamExt as _, channel::mpsc, stream::BoxStream,
+};
 use hash_codec::bytes::{JsonLinesDecoder, JsonLinesEncoder};
 use hash_graph_authorization::{
     AuthorizationApi as _, NoAuthorization,
@@ -7,22 +13,45 @@ use hash_graph_authorization::{
     zanzibar::ZanzibarClient,
 };
 use hash_graph_postgres_store::{
-    snapshot::{SnapshotDumpSettings, SnapshotEntry, SnapshotStore},
+    snapshot::{
+        SnapshotContainer, SnapshotContainerError, SnapshotContainerWriter, SnapshotDumpError,
+        SnapshotDumpFilter, SnapshotDumpSettings, SnapshotEntry, SnapshotStore,
+    },
     store::{DatabaseConnectionInfo, DatabasePoolConfig, PostgresStorePool, PostgresStoreSettings},
 };
 use hash_graph_store::pool::StorePool as _;
-use tokio::io;
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
+use tokio::{
+    fs,
+    io::{self, AsyncReadExt as _, AsyncWriteExt as _},
+};
 use tokio_postgres::NoTls;
 use tokio_util::codec::{FramedRead, FramedWrite};
+use type_system::{ontology::VersionedUrl, web::OwnedById};
+use uuid::Uuid;
 
 use crate::error::GraphError;
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum SnapshotFormat {
+    /// Newline-delimited JSON with one entry per line.
+    JsonLines,
+    /// Compressed chunks of entries with a manifest containing their checksums.
+    Container,
+}
+
 #[expect(
     clippy::struct_excessive_bools,
     reason = "This is a configuration struct"
 )]
 #[derive(Debug, Parser)]
 pub struct SnapshotDumpArgs {
+    /// The format to write the snapshot in.
+    ///
+    /// Restoring detects the format automatically.
+    #[clap(long, value_enum, default_value_t = SnapshotFormat::JsonLines)]
+    pub format: SnapshotFormat,
+
     /// Whether to skip dumping the webs.
     #[clap(long)]
     pub no_webs: bool,
@@ -58,6 +87,26 @@ pub struct SnapshotDumpArgs {
     /// Whether to skip dumping the relations.
     #[clap(long)]
     pub no_relations: bool,
+
+    /// Only dumps the entities owned by this web, together with all records they reference.
+    ///
+    /// May be passed multiple times.
+    #[clap(long = "web")]
+    pub webs: Vec<Uuid>,
+
+    /// Only dumps the entities of this entity type, together with all records they reference.
+    ///
+    /// Entities of types inheriting from this type are dumped as well. May be passed multiple
+    /// times.
+    #[clap(long = "entity-type")]
+    pub entity_types: Vec<VersionedUrl>,
+
+    /// Only dumps the records which changed after this transaction time (RFC 3339).
+    ///
+    /// Pass the `takenAt` time from the metadata of the previous snapshot to chain the dumps. The
+    /// resulting snapshot is restored on top of a graph restored from the previous snapshot.
+    #[clap(long)]
+    pub since: Option<Timestamp<TransactionTime>>,
 }
 
 #[derive(Debug, Parser)]
@@ -106,6 +155,74 @@ pub struct SnapshotArgs {
     pub spicedb_grpc_preshared_key: Option<String>,
 }
 
+fn dump_snapshot(
+    pool: &PostgresStorePool,
+    sink: impl Sink<SnapshotEntry, Error = Report<impl Error + Send + Sync + 'static>> + Send + 'static,
+    authorization: Option<&ZanzibarClient<SpiceDbOpenApi>>,
+    settings: SnapshotDumpSettings,
+) -> Result<(), Report<SnapshotDumpError>> {
+    if let Some(authorization) = authorization {
+        pool.dump_snapshot(sink, authorization, settings)
+    } else {
+        pool.dump_snapshot(sink, &NoAuthorization, settings)
+    }
+}
+
+/// Reads a snapshot from stdin, either as plain newline-delimited JSON or as a container.
+///
+/// A container is spooled into a temporary file and verified before any entry is returned. The
+/// entries are then read from the file one chunk at a time.
+async fn read_snapshot()
+-> Result<BoxStream<'static, Result<SnapshotEntry, Report<GraphError>>>, Report<GraphError>> {
+    let mut stdin = io::BufReader::new(io::stdin());
+    let mut prefix = Vec::with_capacity(SnapshotContainer::MAGIC.len());
+    (&mut stdin)
+        .take(SnapshotContainer::MAGIC.len() as u64)
+        .read_to_end(&mut prefix)
+        .await
+        .change_context(GraphError)?;
+
+    if prefix == SnapshotContainer::MAGIC {
+        let path = env::temp_dir().join(format!("hash-graph-snapshot-{}", Uuid::new_v4()));
+        let mut file = fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)
+            .await
+            .change_context(GraphError)
+            .attach_printable_lazy(|| format!("Failed to create {}", path.display()))?;
+        // The file is only accessed through the open handle, so it is unlinked right away and
+        // removed once the handle is closed.
+        fs::remove_file(&path)
+            .await
+            .change_context(GraphError)
+            .attach_printable_lazy(|| format!("Failed to remove {}", path.display()))?;
+
+        io::copy(&mut Cursor::new(prefix).chain(stdin), &mut file)
+            .await
+            .change_context(GraphError)
+            .attach_printable("Failed to spool snapshot container")?;
+        file.flush().await.change_context(GraphError)?;
+
+        let container = SnapshotContainer::open(file)
+            .await
+            .change_context(GraphError)
+            .attach_printable("Failed to verify snapshot container")?;
+        Ok(container
+            .into_entries()
+            .map_err(|report| report.change_context(GraphError))
+            .boxed())
+    } else {
+        Ok(FramedRead::new(
+            Cursor::new(prefix).chain(stdin),
+            JsonLinesDecoder::default(),
+        )
+        .map_err(|report| report.change_context(GraphError))
+        .boxed())
+    }
+}
+
 pub async fn snapshot(args: SnapshotArgs) -> Result<(), Report<GraphError>> {
     SnapshotEntry::install_error_stack_hook();
 
@@ -149,10 +266,6 @@ pub async fn snapshot(args: SnapshotArgs) -> Result<(), Report<GraphError>> {
 
     match args.command {
         SnapshotCommand::Dump(args) => {
-            let write = FramedWrite::new(
-                io::BufWriter::new(io::stdout()),
-                JsonLinesEncoder::default(),
-            );
             let settings = SnapshotDumpSettings {
                 chunk_size: 10_000,
                 dump_webs: !args.no_webs,
@@ -164,23 +277,70 @@ pub async fn snapshot(args: SnapshotArgs) -> Result<(), Report<GraphError>> {
                 dump_data_types: !args.no_data_types,
                 dump_embeddings: !args.no_embeddings,
                 dump_relations: !args.no_relations,
+                filter: (!args.webs.is_empty() || !args.entity_types.is_empty()).then(|| {
+                    SnapshotDumpFilter {
+                        webs: args.webs.into_iter().map(OwnedById::new).collect(),
+                        entity_types: args.entity_types,
+                    }
+                }),
+                since: args.since,
             };
 
-            if let Some(authorization) = authorization {
-                pool.dump_snapshot(write, &authorization, settings)
-            } else {
-                pool.dump_snapshot(write, &NoAuthorization, settings)
+            match args.format {
+                SnapshotFormat::JsonLines => {
+                    let write = FramedWrite::new(
+                        io::BufWriter::new(io::stdout()),
+                        JsonLinesEncoder::default(),
+                    );
+                    dump_snapshot(&pool, write, authorization.as_ref(), settings)
+                        .change_context(GraphError)
+                        .attach_printable("Failed to produce snapshot dump")?;
+                }
+                SnapshotFormat::Container => {
+                    let (entry_tx, mut entry_rx) = mpsc::channel(10_000);
+                    let writer = tokio::spawn(async move {
+                        let mut writer =
+                            SnapshotContainerWriter::new(io::BufWriter::new(io::stdout()), 10_000)
+                                .await?;
+                        while let Some(entry) = entry_rx.next().await {
+                            writer.write(&entry).await?;
+                        }
+                        Ok::<_, Report<SnapshotContainerError>>(writer)
+                    });
+
+                    dump_snapshot(
+                        &pool,
+                        entry_tx.sink_map_err(Report::new),
+                        authorization.as_ref(),
+                        settings,
+                    )
+                    .change_context(GraphError)
+                    .attach_printable("Failed to produce snapshot dump")?;
+
+                    // The manifest is only written if the dump succeeded, so an aborted dump
+                    // cannot be restored.
+                    let manifest = writer
+                        .await
+                        .change_context(GraphError)?
+                        .change_context(GraphError)?
+                        .finish()
+                        .await
+                        .change_context(GraphError)
+                        .attach_printable("Failed to write snapshot container")?;
+                    tracing::info!(
+                        chunks = manifest.chunks.len(),
+                        records = manifest.records(),
+                        "Snapshot container written"
+                    );
+                }
             }
-            .change_context(GraphError)
-            .attach_printable("Failed to produce snapshot dump")?;
 
             tracing::info!("Snapshot dumped successfully");
         }
         SnapshotCommand::Restore(args) => {
             pool.settings.validate_links = !args.skip_validation;
 
-            let read =
-                FramedRead::new(io::BufReader::new(io::stdin()), JsonLinesDecoder::default());
+            let read = read_snapshot().await?;
             if let Some(authorization) = authorization {
                 SnapshotStore::new(
                     pool.acquire(authorization, None)
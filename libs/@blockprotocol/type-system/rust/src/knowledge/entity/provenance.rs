@@ -7,7 +7,10 @@ use hash_graph_temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
 #[cfg(feature = "postgres")]
 use postgres_types::{FromSql, IsNull, Json, ToSql, Type};
 
-use crate::provenance::{ActorEntityUuid, ActorType, OriginProvenance, SourceProvenance};
+use crate::{
+    knowledge::entity::EntityId,
+    provenance::{ActorEntityUuid, ActorType, OriginProvenance, SourceProvenance},
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[cfg_attr(target_arch = "wasm32", derive(tsify_next::Tsify))]
@@ -18,6 +21,10 @@ pub struct EntityEditionProvenance {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub archived_by_id: Option<ActorEntityUuid>,
+    /// The entity this entity was merged into, if this edition was created by a merge.
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merged_into: Option<EntityId>,
     #[serde(flatten)]
     pub provided: ProvidedEntityEditionProvenance,
 }
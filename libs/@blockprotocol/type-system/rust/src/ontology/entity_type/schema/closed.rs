@@ -67,6 +67,27 @@ pub struct ClosedEntityType {
     pub inverse: InverseEntityTypeMetadata,
     #[serde(flatten)]
     pub constraints: EntityConstraints,
+    /// The properties whose values must not be shared by two entities within the same web, mapped
+    /// to the entity types which require them to be unique.
+    ///
+    /// Uniqueness is scoped to each of these entity types, including the entity types inheriting
+    /// from them.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub unique_properties: HashMap<BaseUrl, HashSet<VersionedUrl>>,
+}
+
+/// Adds the unique properties of `entity_type_id` to `unique_properties`.
+fn extend_unique_properties<'p>(
+    unique_properties: &mut HashMap<BaseUrl, HashSet<VersionedUrl>>,
+    entity_type_id: &VersionedUrl,
+    properties: impl IntoIterator<Item = &'p BaseUrl>,
+) {
+    for property in properties {
+        unique_properties
+            .entry(property.clone())
+            .or_default()
+            .insert(entity_type_id.clone());
+    }
 }
 
 #[derive(Debug, Error)]
@@ -103,6 +124,12 @@ impl ClosedEntityType {
         mut schema: EntityType,
         resolve_data: &EntityTypeResolveData,
     ) -> Result<Self, Report<ResolveClosedEntityTypeError>> {
+        let mut unique_properties = HashMap::new();
+        extend_unique_properties(
+            &mut unique_properties,
+            &schema.id,
+            &schema.unique_properties,
+        );
         let mut closed_schema = Self {
             id: schema.id.clone(),
             constraints: schema.constraints,
@@ -116,6 +143,7 @@ impl ClosedEntityType {
                 icon: schema.icon,
                 label_property: schema.label_property,
             }],
+            unique_properties,
         };
 
         for (depth, entity_type) in resolve_data.ordered_schemas() {
@@ -142,6 +170,11 @@ impl ClosedEntityType {
                 label_property: entity_type.label_property.clone(),
                 icon: entity_type.icon.clone(),
             });
+            extend_unique_properties(
+                &mut closed_schema.unique_properties,
+                &entity_type.id,
+                &entity_type.unique_properties,
+            );
         }
 
         ensure!(
@@ -177,6 +210,11 @@ pub struct ClosedMultiEntityType {
         tsify(type = "[ClosedEntityTypeMetadata, ...ClosedEntityTypeMetadata[]]")
     )]
     pub all_of: Vec<ClosedEntityTypeMetadata>,
+
+    /// The unique properties of all the types in this type, see
+    /// [`ClosedEntityType::unique_properties`].
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub unique_properties: HashMap<BaseUrl, HashSet<VersionedUrl>>,
 }
 
 impl ClosedMultiEntityType {
@@ -196,6 +234,7 @@ impl ClosedMultiEntityType {
                 all_of: closed_schemas.all_of,
             }],
             constraints: closed_schemas.constraints,
+            unique_properties: closed_schemas.unique_properties,
         }
     }
 
@@ -258,6 +297,12 @@ impl ClosedMultiEntityType {
             .required
             .extend(schema.constraints.required);
         extend_links(&mut self.constraints.links, schema.constraints.links);
+        for (property, entity_type_ids) in schema.unique_properties {
+            self.unique_properties
+                .entry(property)
+                .or_default()
+                .extend(entity_type_ids);
+        }
         self.all_of.push(ClosedEntityTypeMetadata {
             id: schema.id,
             title: schema.title,
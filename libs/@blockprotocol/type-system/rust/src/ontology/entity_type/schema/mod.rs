@@ -89,6 +89,8 @@ pub struct EntityTypeDisplayMetadata {
 /// - Links to other entity types, defining the entity graph structure
 /// - Inheritance from other entity types via `all_of` references
 /// - Optional display metadata like `label_property` and `icon`
+/// - Optional `unique_properties` whose values must not be shared by two entities of this type
+///   within the same web
 ///
 /// [`PropertyType`]: crate::ontology::property_type::PropertyType
 ///
@@ -221,6 +223,8 @@ pub struct EntityType {
     pub label_property: Option<BaseUrl>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub unique_properties: HashSet<BaseUrl>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -239,6 +243,8 @@ pub struct PartialEntityType {
     pub label_property: Option<BaseUrl>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub icon: Option<String>,
+    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
+    pub unique_properties: HashSet<BaseUrl>,
 }
 
 impl From<EntityType> for PartialEntityType {
@@ -251,6 +257,7 @@ impl From<EntityType> for PartialEntityType {
             inverse: value.inverse,
             label_property: value.label_property,
             icon: value.icon,
+            unique_properties: value.unique_properties,
         }
     }
 }
@@ -24,6 +24,8 @@ pub enum EntityTypeValidationError {
          less than or equal to the maximum amount of items ({max})"
     )]
     IncompatibleLinkNumberConstraints { min: usize, max: usize },
+    #[display("The unique property {property} is not a property of the entity type")]
+    UnknownUniqueProperty { property: BaseUrl },
 }
 
 #[derive(Debug)]
@@ -95,6 +97,14 @@ impl Validator<ClosedEntityType> for EntityTypeValidator {
             }
         }
 
+        for property in value.unique_properties.keys() {
+            if !value.constraints.properties.contains_key(property) {
+                return Err(EntityTypeValidationError::UnknownUniqueProperty {
+                    property: property.clone(),
+                });
+            }
+        }
+
         Ok(Valid::new_ref_unchecked(value))
     }
 }
@@ -0,0 +1,598 @@
+// This file has been generated by `harpc-system`, do not edit it manually.
+
+export interface RpcSubsystemDescriptor {
+  readonly id: number;
+  readonly version: { readonly major: number; readonly minor: number };
+}
+
+export interface RpcTransport {
+  /**
+   * Sends a request to a procedure and resolves with the decoded response.
+   */
+  call(
+    subsystem: RpcSubsystemDescriptor,
+    procedure: number,
+    request: unknown,
+  ): Promise<unknown>;
+
+  /**
+   * Sends a request to a procedure and yields every decoded item of the response as it arrives.
+   */
+  stream(
+    subsystem: RpcSubsystemDescriptor,
+    procedure: number,
+    request: unknown,
+  ): AsyncIterable<unknown>;
+
+  /**
+   * Sends every item of the request to a procedure as it becomes available and resolves with the
+   * decoded response.
+   */
+  send(
+    subsystem: RpcSubsystemDescriptor,
+    procedure: number,
+    request: AsyncIterable<unknown>,
+  ): Promise<unknown>;
+
+  /**
+   * Sends every item of the request to a procedure as it becomes available and yields every
+   * decoded item of the response as it arrives.
+   */
+  exchange(
+    subsystem: RpcSubsystemDescriptor,
+    procedure: number,
+    request: AsyncIterable<unknown>,
+  ): AsyncIterable<unknown>;
+}
+
+export type RpcResult<T, E> = { Ok: T } | { Err: E };
+
+export type AccountGroupPermission = "add_member" | "remove_member";
+
+export type ActorEntityUuid = EntityUuid;
+
+export type ActorGroupId = string;
+
+export type ActorType = "user" | "machine" | "ai";
+
+export type ArchiveDataTypeParams = { dataTypeId: VersionedUrl; };
+
+export type ArchiveEntityTypeParams = { entityTypeId: VersionedUrl; };
+
+export type ArchivePropertyTypeParams = { propertyTypeId: VersionedUrl; };
+
+export type ArrayItemNumberMismatch = ({ data: { actual: number; min: number; }; type: "tooFew"; }) | ({ data: { actual: number; max: number; }; type: "tooMany"; });
+
+export type ArrayMetadata = { confidence?: (Confidence); provenance?: PropertyProvenance; };
+
+export type ArrayValidationReport = { items?: Record<string, PropertyValidationReport>; numItems?: (ArrayItemNumberMismatch); };
+
+export type BaseUrl = string;
+
+export type Change = ({ entityEditionId: EntityEditionId; entityId: EntityId; kind: "entityEditionCreated"; }) | ({ entityEditionId: EntityEditionId; entityId: EntityId; kind: "entityArchived"; }) | ({ kind: "ontologyTypeCreated"; ontologyId: OntologyTypeUuid; ontologyType: OntologyTypeKind; ontologyTypeId: VersionedUrl; }) | ({ kind: "ontologyTypeUnarchived"; ontologyId: OntologyTypeUuid; ontologyType: OntologyTypeKind; ontologyTypeId: VersionedUrl; }) | ({ kind: "ontologyTypeArchived"; ontologyId: OntologyTypeUuid; ontologyType: OntologyTypeKind; ontologyTypeId: VersionedUrl; });
+
+export type ChangeFeedCursor = { id: string; sequence: number; transactionTime: Timestamp; };
+
+export type ChangeFeedRecord = { change: Change; cursor: ChangeFeedCursor; };
+
+export type ClosedDataType = unknown;
+
+export type ClosedDataTypeDefinition = { parents: Array<VersionedUrl>; schema: ClosedDataType; };
+
+export type ClosedEntityType = unknown;
+
+export type ClosedMultiEntityType = unknown;
+
+export type ClosedMultiEntityTypeMap = { inner?: Record<string, ClosedMultiEntityTypeMap>; schema: ClosedMultiEntityType; };
+
+export type ClosedTemporalBound = ({ kind: "inclusive"; limit: Timestamp; });
+
+export type Confidence = number;
+
+export type ConversionDefinition = { expression: ConversionExpression; };
+
+export type ConversionExpression = Array<(Operator) | (ConversionValue)>;
+
+export type ConversionValue = (Variable) | ({ const: Real; type: "number"; }) | (ConversionExpression);
+
+export type Conversions = { from: ConversionDefinition; to: ConversionDefinition; };
+
+export type CreateDataTypeRequest = { conversions: Record<string, Conversions>; ownedById: OwnedById; provenance: ProvidedOntologyEditionProvenance; relationships: Array<DataTypeRelationAndSubject>; schema: (DataType) | (Array<DataType>); };
+
+export type CreateEntityRequest = { confidence?: (Confidence); decisionTime?: (Timestamp); draft: boolean; entityTypeIds: Array<VersionedUrl>; entityUuid?: (EntityUuid); linkData?: (LinkData); ownedById: OwnedById; properties: PropertyObjectWithMetadata; provenance: ProvidedEntityEditionProvenance; relationships: Array<EntityRelationAndSubject>; };
+
+export type CreateEntityTypeRequest = { ownedById: OwnedById; provenance: ProvidedOntologyEditionProvenance; relationships: Array<EntityTypeRelationAndSubject>; schema: (EntityType) | (Array<EntityType>); };
+
+export type CreatePropertyTypeRequest = { ownedById: OwnedById; provenance: ProvidedOntologyEditionProvenance; relationships: Array<PropertyTypeRelationAndSubject>; schema: (PropertyType) | (Array<PropertyType>); };
+
+export type DataType = unknown;
+
+export type DataTypeCanonicalCalculation = ({ error: Report; type: "retrieval"; }) | ({ data: JsonSchemaValueTypeMismatch; type: "wrongType"; });
+
+export type DataTypeConversionError = ({ error: Report; type: "retrieval"; }) | ({ data: JsonSchemaValueTypeMismatch; type: "wrongType"; });
+
+export type DataTypeEditorSubject = ({ kind: "account"; subjectId: ActorEntityUuid; }) | ({ kind: "accountGroup"; subjectId: ActorGroupId; });
+
+export type DataTypeInferenceError = ({ error: Report; type: "retrieval"; }) | ({ data: VersionedUrl; type: "abstract"; }) | ({ data: Array<VersionedUrl>; type: "ambiguous"; });
+
+export type DataTypeMetadata = ({ conversions?: Record<string, Conversions>; ownedById: OwnedById; provenance: OntologyProvenance; recordId: OntologyTypeRecordId; temporalVersioning: OntologyTemporalMetadata; }) | ({ conversions?: Record<string, Conversions>; fetchedAt: Timestamp; provenance: OntologyProvenance; recordId: OntologyTypeRecordId; temporalVersioning: OntologyTemporalMetadata; });
+
+export type DataTypeOwnerSubject = ({ kind: "web"; subjectId: OwnedById; });
+
+export type DataTypeQueryToken = "baseUrl" | "version" | "versionedUrl" | "ownedById" | "title" | "description" | "type" | "inheritsFrom" | "children" | "editionProvenance" | "embedding";
+
+export type DataTypeRelationAndSubject = ({ relation: "owner"; subject: DataTypeOwnerSubject; }) | ({ relation: "setting"; subject: DataTypeSettingSubject; }) | ({ relation: "editor"; subject: DataTypeEditorSubject; }) | ({ relation: "viewer"; subject: DataTypeViewerSubject; });
+
+export type DataTypeSetting = "updateFromWeb";
+
+export type DataTypeSettingSubject = ({ kind: "setting"; subjectId: DataTypeSetting; });
+
+export type DataTypeViewerSubject = ({ kind: "public"; });
+
+export type DataTypeWithMetadata = { metadata: DataTypeMetadata; schema: DataType; };
+
+export type DecisionTime = "decisionTime";
+
+export type DuplicatePropertyValue = { conflictingEntities: Array<EntityId>; entityTypeId: (VersionedUrl); hiddenConflictingEntities: number; };
+
+export type Entity = { linkData?: (LinkData); metadata: EntityMetadata; properties: PropertyObject; };
+
+export type EntityAdministratorSubject = ({ kind: "account"; subjectId: ActorEntityUuid; }) | ({ kind: "accountGroup"; subjectId: ActorGroupId; subjectSet: EntitySubjectSet; });
+
+export type EntityEditionId = string;
+
+export type EntityEditionProvenance = (ProvidedEntityEditionProvenance) & ({ archivedById?: (ActorEntityUuid); createdById: ActorEntityUuid; });
+
+export type EntityEditorSubject = ({ kind: "account"; subjectId: ActorEntityUuid; }) | ({ kind: "accountGroup"; subjectId: ActorGroupId; subjectSet: EntitySubjectSet; });
+
+export type EntityId = string;
+
+export type EntityMetadata = { archived: boolean; confidence?: (Confidence); entityTypeIds: Array<VersionedUrl>; properties?: PropertyObjectMetadata; provenance: EntityProvenance; recordId: EntityRecordId; temporalVersioning: EntityTemporalMetadata; };
+
+export type EntityOwnerSubject = ({ kind: "web"; subjectId: OwnedById; });
+
+export type EntityProvenance = (InferredEntityProvenance) & ({ edition: EntityEditionProvenance; });
+
+export type EntityQueryCursor = Array<Record<string, unknown>>;
+
+export type EntityQuerySortingPath = Array<(EntityQuerySortingToken) | (string) | (number)>;
+
+export type EntityQuerySortingRecord = { nulls: NullOrdering; ordering: Ordering; path: EntityQuerySortingPath; };
+
+export type EntityQuerySortingToken = "uuid" | "archived" | "properties" | "label" | "recordCreatedAtTransactionTime" | "recordCreatedAtDecisionTime" | "createdAtTransactionTime" | "createdAtDecisionTime" | "typeTitle" | "searchRank";
+
+export type EntityQueryToken = "uuid" | "editionId" | "draftId" | "archived" | "ownedById" | "type" | "properties" | "label" | "provenance" | "editionProvenance" | "embedding" | "searchVector" | "incomingLinks" | "outgoingLinks" | "leftEntity" | "rightEntity";
+
+export type EntityRecordId = { editionId: EntityEditionId; entityId: EntityId; };
+
+export type EntityRelationAndSubject = ({ relation: "setting"; subject: EntitySettingSubject; }) | ({ relation: "owner"; subject: EntityOwnerSubject; }) | ({ relation: "administrator"; subject: EntityAdministratorSubject; }) | ({ relation: "editor"; subject: EntityEditorSubject; }) | ({ relation: "viewer"; subject: EntityViewerSubject; });
+
+export type EntitySetting = "administratorFromWeb" | "updateFromWeb" | "viewFromWeb";
+
+export type EntitySettingSubject = ({ kind: "setting"; subjectId: EntitySetting; });
+
+export type EntitySubjectSet = "administrator" | "member";
+
+export type EntityTemporalMetadata = { decisionTime: LeftClosedTemporalInterval; transactionTime: LeftClosedTemporalInterval; };
+
+export type EntityType = unknown;
+
+export type EntityTypeEditorSubject = ({ kind: "account"; subjectId: ActorEntityUuid; }) | ({ kind: "accountGroup"; subjectId: ActorGroupId; });
+
+export type EntityTypeInstantiatorSubject = ({ kind: "public"; }) | ({ kind: "account"; subjectId: ActorEntityUuid; }) | ({ kind: "accountGroup"; subjectId: ActorGroupId; });
+
+export type EntityTypeMetadata = ({ ownedById: OwnedById; provenance: OntologyProvenance; recordId: OntologyTypeRecordId; temporalVersioning: OntologyTemporalMetadata; }) | ({ fetchedAt: Timestamp; provenance: OntologyProvenance; recordId: OntologyTypeRecordId; temporalVersioning: OntologyTemporalMetadata; });
+
+export type EntityTypeOwnerSubject = ({ kind: "web"; subjectId: OwnedById; });
+
+export type EntityTypeQueryToken = "baseUrl" | "version" | "versionedUrl" | "ownedById" | "title" | "description" | "properties" | "required" | "labelProperty" | "icon" | "editionProvenance" | "links" | "linkDestinations" | "inheritsFrom" | "children" | "embedding";
+
+export type EntityTypeRelationAndSubject = ({ relation: "owner"; subject: EntityTypeOwnerSubject; }) | ({ relation: "setting"; subject: EntityTypeSettingSubject; }) | ({ relation: "editor"; subject: EntityTypeEditorSubject; }) | ({ relation: "viewer"; subject: EntityTypeViewerSubject; }) | ({ relation: "instantiator"; subject: EntityTypeInstantiatorSubject; });
+
+export type EntityTypeResolveDefinitions = { dataTypes: Record<string, ClosedDataTypeDefinition>; entityTypes: Record<string, PartialEntityType>; propertyTypes: Record<string, PropertyType>; };
+
+export type EntityTypeSetting = "updateFromWeb";
+
+export type EntityTypeSettingSubject = ({ kind: "setting"; subjectId: EntityTypeSetting; });
+
+export type EntityTypeViewerSubject = ({ kind: "public"; });
+
+export type EntityTypeWithMetadata = { metadata: EntityTypeMetadata; schema: EntityType; };
+
+export type EntityTypesError = ({ error: Report; type: "empty"; }) | ({ error: Report; type: "entityTypeRetrieval"; }) | ({ error: Report; type: "resolveClosedEntityType"; });
+
+export type EntityUuid = string;
+
+export type EntityValidationReport = { link?: LinkValidationReport; metadata?: MetadataValidationReport; properties?: Record<string, ObjectPropertyValidationReport>; uniqueProperties?: Record<string, UniquePropertyError>; };
+
+export type EntityValidationType = Array<VersionedUrl>;
+
+export type EntityViewerSubject = ({ kind: "public"; }) | ({ kind: "account"; subjectId: ActorEntityUuid; }) | ({ kind: "accountGroup"; subjectId: ActorGroupId; subjectSet: EntitySubjectSet; });
+
+export type Filter = ({ all: Array<Filter>; }) | ({ any: Array<Filter>; }) | ({ not: Filter; }) | ({ equal: Array<FilterExpression>; }) | ({ notEqual: Array<FilterExpression>; }) | ({ greater?: Array<FilterExpression>; }) | ({ greaterOrEqual?: Array<FilterExpression>; }) | ({ less?: Array<FilterExpression>; }) | ({ lessOrEqual?: Array<FilterExpression>; }) | ({ cosineDistance: Array<FilterExpression>; }) | ({ startsWith: Array<FilterExpression>; }) | ({ endsWith: Array<FilterExpression>; }) | ({ containsSegment: Array<FilterExpression>; }) | ({ fullTextMatch: Array<FilterExpression>; }) | ({ in: Array<(FilterExpression) | (Array<unknown>)>; }) | ({ iLike: Array<FilterExpression>; }) | ({ regex: Array<FilterExpression>; }) | ({ contains: Array<FilterExpression>; }) | ({ exists: FilterExpression; });
+
+export type FilterExpression = ({ path: Array<(DataTypeQueryToken) | (PropertyTypeQueryToken) | (EntityTypeQueryToken) | (EntityQueryToken) | (Selector) | ("convert") | (string) | (number)>; }) | ({ convert?: { from: VersionedUrl; to: VersionedUrl; }; parameter: unknown; });
+
+export type GetDataTypesParams = { after?: (VersionedUrl); filter: Filter; includeCount?: boolean; includeDrafts: boolean; limit?: number | null; temporalAxes: QueryTemporalAxesUnresolved; };
+
+export type GetDataTypesResponse = { count?: number | null; cursor?: (VersionedUrl); dataTypes: Array<DataTypeWithMetadata>; };
+
+export type GetEntitiesRequest = { conversions?: Array<QueryConversion>; cursor?: (EntityQueryCursor); filter: Filter; includeCount?: boolean; includeCreatedByIds?: boolean; includeDrafts: boolean; includeEditionCreatedByIds?: boolean; includeEntityTypes?: (IncludeEntityTypeOption); includeTypeIds?: boolean; includeTypeTitles?: boolean; includeWebIds?: boolean; limit?: number | null; sortingPaths?: Array<EntityQuerySortingRecord>; temporalAxes: QueryTemporalAxesUnresolved; };
+
+export type GetEntitiesResponse = { closedMultiEntityTypes?: Record<string, ClosedMultiEntityTypeMap>; count?: number | null; createdByIds?: Record<string, number>; cursor?: (EntityQueryCursor); definitions?: (EntityTypeResolveDefinitions); editionCreatedByIds?: Record<string, number>; entities: Array<Entity>; typeIds?: Record<string, number>; typeTitles?: Record<string, string>; webIds?: Record<string, number>; };
+
+export type GetEntityTypesParams = { after?: (VersionedUrl); filter: Filter; includeCount?: boolean; includeDrafts: boolean; includeEditionCreatedByIds?: boolean; includeEntityTypes?: (IncludeEntityTypeOption); includeWebIds?: boolean; limit?: number | null; temporalAxes: QueryTemporalAxesUnresolved; };
+
+export type GetEntityTypesResponse = { closedEntityTypes?: Array<ClosedEntityType>; count?: number | null; cursor?: (VersionedUrl); definitions?: (EntityTypeResolveDefinitions); editionCreatedByIds?: Record<string, number>; entityTypes: Array<EntityTypeWithMetadata>; webIds?: Record<string, number>; };
+
+export type GetPropertyTypesParams = { after?: (VersionedUrl); filter: Filter; includeCount?: boolean; includeDrafts: boolean; limit?: number | null; temporalAxes: QueryTemporalAxesUnresolved; };
+
+export type GetPropertyTypesResponse = { count?: number | null; cursor?: (VersionedUrl); propertyTypes: Array<PropertyTypeWithMetadata>; };
+
+export type IncludeEntityTypeOption = "closed" | "resolved" | "resolvedWithDataTypeChildren";
+
+export type InferredEntityProvenance = { createdAtDecisionTime: Timestamp; createdAtTransactionTime: Timestamp; createdById: ActorEntityUuid; firstNonDraftCreatedAtDecisionTime?: (Timestamp); firstNonDraftCreatedAtTransactionTime?: (Timestamp); };
+
+export type InsertAccountGroupIdParams = { accountGroupId?: ActorGroupId; };
+
+export type InsertAccountIdParams = { accountId?: ActorEntityUuid; accountType: ActorType; };
+
+export type JsonSchemaValueType = "null" | "boolean" | "number" | "string" | "array" | "object";
+
+export type JsonSchemaValueTypeMismatch = { actual: JsonSchemaValueType; expected: JsonSchemaValueType; };
+
+export type LeftClosedTemporalInterval = { end: OpenTemporalBound; start: ClosedTemporalBound; };
+
+export type LimitedTemporalBound = ({ kind: "inclusive"; limit: Timestamp; }) | ({ kind: "exclusive"; limit: Timestamp; });
+
+export type LinkData = { leftEntityConfidence?: (Confidence); leftEntityId: EntityId; leftEntityProvenance?: PropertyProvenance; rightEntityConfidence?: (Confidence); rightEntityId: EntityId; rightEntityProvenance?: PropertyProvenance; };
+
+export type LinkDataStateError = ({ error: Report; type: "missing"; }) | ({ error: Report; type: "unexpected"; });
+
+export type LinkDataValidationReport = { leftEntity?: (LinkedEntityError); linkType?: (LinkError); rightEntity?: (LinkedEntityError); targetType?: (LinkTargetError); };
+
+export type LinkError = ({ data: UnexpectedEntityType; type: "unexpectedEntityType"; });
+
+export type LinkTargetError = ({ data: UnexpectedEntityType; type: "unexpectedEntityType"; });
+
+export type LinkValidationReport = (LinkDataValidationReport) & ({ linkData?: (LinkDataStateError); });
+
+export type LinkedEntityError = ({ error: Report; type: "entityRetrieval"; }) | ({ error: Report; type: "entityTypeRetrieval"; }) | ({ error: Report; type: "resolveClosedEntityType"; });
+
+export type Location = { description?: string; name?: string; uri?: string; };
+
+export type MetadataValidationReport = { entityTypes?: (EntityTypesError); properties?: PropertyMetadataValidationReport; };
+
+export type NullOrdering = "first" | "last";
+
+export type NullableTimestamp = string | null;
+
+export type ObjectMetadata = { confidence?: (Confidence); provenance?: PropertyProvenance; };
+
+export type ObjectPropertyValidationReport = ({ type: "unexpected"; }) | ({ error: Report; type: "retrieval"; }) | ({ data: PropertyValueTypeMismatch; type: "wrongType"; }) | ((OneOfPropertyValidationReports) & ({ type: "value"; })) | ((OneOfArrayValidationReports) & ({ type: "array"; })) | ((OneOfObjectValidationReports) & ({ type: "object"; })) | ((ArrayValidationReport) & ({ type: "propertyArray"; })) | ({ type: "missing"; });
+
+export type ObjectValidationReport = { properties?: Record<string, ObjectPropertyValidationReport>; };
+
+export type OneOfArrayValidationReports = { validations?: Array<PropertyArrayValidationReport>; };
+
+export type OneOfObjectValidationReports = { validations?: Array<PropertyObjectValidationReport>; };
+
+export type OneOfPropertyValidationReports = { canonicalValue?: Array<DataTypeCanonicalCalculation>; dataTypeInference?: Array<DataTypeInferenceError>; validations?: Array<PropertyValueValidationReport>; valueConversion?: (DataTypeConversionError); };
+
+export type OntologyEditionProvenance = (ProvidedOntologyEditionProvenance) & ({ archivedById?: (ActorEntityUuid); createdById: ActorEntityUuid; });
+
+export type OntologyProvenance = { edition: OntologyEditionProvenance; };
+
+export type OntologyTemporalMetadata = { transactionTime: LeftClosedTemporalInterval; };
+
+export type OntologyTypeKind = "dataType" | "propertyType" | "entityType";
+
+export type OntologyTypeRecordId = { baseUrl: BaseUrl; version: number; };
+
+export type OntologyTypeUuid = string;
+
+export type OpenTemporalBound = ({ kind: "exclusive"; limit: Timestamp; }) | ({ kind: "unbounded"; });
+
+export type Operator = "+" | "-" | "*" | "/";
+
+export type Ordering = "ascending" | "descending";
+
+export type OriginProvenance = ({ apiKeyPublicId?: string; deviceId?: string; environment?: string; id?: string; semanticVersion?: string; sessionId?: string; type: "web-app"; userAgent?: string; version?: string; }) | ({ apiKeyPublicId?: string; deviceId?: string; environment?: string; id?: string; semanticVersion?: string; sessionId?: string; type: "mobile-app"; userAgent?: string; version?: string; }) | ({ apiKeyPublicId?: string; deviceId?: string; environment?: string; id?: string; semanticVersion?: string; sessionId?: string; type: "browser-extension"; userAgent?: string; version?: string; }) | ({ apiKeyPublicId?: string; deviceId?: string; environment?: string; id?: string; semanticVersion?: string; sessionId?: string; type: "api"; userAgent?: string; version?: string; }) | ({ apiKeyPublicId?: string; deviceId?: string; environment?: string; id?: string; semanticVersion?: string; sessionId?: string; stepIds?: Array<string>; type: "flow"; userAgent?: string; version?: string; }) | ({ apiKeyPublicId?: string; deviceId?: string; environment?: string; id?: string; semanticVersion?: string; sessionId?: string; type: "migration"; userAgent?: string; version?: string; });
+
+export type OwnedById = string;
+
+export type PartialEntityType = unknown;
+
+export type PatchEntityParams = { archived?: boolean; confidence?: (Confidence); decisionTime?: (Timestamp); draft?: boolean; entityId: EntityId; entityTypeIds?: Array<VersionedUrl>; expectedEditionId?: (EntityEditionId); properties?: Array<PropertyPatchOperation>; provenance: ProvidedEntityEditionProvenance; };
+
+export type PermissionResponse = { has_permission: boolean; };
+
+export type Property = (Array<Property>) | (PropertyObject) | (PropertyValue);
+
+export type PropertyArrayMetadata = { metadata?: ArrayMetadata; value: Array<PropertyMetadata>; };
+
+export type PropertyArrayValidationReport = ({ data: PropertyValueTypeMismatch; type: "wrongType"; }) | ({ data: ArrayValidationReport; type: "arrayValidation"; });
+
+export type PropertyArrayWithMetadata = { metadata?: ArrayMetadata; value: Array<PropertyWithMetadata>; };
+
+export type PropertyMetadata = (PropertyArrayMetadata) | (PropertyObjectMetadata) | (PropertyValueMetadata);
+
+export type PropertyMetadataValidationReport = unknown | null;
+
+export type PropertyObject = Record<string, Property>;
+
+export type PropertyObjectMetadata = { metadata?: ObjectMetadata; value: Record<string, PropertyMetadata>; };
+
+export type PropertyObjectValidationReport = ({ data: PropertyValueTypeMismatch; type: "wrongType"; }) | ((ObjectValidationReport) & ({ type: "objectValidation"; }));
+
+export type PropertyObjectWithMetadata = { metadata?: ObjectMetadata; value: Record<string, PropertyWithMetadata>; };
+
+export type PropertyPatchOperation = ({ op: "add"; path: PropertyPath; property: PropertyWithMetadata; }) | ({ op: "remove"; path: PropertyPath; }) | ({ op: "replace"; path: PropertyPath; property: PropertyWithMetadata; });
+
+export type PropertyPath = Array<PropertyPathElement>;
+
+export type PropertyPathElement = (BaseUrl) | (number);
+
+export type PropertyProvenance = { sources?: Array<SourceProvenance>; };
+
+export type PropertyType = unknown;
+
+export type PropertyTypeEditorSubject = ({ kind: "account"; subjectId: ActorEntityUuid; }) | ({ kind: "accountGroup"; subjectId: ActorGroupId; });
+
+export type PropertyTypeMetadata = ({ ownedById: OwnedById; provenance: OntologyProvenance; recordId: OntologyTypeRecordId; temporalVersioning: OntologyTemporalMetadata; }) | ({ fetchedAt: Timestamp; provenance: OntologyProvenance; recordId: OntologyTypeRecordId; temporalVersioning: OntologyTemporalMetadata; });
+
+export type PropertyTypeOwnerSubject = ({ kind: "web"; subjectId: OwnedById; });
+
+export type PropertyTypeQueryToken = "baseUrl" | "version" | "versionedUrl" | "ownedById" | "title" | "description" | "editionProvenance" | "dataTypes" | "propertyTypes" | "embedding";
+
+export type PropertyTypeRelationAndSubject = ({ relation: "owner"; subject: PropertyTypeOwnerSubject; }) | ({ relation: "setting"; subject: PropertyTypeSettingSubject; }) | ({ relation: "editor"; subject: PropertyTypeEditorSubject; }) | ({ relation: "viewer"; subject: PropertyTypeViewerSubject; });
+
+export type PropertyTypeSetting = "updateFromWeb";
+
+export type PropertyTypeSettingSubject = ({ kind: "setting"; subjectId: PropertyTypeSetting; });
+
+export type PropertyTypeViewerSubject = ({ kind: "public"; });
+
+export type PropertyTypeWithMetadata = { metadata: PropertyTypeMetadata; schema: PropertyType; };
+
+export type PropertyValidationReport = ((OneOfPropertyValidationReports) & ({ type: "value"; })) | ((OneOfArrayValidationReports) & ({ type: "array"; })) | ((OneOfObjectValidationReports) & ({ type: "object"; }));
+
+export type PropertyValue = unknown;
+
+export type PropertyValueMetadata = { metadata: ValueMetadata; };
+
+export type PropertyValueType = "value" | "array" | "object";
+
+export type PropertyValueTypeMismatch = { actual: PropertyValueType; expected: PropertyValueType; };
+
+export type PropertyValueValidationReport = ({ data: PropertyValueTypeMismatch; type: "wrongType"; }) | ({ data: ValueValidationReport; type: "valueValidation"; });
+
+export type PropertyValueWithMetadata = { metadata: ValueMetadata; value: PropertyValue; };
+
+export type PropertyWithMetadata = (PropertyArrayWithMetadata) | (PropertyObjectWithMetadata) | (PropertyValueWithMetadata);
+
+export type ProvidedEntityEditionProvenance = { actorType: ActorType; origin: OriginProvenance; sources?: Array<SourceProvenance>; };
+
+export type ProvidedOntologyEditionProvenance = { actorType: ActorType; origin: OriginProvenance; sources?: Array<SourceProvenance>; };
+
+export type QueryConversion = { dataTypeId: VersionedUrl; path: PropertyPath; };
+
+export type QueryTemporalAxesUnresolved = ({ pinned: { axis: TransactionTime; timestamp: NullableTimestamp; }; variable: { axis: DecisionTime; interval: UnresolvedRightBoundedTemporalInterval; }; }) | ({ pinned: { axis: DecisionTime; timestamp: NullableTimestamp; }; variable: { axis: TransactionTime; interval: UnresolvedRightBoundedTemporalInterval; }; });
+
+export type Real = number;
+
+export type Report = unknown;
+
+export type Selector = "*";
+
+export type SourceProvenance = { authors?: Array<string>; entityId?: (EntityId); firstPublished?: (Timestamp); lastUpdated?: (Timestamp); loadedAt?: (Timestamp); location?: (Location); type: SourceType; };
+
+export type SourceType = "webpage" | "document";
+
+export type TemporalBound = ({ kind: "unbounded"; }) | ({ kind: "inclusive"; limit: Timestamp; }) | ({ kind: "exclusive"; limit: Timestamp; });
+
+export type Timestamp = string;
+
+export type TransactionTime = "transactionTime";
+
+export type UnexpectedEntityType = { actual: Array<VersionedUrl>; expected: Array<VersionedUrl>; };
+
+export type UniquePropertyError = ({ data: DuplicatePropertyValue; type: "duplicate"; }) | ({ error: Report; type: "retrieval"; });
+
+export type UnresolvedRightBoundedTemporalInterval = { end: (LimitedTemporalBound); start: (TemporalBound); };
+
+export type UpdateDataType = unknown;
+
+export type UpdateDataTypeRequest = { conversions: Record<string, Conversions>; provenance: ProvidedOntologyEditionProvenance; relationships: Array<DataTypeRelationAndSubject>; schema: UpdateDataType; typeToUpdate: VersionedUrl; };
+
+export type UpdateEntityType = unknown;
+
+export type UpdateEntityTypeRequest = { provenance: ProvidedOntologyEditionProvenance; relationships: Array<EntityTypeRelationAndSubject>; schema: UpdateEntityType; typeToUpdate: VersionedUrl; };
+
+export type UpdatePropertyType = unknown;
+
+export type UpdatePropertyTypeRequest = { provenance: ProvidedOntologyEditionProvenance; relationships: Array<PropertyTypeRelationAndSubject>; schema: UpdatePropertyType; typeToUpdate: VersionedUrl; };
+
+export type ValidateEntityComponents = { linkData?: boolean; linkValidation?: boolean; numItems?: boolean; requiredProperties?: boolean; };
+
+export type ValidateEntityParams = { components?: (ValidateEntityComponents); entityTypes: EntityValidationType; linkData?: (LinkData); properties: PropertyObjectWithMetadata; };
+
+export type ValueMetadata = { canonical?: Record<string, PropertyValue>; confidence?: (Confidence); dataTypeId: (VersionedUrl); originalDataTypeId?: (VersionedUrl); provenance?: ValueProvenance; };
+
+export type ValueProvenance = { sources?: Array<SourceProvenance>; };
+
+export type ValueValidationError = ({ error: Report; type: "retrieval"; }) | ({ error: Report; type: "constraints"; });
+
+export type ValueValidationReport = { abstract?: (VersionedUrl); incompatible?: (VersionedUrl); provided?: (ValueValidationError); target?: (ValueValidationError); };
+
+export type Variable = "self";
+
+export type VersionedUrl = string;
+
+export const EchoSubsystem: RpcSubsystemDescriptor = {
+  id: 0,
+  version: { major: 0, minor: 0 },
+};
+
+export class EchoClient {
+  constructor(private readonly transport: RpcTransport) {}
+
+  echo(request: string): Promise<RpcResult<string, unknown>> {
+    return this.transport.call(EchoSubsystem, 0, request) as Promise<RpcResult<string, unknown>>;
+  }
+}
+
+export const AuthenticationSubsystem: RpcSubsystemDescriptor = {
+  id: 1,
+  version: { major: 0, minor: 0 },
+};
+
+export class AuthenticationClient {
+  constructor(private readonly transport: RpcTransport) {}
+
+  authenticate(request: ActorEntityUuid): Promise<RpcResult<null, unknown>> {
+    return this.transport.call(AuthenticationSubsystem, 0, request) as Promise<RpcResult<null, unknown>>;
+  }
+}
+
+export const AccountSubsystem: RpcSubsystemDescriptor = {
+  id: 2,
+  version: { major: 0, minor: 0 },
+};
+
+export class AccountClient {
+  constructor(private readonly transport: RpcTransport) {}
+
+  createAccount(request: InsertAccountIdParams): Promise<RpcResult<ActorEntityUuid, unknown>> {
+    return this.transport.call(AccountSubsystem, 0, request) as Promise<RpcResult<ActorEntityUuid, unknown>>;
+  }
+
+  createAccountGroup(request: InsertAccountGroupIdParams): Promise<RpcResult<ActorGroupId, unknown>> {
+    return this.transport.call(AccountSubsystem, 1, request) as Promise<RpcResult<ActorGroupId, unknown>>;
+  }
+
+  checkAccountGroupPermission(request: [ActorGroupId, AccountGroupPermission]): Promise<RpcResult<PermissionResponse, unknown>> {
+    return this.transport.call(AccountSubsystem, 2, request) as Promise<RpcResult<PermissionResponse, unknown>>;
+  }
+
+  addAccountGroupMember(request: [ActorGroupId, ActorEntityUuid]): Promise<RpcResult<null, unknown>> {
+    return this.transport.call(AccountSubsystem, 3, request) as Promise<RpcResult<null, unknown>>;
+  }
+
+  removeAccountGroupMember(request: [ActorGroupId, ActorEntityUuid]): Promise<RpcResult<null, unknown>> {
+    return this.transport.call(AccountSubsystem, 4, request) as Promise<RpcResult<null, unknown>>;
+  }
+}
+
+export const EntitySubsystem: RpcSubsystemDescriptor = {
+  id: 3,
+  version: { major: 0, minor: 0 },
+};
+
+export class EntityClient {
+  constructor(private readonly transport: RpcTransport) {}
+
+  createEntity(request: CreateEntityRequest): Promise<RpcResult<Entity, unknown>> {
+    return this.transport.call(EntitySubsystem, 0, request) as Promise<RpcResult<Entity, unknown>>;
+  }
+
+  getEntities(request: GetEntitiesRequest): Promise<RpcResult<GetEntitiesResponse, unknown>> {
+    return this.transport.call(EntitySubsystem, 1, request) as Promise<RpcResult<GetEntitiesResponse, unknown>>;
+  }
+
+  patchEntity(request: PatchEntityParams): Promise<RpcResult<Entity, unknown>> {
+    return this.transport.call(EntitySubsystem, 2, request) as Promise<RpcResult<Entity, unknown>>;
+  }
+
+  validateEntity(request: ValidateEntityParams): Promise<RpcResult<Record<string, EntityValidationReport>, unknown>> {
+    return this.transport.call(EntitySubsystem, 3, request) as Promise<RpcResult<Record<string, EntityValidationReport>, unknown>>;
+  }
+}
+
+export const DataTypeSubsystem: RpcSubsystemDescriptor = {
+  id: 4,
+  version: { major: 0, minor: 0 },
+};
+
+export class DataTypeClient {
+  constructor(private readonly transport: RpcTransport) {}
+
+  getDataTypes(request: GetDataTypesParams): Promise<RpcResult<GetDataTypesResponse, unknown>> {
+    return this.transport.call(DataTypeSubsystem, 0, request) as Promise<RpcResult<GetDataTypesResponse, unknown>>;
+  }
+
+  createDataTypes(request: CreateDataTypeRequest): Promise<RpcResult<Array<DataTypeMetadata>, unknown>> {
+    return this.transport.call(DataTypeSubsystem, 1, request) as Promise<RpcResult<Array<DataTypeMetadata>, unknown>>;
+  }
+
+  updateDataType(request: UpdateDataTypeRequest): Promise<RpcResult<DataTypeMetadata, unknown>> {
+    return this.transport.call(DataTypeSubsystem, 2, request) as Promise<RpcResult<DataTypeMetadata, unknown>>;
+  }
+
+  archiveDataType(request: ArchiveDataTypeParams): Promise<RpcResult<OntologyTemporalMetadata, unknown>> {
+    return this.transport.call(DataTypeSubsystem, 3, request) as Promise<RpcResult<OntologyTemporalMetadata, unknown>>;
+  }
+}
+
+export const PropertyTypeSubsystem: RpcSubsystemDescriptor = {
+  id: 5,
+  version: { major: 0, minor: 0 },
+};
+
+export class PropertyTypeClient {
+  constructor(private readonly transport: RpcTransport) {}
+
+  getPropertyTypes(request: GetPropertyTypesParams): Promise<RpcResult<GetPropertyTypesResponse, unknown>> {
+    return this.transport.call(PropertyTypeSubsystem, 0, request) as Promise<RpcResult<GetPropertyTypesResponse, unknown>>;
+  }
+
+  createPropertyTypes(request: CreatePropertyTypeRequest): Promise<RpcResult<Array<PropertyTypeMetadata>, unknown>> {
+    return this.transport.call(PropertyTypeSubsystem, 1, request) as Promise<RpcResult<Array<PropertyTypeMetadata>, unknown>>;
+  }
+
+  updatePropertyType(request: UpdatePropertyTypeRequest): Promise<RpcResult<PropertyTypeMetadata, unknown>> {
+    return this.transport.call(PropertyTypeSubsystem, 2, request) as Promise<RpcResult<PropertyTypeMetadata, unknown>>;
+  }
+
+  archivePropertyType(request: ArchivePropertyTypeParams): Promise<RpcResult<OntologyTemporalMetadata, unknown>> {
+    return this.transport.call(PropertyTypeSubsystem, 3, request) as Promise<RpcResult<OntologyTemporalMetadata, unknown>>;
+  }
+}
+
+export const EntityTypeSubsystem: RpcSubsystemDescriptor = {
+  id: 6,
+  version: { major: 0, minor: 0 },
+};
+
+export class EntityTypeClient {
+  constructor(private readonly transport: RpcTransport) {}
+
+  getEntityTypes(request: GetEntityTypesParams): Promise<RpcResult<GetEntityTypesResponse, unknown>> {
+    return this.transport.call(EntityTypeSubsystem, 0, request) as Promise<RpcResult<GetEntityTypesResponse, unknown>>;
+  }
+
+  createEntityTypes(request: CreateEntityTypeRequest): Promise<RpcResult<Array<EntityTypeMetadata>, unknown>> {
+    return this.transport.call(EntityTypeSubsystem, 1, request) as Promise<RpcResult<Array<EntityTypeMetadata>, unknown>>;
+  }
+
+  updateEntityType(request: UpdateEntityTypeRequest): Promise<RpcResult<EntityTypeMetadata, unknown>> {
+    return this.transport.call(EntityTypeSubsystem, 2, request) as Promise<RpcResult<EntityTypeMetadata, unknown>>;
+  }
+
+  archiveEntityType(request: ArchiveEntityTypeParams): Promise<RpcResult<OntologyTemporalMetadata, unknown>> {
+    return this.transport.call(EntityTypeSubsystem, 3, request) as Promise<RpcResult<OntologyTemporalMetadata, unknown>>;
+  }
+}
+
+export const ChangeFeedSubsystem: RpcSubsystemDescriptor = {
+  id: 7,
+  version: { major: 0, minor: 0 },
+};
+
+export class ChangeFeedClient {
+  constructor(private readonly transport: RpcTransport) {}
+
+  streamChanges(request: ChangeFeedCursor | null): AsyncIterable<RpcResult<ChangeFeedRecord, unknown>> {
+    return this.transport.stream(ChangeFeedSubsystem, 0, request) as AsyncIterable<RpcResult<ChangeFeedRecord, unknown>>;
+  }
+}
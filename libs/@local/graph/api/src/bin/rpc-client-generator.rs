@@ -0,0 +1,19 @@
+use std::{fs, io};
+
+use error_stack::{Report, ResultExt as _};
+use harpc_system::codegen::typescript::TypeScriptClient;
+use hash_graph_api::rpc::client_schema;
+
+fn main() -> Result<(), Report<io::Error>> {
+    let rpc_path = std::path::Path::new("rpc");
+    let client_path = rpc_path.join("client.ts");
+
+    fs::create_dir_all(rpc_path).attach_printable_lazy(|| rpc_path.display().to_string())?;
+
+    let schema = client_schema();
+    fs::write(&client_path, TypeScriptClient::new(&schema).to_string())
+        .attach_printable("could not write RPC client")
+        .attach_printable_lazy(|| client_path.display().to_string())?;
+
+    Ok(())
+}
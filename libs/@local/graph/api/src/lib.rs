@@ -10,5 +10,6 @@
 
 extern crate alloc;
 
+mod operation;
 pub mod rest;
 pub mod rpc;
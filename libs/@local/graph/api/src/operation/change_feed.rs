@@ -0,0 +1,62 @@
+//! Streaming of the change feed.
+//!
+//! The feed is read page by page. Once it has been read completely, the store is polled for new
+//! changes, so the stream only ends when an error occurs or it is dropped.
+
+use core::time::Duration;
+
+use error_stack::Report;
+use futures::{Stream, stream};
+use hash_graph_store::{
+    change_feed::{ChangeFeedCursor, ChangeFeedRecord, ChangeFeedStore, ReadChangesParams},
+    error::QueryError,
+};
+use hash_graph_temporal_versioning::Timestamp;
+use type_system::provenance::ActorEntityUuid;
+
+use crate::operation::pagination::{PAGE_SIZE, flatten_pages};
+
+/// Interval in which the store is polled for new changes once the feed has been read completely.
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Changes are only streamed once they are older than this duration.
+///
+/// Transactions set their transaction time when they start, so a change may only become visible
+/// after later changes have already been streamed. Delaying the stream prevents missing changes
+/// of transactions which take less than this duration.
+const SETTLE_DURATION: time::Duration = time::Duration::seconds(10);
+
+/// Reads the change feed page by page and waits for new changes once it has been read completely.
+pub(crate) fn change_stream<T>(
+    store: T,
+    actor_id: ActorEntityUuid,
+    after: Option<ChangeFeedCursor>,
+) -> impl Stream<Item = Result<ChangeFeedRecord, Report<QueryError>>> + Send + 'static
+where
+    T: ChangeFeedStore + Send + Sync + 'static,
+{
+    flatten_pages(stream::try_unfold(
+        (store, after, false),
+        move |(store, after, wait)| async move {
+            if wait {
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+
+            let response = store
+                .read_changes(
+                    actor_id,
+                    ReadChangesParams {
+                        after,
+                        before: Some(Timestamp::now() - SETTLE_DURATION),
+                        limit: PAGE_SIZE,
+                    },
+                )
+                .await?;
+
+            Ok(Some((
+                response.changes,
+                (store, response.cursor, !response.has_more),
+            )))
+        },
+    ))
+}
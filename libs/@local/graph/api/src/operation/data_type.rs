@@ -0,0 +1,149 @@
+//! Creation and update of data types.
+//!
+//! A [`CreateDataTypeRequest`] may contain multiple schemas, which share the owner, the
+//! relationships, the provenance and the conversions of the request.
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::schema::DataTypeRelationAndSubject;
+use hash_graph_postgres_store::ontology::{PatchAndParseError, patch_id_and_parse};
+use hash_graph_store::{
+    data_type::{CreateDataTypeParams, UpdateDataTypesParams},
+    query::ConflictBehavior,
+};
+use hash_status::StatusCode;
+use type_system::ontology::{
+    id::OntologyTypeVersion,
+    json_schema::{DomainValidationError, DomainValidator, ValidateOntologyType as _},
+    provenance::OntologyOwnership,
+};
+
+use crate::rest::data_type::{CreateDataTypeRequest, UpdateDataTypeRequest};
+
+/// Returns the parameters to create the data types of the request.
+///
+/// # Errors
+///
+/// Returns [`InvalidArgument`] if the ID of a data type is not hosted on an allowed domain. The
+/// [`VersionedUrl`] of the data type is attached to the report.
+///
+/// [`InvalidArgument`]: StatusCode::InvalidArgument
+/// [`VersionedUrl`]: type_system::ontology::id::VersionedUrl
+pub(crate) fn create_params(
+    request: CreateDataTypeRequest,
+    domain_validator: &DomainValidator,
+) -> Result<Vec<CreateDataTypeParams<Vec<DataTypeRelationAndSubject>>>, Report<DomainValidationError>>
+{
+    let CreateDataTypeRequest {
+        schema,
+        owned_by_id,
+        relationships,
+        provenance,
+        conversions,
+    } = request;
+
+    schema
+        .into_iter()
+        .map(|schema| {
+            domain_validator
+                .validate(&schema)
+                .attach(StatusCode::InvalidArgument)
+                .attach(schema.id.clone())?;
+
+            Ok(CreateDataTypeParams {
+                schema,
+                ownership: OntologyOwnership::Local { owned_by_id },
+                relationships: relationships.clone(),
+                conflict_behavior: ConflictBehavior::Fail,
+                provenance: provenance.clone(),
+                conversions: conversions.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the parameters to create the next version of the data type to update.
+///
+/// # Errors
+///
+/// Returns [`InvalidArgument`] if the schema is not a valid data type.
+///
+/// [`InvalidArgument`]: StatusCode::InvalidArgument
+pub(crate) fn update_params(
+    request: UpdateDataTypeRequest,
+) -> Result<UpdateDataTypesParams<Vec<DataTypeRelationAndSubject>>, Report<PatchAndParseError>> {
+    let UpdateDataTypeRequest {
+        schema,
+        mut type_to_update,
+        relationships,
+        provenance,
+        conversions,
+    } = request;
+
+    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
+
+    Ok(UpdateDataTypesParams {
+        schema: patch_id_and_parse(&type_to_update, schema).attach(StatusCode::InvalidArgument)?,
+        relationships,
+        provenance,
+        conversions,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core::str::FromStr as _;
+
+    use hash_status::StatusCode;
+    use serde_json::json;
+    use type_system::ontology::id::{OntologyTypeVersion, VersionedUrl};
+
+    use super::update_params;
+    use crate::rest::data_type::UpdateDataTypeRequest;
+
+    fn update_request(schema: serde_json::Value) -> UpdateDataTypeRequest {
+        serde_json::from_value(json!({
+            "schema": schema,
+            "typeToUpdate": "https://example.com/@alice/types/data-type/length/v/1",
+            "relationships": [],
+            "provenance": {
+                "actorType": "machine",
+                "origin": { "type": "api" },
+            },
+            "conversions": {},
+        }))
+        .expect("request should be valid")
+    }
+
+    #[test]
+    fn update_creates_next_version() {
+        let params = update_params(update_request(json!({
+            "$schema": "https://blockprotocol.org/types/modules/graph/0.3/schema/data-type",
+            "kind": "dataType",
+            "allOf": [
+                { "$ref": "https://blockprotocol.org/@blockprotocol/types/data-type/number/v/1" }
+            ],
+            "title": "Length",
+            "description": "A measure of distance",
+            "type": "number",
+        })))
+        .expect("schema should be a valid data type");
+
+        assert_eq!(
+            params.schema.id,
+            VersionedUrl::from_str("https://example.com/@alice/types/data-type/length/v/2")
+                .expect("URL should be valid")
+        );
+        assert_eq!(params.schema.id.version, OntologyTypeVersion::new(2));
+    }
+
+    #[test]
+    fn update_rejects_invalid_schema() {
+        let report = update_params(update_request(json!({ "kind": "dataType" })))
+            .expect_err("schema should not be a valid data type");
+
+        assert_eq!(
+            report.request_ref::<StatusCode>().next(),
+            Some(&StatusCode::InvalidArgument)
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Queries and updates of entities.
+
+use error_stack::Report;
+use hash_graph_postgres_store::store::error::{EntityDoesNotExist, RaceConditionOnUpdate};
+use hash_graph_store::{entity::GetEntitiesParams, error::UpdateError};
+use hash_status::StatusCode;
+use type_system::provenance::ActorEntityUuid;
+
+use crate::rest::entity::GetEntitiesRequest;
+
+/// Returns the parameters to read the entities requested by the actor.
+pub(crate) fn get_entities_params<'q, 'p: 'q>(
+    actor_id: ActorEntityUuid,
+    request: GetEntitiesRequest<'q, '_, 'p>,
+) -> GetEntitiesParams<'q> {
+    if request.limit == Some(0) {
+        tracing::warn!(
+            %actor_id,
+            "The limit is set to zero, so no entities will be returned."
+        );
+    }
+
+    request.into()
+}
+
+/// Attaches the [`StatusCode`] of expected errors when patching an entity.
+///
+/// The entity either does not exist or was updated concurrently.
+pub(crate) fn patch_entity_status(report: Report<UpdateError>) -> Report<UpdateError> {
+    if report.contains::<EntityDoesNotExist>() {
+        report.attach(StatusCode::NotFound)
+    } else if report.contains::<RaceConditionOnUpdate>() {
+        report.attach(StatusCode::Cancelled)
+    } else {
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::Report;
+    use hash_graph_postgres_store::store::error::{EntityDoesNotExist, RaceConditionOnUpdate};
+    use hash_graph_store::error::UpdateError;
+    use hash_status::StatusCode;
+
+    use super::patch_entity_status;
+
+    fn status_code(report: &Report<UpdateError>) -> Option<StatusCode> {
+        report.request_ref::<StatusCode>().next().copied()
+    }
+
+    #[test]
+    fn patch_status() {
+        let missing =
+            patch_entity_status(Report::new(EntityDoesNotExist).change_context(UpdateError));
+        assert_eq!(status_code(&missing), Some(StatusCode::NotFound));
+
+        let concurrent =
+            patch_entity_status(Report::new(RaceConditionOnUpdate).change_context(UpdateError));
+        assert_eq!(status_code(&concurrent), Some(StatusCode::Cancelled));
+
+        let unexpected = patch_entity_status(Report::new(UpdateError));
+        assert_eq!(status_code(&unexpected), None);
+    }
+}
@@ -0,0 +1,89 @@
+//! Creation and update of entity types.
+//!
+//! The REST API reports an entity type failing domain validation together with its ID, which is
+//! why the ID is attached to the report.
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::schema::EntityTypeRelationAndSubject;
+use hash_graph_postgres_store::ontology::{PatchAndParseError, patch_id_and_parse};
+use hash_graph_store::{
+    entity_type::{CreateEntityTypeParams, UpdateEntityTypesParams},
+    query::ConflictBehavior,
+};
+use hash_status::StatusCode;
+use type_system::ontology::{
+    id::OntologyTypeVersion,
+    json_schema::{DomainValidationError, DomainValidator, ValidateOntologyType as _},
+    provenance::OntologyOwnership,
+};
+
+use crate::rest::entity_type::{CreateEntityTypeRequest, UpdateEntityTypeRequest};
+
+/// Returns the parameters to create the entity types of the request.
+///
+/// # Errors
+///
+/// Returns [`InvalidArgument`] if the ID of an entity type is not hosted on an allowed domain.
+/// The [`VersionedUrl`] of the entity type is attached to the report.
+///
+/// [`InvalidArgument`]: StatusCode::InvalidArgument
+/// [`VersionedUrl`]: type_system::ontology::id::VersionedUrl
+pub(crate) fn create_params(
+    request: CreateEntityTypeRequest,
+    domain_validator: &DomainValidator,
+) -> Result<
+    Vec<CreateEntityTypeParams<Vec<EntityTypeRelationAndSubject>>>,
+    Report<DomainValidationError>,
+> {
+    let CreateEntityTypeRequest {
+        schema,
+        owned_by_id,
+        relationships,
+        provenance,
+    } = request;
+
+    schema
+        .into_iter()
+        .map(|schema| {
+            domain_validator
+                .validate(&schema)
+                .attach(StatusCode::InvalidArgument)
+                .attach(schema.id.clone())?;
+
+            Ok(CreateEntityTypeParams {
+                schema,
+                ownership: OntologyOwnership::Local { owned_by_id },
+                relationships: relationships.clone(),
+                conflict_behavior: ConflictBehavior::Fail,
+                provenance: provenance.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the parameters to create the next version of the entity type to update.
+///
+/// # Errors
+///
+/// Returns [`InvalidArgument`] if the schema is not a valid entity type.
+///
+/// [`InvalidArgument`]: StatusCode::InvalidArgument
+pub(crate) fn update_params(
+    request: UpdateEntityTypeRequest,
+) -> Result<UpdateEntityTypesParams<Vec<EntityTypeRelationAndSubject>>, Report<PatchAndParseError>>
+{
+    let UpdateEntityTypeRequest {
+        schema,
+        mut type_to_update,
+        relationships,
+        provenance,
+    } = request;
+
+    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
+
+    Ok(UpdateEntityTypesParams {
+        schema: patch_id_and_parse(&type_to_update, schema).attach(StatusCode::InvalidArgument)?,
+        relationships,
+        provenance,
+    })
+}
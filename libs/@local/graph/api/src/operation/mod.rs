@@ -0,0 +1,66 @@
+//! Operations shared by the REST and the RPC API.
+//!
+//! Both APIs accept the same requests but differ in how they receive them and how they report
+//! errors. The functions in this module turn requests into the parameters of the store and attach
+//! the [`StatusCode`] of expected store errors, so that a request behaves the same regardless of
+//! the API it was sent to. Results which are too large to be read at once are read as a stream,
+//! which each API forwards in its own format.
+
+pub(crate) mod change_feed;
+pub(crate) mod data_type;
+pub(crate) mod entity;
+pub(crate) mod entity_type;
+pub(crate) mod pagination;
+pub(crate) mod property_type;
+
+use error_stack::Report;
+use hash_graph_postgres_store::store::error::{
+    OntologyVersionDoesNotExist, VersionedUrlAlreadyExists,
+};
+use hash_graph_store::error::UpdateError;
+use hash_status::StatusCode;
+
+/// Attaches the [`StatusCode`] of expected errors when archiving or unarchiving an ontology type.
+///
+/// The type either does not exist or is already in the requested state.
+pub(crate) fn archive_ontology_type_status(mut report: Report<UpdateError>) -> Report<UpdateError> {
+    if report.contains::<OntologyVersionDoesNotExist>() {
+        report = report.attach(StatusCode::NotFound);
+    }
+    if report.contains::<VersionedUrlAlreadyExists>() {
+        report = report.attach(StatusCode::AlreadyExists);
+    }
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use error_stack::Report;
+    use hash_graph_postgres_store::store::error::{
+        OntologyVersionDoesNotExist, VersionedUrlAlreadyExists,
+    };
+    use hash_graph_store::error::UpdateError;
+    use hash_status::StatusCode;
+
+    use super::archive_ontology_type_status;
+
+    fn status_code(report: &Report<UpdateError>) -> Option<StatusCode> {
+        report.request_ref::<StatusCode>().next().copied()
+    }
+
+    #[test]
+    fn archive_status() {
+        let missing = archive_ontology_type_status(
+            Report::new(OntologyVersionDoesNotExist).change_context(UpdateError),
+        );
+        assert_eq!(status_code(&missing), Some(StatusCode::NotFound));
+
+        let archived = archive_ontology_type_status(
+            Report::new(VersionedUrlAlreadyExists).change_context(UpdateError),
+        );
+        assert_eq!(status_code(&archived), Some(StatusCode::AlreadyExists));
+
+        let unexpected = archive_ontology_type_status(Report::new(UpdateError));
+        assert_eq!(status_code(&unexpected), None);
+    }
+}
@@ -0,0 +1,115 @@
+//! Reading query results from the store page by page.
+//!
+//! Large results are streamed instead of being read at once. The store is queried for a single
+//! page at a time, so only a single page is kept in memory.
+
+use futures::{Stream, StreamExt as _, stream};
+
+/// Number of records read from the store at once when streaming query results.
+pub(crate) const PAGE_SIZE: usize = 1000;
+
+/// Tracks the progress of a query which is read from the store page by page.
+#[derive(Debug)]
+pub(crate) struct Pagination<C> {
+    cursor: Option<C>,
+    remaining: Option<usize>,
+    first: bool,
+    finished: bool,
+}
+
+impl<C> Pagination<C>
+where
+    C: Clone,
+{
+    /// Starts reading at `cursor`, returning at most `limit` records in total.
+    pub(crate) const fn new(cursor: Option<C>, limit: Option<usize>) -> Self {
+        Self {
+            cursor,
+            remaining: limit,
+            first: true,
+            finished: matches!(limit, Some(0)),
+        }
+    }
+
+    /// The cursor to start the next page at.
+    ///
+    /// After the last page this is the cursor to resume reading at, if any records are left.
+    pub(crate) fn cursor(&self) -> Option<C> {
+        self.cursor.clone()
+    }
+
+    /// The number of records to request for the next page.
+    pub(crate) fn limit(&self) -> usize {
+        self.remaining
+            .map_or(PAGE_SIZE, |remaining| remaining.min(PAGE_SIZE))
+    }
+
+    /// Returns `true` if no page has been read yet.
+    pub(crate) const fn is_first(&self) -> bool {
+        self.first
+    }
+
+    /// Returns `true` if no further page has to be read.
+    pub(crate) const fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /// Advances past a page of `len` records, which ended at `cursor`.
+    ///
+    /// The store only returns a cursor if the page is full, so a missing cursor marks the end of
+    /// the query.
+    pub(crate) fn advance(&mut self, len: usize, cursor: Option<C>) {
+        if let Some(remaining) = &mut self.remaining {
+            *remaining = remaining.saturating_sub(len);
+        }
+
+        self.first = false;
+        self.finished = cursor.is_none() || self.remaining == Some(0);
+        self.cursor = cursor;
+    }
+}
+
+/// Flattens a stream of pages into a stream of records.
+pub(crate) fn flatten_pages<S, T, E>(pages: S) -> impl Stream<Item = Result<T, E>> + Send
+where
+    S: Stream<Item = Result<Vec<T>, E>> + Send,
+    T: Send,
+    E: Send,
+{
+    pages.flat_map(|page| match page {
+        Ok(records) => stream::iter(records.into_iter().map(Ok)).left_stream(),
+        Err(error) => stream::once(async move { Err(error) }).right_stream(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PAGE_SIZE, Pagination};
+
+    #[test]
+    fn pagination_respects_limit() {
+        let mut pagination = Pagination::new(None, Some(PAGE_SIZE + 10));
+        assert_eq!(pagination.limit(), PAGE_SIZE);
+
+        pagination.advance(PAGE_SIZE, Some(1));
+        assert!(!pagination.is_finished());
+        assert_eq!(pagination.limit(), 10);
+        assert_eq!(pagination.cursor(), Some(1));
+
+        pagination.advance(10, Some(2));
+        assert!(pagination.is_finished());
+        assert_eq!(pagination.cursor(), Some(2));
+    }
+
+    #[test]
+    fn pagination_without_limit() {
+        let mut pagination = Pagination::<u32>::new(None, None);
+
+        pagination.advance(PAGE_SIZE, Some(1));
+        assert!(!pagination.is_finished());
+
+        pagination.advance(3, None);
+        assert!(pagination.is_finished());
+        assert_eq!(pagination.cursor(), None);
+    }
+}
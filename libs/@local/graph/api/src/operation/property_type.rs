@@ -0,0 +1,88 @@
+//! Creation and update of property types.
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::schema::PropertyTypeRelationAndSubject;
+use hash_graph_postgres_store::ontology::{PatchAndParseError, patch_id_and_parse};
+use hash_graph_store::{
+    property_type::{CreatePropertyTypeParams, UpdatePropertyTypesParams},
+    query::ConflictBehavior,
+};
+use hash_status::StatusCode;
+use type_system::ontology::{
+    id::OntologyTypeVersion,
+    json_schema::{DomainValidationError, DomainValidator, ValidateOntologyType as _},
+    provenance::OntologyOwnership,
+};
+
+use crate::rest::property_type::{CreatePropertyTypeRequest, UpdatePropertyTypeRequest};
+
+/// Returns the parameters to create the property types of the request.
+///
+/// # Errors
+///
+/// Returns [`InvalidArgument`] if the ID of a property type is not hosted on an allowed domain.
+/// The [`VersionedUrl`] of the property type is attached to the report.
+///
+/// [`InvalidArgument`]: StatusCode::InvalidArgument
+/// [`VersionedUrl`]: type_system::ontology::id::VersionedUrl
+pub(crate) fn create_params(
+    request: CreatePropertyTypeRequest,
+    domain_validator: &DomainValidator,
+) -> Result<
+    Vec<CreatePropertyTypeParams<Vec<PropertyTypeRelationAndSubject>>>,
+    Report<DomainValidationError>,
+> {
+    let CreatePropertyTypeRequest {
+        schema,
+        owned_by_id,
+        relationships,
+        provenance,
+    } = request;
+
+    schema
+        .into_iter()
+        .map(|schema| {
+            domain_validator
+                .validate(&schema)
+                .attach(StatusCode::InvalidArgument)
+                .attach(schema.id.clone())?;
+
+            Ok(CreatePropertyTypeParams {
+                schema,
+                ownership: OntologyOwnership::Local { owned_by_id },
+                relationships: relationships.clone(),
+                conflict_behavior: ConflictBehavior::Fail,
+                provenance: provenance.clone(),
+            })
+        })
+        .collect()
+}
+
+/// Returns the parameters to create the next version of the property type to update.
+///
+/// # Errors
+///
+/// Returns [`InvalidArgument`] if the schema is not a valid property type.
+///
+/// [`InvalidArgument`]: StatusCode::InvalidArgument
+pub(crate) fn update_params(
+    request: UpdatePropertyTypeRequest,
+) -> Result<
+    UpdatePropertyTypesParams<Vec<PropertyTypeRelationAndSubject>>,
+    Report<PatchAndParseError>,
+> {
+    let UpdatePropertyTypeRequest {
+        schema,
+        mut type_to_update,
+        relationships,
+        provenance,
+    } = request;
+
+    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
+
+    Ok(UpdatePropertyTypesParams {
+        schema: patch_id_and_parse(&type_to_update, schema).attach(StatusCode::InvalidArgument)?,
+        relationships,
+        provenance,
+    })
+}
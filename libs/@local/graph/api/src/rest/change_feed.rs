@@ -0,0 +1,209 @@
+//! Web routes for reading the change feed.
+
+use alloc::sync::Arc;
+
+use axum::{
+    Extension, Router,
+    extract::Query,
+    http::HeaderMap,
+    response::{
+        Response,
+        sse::{Event, KeepAlive, Sse},
+    },
+    routing::{get, post},
+};
+use error_stack::Report;
+use futures::{Stream, StreamExt as _};
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    change_feed::{
+        Change, ChangeFeedCursor, ChangeFeedRecord, ChangeFeedStore as _, OntologyTypeKind,
+        ReadChangesParams, ReadChangesResponse,
+    },
+    error::QueryError,
+    pool::StorePool,
+};
+use hash_temporal_client::TemporalClient;
+use serde::Deserialize;
+use type_system::{ontology::id::OntologyTypeUuid, provenance::ActorEntityUuid};
+use utoipa::OpenApi;
+
+use crate::{
+    operation::change_feed::change_stream,
+    rest::{
+        AuthenticatedUserHeader,
+        api_resource::RoutedResource,
+        json::Json,
+        status::{report_to_response, report_to_status},
+    },
+};
+
+/// Header sent by `EventSource` clients when reconnecting.
+const LAST_EVENT_ID: &str = "Last-Event-ID";
+
+#[derive(OpenApi)]
+#[openapi(
+    paths(
+        read_changes,
+        stream_changes,
+    ),
+    components(
+        schemas(
+            ReadChangesParams,
+            ReadChangesResponse,
+            ChangeFeedRecord,
+            ChangeFeedCursor,
+            Change,
+            OntologyTypeKind,
+            OntologyTypeUuid,
+        )
+    ),
+    tags(
+        (name = "ChangeFeed", description = "Change feed API")
+    )
+)]
+pub(crate) struct ChangeFeedResource;
+
+impl RoutedResource for ChangeFeedResource {
+    /// Create routes for reading the change feed.
+    fn routes<S, A>() -> Router
+    where
+        S: StorePool + Send + Sync + 'static,
+        A: AuthorizationApiPool + Send + Sync + 'static,
+    {
+        Router::new().nest(
+            "/changes",
+            Router::new()
+                .route("/", post(read_changes::<S, A>))
+                .route("/stream", get(stream_changes::<S, A>)),
+        )
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/changes",
+    request_body = ReadChangesParams,
+    tag = "ChangeFeed",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", body = ReadChangesResponse, description = "The changes after the requested cursor in transaction-time order"),
+
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn read_changes<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<ReadChangesParams>,
+) -> Result<Json<ReadChangesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .read_changes(actor_id, params)
+        .await
+        .map(Json)
+        .map_err(report_to_response)
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StreamChangesQuery {
+    after: Option<String>,
+}
+
+fn change_event(
+    record: Result<ChangeFeedRecord, Report<QueryError>>,
+) -> Result<Event, axum::Error> {
+    match record {
+        Ok(record) => Event::default()
+            .event("change")
+            .id(serde_json::to_string(&record.cursor).map_err(axum::Error::new)?)
+            .json_data(&record),
+        Err(report) => Event::default()
+            .event("error")
+            .json_data(report_to_status(report)),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/changes/stream",
+    tag = "ChangeFeed",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("Last-Event-ID" = Option<String>, Header, description = "The ID of the last received event. Takes precedence over `after`"),
+        ("after" = Option<String>, Query, description = "The JSON encoded cursor to start streaming after"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "text/event-stream",
+            body = String,
+            description = "A server-sent event stream of the changes after the requested cursor. Every `change` event contains a `ChangeFeedRecord` and uses its cursor as event ID. New changes are streamed with a delay of ten seconds. If an error occurs, an `error` event containing the status is sent and the stream is closed.",
+        ),
+        (status = 400, content_type = "application/json", description = "The cursor is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, headers)
+)]
+async fn stream_changes<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    headers: HeaderMap,
+    Query(query): Query<StreamChangesQuery>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, Response>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    let after = headers
+        .get(LAST_EVENT_ID)
+        .map(|value| value.as_bytes())
+        .or_else(|| query.after.as_deref().map(str::as_bytes))
+        .map(serde_json::from_slice::<ChangeFeedCursor>)
+        .transpose()
+        .map_err(|error| {
+            report_to_response(Report::new(error).attach(hash_status::StatusCode::InvalidArgument))
+        })?;
+
+    let authorization_api = authorization_api_pool
+        .acquire_owned()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire_owned(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    Ok(
+        Sse::new(change_stream(store, actor_id, after).map(change_event))
+            .keep_alive(KeepAlive::default()),
+    )
+}
@@ -20,21 +20,16 @@ use hash_graph_authorization::{
     },
     zanzibar::Consistency,
 };
-use hash_graph_postgres_store::{
-    ontology::patch_id_and_parse,
-    store::error::{OntologyVersionDoesNotExist, VersionedUrlAlreadyExists},
-};
 use hash_graph_store::{
     data_type::{
         ArchiveDataTypeParams, CreateDataTypeParams, DataTypeConversionTargets, DataTypeQueryToken,
         DataTypeStore as _, GetDataTypeConversionTargetsParams,
         GetDataTypeConversionTargetsResponse, GetDataTypeSubgraphParams, GetDataTypesParams,
         GetDataTypesResponse, UnarchiveDataTypeParams, UpdateDataTypeEmbeddingParams,
-        UpdateDataTypesParams,
     },
     entity_type::ClosedDataTypeDefinition,
     pool::StorePool,
-    query::ConflictBehavior,
+    query::{ConflictBehavior, QueryExplanation, VersionedUrlSorting},
 };
 use hash_status::Status;
 use hash_temporal_client::TemporalClient;
@@ -48,8 +43,8 @@ use type_system::{
             ConversionDefinition, ConversionExpression, ConversionValue, Conversions, DataType,
             DataTypeMetadata, DataTypeUuid, Operator, Variable,
         },
-        id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
-        json_schema::{DomainValidator, JsonSchemaValueType, ValidateOntologyType as _},
+        id::{BaseUrl, VersionedUrl},
+        json_schema::{DomainValidator, JsonSchemaValueType},
         provenance::{OntologyOwnership, ProvidedOntologyEditionProvenance},
     },
     web::OwnedById,
@@ -57,11 +52,15 @@ use type_system::{
 use utoipa::{OpenApi, ToSchema};
 
 use super::api_resource::RoutedResource;
-use crate::rest::{
-    AuthenticatedUserHeader, OpenApiQuery, PermissionResponse, QueryLogger, RestApiStore,
-    json::Json,
-    status::{report_to_response, status_to_response},
-    utoipa_typedef::{ListOrValue, MaybeListOfDataType, subgraph::Subgraph},
+use crate::{
+    operation,
+    rest::{
+        AuthenticatedUserHeader, ExplainedQuery, InstanceAdministrators, OpenApiQuery,
+        PermissionResponse, QueryLogger, RestApiStore, explain_query,
+        json::Json,
+        status::{report_to_response, status_to_response},
+        utoipa_typedef::{ListOrValue, MaybeListOfDataType, subgraph::Subgraph},
+    },
 };
 
 #[derive(OpenApi)]
@@ -75,6 +74,7 @@ use crate::rest::{
         load_external_data_type,
         get_data_types,
         get_data_type_subgraph,
+        explain_data_types,
         get_data_type_conversion_targets,
         update_data_type,
         update_data_types,
@@ -167,7 +167,8 @@ impl RoutedResource for DataTypeResource {
                         .route(
                             "/conversions",
                             post(get_data_type_conversion_targets::<S, A>),
-                        ),
+                        )
+                        .route("/explain", post(explain_data_types::<S, A>)),
                 )
                 .route("/load", post(load_external_data_type::<S, A>))
                 .route("/archive", put(archive_data_type::<S, A>))
@@ -179,13 +180,13 @@ impl RoutedResource for DataTypeResource {
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct CreateDataTypeRequest {
+pub struct CreateDataTypeRequest {
     #[schema(inline)]
-    schema: MaybeListOfDataType,
-    owned_by_id: OwnedById,
-    relationships: Vec<DataTypeRelationAndSubject>,
-    provenance: ProvidedOntologyEditionProvenance,
-    conversions: HashMap<BaseUrl, Conversions>,
+    pub(crate) schema: MaybeListOfDataType,
+    pub(crate) owned_by_id: OwnedById,
+    pub(crate) relationships: Vec<DataTypeRelationAndSubject>,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) conversions: HashMap<BaseUrl, Conversions>,
 }
 
 #[utoipa::path(
@@ -231,36 +232,15 @@ where
         .await
         .map_err(report_to_response)?;
 
-    let Json(CreateDataTypeRequest {
-        schema,
-        owned_by_id,
-        relationships,
-        provenance,
-        conversions,
-    }) = body;
+    let Json(request) = body;
 
-    let is_list = matches!(&schema, ListOrValue::List(_));
+    let is_list = matches!(&request.schema, ListOrValue::List(_));
 
     let mut metadata = store
         .create_data_types(
             actor_id,
-            schema
-                .into_iter()
-                .map(|schema| {
-                    domain_validator
-                        .validate(&schema)
-                        .map_err(report_to_response)?;
-
-                    Ok(CreateDataTypeParams {
-                        schema,
-                        ownership: OntologyOwnership::Local { owned_by_id },
-                        relationships: relationships.clone(),
-                        conflict_behavior: ConflictBehavior::Fail,
-                        provenance: provenance.clone(),
-                        conversions: conversions.clone(),
-                    })
-                })
-                .collect::<Result<Vec<_>, Response>>()?,
+            operation::data_type::create_params(request, &domain_validator)
+                .map_err(report_to_response)?,
         )
         .await
         .map_err(report_to_response)?;
@@ -532,6 +512,72 @@ where
     response
 }
 
+#[utoipa::path(
+    post,
+    path = "/data-types/query/explain",
+    request_body = GetDataTypesParams,
+    tag = "DataType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = QueryExplanation,
+            description = "The compiled query for data types and its execution plan",
+        ),
+        (status = 403, description = "The actor is not an instance administrator"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(
+        store_pool,
+        authorization_api_pool,
+        temporal_client,
+        instance_administrators,
+        request
+    )
+)]
+async fn explain_data_types<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    instance_administrators: Option<Extension<InstanceAdministrators>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<QueryExplanation>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+    for<'pool> S::Store<'pool, A::Api<'pool>>: RestApiStore,
+{
+    explain_query(
+        actor_id,
+        &*store_pool,
+        &*authorization_api_pool,
+        temporal_client.0,
+        instance_administrators.map(|Extension(administrators)| administrators),
+        || {
+            let params = GetDataTypesParams::deserialize(&request)?;
+            Ok(ExplainedQuery {
+                filter: params.filter,
+                temporal_axes: params.temporal_axes,
+                sorting: VersionedUrlSorting {
+                    cursor: params.after,
+                },
+                limit: params.limit,
+                include_drafts: params.include_drafts,
+            })
+        },
+    )
+    .await
+    .map(Json)
+}
+
 #[utoipa::path(
     post,
     path = "/data-types/query/conversions",
@@ -582,13 +628,13 @@ where
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct UpdateDataTypeRequest {
+pub struct UpdateDataTypeRequest {
     #[schema(value_type = UpdateDataType)]
-    schema: serde_json::Value,
-    type_to_update: VersionedUrl,
-    relationships: Vec<DataTypeRelationAndSubject>,
-    provenance: ProvidedOntologyEditionProvenance,
-    conversions: HashMap<BaseUrl, Conversions>,
+    pub(crate) schema: serde_json::Value,
+    pub(crate) type_to_update: VersionedUrl,
+    pub(crate) relationships: Vec<DataTypeRelationAndSubject>,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) conversions: HashMap<BaseUrl, Conversions>,
 }
 
 #[utoipa::path(
@@ -622,17 +668,9 @@ where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
-    let Json(UpdateDataTypeRequest {
-        schema,
-        mut type_to_update,
-        relationships,
-        provenance,
-        conversions,
-    }) = body;
+    let Json(request) = body;
 
-    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
-
-    let data_type = patch_id_and_parse(&type_to_update, schema).map_err(report_to_response)?;
+    let params = operation::data_type::update_params(request).map_err(report_to_response)?;
 
     let authorization_api = authorization_api_pool
         .acquire()
@@ -645,15 +683,7 @@ where
         .map_err(report_to_response)?;
 
     store
-        .update_data_type(
-            actor_id,
-            UpdateDataTypesParams {
-                schema: data_type,
-                relationships,
-                provenance,
-                conversions,
-            },
-        )
+        .update_data_type(actor_id, params)
         .await
         .map_err(report_to_response)
         .map(Json)
@@ -703,27 +733,9 @@ where
     let params = bodies
         .0
         .into_iter()
-        .map(
-            |UpdateDataTypeRequest {
-                 schema,
-                 mut type_to_update,
-                 relationships,
-                 provenance,
-                 conversions,
-             }| {
-                type_to_update.version =
-                    OntologyTypeVersion::new(type_to_update.version.inner() + 1);
-
-                Ok(UpdateDataTypesParams {
-                    schema: patch_id_and_parse(&type_to_update, schema)
-                        .map_err(report_to_response)?,
-                    relationships,
-                    provenance,
-                    conversions,
-                })
-            },
-        )
-        .collect::<Result<Vec<_>, Response>>()?;
+        .map(operation::data_type::update_params)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(report_to_response)?;
     store
         .update_data_types(actor_id, params)
         .await
@@ -834,15 +846,7 @@ where
     store
         .archive_data_type(actor_id, params)
         .await
-        .map_err(|mut report| {
-            if report.contains::<OntologyVersionDoesNotExist>() {
-                report = report.attach(hash_status::StatusCode::NotFound);
-            }
-            if report.contains::<VersionedUrlAlreadyExists>() {
-                report = report.attach(hash_status::StatusCode::AlreadyExists);
-            }
-            report_to_response(report)
-        })
+        .map_err(|report| report_to_response(operation::archive_ontology_type_status(report)))
         .map(Json)
 }
 
@@ -897,15 +901,7 @@ where
     store
         .unarchive_data_type(actor_id, params)
         .await
-        .map_err(|mut report| {
-            if report.contains::<OntologyVersionDoesNotExist>() {
-                report = report.attach(hash_status::StatusCode::NotFound);
-            }
-            if report.contains::<VersionedUrlAlreadyExists>() {
-                report = report.attach(hash_status::StatusCode::AlreadyExists);
-            }
-            report_to_response(report)
-        })
+        .map_err(|report| report_to_response(operation::archive_ontology_type_status(report)))
         .map(Json)
 }
 
@@ -6,11 +6,12 @@ use std::collections::HashMap;
 use axum::{
     Extension, Router,
     extract::Path,
-    http::StatusCode,
-    response::Response,
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    response::{IntoResponse as _, Response},
     routing::{get, post},
 };
 use error_stack::{Report, ResultExt as _};
+use futures::{Stream, stream};
 use hash_graph_authorization::{
     AuthorizationApi as _, AuthorizationApiPool,
     backend::{ModifyRelationshipOperation, PermissionAssertion},
@@ -21,25 +22,38 @@ use hash_graph_authorization::{
     },
     zanzibar::Consistency,
 };
-use hash_graph_postgres_store::store::error::{EntityDoesNotExist, RaceConditionOnUpdate};
+use hash_graph_postgres_store::store::error::{
+    EntityDoesNotExist, EntityEditionMismatch, RaceConditionOnUpdate,
+};
 use hash_graph_store::{
     account::AccountStore as _,
     entity::{
-        ClosedMultiEntityTypeMap, CountEntitiesParams, CreateEntityRequest, DiffEntityParams,
-        DiffEntityResult, EntityQueryCursor, EntityQueryPath, EntityQuerySorting,
-        EntityQuerySortingRecord, EntityQuerySortingToken, EntityQueryToken, EntityStore as _,
-        EntityTypesError, EntityValidationReport, EntityValidationType, GetEntitiesParams,
-        GetEntitiesResponse, GetEntitySubgraphParams, LinkDataStateError, LinkDataValidationReport,
+        AggregateEntitiesParams, AggregateEntitiesResponse, ArchiveEntityBatchOperation,
+        ClosedMultiEntityTypeMap, CountEntitiesParams, CreateEntityBatchOperation,
+        CreateEntityRequest, DiffEntityParams, DiffEntityResult, DuplicatePropertyValue,
+        EntityAggregation, EntityAggregationGroup, EntityBatchLinkData, EntityBatchOperation,
+        EntityBatchOperationResult, EntityBatchReference, EntityHistoryEdition, EntityPath,
+        EntityPathHop, EntityQueryCursor, EntityQueryPath, EntityQuerySorting,
+        EntityQuerySortingRecord, EntityQuerySortingToken, EntityQueryToken, EntityStore,
+        EntityTombstone, EntityTypesError, EntityValidationReport, EntityValidationType,
+        EraseEntityParams, FindEntityPathsParams, GetEntitiesParams, GetEntitiesResponse,
+        GetEntityHistoryParams, GetEntityHistoryResponse, GetEntitySubgraphParams,
+        InvalidEntityBatchReference, LinkDataStateError, LinkDataValidationReport, LinkDirection,
         LinkError, LinkTargetError, LinkValidationReport, LinkedEntityError,
-        MetadataValidationReport, PatchEntityParams, PropertyMetadataValidationReport,
-        QueryConversion, UnexpectedEntityType, UpdateEntityEmbeddingsParams,
+        MetadataValidationReport, ModifyEntityRelationshipBatchOperation,
+        PatchEntityBatchOperation, PatchEntityParams, PropertyMetadataValidationReport,
+        QueryConversion, UnexpectedEntityType, UniquePropertyError, UpdateEntityEmbeddingsParams,
         ValidateEntityComponents, ValidateEntityParams,
     },
     entity_type::{EntityTypeResolveDefinitions, IncludeEntityTypeOption},
+    error::QueryError,
     filter::Filter,
     pool::StorePool,
-    query::{NullOrdering, Ordering},
-    subgraph::{edges::GraphResolveDepths, temporal_axes::QueryTemporalAxesUnresolved},
+    query::{AggregationFunction, NullOrdering, Ordering, QueryExplanation},
+    subgraph::{
+        edges::GraphResolveDepths,
+        temporal_axes::{QueryTemporalAxes, QueryTemporalAxesUnresolved},
+    },
 };
 use hash_graph_types::{
     Embedding,
@@ -88,11 +102,23 @@ use type_system::{
     web::OwnedById,
 };
 use utoipa::{OpenApi, ToSchema};
+use uuid::Uuid;
 
-use crate::rest::{
-    AuthenticatedUserHeader, OpenApiQuery, PermissionResponse, QueryLogger,
-    api_resource::RoutedResource, json::Json, status::report_to_response,
-    utoipa_typedef::subgraph::Subgraph,
+use crate::{
+    operation::{
+        self,
+        pagination::{Pagination, flatten_pages},
+    },
+    rest::{
+        AuthenticatedUserHeader, ExplainedQuery, InstanceAdministrators, OpenApiQuery,
+        PermissionResponse, QueryLogger, RestApiStore,
+        api_resource::RoutedResource,
+        assert_instance_administrator, explain_query,
+        json::Json,
+        ndjson::{accepts_ndjson, ndjson_response},
+        status::report_to_response,
+        utoipa_typedef::subgraph::Subgraph,
+    },
 };
 
 #[derive(OpenApi)]
@@ -100,14 +126,22 @@ use crate::rest::{
     paths(
         create_entity,
         create_entities,
+        execute_entity_batch,
         validate_entity,
         check_entity_permission,
         get_entities,
         get_entity_subgraph,
         count_entities,
+        find_entity_paths,
+        aggregate_entities,
+        explain_entities,
         patch_entity,
         update_entity_embeddings,
         diff_entity,
+        get_entity_history,
+        erase_entity,
+        merge_entities,
+        upgrade_entity_type,
 
         get_entity_authorization_relationships,
         modify_entity_authorization_relationships,
@@ -120,12 +154,25 @@ use crate::rest::{
     components(
         schemas(
             CreateEntityRequest,
+            EntityBatchOperation,
+            EntityBatchOperationResult,
+            EntityBatchReference,
+            EntityBatchLinkData,
+            CreateEntityBatchOperation,
+            PatchEntityBatchOperation,
+            ArchiveEntityBatchOperation,
+            ModifyEntityRelationshipBatchOperation,
             PropertyWithMetadata,
             PropertyValueWithMetadata,
             PropertyArrayWithMetadata,
             PropertyObjectWithMetadata,
             ValidateEntityParams,
             CountEntitiesParams,
+            AggregateEntitiesParams,
+            AggregateEntitiesResponse,
+            EntityAggregation,
+            EntityAggregationGroup,
+            AggregationFunction,
             EntityValidationType,
             ValidateEntityComponents,
             Embedding,
@@ -197,6 +244,8 @@ use crate::rest::{
             LinkError,
             LinkTargetError,
             UnexpectedEntityType,
+            UniquePropertyError,
+            DuplicatePropertyValue,
             MetadataValidationReport,
             EntityTypesError,
             PropertyMetadataValidationReport,
@@ -221,6 +270,23 @@ use crate::rest::{
 
             DiffEntityParams,
             DiffEntityResult,
+            GetEntityHistoryParams,
+            GetEntityHistoryResponse,
+            EntityHistoryEdition,
+            EraseEntityParams,
+            EntityTombstone,
+            MergeEntitiesParams,
+            MergeEntitiesResponse,
+            PropertyMergeStrategy,
+            EntityUpgradeFailure,
+            PropertyUpgradeOperation,
+            UpgradeEntityTypeParams,
+            UpgradeEntityTypeResponse,
+            FindEntityPathsParams,
+            FindEntityPathsResponse,
+            EntityPath,
+            EntityPathHop,
+            LinkDirection,
             EntityTypeIdDiff,
             PropertyDiff,
             PropertyPath,
@@ -247,11 +313,16 @@ impl RoutedResource for EntityResource {
             Router::new()
                 .route("/", post(create_entity::<S, A>).patch(patch_entity::<S, A>))
                 .route("/bulk", post(create_entities::<S, A>))
+                .route("/batch", post(execute_entity_batch::<S, A>))
                 .route(
                     "/relationships",
                     post(modify_entity_authorization_relationships::<A>),
                 )
                 .route("/diff", post(diff_entity::<S, A>))
+                .route("/history", post(get_entity_history::<S, A>))
+                .route("/erase", post(erase_entity::<S, A>))
+                .route("/merge", post(merge_entities::<S, A>))
+                .route("/upgrade-type", post(upgrade_entity_type::<S, A>))
                 .route("/validate", post(validate_entity::<S, A>))
                 .route("/embeddings", post(update_entity_embeddings::<S, A>))
                 .nest(
@@ -280,7 +351,10 @@ impl RoutedResource for EntityResource {
                     Router::new()
                         .route("/", post(get_entities::<S, A>))
                         .route("/subgraph", post(get_entity_subgraph::<S, A>))
-                        .route("/count", post(count_entities::<S, A>)),
+                        .route("/count", post(count_entities::<S, A>))
+                        .route("/paths", post(find_entity_paths::<S, A>))
+                        .route("/aggregate", post(aggregate_entities::<S, A>))
+                        .route("/explain", post(explain_entities::<S, A>)),
                 ),
         )
     }
@@ -295,7 +369,9 @@ impl RoutedResource for EntityResource {
         ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
     ),
     responses(
-        (status = 200, content_type = "application/json", description = "The created entity", body = Entity),
+        (status = 200, content_type = "application/json", description = "The created entity", body = Entity, headers(
+            ("ETag" = String, description = "The edition ID of the created entity"),
+        )),
         (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
 
         (status = 404, description = "Entity Type URL was not found"),
@@ -312,7 +388,7 @@ async fn create_entity<S, A>(
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
     Json(body): Json<serde_json::Value>,
-) -> Result<Json<Entity>, Response>
+) -> Result<Response, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
@@ -335,7 +411,7 @@ where
         .create_entity(actor_id, params)
         .await
         .map_err(report_to_response)
-        .map(Json)
+        .map(entity_response)
 }
 
 #[utoipa::path(
@@ -387,6 +463,68 @@ where
         .map(Json)
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/batch",
+    request_body = [EntityBatchOperation],
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The results of the operations in the order they were provided", body = [EntityBatchOperationResult]),
+        (status = 400, content_type = "application/json", description = "An operation refers to an entity which was not created earlier in the batch"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "Insufficient permissions to execute an operation"),
+        (status = 404, description = "An entity or entity type was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+)]
+#[tracing::instrument(level = "info", skip_all)]
+async fn execute_entity_batch<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(body): Json<serde_json::Value>,
+) -> Result<Json<Vec<EntityBatchOperationResult>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let operations = Vec::<EntityBatchOperation>::deserialize(&body)
+        .map_err(Report::from)
+        .map_err(report_to_response)?;
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .execute_entity_batch(actor_id, operations)
+        .await
+        .map_err(|report| {
+            if report.contains::<InvalidEntityBatchReference>() {
+                report.attach(hash_status::StatusCode::InvalidArgument)
+            } else if report.contains::<EntityDoesNotExist>() {
+                report.attach(hash_status::StatusCode::NotFound)
+            } else if report.contains::<RaceConditionOnUpdate>() {
+                report.attach(hash_status::StatusCode::Cancelled)
+            } else {
+                report
+            }
+        })
+        .map_err(report_to_response)
+        .map(Json)
+}
+
 #[utoipa::path(
     post,
     path = "/entities/validate",
@@ -666,9 +804,14 @@ impl<'q, 's, 'p: 'q> From<GetEntitiesRequest<'q, 's, 'p>> for GetEntitiesParams<
     responses(
         (
             status = 200,
-            content_type = "application/json",
-            body = GetEntitiesResponse,
-            description = "A list of entities that satisfy the given query.",
+            content(
+                ("application/json" = GetEntitiesResponse),
+                ("application/x-ndjson" = String),
+            ),
+            description = "A list of entities that satisfy the given query. If `application/x-ndjson` is accepted, the entities are streamed as one record per line, followed by a trailing record containing the cursor and the requested metadata.",
+            headers(
+                ("ETag" = String, description = "The edition ID of the entity if exactly one entity is returned as JSON"),
+            ),
         ),
         (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
         (status = 500, description = "Store error occurred"),
@@ -685,16 +828,56 @@ async fn get_entities<S, A>(
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
     mut query_logger: Option<Extension<QueryLogger>>,
+    headers: HeaderMap,
     Json(request): Json<serde_json::Value>,
-) -> Result<Json<GetEntitiesResponse<'static>>, Response>
+) -> Result<Response, Response>
 where
-    S: StorePool + Send + Sync,
-    A: AuthorizationApiPool + Send + Sync,
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
 {
     if let Some(query_logger) = &mut query_logger {
         query_logger.capture(actor_id, OpenApiQuery::GetEntities(&request));
     }
 
+    if accepts_ndjson(&headers) {
+        let (temporal_axes, cursor, limit) = {
+            let request = GetEntitiesRequest::deserialize(&request)
+                .map_err(Report::from)
+                .map_err(report_to_response)?;
+            (
+                request.temporal_axes.resolve(),
+                request.cursor.map(EntityQueryCursor::into_owned),
+                request.limit,
+            )
+        };
+
+        let authorization_api = authorization_api_pool
+            .acquire_owned()
+            .await
+            .map_err(report_to_response)?;
+
+        let store = store_pool
+            .acquire_owned(authorization_api, temporal_client.0)
+            .await
+            .map_err(report_to_response)?;
+
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.send().await.map_err(report_to_response)?;
+        }
+
+        return Ok(ndjson_response(
+            EntityQueryStream::new(
+                store,
+                actor_id,
+                request,
+                EntityQueryKind::Entities,
+                temporal_axes,
+                Pagination::new(cursor, limit),
+            )
+            .into_stream(),
+        ));
+    }
+
     let authorization_api = authorization_api_pool
         .acquire()
         .await
@@ -709,18 +892,18 @@ where
         .map_err(Report::from)
         .map_err(report_to_response)?;
 
-    if request.limit == Some(0) {
-        tracing::warn!(
-            %actor_id,
-            "The limit is set to zero, so no entities will be returned."
-        );
-    }
-
     let response = store
-        .get_entities(actor_id, request.into())
+        .get_entities(
+            actor_id,
+            operation::entity::get_entities_params(actor_id, request),
+        )
         .await
         .map(|response| {
-            Json(GetEntitiesResponse {
+            let entity_tag = match response.entities.as_slice() {
+                [entity] => Some(entity_tag(entity)),
+                _ => None,
+            };
+            let mut response = Json(GetEntitiesResponse {
                 entities: response.entities,
                 cursor: response.cursor.map(EntityQueryCursor::into_owned),
                 count: response.count,
@@ -732,6 +915,11 @@ where
                 type_ids: response.type_ids,
                 type_titles: response.type_titles,
             })
+            .into_response();
+            if let Some(entity_tag) = entity_tag {
+                response.headers_mut().insert(header::ETAG, entity_tag);
+            }
+            response
         })
         .map_err(report_to_response);
     if let Some(query_logger) = &mut query_logger {
@@ -831,6 +1019,221 @@ struct GetEntitySubgraphResponse<'r> {
     type_titles: Option<HashMap<VersionedUrl, String>>,
 }
 
+/// A single line of a streamed entity query.
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum EntityQueryRecord {
+    Entity(Entity),
+    Subgraph(Subgraph),
+    #[serde(rename_all = "camelCase")]
+    EntityTypes {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        closed_multi_entity_types: Option<HashMap<VersionedUrl, ClosedMultiEntityTypeMap>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        definitions: Option<EntityTypeResolveDefinitions>,
+    },
+    Trailer(EntityQueryTrailer),
+}
+
+impl EntityQueryRecord {
+    fn entity_types(
+        closed_multi_entity_types: Option<HashMap<VersionedUrl, ClosedMultiEntityTypeMap>>,
+        definitions: Option<EntityTypeResolveDefinitions>,
+    ) -> Option<Self> {
+        (closed_multi_entity_types.is_some() || definitions.is_some()).then_some(
+            Self::EntityTypes {
+                closed_multi_entity_types,
+                definitions,
+            },
+        )
+    }
+}
+
+/// The last line of a streamed entity query.
+///
+/// The metadata is computed over the whole query, the cursor can be used to continue reading if
+/// the requested limit was reached.
+#[derive(Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EntityQueryTrailer {
+    cursor: Option<EntityQueryCursor<'static>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    web_ids: Option<HashMap<OwnedById, usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    created_by_ids: Option<HashMap<ActorEntityUuid, usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    edition_created_by_ids: Option<HashMap<ActorEntityUuid, usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    type_ids: Option<HashMap<VersionedUrl, usize>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    type_titles: Option<HashMap<VersionedUrl, String>>,
+}
+
+#[derive(Debug, Copy, Clone)]
+enum EntityQueryKind {
+    Entities,
+    Subgraph,
+}
+
+/// Reads the results of an entity query page by page, so only a single page is kept in memory.
+///
+/// The temporal axes are resolved once when the stream is created, so every page is read at the
+/// same point in time and the pages are consistent with each other even if the graph is written to
+/// while the stream is read.
+struct EntityQueryStream<T> {
+    store: T,
+    actor_id: ActorEntityUuid,
+    request: serde_json::Value,
+    kind: EntityQueryKind,
+    temporal_axes: QueryTemporalAxes,
+    pagination: Pagination<EntityQueryCursor<'static>>,
+    trailer: Option<EntityQueryTrailer>,
+}
+
+impl<T> EntityQueryStream<T>
+where
+    T: EntityStore + Send + Sync + 'static,
+{
+    fn new(
+        store: T,
+        actor_id: ActorEntityUuid,
+        request: serde_json::Value,
+        kind: EntityQueryKind,
+        temporal_axes: QueryTemporalAxes,
+        pagination: Pagination<EntityQueryCursor<'static>>,
+    ) -> Self {
+        Self {
+            store,
+            actor_id,
+            request,
+            kind,
+            temporal_axes,
+            pagination,
+            // A trailer is sent even if no page is read, e.g. if the limit is zero.
+            trailer: Some(EntityQueryTrailer::default()),
+        }
+    }
+
+    async fn read_entities(&mut self) -> Result<Vec<EntityQueryRecord>, Report<QueryError>> {
+        let mut request =
+            GetEntitiesRequest::deserialize(&self.request).change_context(QueryError)?;
+        request.temporal_axes = self.temporal_axes.clone().into();
+        request.limit = Some(self.pagination.limit());
+        request.cursor = self.pagination.cursor();
+
+        // The metadata spans the whole query, so it's only requested alongside the first page.
+        let first = self.pagination.is_first();
+        request.include_count &= first;
+        request.include_web_ids &= first;
+        request.include_created_by_ids &= first;
+        request.include_edition_created_by_ids &= first;
+        request.include_type_ids &= first;
+        request.include_type_titles &= first;
+
+        let response = self
+            .store
+            .get_entities(self.actor_id, request.into())
+            .await?;
+
+        self.pagination.advance(
+            response.entities.len(),
+            response.cursor.map(EntityQueryCursor::into_owned),
+        );
+        if first {
+            self.trailer = Some(EntityQueryTrailer {
+                cursor: None,
+                count: response.count,
+                web_ids: response.web_ids,
+                created_by_ids: response.created_by_ids,
+                edition_created_by_ids: response.edition_created_by_ids,
+                type_ids: response.type_ids,
+                type_titles: response.type_titles,
+            });
+        }
+
+        Ok(EntityQueryRecord::entity_types(
+            response.closed_multi_entity_types,
+            response.definitions,
+        )
+        .into_iter()
+        .chain(response.entities.into_iter().map(EntityQueryRecord::Entity))
+        .collect())
+    }
+
+    async fn read_subgraph(&mut self) -> Result<Vec<EntityQueryRecord>, Report<QueryError>> {
+        let mut request =
+            GetEntitySubgraphRequest::deserialize(&self.request).change_context(QueryError)?;
+        request.temporal_axes = self.temporal_axes.clone().into();
+        request.limit = Some(self.pagination.limit());
+        request.cursor = self.pagination.cursor();
+
+        // The metadata spans the whole query, so it's only requested alongside the first page.
+        let first = self.pagination.is_first();
+        request.include_count &= first;
+        request.include_web_ids &= first;
+        request.include_created_by_ids &= first;
+        request.include_edition_created_by_ids &= first;
+        request.include_type_ids &= first;
+        request.include_type_titles &= first;
+
+        let response = self
+            .store
+            .get_entity_subgraph(self.actor_id, request.into())
+            .await?;
+
+        self.pagination.advance(
+            response.subgraph.roots.len(),
+            response.cursor.map(EntityQueryCursor::into_owned),
+        );
+        if first {
+            self.trailer = Some(EntityQueryTrailer {
+                cursor: None,
+                count: response.count,
+                web_ids: response.web_ids,
+                created_by_ids: response.created_by_ids,
+                edition_created_by_ids: response.edition_created_by_ids,
+                type_ids: response.type_ids,
+                type_titles: response.type_titles,
+            });
+        }
+
+        Ok(EntityQueryRecord::entity_types(
+            response.closed_multi_entity_types,
+            response.definitions,
+        )
+        .into_iter()
+        .chain([EntityQueryRecord::Subgraph(response.subgraph.into())])
+        .collect())
+    }
+
+    /// Reads the next page, or returns the trailer after the last page has been read.
+    async fn next_page(&mut self) -> Result<Option<Vec<EntityQueryRecord>>, Report<QueryError>> {
+        if self.pagination.is_finished() {
+            return Ok(self.trailer.take().map(|trailer| {
+                vec![EntityQueryRecord::Trailer(EntityQueryTrailer {
+                    cursor: self.pagination.cursor(),
+                    ..trailer
+                })]
+            }));
+        }
+
+        match self.kind {
+            EntityQueryKind::Entities => self.read_entities().await.map(Some),
+            EntityQueryKind::Subgraph => self.read_subgraph().await.map(Some),
+        }
+    }
+
+    fn into_stream(
+        self,
+    ) -> impl Stream<Item = Result<EntityQueryRecord, Report<QueryError>>> + Send + 'static {
+        flatten_pages(stream::try_unfold(self, |mut state| async move {
+            Ok(state.next_page().await?.map(|records| (records, state)))
+        }))
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/entities/query/subgraph",
@@ -844,9 +1247,11 @@ struct GetEntitySubgraphResponse<'r> {
     responses(
         (
             status = 200,
-            content_type = "application/json",
-            body = GetEntitySubgraphResponse,
-            description = "A subgraph rooted at entities that satisfy the given query, each resolved to the requested depth.",
+            content(
+                ("application/json" = GetEntitySubgraphResponse),
+                ("application/x-ndjson" = String),
+            ),
+            description = "A subgraph rooted at entities that satisfy the given query, each resolved to the requested depth. If `application/x-ndjson` is accepted, one subgraph is streamed per page of root entities, followed by a trailing record containing the cursor and the requested metadata.",
         ),
         (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
         (status = 500, description = "Store error occurred"),
@@ -863,16 +1268,56 @@ async fn get_entity_subgraph<S, A>(
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
     mut query_logger: Option<Extension<QueryLogger>>,
+    headers: HeaderMap,
     Json(request): Json<serde_json::Value>,
-) -> Result<Json<GetEntitySubgraphResponse<'static>>, Response>
+) -> Result<Response, Response>
 where
-    S: StorePool + Send + Sync,
-    A: AuthorizationApiPool + Send + Sync,
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
 {
     if let Some(query_logger) = &mut query_logger {
         query_logger.capture(actor_id, OpenApiQuery::GetEntitySubgraph(&request));
     }
 
+    if accepts_ndjson(&headers) {
+        let (temporal_axes, cursor, limit) = {
+            let request = GetEntitySubgraphRequest::deserialize(&request)
+                .map_err(Report::from)
+                .map_err(report_to_response)?;
+            (
+                request.temporal_axes.resolve(),
+                request.cursor.map(EntityQueryCursor::into_owned),
+                request.limit,
+            )
+        };
+
+        let authorization_api = authorization_api_pool
+            .acquire_owned()
+            .await
+            .map_err(report_to_response)?;
+
+        let store = store_pool
+            .acquire_owned(authorization_api, temporal_client.0)
+            .await
+            .map_err(report_to_response)?;
+
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.send().await.map_err(report_to_response)?;
+        }
+
+        return Ok(ndjson_response(
+            EntityQueryStream::new(
+                store,
+                actor_id,
+                request,
+                EntityQueryKind::Subgraph,
+                temporal_axes,
+                Pagination::new(cursor, limit),
+            )
+            .into_stream(),
+        ));
+    }
+
     let authorization_api = authorization_api_pool
         .acquire()
         .await
@@ -910,6 +1355,7 @@ where
                 type_ids: response.type_ids,
                 type_titles: response.type_titles,
             })
+            .into_response()
         })
         .map_err(report_to_response);
     if let Some(query_logger) = &mut query_logger {
@@ -983,111 +1429,390 @@ where
     response
 }
 
+#[derive(Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct FindEntityPathsResponse {
+    paths: Vec<EntityPath>,
+    subgraph: Subgraph,
+}
+
 #[utoipa::path(
-    patch,
-    path = "/entities",
+    post,
+    path = "/entities/query/paths",
+    request_body = FindEntityPathsParams,
     tag = "Entity",
     params(
         ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
     ),
     responses(
-        (status = 200, content_type = "application/json", description = "The updated entity", body = Entity),
-        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
-        (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
-
-        (status = 404, description = "Entity ID or Entity Type URL was not found"),
+        (
+            status = 200,
+            content_type = "application/json",
+            body = FindEntityPathsResponse,
+            description = "The paths between the two entities, shortest first, and a subgraph containing the entities on the paths",
+        ),
+        (status = 400, content_type = "text/plain", description = "`maxDepth` or `limit` exceeds the maximum supported by the server"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
         (status = 500, description = "Store error occurred"),
-    ),
-    request_body = PatchEntityParams,
+    )
 )]
 #[tracing::instrument(
     level = "info",
-    skip(store_pool, authorization_api_pool, temporal_client)
+    skip(store_pool, authorization_api_pool, temporal_client, request)
 )]
-async fn patch_entity<S, A>(
+async fn find_entity_paths<S, A>(
     AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
-    Json(params): Json<PatchEntityParams>,
-) -> Result<Json<Entity>, Response>
+    mut query_logger: Option<Extension<QueryLogger>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<FindEntityPathsResponse>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.capture(actor_id, OpenApiQuery::FindEntityPaths(&request));
+    }
+
     let authorization_api = authorization_api_pool
         .acquire()
         .await
         .map_err(report_to_response)?;
 
-    let mut store = store_pool
+    let store = store_pool
         .acquire(authorization_api, temporal_client.0)
         .await
         .map_err(report_to_response)?;
 
-    store
-        .patch_entity(actor_id, params)
+    let response = store
+        .find_entity_paths(
+            actor_id,
+            FindEntityPathsParams::deserialize(&request)
+                .map_err(Report::from)
+                .map_err(report_to_response)?,
+        )
         .await
-        .map_err(|report| {
-            if report.contains::<EntityDoesNotExist>() {
-                report.attach(hash_status::StatusCode::NotFound)
-            } else if report.contains::<RaceConditionOnUpdate>() {
-                report.attach(hash_status::StatusCode::Cancelled)
-            } else {
-                report
-            }
+        .map(|response| {
+            Json(FindEntityPathsResponse {
+                paths: response.paths,
+                subgraph: response.subgraph.into(),
+            })
         })
-        .map_err(report_to_response)
-        .map(Json)
+        .map_err(report_to_response);
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.send().await.map_err(report_to_response)?;
+    }
+    response
 }
 
 #[utoipa::path(
     post,
-    path = "/entities/embeddings",
+    path = "/entities/query/aggregate",
+    request_body = AggregateEntitiesParams,
     tag = "Entity",
     params(
         ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
     ),
     responses(
-        (status = 204, content_type = "application/json", description = "The embeddings were created"),
-
-        (status = 403, description = "Insufficient permissions to update the entity"),
+        (
+            status = 200,
+            content_type = "application/json",
+            body = AggregateEntitiesResponse,
+            description = "The aggregated values for every group of entities",
+        ),
+        (status = 400, content_type = "application/json", description = "An aggregation cannot be applied to the requested path"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
         (status = 500, description = "Store error occurred"),
-    ),
-    request_body = UpdateEntityEmbeddingsParams,
+    )
 )]
 #[tracing::instrument(
     level = "info",
-    skip(store_pool, authorization_api_pool, temporal_client, body)
+    skip(store_pool, authorization_api_pool, temporal_client, request)
 )]
-async fn update_entity_embeddings<S, A>(
+async fn aggregate_entities<S, A>(
     AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
     store_pool: Extension<Arc<S>>,
     authorization_api_pool: Extension<Arc<A>>,
     temporal_client: Extension<Option<Arc<TemporalClient>>>,
-    Json(body): Json<serde_json::Value>,
-) -> Result<(), Response>
+    mut query_logger: Option<Extension<QueryLogger>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<AggregateEntitiesResponse>, Response>
 where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
-    // Manually deserialize the request from a JSON value to allow borrowed deserialization and
-    // better error reporting.
-    let params = UpdateEntityEmbeddingsParams::deserialize(body)
-        .attach(hash_status::StatusCode::InvalidArgument)
-        .map_err(report_to_response)?;
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.capture(actor_id, OpenApiQuery::AggregateEntities(&request));
+    }
 
     let authorization_api = authorization_api_pool
         .acquire()
         .await
         .map_err(report_to_response)?;
 
-    let mut store = store_pool
+    let store = store_pool
         .acquire(authorization_api, temporal_client.0)
         .await
         .map_err(report_to_response)?;
 
-    store
+    let response = store
+        .aggregate_entities(
+            actor_id,
+            AggregateEntitiesParams::deserialize(&request)
+                .map_err(Report::from)
+                .map_err(report_to_response)?,
+        )
+        .await
+        .map(Json)
+        .map_err(report_to_response);
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.send().await.map_err(report_to_response)?;
+    }
+    response
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/query/explain",
+    request_body = GetEntitiesRequest,
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = QueryExplanation,
+            description = "The compiled query for entities and its execution plan",
+        ),
+        (status = 403, description = "The actor is not an instance administrator"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(
+        store_pool,
+        authorization_api_pool,
+        temporal_client,
+        instance_administrators,
+        request
+    )
+)]
+async fn explain_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    instance_administrators: Option<Extension<InstanceAdministrators>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<QueryExplanation>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+    for<'pool> S::Store<'pool, A::Api<'pool>>: RestApiStore,
+{
+    explain_query(
+        actor_id,
+        &*store_pool,
+        &*authorization_api_pool,
+        temporal_client.0,
+        instance_administrators.map(|Extension(administrators)| administrators),
+        || {
+            let request = GetEntitiesRequest::deserialize(&request)?;
+            Ok(ExplainedQuery {
+                sorting: generate_sorting_paths(
+                    request.sorting_paths,
+                    request.limit,
+                    request.cursor,
+                    &request.temporal_axes,
+                ),
+                filter: request.filter,
+                temporal_axes: request.temporal_axes,
+                limit: request.limit,
+                include_drafts: request.include_drafts,
+            })
+        },
+    )
+    .await
+    .map(Json)
+}
+
+#[utoipa::path(
+    patch,
+    path = "/entities",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+        ("If-Match" = Option<String>, Header, description = "The entity tag of the edition the entity is expected to be at. The patch is only applied if this is the current edition of the entity"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The updated entity", body = Entity, headers(
+            ("ETag" = String, description = "The edition ID of the updated entity"),
+        )),
+        (status = 400, content_type = "application/json", description = "The `If-Match` header is invalid"),
+        (status = 412, content_type = "application/json", description = "The current edition of the entity does not match the expected edition"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+        (status = 423, content_type = "text/plain", description = "The entity that should be updated was unexpectedly updated at the same time"),
+
+        (status = 404, description = "Entity ID or Entity Type URL was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = PatchEntityParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, headers)
+)]
+async fn patch_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    headers: HeaderMap,
+    Json(mut params): Json<PatchEntityParams>,
+) -> Result<Response, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    if let Some(expected_edition_id) = if_match_edition_id(&headers).map_err(|report| {
+        report_to_response(report.attach(hash_status::StatusCode::InvalidArgument))
+    })? {
+        if params
+            .expected_edition_id
+            .is_some_and(|edition_id| edition_id != expected_edition_id)
+        {
+            return Err(report_to_response(
+                Report::new(InvalidIfMatchHeader)
+                    .attach_printable("The entity tag conflicts with `expectedEditionId`")
+                    .attach(hash_status::StatusCode::InvalidArgument),
+            ));
+        }
+        params.expected_edition_id = Some(expected_edition_id);
+    }
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .patch_entity(actor_id, params)
+        .await
+        .map_err(|report| {
+            if report.contains::<EntityEditionMismatch>() {
+                let mut response = report_to_response(report);
+                *response.status_mut() = StatusCode::PRECONDITION_FAILED;
+                return response;
+            }
+
+            report_to_response(operation::entity::patch_entity_status(report))
+        })
+        .map(entity_response)
+}
+
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+#[display("The `If-Match` header must be `*` or a single strong entity tag")]
+struct InvalidIfMatchHeader;
+
+/// Returns the entity tag of an entity, which is its quoted edition ID.
+fn entity_tag(entity: &Entity) -> HeaderValue {
+    HeaderValue::try_from(format!(
+        "\"{}\"",
+        entity.metadata.record_id.edition_id.as_uuid()
+    ))
+    .expect("a quoted UUID should be a valid header value")
+}
+
+/// Responds with the entity and its edition ID as `ETag`.
+fn entity_response(entity: Entity) -> Response {
+    let entity_tag = entity_tag(&entity);
+    let mut response = Json(entity).into_response();
+    response.headers_mut().insert(header::ETAG, entity_tag);
+    response
+}
+
+/// Parses the edition ID an entity is expected to be at from the `If-Match` header.
+///
+/// The wildcard `*` matches any edition. As an entity only has a single current edition, lists of
+/// entity tags and weak entity tags are not supported.
+fn if_match_edition_id(
+    headers: &HeaderMap,
+) -> Result<Option<EntityEditionId>, Report<InvalidIfMatchHeader>> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+    let value = value.to_str().change_context(InvalidIfMatchHeader)?.trim();
+    if value == "*" {
+        return Ok(None);
+    }
+
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(|| Report::new(InvalidIfMatchHeader))?
+        .parse::<Uuid>()
+        .change_context(InvalidIfMatchHeader)
+        .map(|edition_id| Some(EntityEditionId::new(edition_id)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/embeddings",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 204, content_type = "application/json", description = "The embeddings were created"),
+
+        (status = 403, description = "Insufficient permissions to update the entity"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = UpdateEntityEmbeddingsParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client, body)
+)]
+async fn update_entity_embeddings<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(body): Json<serde_json::Value>,
+) -> Result<(), Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    // Manually deserialize the request from a JSON value to allow borrowed deserialization and
+    // better error reporting.
+    let params = UpdateEntityEmbeddingsParams::deserialize(body)
+        .attach(hash_status::StatusCode::InvalidArgument)
+        .map_err(report_to_response)?;
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
         .update_entity_embeddings(actor_id, params)
         .await
         .map_err(report_to_response)
@@ -1157,6 +1882,224 @@ where
     response
 }
 
+#[utoipa::path(
+    post,
+    path = "/entities/history",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The editions of the entity", body = GetEntityHistoryResponse),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "The actor is not allowed to view the entity"),
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = GetEntityHistoryParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn get_entity_history<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    mut query_logger: Option<Extension<QueryLogger>>,
+    Json(params): Json<GetEntityHistoryParams>,
+) -> Result<Json<GetEntityHistoryResponse<'static>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.capture(actor_id, OpenApiQuery::GetEntityHistory(&params));
+    }
+
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    let response = store
+        .get_entity_history(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json);
+    if let Some(query_logger) = &mut query_logger {
+        query_logger.send().await.map_err(report_to_response)?;
+    }
+    response
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/erase",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The tombstones of the erased entities", body = [EntityTombstone]),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "The actor is not an instance administrator"),
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = EraseEntityParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(
+        store_pool,
+        authorization_api_pool,
+        temporal_client,
+        instance_administrators
+    )
+)]
+async fn erase_entity<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    instance_administrators: Option<Extension<InstanceAdministrators>>,
+    Json(params): Json<EraseEntityParams>,
+) -> Result<Json<Vec<EntityTombstone>>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    assert_instance_administrator(
+        actor_id,
+        &authorization_api,
+        instance_administrators.map(|Extension(administrators)| administrators),
+    )
+    .await?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .erase_entity(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/merge",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The surviving entity, the archived entity and the links which replace its links", body = MergeEntitiesResponse),
+        (status = 400, content_type = "text/plain", description = "The entity was requested to be merged into itself"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 403, description = "The actor is not allowed to update both entities or the links of the merged entity"),
+        (status = 404, description = "Entity ID was not found"),
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = MergeEntitiesParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn merge_entities<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<MergeEntitiesParams>,
+) -> Result<Json<MergeEntitiesResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .merge_entities(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
+#[utoipa::path(
+    post,
+    path = "/entities/upgrade-type",
+    tag = "Entity",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 200, content_type = "application/json", description = "The upgraded entities and the entities which could not be upgraded", body = UpgradeEntityTypeResponse),
+        (status = 400, content_type = "text/plain", description = "The batch size is invalid"),
+        (status = 422, content_type = "text/plain", description = "Provided request body is invalid"),
+
+        (status = 500, description = "Store error occurred"),
+    ),
+    request_body = UpgradeEntityTypeParams,
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn upgrade_entity_type<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    Json(params): Json<UpgradeEntityTypeParams>,
+) -> Result<Json<UpgradeEntityTypeResponse>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .upgrade_entity_type(actor_id, params)
+        .await
+        .map_err(report_to_response)
+        .map(Json)
+}
+
 #[utoipa::path(
     get,
     path = "/entities/{entity_id}/relationships",
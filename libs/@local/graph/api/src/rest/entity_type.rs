@@ -21,20 +21,17 @@ use hash_graph_authorization::{
     },
     zanzibar::Consistency,
 };
-use hash_graph_postgres_store::{
-    ontology::patch_id_and_parse,
-    store::error::{BaseUrlAlreadyExists, OntologyVersionDoesNotExist, VersionedUrlAlreadyExists},
-};
+use hash_graph_postgres_store::store::error::BaseUrlAlreadyExists;
 use hash_graph_store::{
     entity_type::{
         ArchiveEntityTypeParams, CreateEntityTypeParams, EntityTypeQueryToken,
         EntityTypeResolveDefinitions, EntityTypeStore as _, GetClosedMultiEntityTypesParams,
         GetClosedMultiEntityTypesResponse, GetEntityTypeSubgraphParams, GetEntityTypesParams,
         GetEntityTypesResponse, IncludeEntityTypeOption, IncludeResolvedEntityTypeOption,
-        UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
+        UnarchiveEntityTypeParams, UpdateEntityTypeEmbeddingParams,
     },
     pool::StorePool,
-    query::ConflictBehavior,
+    query::{ConflictBehavior, QueryExplanation, VersionedUrlSorting},
 };
 use hash_graph_type_defs::error::{ErrorInfo, Status, StatusPayloads};
 use hash_graph_types::ontology::EntityTypeEmbedding;
@@ -46,8 +43,8 @@ use type_system::{
     ontology::{
         OntologyTemporalMetadata, OntologyTypeMetadata, OntologyTypeReference,
         entity_type::{EntityType, EntityTypeMetadata, EntityTypeUuid, EntityTypeWithMetadata},
-        id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
-        json_schema::{DomainValidator, ValidateOntologyType as _},
+        id::{BaseUrl, VersionedUrl},
+        json_schema::DomainValidator,
         provenance::{OntologyOwnership, ProvidedOntologyEditionProvenance},
     },
     provenance::ActorEntityUuid,
@@ -55,12 +52,17 @@ use type_system::{
 };
 use utoipa::{OpenApi, ToSchema};
 
-use crate::rest::{
-    AuthenticatedUserHeader, OpenApiQuery, PermissionResponse, QueryLogger, RestApiStore,
-    api_resource::RoutedResource,
-    json::Json,
-    status::{report_to_response, status_to_response},
-    utoipa_typedef::{ListOrValue, MaybeListOfEntityType, subgraph::Subgraph},
+use crate::{
+    operation,
+    rest::{
+        AuthenticatedUserHeader, ExplainedQuery, InstanceAdministrators, OpenApiQuery,
+        PermissionResponse, QueryLogger, RestApiStore,
+        api_resource::RoutedResource,
+        explain_query,
+        json::Json,
+        status::{report_to_response, status_to_response},
+        utoipa_typedef::{ListOrValue, MaybeListOfEntityType, subgraph::Subgraph},
+    },
 };
 
 #[derive(OpenApi)]
@@ -74,6 +76,7 @@ use crate::rest::{
         load_external_entity_type,
         get_entity_types,
         get_entity_type_subgraph,
+        explain_entity_types,
         get_closed_multi_entity_types,
         update_entity_type,
         update_entity_types,
@@ -158,7 +161,8 @@ impl RoutedResource for EntityTypeResource {
                     Router::new()
                         .route("/", post(get_entity_types::<S, A>))
                         .route("/multi", post(get_closed_multi_entity_types::<S, A>))
-                        .route("/subgraph", post(get_entity_type_subgraph::<S, A>)),
+                        .route("/subgraph", post(get_entity_type_subgraph::<S, A>))
+                        .route("/explain", post(explain_entity_types::<S, A>)),
                 )
                 .route("/load", post(load_external_entity_type::<S, A>))
                 .route("/archive", put(archive_entity_type::<S, A>))
@@ -170,12 +174,12 @@ impl RoutedResource for EntityTypeResource {
 
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct CreateEntityTypeRequest {
+pub struct CreateEntityTypeRequest {
     #[schema(inline)]
-    schema: MaybeListOfEntityType,
-    owned_by_id: OwnedById,
-    relationships: Vec<EntityTypeRelationAndSubject>,
-    provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) schema: MaybeListOfEntityType,
+    pub(crate) owned_by_id: OwnedById,
+    pub(crate) relationships: Vec<EntityTypeRelationAndSubject>,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -444,49 +448,40 @@ where
             ))
         })?;
 
-    let Json(CreateEntityTypeRequest {
-        schema,
-        owned_by_id,
-        relationships,
-        provenance,
-    }) = body;
+    let Json(request) = body;
 
-    let is_list = matches!(&schema, ListOrValue::List(_));
+    let is_list = matches!(&request.schema, ListOrValue::List(_));
+
+    let params =
+        operation::entity_type::create_params(request, &domain_validator).map_err(|report| {
+            let entity_type_id = report
+                .request_ref::<VersionedUrl>()
+                .next()
+                .expect("the entity type ID should be attached to the report");
+            tracing::error!(error=?report, id=%entity_type_id, "Entity Type ID failed to validate");
+            status_to_response(Status::new(
+                hash_status::StatusCode::InvalidArgument,
+                Some(
+                    "Entity Type ID failed to validate against the given domain regex. Are you \
+                     sure the service is able to host a type under the domain you supplied?"
+                        .to_owned(),
+                ),
+                vec![StatusPayloads::ErrorInfo(ErrorInfo::new(
+                    HashMap::from([(
+                        "entityTypeId".to_owned(),
+                        serde_json::to_value(entity_type_id)
+                            .expect("Could not serialize entity type id"),
+                    )]),
+                    // TODO: We should encapsulate these Reasons within the type system, perhaps
+                    //       requiring top level contexts to implement a trait
+                    // `ErrorReason::to_reason`       or perhaps as a big enum
+                    "INVALID_TYPE_ID".to_owned(),
+                ))],
+            ))
+        })?;
 
     let mut metadata = store
-        .create_entity_types(
-            actor_id,
-            schema.into_iter().map(|schema| {
-                domain_validator.validate(&schema).map_err(|report| {
-                    tracing::error!(error=?report, id=%schema.id, "Entity Type ID failed to validate");
-                    status_to_response(Status::new(
-                        hash_status::StatusCode::InvalidArgument,
-                        Some("Entity Type ID failed to validate against the given domain regex. Are you sure the service is able to host a type under the domain you supplied?".to_owned()),
-                        vec![StatusPayloads::ErrorInfo(ErrorInfo::new(
-                            HashMap::from([
-                                (
-                                    "entityTypeId".to_owned(),
-                                    serde_json::to_value(&schema.id)
-                                        .expect("Could not serialize entity type id"),
-                                ),
-                            ]),
-                            // TODO: We should encapsulate these Reasons within the type system, perhaps
-                            //       requiring top level contexts to implement a trait `ErrorReason::to_reason`
-                            //       or perhaps as a big enum
-                            "INVALID_TYPE_ID".to_owned()
-                        ))],
-                    ))
-                })?;
-
-                Ok(CreateEntityTypeParams {
-                    schema,
-                    ownership: OntologyOwnership::Local { owned_by_id },
-                    relationships: relationships.clone(),
-                    conflict_behavior: ConflictBehavior::Fail,
-                    provenance: provenance.clone(),
-                })
-            }).collect::<Result<Vec<_>, _>>()?
-        )
+        .create_entity_types(actor_id, params)
         .await
         .map_err(|report| {
             tracing::error!(error=?report, "Could not create entity types");
@@ -523,7 +518,8 @@ where
                         metadata,
                         // TODO: We should encapsulate these Reasons within the type system,
                         //       perhaps requiring top level contexts to implement a trait
-                        //       `ErrorReason::to_reason` or perhaps as a big enum, or as an attachment
+                        //       `ErrorReason::to_reason` or perhaps as a big enum, or as an
+                        // attachment
                         "BASE_URI_ALREADY_EXISTS".to_owned(),
                     ))],
                 ));
@@ -924,12 +920,78 @@ where
 
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct UpdateEntityTypeRequest {
+pub struct UpdateEntityTypeRequest {
     #[schema(value_type = UpdateEntityType)]
-    schema: serde_json::Value,
-    type_to_update: VersionedUrl,
-    relationships: Vec<EntityTypeRelationAndSubject>,
-    provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) schema: serde_json::Value,
+    pub(crate) type_to_update: VersionedUrl,
+    pub(crate) relationships: Vec<EntityTypeRelationAndSubject>,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
+}
+
+#[utoipa::path(
+    post,
+    path = "/entity-types/query/explain",
+    request_body = GetEntityTypesParams,
+    tag = "EntityType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = QueryExplanation,
+            description = "The compiled query for entity types and its execution plan",
+        ),
+        (status = 403, description = "The actor is not an instance administrator"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(
+        store_pool,
+        authorization_api_pool,
+        temporal_client,
+        instance_administrators,
+        request
+    )
+)]
+async fn explain_entity_types<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    instance_administrators: Option<Extension<InstanceAdministrators>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<QueryExplanation>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+    for<'pool> S::Store<'pool, A::Api<'pool>>: RestApiStore,
+{
+    explain_query(
+        actor_id,
+        &*store_pool,
+        &*authorization_api_pool,
+        temporal_client.0,
+        instance_administrators.map(|Extension(administrators)| administrators),
+        || {
+            let params = GetEntityTypesParams::deserialize(&request)?;
+            Ok(ExplainedQuery {
+                filter: params.filter,
+                temporal_axes: params.temporal_axes,
+                sorting: VersionedUrlSorting {
+                    cursor: params.after,
+                },
+                limit: params.limit,
+                include_drafts: params.include_drafts,
+            })
+        },
+    )
+    .await
+    .map(Json)
 }
 
 #[utoipa::path(
@@ -963,16 +1025,9 @@ where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
-    let Json(UpdateEntityTypeRequest {
-        schema,
-        mut type_to_update,
-        relationships,
-        provenance,
-    }) = body;
+    let Json(request) = body;
 
-    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
-
-    let entity_type = patch_id_and_parse(&type_to_update, schema).map_err(report_to_response)?;
+    let params = operation::entity_type::update_params(request).map_err(report_to_response)?;
 
     let authorization_api = authorization_api_pool
         .acquire()
@@ -985,14 +1040,7 @@ where
         .map_err(report_to_response)?;
 
     store
-        .update_entity_type(
-            actor_id,
-            UpdateEntityTypesParams {
-                schema: entity_type,
-                relationships,
-                provenance,
-            },
-        )
+        .update_entity_type(actor_id, params)
         .await
         .map_err(report_to_response)
         .map(Json)
@@ -1042,25 +1090,9 @@ where
     let params = bodies
         .0
         .into_iter()
-        .map(
-            |UpdateEntityTypeRequest {
-                 schema,
-                 mut type_to_update,
-                 relationships,
-                 provenance,
-             }| {
-                type_to_update.version =
-                    OntologyTypeVersion::new(type_to_update.version.inner() + 1);
-
-                Ok(UpdateEntityTypesParams {
-                    schema: patch_id_and_parse(&type_to_update, schema)
-                        .map_err(report_to_response)?,
-                    relationships,
-                    provenance,
-                })
-            },
-        )
-        .collect::<Result<Vec<_>, Response>>()?;
+        .map(operation::entity_type::update_params)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(report_to_response)?;
     store
         .update_entity_types(actor_id, params)
         .await
@@ -1171,15 +1203,7 @@ where
     store
         .archive_entity_type(actor_id, params)
         .await
-        .map_err(|mut report| {
-            if report.contains::<OntologyVersionDoesNotExist>() {
-                report = report.attach(hash_status::StatusCode::NotFound);
-            }
-            if report.contains::<VersionedUrlAlreadyExists>() {
-                report = report.attach(hash_status::StatusCode::AlreadyExists);
-            }
-            report_to_response(report)
-        })
+        .map_err(|report| report_to_response(operation::archive_ontology_type_status(report)))
         .map(Json)
 }
 
@@ -1234,14 +1258,6 @@ where
     store
         .unarchive_entity_type(actor_id, params)
         .await
-        .map_err(|mut report| {
-            if report.contains::<OntologyVersionDoesNotExist>() {
-                report = report.attach(hash_status::StatusCode::NotFound);
-            }
-            if report.contains::<VersionedUrlAlreadyExists>() {
-                report = report.attach(hash_status::StatusCode::AlreadyExists);
-            }
-            report_to_response(report)
-        })
+        .map_err(|report| report_to_response(operation::archive_ontology_type_status(report)))
         .map(Json)
 }
@@ -3,6 +3,7 @@
 //! Handler methods are grouped by routes that make up the REST API.
 
 pub mod account;
+pub mod change_feed;
 pub mod data_type;
 pub mod entity;
 pub mod entity_type;
@@ -13,6 +14,7 @@ pub mod web;
 
 mod api_resource;
 mod json;
+mod ndjson;
 mod utoipa_typedef;
 use alloc::{borrow::Cow, sync::Arc};
 use core::str::FromStr as _;
@@ -30,21 +32,24 @@ use error_stack::{Report, ResultExt as _};
 use futures::{SinkExt as _, channel::mpsc::Sender};
 use hash_codec::numeric::Real;
 use hash_graph_authorization::{
-    AuthorizationApiPool,
+    AuthorizationApi, AuthorizationApiPool,
+    backend::PermissionAssertion,
     schema::{
         AccountGroupPermission, DataTypePermission, EntityPermission, EntityTypePermission,
         PropertyTypePermission,
     },
+    zanzibar::Consistency,
 };
 use hash_graph_postgres_store::store::error::VersionedUrlAlreadyExists;
 use hash_graph_store::{
     account::AccountStore,
     data_type::DataTypeStore,
-    entity::{DiffEntityParams, EntityStore},
+    entity::{DiffEntityParams, EntityQuerySorting, EntityStore, GetEntityHistoryParams},
     entity_type::EntityTypeStore,
-    filter::{ParameterConversion, Selector},
+    filter::{Filter, ParameterConversion, QueryRecord, Selector},
     pool::StorePool,
     property_type::PropertyTypeStore,
+    query::{ExplainQuery, JoinedTable, QueryExplanation, Sorting, VersionedUrlSorting},
     subgraph::{
         edges::{
             EdgeResolveDepths, GraphResolveDepths, KnowledgeGraphEdgeKind, OntologyEdgeKind,
@@ -72,14 +77,18 @@ use sentry::integrations::tower::{NewSentryLayer, SentryHttpLayer};
 use serde::{Deserialize, Serialize};
 use serde_json::{Number as JsonNumber, Value as JsonValue};
 use type_system::{
-    knowledge::entity::{EntityId, id::EntityUuid},
+    knowledge::{
+        Entity,
+        entity::{EntityId, id::EntityUuid},
+    },
     ontology::{
-        OntologyTemporalMetadata, OntologyTypeMetadata, OntologyTypeReference,
-        data_type::DataTypeMetadata,
+        EntityTypeWithMetadata, OntologyTemporalMetadata, OntologyTypeMetadata,
+        OntologyTypeReference,
+        data_type::{DataTypeMetadata, DataTypeWithMetadata},
         entity_type::EntityTypeMetadata,
         id::{BaseUrl, OntologyTypeRecordId, OntologyTypeVersion, VersionedUrl},
         json_schema::DomainValidator,
-        property_type::PropertyTypeMetadata,
+        property_type::{PropertyTypeMetadata, PropertyTypeWithMetadata},
         provenance::{
             OntologyEditionProvenance, OntologyProvenance, ProvidedOntologyEditionProvenance,
         },
@@ -140,7 +149,16 @@ pub struct PermissionResponse {
 }
 
 pub trait RestApiStore:
-    AccountStore + DataTypeStore + PropertyTypeStore + EntityTypeStore + EntityStore + TypeFetcher
+    AccountStore
+    + DataTypeStore
+    + PropertyTypeStore
+    + EntityTypeStore
+    + EntityStore
+    + TypeFetcher
+    + ExplainQuery<DataTypeWithMetadata, VersionedUrlSorting>
+    + ExplainQuery<PropertyTypeWithMetadata, VersionedUrlSorting>
+    + ExplainQuery<EntityTypeWithMetadata, VersionedUrlSorting>
+    + ExplainQuery<Entity, EntityQuerySorting<'static>>
 {
     fn load_external_type(
         &mut self,
@@ -158,6 +176,10 @@ where
         + EntityTypeStore
         + EntityStore
         + TypeFetcher
+        + ExplainQuery<DataTypeWithMetadata, VersionedUrlSorting>
+        + ExplainQuery<PropertyTypeWithMetadata, VersionedUrlSorting>
+        + ExplainQuery<EntityTypeWithMetadata, VersionedUrlSorting>
+        + ExplainQuery<Entity, EntityQuerySorting<'static>>
         + Send,
 {
     async fn load_external_type(
@@ -200,6 +222,7 @@ where
 {
     vec![
         account::AccountResource::routes::<S, A>(),
+        change_feed::ChangeFeedResource::routes::<S, A>(),
         data_type::DataTypeResource::routes::<S, A>(),
         property_type::PropertyTypeResource::routes::<S, A>(),
         entity_type::EntityTypeResource::routes::<S, A>(),
@@ -211,6 +234,7 @@ where
 fn api_documentation() -> Vec<openapi::OpenApi> {
     vec![
         account::AccountResource::documentation(),
+        change_feed::ChangeFeedResource::documentation(),
         data_type::DataTypeResource::documentation(),
         property_type::PropertyTypeResource::documentation(),
         entity_type::EntityTypeResource::documentation(),
@@ -219,6 +243,102 @@ fn api_documentation() -> Vec<openapi::OpenApi> {
     ]
 }
 
+/// The account group whose members are allowed to use administrative endpoints.
+#[derive(Debug, Copy, Clone)]
+pub struct InstanceAdministrators(pub ActorGroupId);
+
+/// Ensures that the actor is a member of the [`InstanceAdministrators`] account group.
+///
+/// If no administrators are configured, administrative endpoints are unavailable for everyone.
+async fn assert_instance_administrator<A>(
+    actor_id: ActorEntityUuid,
+    authorization_api: &A,
+    instance_administrators: Option<InstanceAdministrators>,
+) -> Result<(), Response>
+where
+    A: AuthorizationApi + Sync,
+{
+    let has_permission =
+        if let Some(InstanceAdministrators(account_group_id)) = instance_administrators {
+            authorization_api
+                .check_account_group_permission(
+                    actor_id,
+                    AccountGroupPermission::Member,
+                    account_group_id,
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .map_err(report_to_response)?
+                .has_permission
+        } else {
+            false
+        };
+
+    if has_permission {
+        Ok(())
+    } else {
+        Err(report_to_response(
+            Report::new(PermissionAssertion).attach(hash_status::StatusCode::PermissionDenied),
+        ))
+    }
+}
+
+/// A query as it is read by the endpoint whose statement is explained.
+struct ExplainedQuery<'q, R: QueryRecord, S> {
+    filter: Filter<'q, R>,
+    temporal_axes: QueryTemporalAxesUnresolved,
+    sorting: S,
+    limit: Option<usize>,
+    include_drafts: bool,
+}
+
+/// Explains the statement of a query on behalf of an instance administrator.
+///
+/// The query is only parsed after the actor is known to be an instance administrator, so other
+/// actors cannot probe the endpoint.
+async fn explain_query<'q, S, A, R, Srt>(
+    actor_id: ActorEntityUuid,
+    store_pool: &S,
+    authorization_api_pool: &A,
+    temporal_client: Option<Arc<TemporalClient>>,
+    instance_administrators: Option<InstanceAdministrators>,
+    query: impl FnOnce() -> Result<ExplainedQuery<'q, R, Srt>, serde_json::Error> + Send,
+) -> Result<QueryExplanation, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+    R: QueryRecord,
+    Srt: Sorting + Sync,
+    for<'pool> S::Store<'pool, A::Api<'pool>>: ExplainQuery<R, Srt>,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    assert_instance_administrator(actor_id, &authorization_api, instance_administrators).await?;
+
+    let store = store_pool
+        .acquire(authorization_api, temporal_client)
+        .await
+        .map_err(report_to_response)?;
+
+    let query = query().map_err(Report::from).map_err(report_to_response)?;
+    let temporal_axes = query.temporal_axes.resolve();
+
+    store
+        .explain_query(
+            actor_id,
+            query.filter,
+            Some(&temporal_axes),
+            &query.sorting,
+            query.limit,
+            query.include_drafts,
+        )
+        .await
+        .map_err(report_to_response)
+}
+
 #[derive(Debug, Clone)]
 pub struct QueryLogger {
     sender: Sender<JsonValue>,
@@ -323,9 +443,12 @@ pub enum OpenApiQuery<'a> {
     },
     GetEntities(&'a JsonValue),
     CountEntities(&'a JsonValue),
+    AggregateEntities(&'a JsonValue),
     GetEntitySubgraph(&'a JsonValue),
+    FindEntityPaths(&'a JsonValue),
     ValidateEntity(&'a JsonValue),
     DiffEntity(&'a DiffEntityParams),
+    GetEntityHistory(&'a GetEntityHistoryParams),
     GetEntityAuthorizationRelationships {
         entity_id: EntityId,
     },
@@ -345,6 +468,7 @@ where
     pub temporal_client: Option<TemporalClient>,
     pub domain_regex: DomainValidator,
     pub query_logger: Option<QueryLogger>,
+    pub instance_administrators: Option<InstanceAdministrators>,
 }
 
 /// A [`Router`] that only serves the `OpenAPI` specification (JSON, and necessary subschemas) for
@@ -391,6 +515,9 @@ where
     if let Some(query_logger) = dependencies.query_logger {
         router = router.layer(Extension(query_logger));
     }
+    if let Some(instance_administrators) = dependencies.instance_administrators {
+        router = router.layer(Extension(instance_administrators));
+    }
 
     router.merge(openapi_only_router())
 }
@@ -476,6 +603,9 @@ async fn serve_static_schema(Path(path): Path<String>) -> Result<Response, Statu
             TransactionTime,
             QueryTemporalAxes,
             QueryTemporalAxesUnresolved,
+
+            QueryExplanation,
+            JoinedTable,
         )
     ),
 )]
@@ -790,6 +920,76 @@ impl Modify for FilterSchemaAddon {
                                 )
                                 .required("containsSegment"),
                         )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("FullTextMatchFilter"))
+                                .property(
+                                    "fullTextMatch",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("fullTextMatch"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("InFilter"))
+                                .property(
+                                    "in",
+                                    ArrayBuilder::new()
+                                        .items(
+                                            OneOfBuilder::new()
+                                                .item(Ref::from_schema_name("FilterExpression"))
+                                                .item(ArrayBuilder::new().items(Any::schema().1)),
+                                        )
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("in"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ILikeFilter"))
+                                .property(
+                                    "iLike",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("iLike"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("RegexFilter"))
+                                .property(
+                                    "regex",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("regex"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ContainsFilter"))
+                                .property(
+                                    "contains",
+                                    ArrayBuilder::new()
+                                        .items(Ref::from_schema_name("FilterExpression"))
+                                        .min_items(Some(2))
+                                        .max_items(Some(2)),
+                                )
+                                .required("contains"),
+                        )
+                        .item(
+                            ObjectBuilder::new()
+                                .title(Some("ExistsFilter"))
+                                .property("exists", Ref::from_schema_name("FilterExpression"))
+                                .required("exists"),
+                        )
                         .build(),
                 )
                 .into(),
@@ -838,6 +1038,20 @@ impl Modify for FilterSchemaAddon {
                 )
                 .into(),
             );
+            components.schemas.insert(
+                "EntityQueryPath".to_owned(),
+                schema::Schema::Array(
+                    ArrayBuilder::new()
+                        .items(
+                            OneOfBuilder::new()
+                                .item(Ref::from_schema_name("EntityQueryToken"))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::String))
+                                .item(ObjectBuilder::new().schema_type(SchemaType::Number)),
+                        )
+                        .build(),
+                )
+                .into(),
+            );
             components.schemas.insert(
                 "EntityQuerySortingPath".to_owned(),
                 schema::Schema::Array(
@@ -911,3 +1125,28 @@ impl Modify for TimeSchemaAddon {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hash_graph_authorization::NoAuthorization;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn explain_requires_configured_administrators() {
+        let actor_id = ActorEntityUuid::new(EntityUuid::new(Uuid::new_v4()));
+
+        let response = assert_instance_administrator(actor_id, &NoAuthorization, None)
+            .await
+            .expect_err("administrative endpoints should be unavailable without administrators");
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        assert_instance_administrator(
+            actor_id,
+            &NoAuthorization,
+            Some(InstanceAdministrators(ActorGroupId::new(Uuid::new_v4()))),
+        )
+        .await
+        .expect("members of the administrators group should be allowed");
+    }
+}
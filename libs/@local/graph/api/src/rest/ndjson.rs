@@ -0,0 +1,111 @@
+//! Streaming responses as newline delimited JSON.
+//!
+//! Clients opt in by sending `Accept: application/x-ndjson`. Every line of the response is a
+//! single JSON record. As the status code has been sent before the first record, errors which
+//! occur while streaming are reported as a trailing `{"type": "error", ...}` record.
+
+use core::{convert::Infallible, error::Error};
+
+use axum::{
+    body::Body,
+    http::{HeaderMap, HeaderValue, header},
+    response::{IntoResponse as _, Response},
+};
+use bytes::Bytes;
+use error_stack::Report;
+use futures::{Stream, StreamExt as _};
+use hash_status::Status;
+use serde::Serialize;
+
+use crate::rest::status::report_to_status;
+
+pub(crate) const NDJSON_CONTENT_TYPE: &str = "application/x-ndjson";
+
+/// Returns `true` if the client accepts newline delimited JSON.
+pub(crate) fn accepts_ndjson(headers: &HeaderMap) -> bool {
+    headers
+        .get_all(header::ACCEPT)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .any(|media_type| {
+            media_type
+                .split(';')
+                .next()
+                .is_some_and(|essence| essence.trim() == NDJSON_CONTENT_TYPE)
+        })
+}
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename = "error")]
+struct ErrorRecord {
+    status: Status<String>,
+}
+
+fn encode_line(record: &impl Serialize) -> Bytes {
+    let mut line = serde_json::to_vec(record).unwrap_or_else(|error| {
+        tracing::error!(?error, "Could not serialize record");
+
+        serde_json::to_vec(&ErrorRecord {
+            status: Status::new(
+                hash_status::StatusCode::Internal,
+                Some("could not serialize record".to_owned()),
+                Vec::new(),
+            ),
+        })
+        .expect("error record should be serializable")
+    });
+    line.push(b'\n');
+
+    Bytes::from(line)
+}
+
+/// Creates a response which writes every record of the stream as a single line.
+///
+/// The stream is expected to end after the first error.
+pub(crate) fn ndjson_response<S, T, C>(records: S) -> Response
+where
+    S: Stream<Item = Result<T, Report<C>>> + Send + 'static,
+    T: Serialize,
+    C: Error + Send + Sync + 'static,
+{
+    let lines = records.map(|record| {
+        let line = match record {
+            Ok(record) => encode_line(&record),
+            Err(report) => encode_line(&ErrorRecord {
+                status: report_to_status(report),
+            }),
+        };
+
+        Ok::<_, Infallible>(line)
+    });
+
+    let mut response = Body::from_stream(lines).into_response();
+    response.headers_mut().insert(
+        header::CONTENT_TYPE,
+        HeaderValue::from_static(NDJSON_CONTENT_TYPE),
+    );
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderMap, HeaderValue, header};
+
+    use super::accepts_ndjson;
+
+    #[test]
+    fn accept_header() {
+        let mut headers = HeaderMap::new();
+        assert!(!accepts_ndjson(&headers));
+
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/json"));
+        assert!(!accepts_ndjson(&headers));
+
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/json, application/x-ndjson;q=0.9"),
+        );
+        assert!(accepts_ndjson(&headers));
+    }
+}
@@ -20,19 +20,14 @@ use hash_graph_authorization::{
     },
     zanzibar::Consistency,
 };
-use hash_graph_postgres_store::{
-    ontology::patch_id_and_parse,
-    store::error::{OntologyVersionDoesNotExist, VersionedUrlAlreadyExists},
-};
 use hash_graph_store::{
     pool::StorePool,
     property_type::{
         ArchivePropertyTypeParams, CreatePropertyTypeParams, GetPropertyTypeSubgraphParams,
         GetPropertyTypesParams, GetPropertyTypesResponse, PropertyTypeQueryToken,
         PropertyTypeStore as _, UnarchivePropertyTypeParams, UpdatePropertyTypeEmbeddingParams,
-        UpdatePropertyTypesParams,
     },
-    query::ConflictBehavior,
+    query::{ConflictBehavior, QueryExplanation, VersionedUrlSorting},
 };
 use hash_graph_types::ontology::PropertyTypeEmbedding;
 use hash_status::Status;
@@ -43,8 +38,8 @@ use type_system::{
     ontology::{
         OntologyTemporalMetadata, OntologyTypeMetadata, OntologyTypeReference,
         PropertyTypeWithMetadata,
-        id::{OntologyTypeVersion, VersionedUrl},
-        json_schema::{DomainValidator, ValidateOntologyType as _},
+        id::VersionedUrl,
+        json_schema::DomainValidator,
         property_type::{
             PropertyType, PropertyTypeMetadata, PropertyTypeUuid, schema::PropertyValueType,
         },
@@ -55,11 +50,15 @@ use type_system::{
 use utoipa::{OpenApi, ToSchema};
 
 use super::api_resource::RoutedResource;
-use crate::rest::{
-    AuthenticatedUserHeader, OpenApiQuery, PermissionResponse, QueryLogger, RestApiStore,
-    json::Json,
-    status::{report_to_response, status_to_response},
-    utoipa_typedef::{ListOrValue, MaybeListOfPropertyType, subgraph::Subgraph},
+use crate::{
+    operation,
+    rest::{
+        AuthenticatedUserHeader, ExplainedQuery, InstanceAdministrators, OpenApiQuery,
+        PermissionResponse, QueryLogger, RestApiStore, explain_query,
+        json::Json,
+        status::{report_to_response, status_to_response},
+        utoipa_typedef::{ListOrValue, MaybeListOfPropertyType, subgraph::Subgraph},
+    },
 };
 
 #[derive(OpenApi)]
@@ -73,6 +72,7 @@ use crate::rest::{
         load_external_property_type,
         get_property_types,
         get_property_type_subgraph,
+        explain_property_types,
         update_property_type,
         update_property_types,
         update_property_type_embeddings,
@@ -150,7 +150,8 @@ impl RoutedResource for PropertyTypeResource {
                     "/query",
                     Router::new()
                         .route("/", post(get_property_types::<S, A>))
-                        .route("/subgraph", post(get_property_type_subgraph::<S, A>)),
+                        .route("/subgraph", post(get_property_type_subgraph::<S, A>))
+                        .route("/explain", post(explain_property_types::<S, A>)),
                 )
                 .route("/load", post(load_external_property_type::<S, A>))
                 .route("/archive", put(archive_property_type::<S, A>))
@@ -162,12 +163,12 @@ impl RoutedResource for PropertyTypeResource {
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct CreatePropertyTypeRequest {
+pub struct CreatePropertyTypeRequest {
     #[schema(inline)]
-    schema: MaybeListOfPropertyType,
-    owned_by_id: OwnedById,
-    relationships: Vec<PropertyTypeRelationAndSubject>,
-    provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) schema: MaybeListOfPropertyType,
+    pub(crate) owned_by_id: OwnedById,
+    pub(crate) relationships: Vec<PropertyTypeRelationAndSubject>,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
 }
 
 #[utoipa::path(
@@ -213,34 +214,15 @@ where
         .await
         .map_err(report_to_response)?;
 
-    let Json(CreatePropertyTypeRequest {
-        schema,
-        owned_by_id,
-        relationships,
-        provenance,
-    }) = body;
+    let Json(request) = body;
 
-    let is_list = matches!(&schema, ListOrValue::List(_));
+    let is_list = matches!(&request.schema, ListOrValue::List(_));
 
     let mut metadata = store
         .create_property_types(
             actor_id,
-            schema
-                .into_iter()
-                .map(|schema| {
-                    domain_validator
-                        .validate(&schema)
-                        .map_err(report_to_response)?;
-
-                    Ok(CreatePropertyTypeParams {
-                        schema,
-                        ownership: OntologyOwnership::Local { owned_by_id },
-                        relationships: relationships.clone(),
-                        conflict_behavior: ConflictBehavior::Fail,
-                        provenance: provenance.clone(),
-                    })
-                })
-                .collect::<Result<Vec<_>, Response>>()?,
+            operation::property_type::create_params(request, &domain_validator)
+                .map_err(report_to_response)?,
         )
         .await
         .map_err(report_to_response)?;
@@ -512,12 +494,78 @@ where
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
-struct UpdatePropertyTypeRequest {
+pub struct UpdatePropertyTypeRequest {
     #[schema(value_type = UpdatePropertyType)]
-    schema: serde_json::Value,
-    type_to_update: VersionedUrl,
-    relationships: Vec<PropertyTypeRelationAndSubject>,
-    provenance: ProvidedOntologyEditionProvenance,
+    pub(crate) schema: serde_json::Value,
+    pub(crate) type_to_update: VersionedUrl,
+    pub(crate) relationships: Vec<PropertyTypeRelationAndSubject>,
+    pub(crate) provenance: ProvidedOntologyEditionProvenance,
+}
+
+#[utoipa::path(
+    post,
+    path = "/property-types/query/explain",
+    request_body = GetPropertyTypesParams,
+    tag = "PropertyType",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (
+            status = 200,
+            content_type = "application/json",
+            body = QueryExplanation,
+            description = "The compiled query for property types and its execution plan",
+        ),
+        (status = 403, description = "The actor is not an instance administrator"),
+        (status = 422, content_type = "text/plain", description = "Provided query is invalid"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(
+        store_pool,
+        authorization_api_pool,
+        temporal_client,
+        instance_administrators,
+        request
+    )
+)]
+async fn explain_property_types<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    store_pool: Extension<Arc<S>>,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    instance_administrators: Option<Extension<InstanceAdministrators>>,
+    Json(request): Json<serde_json::Value>,
+) -> Result<Json<QueryExplanation>, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+    for<'pool> S::Store<'pool, A::Api<'pool>>: RestApiStore,
+{
+    explain_query(
+        actor_id,
+        &*store_pool,
+        &*authorization_api_pool,
+        temporal_client.0,
+        instance_administrators.map(|Extension(administrators)| administrators),
+        || {
+            let params = GetPropertyTypesParams::deserialize(&request)?;
+            Ok(ExplainedQuery {
+                filter: params.filter,
+                temporal_axes: params.temporal_axes,
+                sorting: VersionedUrlSorting {
+                    cursor: params.after,
+                },
+                limit: params.limit,
+                include_drafts: params.include_drafts,
+            })
+        },
+    )
+    .await
+    .map(Json)
 }
 
 #[utoipa::path(
@@ -551,16 +599,9 @@ where
     S: StorePool + Send + Sync,
     A: AuthorizationApiPool + Send + Sync,
 {
-    let Json(UpdatePropertyTypeRequest {
-        schema,
-        mut type_to_update,
-        relationships,
-        provenance,
-    }) = body;
-
-    type_to_update.version = OntologyTypeVersion::new(type_to_update.version.inner() + 1);
+    let Json(request) = body;
 
-    let property_type = patch_id_and_parse(&type_to_update, schema).map_err(report_to_response)?;
+    let params = operation::property_type::update_params(request).map_err(report_to_response)?;
 
     let authorization_api = authorization_api_pool
         .acquire()
@@ -573,14 +614,7 @@ where
         .map_err(report_to_response)?;
 
     store
-        .update_property_type(
-            actor_id,
-            UpdatePropertyTypesParams {
-                schema: property_type,
-                relationships,
-                provenance,
-            },
-        )
+        .update_property_type(actor_id, params)
         .await
         .map_err(report_to_response)
         .map(Json)
@@ -630,25 +664,9 @@ where
     let params = bodies
         .0
         .into_iter()
-        .map(
-            |UpdatePropertyTypeRequest {
-                 schema,
-                 mut type_to_update,
-                 relationships,
-                 provenance,
-             }| {
-                type_to_update.version =
-                    OntologyTypeVersion::new(type_to_update.version.inner() + 1);
-
-                Ok(UpdatePropertyTypesParams {
-                    schema: patch_id_and_parse(&type_to_update, schema)
-                        .map_err(report_to_response)?,
-                    relationships,
-                    provenance,
-                })
-            },
-        )
-        .collect::<Result<Vec<_>, Response>>()?;
+        .map(operation::property_type::update_params)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(report_to_response)?;
     store
         .update_property_types(actor_id, params)
         .await
@@ -759,15 +777,7 @@ where
     store
         .archive_property_type(actor_id, params)
         .await
-        .map_err(|mut report| {
-            if report.contains::<OntologyVersionDoesNotExist>() {
-                report = report.attach(hash_status::StatusCode::NotFound);
-            }
-            if report.contains::<VersionedUrlAlreadyExists>() {
-                report = report.attach(hash_status::StatusCode::AlreadyExists);
-            }
-            report_to_response(report)
-        })
+        .map_err(|report| report_to_response(operation::archive_ontology_type_status(report)))
         .map(Json)
 }
 
@@ -822,15 +832,7 @@ where
     store
         .unarchive_property_type(actor_id, params)
         .await
-        .map_err(|mut report| {
-            if report.contains::<OntologyVersionDoesNotExist>() {
-                report = report.attach(hash_status::StatusCode::NotFound);
-            }
-            if report.contains::<VersionedUrlAlreadyExists>() {
-                report = report.attach(hash_status::StatusCode::AlreadyExists);
-            }
-            report_to_response(report)
-        })
+        .map_err(|report| report_to_response(operation::archive_ontology_type_status(report)))
         .map(Json)
 }
 
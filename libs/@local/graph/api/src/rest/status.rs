@@ -36,12 +36,11 @@ struct ValidationContent<C> {
     report: Report<[C]>,
 }
 
-pub(crate) fn report_to_response<C>(report: impl Into<Report<[C]>>) -> Response
-where
-    C: Error + Send + Sync + 'static,
-{
-    let mut report = report.into();
-    let status_code = report
+/// Determines the [`StatusCode`] of a report.
+///
+/// An explicitly attached [`StatusCode`] takes precedence over the one derived from the contexts.
+pub(crate) fn report_status_code<C>(report: &Report<[C]>) -> StatusCode {
+    report
         .request_ref::<StatusCode>()
         .next()
         .copied()
@@ -54,7 +53,29 @@ where
             } else {
                 StatusCode::Unknown
             }
-        });
+        })
+}
+
+/// Converts a report into a [`Status`] without details.
+///
+/// This is used to report errors after a response has been started, e.g. in a stream.
+pub(crate) fn report_to_status<C>(report: impl Into<Report<[C]>>) -> Status<String>
+where
+    C: Error + Send + Sync + 'static,
+{
+    let report = report.into();
+    let status_code = report_status_code(&report);
+    tracing::error!(error = ?report, tags.code = ?status_code.to_http_code());
+
+    Status::new(status_code, Some(report.to_string()), Vec::new())
+}
+
+pub(crate) fn report_to_response<C>(report: impl Into<Report<[C]>>) -> Response
+where
+    C: Error + Send + Sync + 'static,
+{
+    let mut report = report.into();
+    let status_code = report_status_code(&report);
 
     // TODO: Currently, this mostly duplicates the error printed below, when more information is
     //       added to the `Report` event consider commenting in this line again.
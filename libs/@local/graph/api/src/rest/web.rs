@@ -21,7 +21,7 @@ use hash_graph_authorization::{
     zanzibar::Consistency,
 };
 use hash_graph_store::{
-    account::{AccountStore as _, InsertWebIdParams},
+    account::{AccountStore as _, InsertWebIdParams, UpdateWebTextSearchConfigParams},
     pool::StorePool,
 };
 use hash_temporal_client::TemporalClient;
@@ -36,6 +36,7 @@ use crate::rest::{AuthenticatedUserHeader, PermissionResponse, status::report_to
 #[openapi(
     paths(
         create_web,
+        update_web_text_search_config,
         check_web_permission,
         modify_web_authorization_relationships,
         get_web_authorization_relationships,
@@ -43,6 +44,7 @@ use crate::rest::{AuthenticatedUserHeader, PermissionResponse, status::report_to
     components(
         schemas(
             InsertWebIdParams,
+            UpdateWebTextSearchConfigParams,
 
             WebRelationAndSubject,
             WebPermission,
@@ -77,6 +79,10 @@ impl RoutedResource for WebResource {
                     post(modify_web_authorization_relationships::<A>),
                 )
                 .route("/", post(create_web::<S, A>))
+                .route(
+                    "/text-search-config",
+                    post(update_web_text_search_config::<S, A>),
+                )
                 .nest(
                     "/:web_id",
                     Router::new()
@@ -144,6 +150,55 @@ where
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/webs/text-search-config",
+    request_body = UpdateWebTextSearchConfigParams,
+    tag = "Web",
+    params(
+        ("X-Authenticated-User-Actor-Id" = ActorEntityUuid, Header, description = "The ID of the actor which is used to authorize the request"),
+    ),
+    responses(
+        (status = 204, description = "The text search configuration of the web was updated and its entities were reindexed"),
+
+        (status = 400, description = "The text search configuration does not exist"),
+        (status = 403, description = "Permission denied"),
+        (status = 500, description = "Store error occurred"),
+    )
+)]
+#[tracing::instrument(
+    level = "info",
+    skip(store_pool, authorization_api_pool, temporal_client)
+)]
+async fn update_web_text_search_config<S, A>(
+    AuthenticatedUserHeader(actor_id): AuthenticatedUserHeader,
+    authorization_api_pool: Extension<Arc<A>>,
+    temporal_client: Extension<Option<Arc<TemporalClient>>>,
+    store_pool: Extension<Arc<S>>,
+    Json(params): Json<UpdateWebTextSearchConfigParams>,
+) -> Result<StatusCode, Response>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    let authorization_api = authorization_api_pool
+        .acquire()
+        .await
+        .map_err(report_to_response)?;
+
+    let mut store = store_pool
+        .acquire(authorization_api, temporal_client.0)
+        .await
+        .map_err(report_to_response)?;
+
+    store
+        .update_web_text_search_config(actor_id, params)
+        .await
+        .map_err(report_to_response)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 #[utoipa::path(
     get,
     path = "/webs/{web_id}/permissions/{permission}",
@@ -103,11 +103,15 @@ pub mod meta {
     use frunk::HList;
     use harpc_system::{
         Subsystem,
+        codegen::{DescribeProcedure, DescribeSubsystem, Type},
         procedure::{Procedure, ProcedureIdentifier},
     };
     use harpc_types::{procedure::ProcedureId, version::Version};
+    use hash_graph_authorization::schema::AccountGroupPermission;
+    use hash_graph_store::account::{InsertAccountGroupIdParams, InsertAccountIdParams};
+    use type_system::{provenance::ActorEntityUuid, web::ActorGroupId};
 
-    use crate::rpc::GraphSubsystemId;
+    use crate::rpc::{GraphSubsystemId, fallible};
 
     pub enum AccountProcedureId {
         CreateAccount,
@@ -162,6 +166,10 @@ pub mod meta {
         };
     }
 
+    impl DescribeSubsystem for AccountSystem {
+        const NAME: &'static str = "Account";
+    }
+
     pub struct ProcedureCreateAccount;
 
     impl Procedure for ProcedureCreateAccount {
@@ -170,6 +178,18 @@ pub mod meta {
         const ID: <Self::Subsystem as Subsystem>::ProcedureId = AccountProcedureId::CreateAccount;
     }
 
+    impl DescribeProcedure for ProcedureCreateAccount {
+        const NAME: &'static str = "create_account";
+
+        fn request() -> Type {
+            Type::of::<InsertAccountIdParams>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<ActorEntityUuid>())
+        }
+    }
+
     pub struct ProcedureCreateAccountGroup;
 
     impl Procedure for ProcedureCreateAccountGroup {
@@ -179,6 +199,18 @@ pub mod meta {
             AccountProcedureId::CreateAccountGroup;
     }
 
+    impl DescribeProcedure for ProcedureCreateAccountGroup {
+        const NAME: &'static str = "create_account_group";
+
+        fn request() -> Type {
+            Type::of::<InsertAccountGroupIdParams>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<ActorGroupId>())
+        }
+    }
+
     pub struct ProcedureCheckAccountGroupPermission;
 
     impl Procedure for ProcedureCheckAccountGroupPermission {
@@ -188,6 +220,22 @@ pub mod meta {
             AccountProcedureId::CheckAccountGroupPermission;
     }
 
+    impl DescribeProcedure for ProcedureCheckAccountGroupPermission {
+        const NAME: &'static str = "check_account_group_permission";
+
+        fn request() -> Type {
+            Type::tuple([
+                Type::of::<ActorGroupId>(),
+                Type::of::<AccountGroupPermission>(),
+            ])
+        }
+
+        fn response() -> Type {
+            // `PermissionResponse` of the RPC and the REST API share the same representation
+            fallible(Type::named("PermissionResponse"))
+        }
+    }
+
     pub struct ProcedureAddAccountGroupMember;
 
     impl Procedure for ProcedureAddAccountGroupMember {
@@ -197,6 +245,18 @@ pub mod meta {
             AccountProcedureId::AddAccountGroupMember;
     }
 
+    impl DescribeProcedure for ProcedureAddAccountGroupMember {
+        const NAME: &'static str = "add_account_group_member";
+
+        fn request() -> Type {
+            Type::tuple([Type::of::<ActorGroupId>(), Type::of::<ActorEntityUuid>()])
+        }
+
+        fn response() -> Type {
+            fallible(Type::Unit)
+        }
+    }
+
     pub struct ProcedureRemoveAccountGroupMember;
 
     impl Procedure for ProcedureRemoveAccountGroupMember {
@@ -205,6 +265,18 @@ pub mod meta {
         const ID: <Self::Subsystem as Subsystem>::ProcedureId =
             AccountProcedureId::RemoveAccountGroupMember;
     }
+
+    impl DescribeProcedure for ProcedureRemoveAccountGroupMember {
+        const NAME: &'static str = "remove_account_group_member";
+
+        fn request() -> Type {
+            Type::tuple([Type::of::<ActorGroupId>(), Type::of::<ActorEntityUuid>()])
+        }
+
+        fn response() -> Type {
+            fallible(Type::Unit)
+        }
+    }
 }
 
 #[derive(Debug)]
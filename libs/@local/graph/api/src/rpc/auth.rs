@@ -38,11 +38,13 @@ pub mod meta {
     use frunk::HList;
     use harpc_system::{
         Subsystem,
+        codegen::{DescribeProcedure, DescribeSubsystem, Type},
         procedure::{Procedure, ProcedureIdentifier},
     };
     use harpc_types::{procedure::ProcedureId, version::Version};
+    use type_system::provenance::ActorEntityUuid;
 
-    use crate::rpc::GraphSubsystemId;
+    use crate::rpc::{GraphSubsystemId, fallible};
 
     pub enum AuthenticationProcedureId {
         Authenticate,
@@ -79,6 +81,10 @@ pub mod meta {
         };
     }
 
+    impl DescribeSubsystem for AuthenticationSystem {
+        const NAME: &'static str = "Authentication";
+    }
+
     pub struct ProcedureAuthenticate;
 
     impl Procedure for ProcedureAuthenticate {
@@ -87,6 +93,18 @@ pub mod meta {
         const ID: <Self::Subsystem as Subsystem>::ProcedureId =
             AuthenticationProcedureId::Authenticate;
     }
+
+    impl DescribeProcedure for ProcedureAuthenticate {
+        const NAME: &'static str = "authenticate";
+
+        fn request() -> Type {
+            Type::of::<ActorEntityUuid>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::Unit)
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -0,0 +1,251 @@
+//! Subsystem to stream the change feed of the graph.
+//!
+//! The stream starts after the requested cursor and waits for new changes once the feed has been
+//! read completely, so it only ends when an error occurs or the caller disconnects.
+
+use core::marker::PhantomData;
+
+use error_stack::{Report, ResultExt as _};
+use futures::{Stream, StreamExt as _, TryStreamExt as _, stream};
+use harpc_client::{connection::Connection, utils::invoke_call_server_stream};
+use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use harpc_server::{
+    error::DelegationError,
+    session::Session,
+    utils::{delegate_call_server_stream, parse_procedure_id},
+};
+use harpc_system::delegate::SubsystemDelegate;
+use harpc_tower::{body::Body, request::Request, response::Response};
+use harpc_types::response_kind::ResponseKind;
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    change_feed::{ChangeFeedCursor, ChangeFeedRecord},
+    pool::StorePool,
+};
+
+use super::{server::GraphServer, session::Account};
+use crate::operation::change_feed::change_stream;
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to stream the change feed")]
+pub struct ChangeFeedError;
+
+pub trait ChangeFeedSystem {
+    type ExecutionScope;
+
+    /// Streams the changes after the cursor in transaction-time order.
+    ///
+    /// If no cursor is provided, the feed is streamed from the start. The stream ends after the
+    /// first error.
+    async fn stream_changes(
+        &self,
+        scope: Self::ExecutionScope,
+        after: Option<ChangeFeedCursor>,
+    ) -> Result<
+        impl Stream<Item = Result<ChangeFeedRecord, Report<ChangeFeedError>>> + Send + 'static,
+        Report<ChangeFeedError>,
+    >;
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+pub mod meta {
+    //! The `meta` module contains the metadata for the change feed service.
+    //! In the future this will be automatically generated by the `harpc` crate.
+
+    use frunk::HList;
+    use harpc_system::{
+        Subsystem,
+        codegen::{DescribeProcedure, DescribeSubsystem, Type},
+        procedure::{Procedure, ProcedureIdentifier},
+    };
+    use harpc_types::{procedure::ProcedureId, version::Version};
+    use hash_graph_store::change_feed::{ChangeFeedCursor, ChangeFeedRecord};
+
+    use crate::rpc::{GraphSubsystemId, fallible};
+
+    pub enum ChangeFeedProcedureId {
+        StreamChanges,
+    }
+
+    impl ProcedureIdentifier for ChangeFeedProcedureId {
+        type Subsystem = ChangeFeedSystem;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::StreamChanges),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::StreamChanges => ProcedureId::new(0x00),
+            }
+        }
+    }
+
+    pub struct ChangeFeedSystem;
+
+    impl Subsystem for ChangeFeedSystem {
+        type ProcedureId = ChangeFeedProcedureId;
+        type Procedures = HList![ProcedureStreamChanges];
+        type SubsystemId = GraphSubsystemId;
+
+        const ID: GraphSubsystemId = GraphSubsystemId::ChangeFeed;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x00,
+        };
+    }
+
+    impl DescribeSubsystem for ChangeFeedSystem {
+        const NAME: &'static str = "ChangeFeed";
+    }
+
+    pub struct ProcedureStreamChanges;
+
+    impl Procedure for ProcedureStreamChanges {
+        type Subsystem = ChangeFeedSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            ChangeFeedProcedureId::StreamChanges;
+    }
+
+    impl DescribeProcedure for ProcedureStreamChanges {
+        const NAME: &'static str = "stream_changes";
+
+        fn request() -> Type {
+            Type::option(Type::of::<ChangeFeedCursor>())
+        }
+
+        fn response() -> Type {
+            Type::stream(fallible(Type::of::<ChangeFeedRecord>()))
+        }
+    }
+}
+
+impl<S, A> ChangeFeedSystem for GraphServer<S, A>
+where
+    S: StorePool + Send + Sync + 'static,
+    A: AuthorizationApiPool + Send + Sync + 'static,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn stream_changes(
+        &self,
+        scope: Session<Account>,
+        after: Option<ChangeFeedCursor>,
+    ) -> Result<
+        impl Stream<Item = Result<ChangeFeedRecord, Report<ChangeFeedError>>> + Send + 'static,
+        Report<ChangeFeedError>,
+    > {
+        let actor_id = Self::actor(&scope, ChangeFeedError)?;
+
+        let store = self.store_owned(ChangeFeedError).await?;
+
+        Ok(change_stream(store, actor_id, after).map_err(|report| {
+            tracing::error!(error=?report, "Could not read changes");
+            report.change_context(ChangeFeedError)
+        }))
+    }
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ChangeFeedDelegate<T> {
+    inner: T,
+}
+
+impl<T> ChangeFeedDelegate<T> {
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, C> SubsystemDelegate<C> for ChangeFeedDelegate<T>
+where
+    T: ChangeFeedSystem<stream_changes(..): Send, ExecutionScope: Send> + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+{
+    type Error = Report<DelegationError>;
+    type ExecutionScope = T::ExecutionScope;
+    type Subsystem = meta::ChangeFeedSystem;
+
+    type Body<Source>
+        = impl Body<Control: AsRef<ResponseKind>, Error = <C as Encoder>::Error>
+    where
+        Source: Body<Control = !, Error: Send + Sync> + Send;
+
+    async fn call<B>(
+        self,
+        request: Request<B>,
+        scope: T::ExecutionScope,
+        codec: C,
+    ) -> Result<Response<Self::Body<B>>, Self::Error>
+    where
+        B: Body<Control = !, Error: Send + Sync> + Send,
+    {
+        let id = parse_procedure_id(&request)?;
+
+        match id {
+            meta::ChangeFeedProcedureId::StreamChanges => {
+                delegate_call_server_stream(request, codec, |after| async move {
+                    match self.inner.stream_changes(scope, after).await {
+                        Ok(changes) => changes.left_stream(),
+                        Err(report) => stream::once(async move { Err(report) }).right_stream(),
+                    }
+                })
+                .await
+            }
+        }
+    }
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+#[derive_where::derive_where(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ChangeFeedClient<S, C> {
+    _session: PhantomData<fn() -> *const S>,
+    _codec: PhantomData<fn() -> *const C>,
+}
+
+impl<S, C> ChangeFeedClient<S, C> {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            _session: PhantomData,
+            _codec: PhantomData,
+        }
+    }
+}
+
+impl<S, C> Default for ChangeFeedClient<S, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, C> ChangeFeedSystem for ChangeFeedClient<S, C>
+where
+    S: harpc_client::connection::ConnectionService<C> + 'static,
+    C: harpc_client::connection::ConnectionCodec + 'static,
+{
+    type ExecutionScope = Connection<S, C>;
+
+    async fn stream_changes(
+        &self,
+        scope: Connection<S, C>,
+        after: Option<ChangeFeedCursor>,
+    ) -> Result<
+        impl Stream<Item = Result<ChangeFeedRecord, Report<ChangeFeedError>>> + Send + 'static,
+        Report<ChangeFeedError>,
+    > {
+        let changes =
+            invoke_call_server_stream(scope, meta::ChangeFeedProcedureId::StreamChanges, [after])
+                .await
+                .change_context(ChangeFeedError)?;
+
+        Ok(changes.map_err(|report| report.change_context(ChangeFeedError)))
+    }
+}
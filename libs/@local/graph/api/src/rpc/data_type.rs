@@ -0,0 +1,411 @@
+//! Subsystem to query, create, update and archive data types.
+//!
+//! Data types are created together with their conversions to other data types. The schema of a
+//! data type is checked to be hosted on a domain the graph is allowed to create types for.
+
+use error_stack::{Report, ResultExt as _};
+use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use harpc_server::{
+    error::DelegationError,
+    session::Session,
+    utils::{delegate_call_discrete, parse_procedure_id},
+};
+use harpc_system::delegate::SubsystemDelegate;
+use harpc_tower::{body::Body, either::Either, request::Request, response::Response};
+use harpc_types::response_kind::ResponseKind;
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    data_type::{
+        ArchiveDataTypeParams, DataTypeStore as _, GetDataTypesParams, GetDataTypesResponse,
+    },
+    pool::StorePool,
+};
+use type_system::ontology::{OntologyTemporalMetadata, data_type::DataTypeMetadata};
+
+use super::{Document, server::GraphServer, session::Account};
+use crate::{
+    operation,
+    rest::{
+        OpenApiQuery,
+        data_type::{CreateDataTypeRequest, UpdateDataTypeRequest},
+    },
+};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil data type request")]
+pub struct DataTypeError;
+
+pub trait DataTypeSystem {
+    type ExecutionScope;
+
+    async fn get_data_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: Document<GetDataTypesParams<'static>>,
+    ) -> Result<GetDataTypesResponse, Report<DataTypeError>>;
+
+    /// Creates the data types of the request, the metadata is returned in the order of the schemas.
+    async fn create_data_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: CreateDataTypeRequest,
+    ) -> Result<Vec<DataTypeMetadata>, Report<DataTypeError>>;
+
+    /// Creates the next version of the data type to update.
+    async fn update_data_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: UpdateDataTypeRequest,
+    ) -> Result<DataTypeMetadata, Report<DataTypeError>>;
+
+    async fn archive_data_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: Document<ArchiveDataTypeParams<'static>>,
+    ) -> Result<OntologyTemporalMetadata, Report<DataTypeError>>;
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+pub mod meta {
+    //! The `meta` module contains the metadata for the data type service.
+    //! In the future this will be automatically generated by the `harpc` crate.
+
+    use frunk::HList;
+    use harpc_system::{
+        Subsystem,
+        codegen::{DescribeProcedure, DescribeSubsystem, Type},
+        procedure::{Procedure, ProcedureIdentifier},
+    };
+    use harpc_types::{procedure::ProcedureId, version::Version};
+    use hash_graph_store::data_type::{
+        ArchiveDataTypeParams, GetDataTypesParams, GetDataTypesResponse,
+    };
+    use type_system::ontology::{OntologyTemporalMetadata, data_type::DataTypeMetadata};
+
+    use crate::{
+        rest::data_type::{CreateDataTypeRequest, UpdateDataTypeRequest},
+        rpc::{GraphSubsystemId, fallible},
+    };
+
+    pub enum DataTypeProcedureId {
+        GetDataTypes,
+        CreateDataTypes,
+        UpdateDataType,
+        ArchiveDataType,
+    }
+
+    impl ProcedureIdentifier for DataTypeProcedureId {
+        type Subsystem = DataTypeSystem;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::GetDataTypes),
+                0x01 => Some(Self::CreateDataTypes),
+                0x02 => Some(Self::UpdateDataType),
+                0x03 => Some(Self::ArchiveDataType),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::GetDataTypes => ProcedureId::new(0x00),
+                Self::CreateDataTypes => ProcedureId::new(0x01),
+                Self::UpdateDataType => ProcedureId::new(0x02),
+                Self::ArchiveDataType => ProcedureId::new(0x03),
+            }
+        }
+    }
+
+    pub struct DataTypeSystem;
+
+    impl Subsystem for DataTypeSystem {
+        type ProcedureId = DataTypeProcedureId;
+        type Procedures = HList![
+            ProcedureGetDataTypes,
+            ProcedureCreateDataTypes,
+            ProcedureUpdateDataType,
+            ProcedureArchiveDataType
+        ];
+        type SubsystemId = GraphSubsystemId;
+
+        const ID: GraphSubsystemId = GraphSubsystemId::DataType;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x00,
+        };
+    }
+
+    impl DescribeSubsystem for DataTypeSystem {
+        const NAME: &'static str = "DataType";
+    }
+
+    pub struct ProcedureGetDataTypes;
+
+    impl Procedure for ProcedureGetDataTypes {
+        type Subsystem = DataTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId = DataTypeProcedureId::GetDataTypes;
+    }
+
+    impl DescribeProcedure for ProcedureGetDataTypes {
+        const NAME: &'static str = "get_data_types";
+
+        fn request() -> Type {
+            Type::of::<GetDataTypesParams<'static>>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<GetDataTypesResponse>())
+        }
+    }
+
+    pub struct ProcedureCreateDataTypes;
+
+    impl Procedure for ProcedureCreateDataTypes {
+        type Subsystem = DataTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            DataTypeProcedureId::CreateDataTypes;
+    }
+
+    impl DescribeProcedure for ProcedureCreateDataTypes {
+        const NAME: &'static str = "create_data_types";
+
+        fn request() -> Type {
+            Type::of::<CreateDataTypeRequest>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::list(Type::of::<DataTypeMetadata>()))
+        }
+    }
+
+    pub struct ProcedureUpdateDataType;
+
+    impl Procedure for ProcedureUpdateDataType {
+        type Subsystem = DataTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId = DataTypeProcedureId::UpdateDataType;
+    }
+
+    impl DescribeProcedure for ProcedureUpdateDataType {
+        const NAME: &'static str = "update_data_type";
+
+        fn request() -> Type {
+            Type::of::<UpdateDataTypeRequest>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<DataTypeMetadata>())
+        }
+    }
+
+    pub struct ProcedureArchiveDataType;
+
+    impl Procedure for ProcedureArchiveDataType {
+        type Subsystem = DataTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            DataTypeProcedureId::ArchiveDataType;
+    }
+
+    impl DescribeProcedure for ProcedureArchiveDataType {
+        const NAME: &'static str = "archive_data_type";
+
+        fn request() -> Type {
+            Type::of::<ArchiveDataTypeParams<'static>>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<OntologyTemporalMetadata>())
+        }
+    }
+}
+
+impl<S, A> DataTypeSystem for GraphServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn get_data_types(
+        &self,
+        scope: Session<Account>,
+        request: Document<GetDataTypesParams<'static>>,
+    ) -> Result<GetDataTypesResponse, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope, DataTypeError)?;
+
+        let mut query_logger = self.query_logger.clone();
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.capture(actor_id, OpenApiQuery::GetDataTypes(request.as_json()));
+        }
+
+        let params = request.parse().change_context(DataTypeError)?;
+
+        let store = self.store(DataTypeError).await?;
+
+        let response = store
+            .get_data_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not read data types");
+            })
+            .change_context(DataTypeError);
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.send().await.change_context(DataTypeError)?;
+        }
+        response
+    }
+
+    async fn create_data_types(
+        &self,
+        scope: Session<Account>,
+        request: CreateDataTypeRequest,
+    ) -> Result<Vec<DataTypeMetadata>, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope, DataTypeError)?;
+
+        let params = operation::data_type::create_params(request, &self.domain_validator)
+            .change_context(DataTypeError)?;
+
+        let mut store = self.store(DataTypeError).await?;
+
+        store
+            .create_data_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create data types");
+            })
+            .change_context(DataTypeError)
+    }
+
+    async fn update_data_type(
+        &self,
+        scope: Session<Account>,
+        request: UpdateDataTypeRequest,
+    ) -> Result<DataTypeMetadata, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope, DataTypeError)?;
+
+        let params = operation::data_type::update_params(request).change_context(DataTypeError)?;
+
+        let mut store = self.store(DataTypeError).await?;
+
+        store
+            .update_data_type(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not update data type");
+            })
+            .change_context(DataTypeError)
+    }
+
+    async fn archive_data_type(
+        &self,
+        scope: Session<Account>,
+        request: Document<ArchiveDataTypeParams<'static>>,
+    ) -> Result<OntologyTemporalMetadata, Report<DataTypeError>> {
+        let actor_id = Self::actor(&scope, DataTypeError)?;
+
+        let params = request.parse().change_context(DataTypeError)?;
+
+        let mut store = self.store(DataTypeError).await?;
+
+        store
+            .archive_data_type(actor_id, params)
+            .await
+            .map_err(operation::archive_ontology_type_status)
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not archive data type");
+            })
+            .change_context(DataTypeError)
+    }
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct DataTypeDelegate<T> {
+    inner: T,
+}
+
+impl<T> DataTypeDelegate<T> {
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, C> SubsystemDelegate<C> for DataTypeDelegate<T>
+where
+    T: DataTypeSystem<
+            get_data_types(..): Send,
+            create_data_types(..): Send,
+            update_data_type(..): Send,
+            archive_data_type(..): Send,
+            ExecutionScope: Send,
+        > + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+{
+    type Error = Report<DelegationError>;
+    type ExecutionScope = T::ExecutionScope;
+    type Subsystem = meta::DataTypeSystem;
+
+    type Body<Source>
+        = impl Body<Control: AsRef<ResponseKind>, Error = <C as Encoder>::Error>
+    where
+        Source: Body<Control = !, Error: Send + Sync> + Send;
+
+    async fn call<B>(
+        self,
+        request: Request<B>,
+        scope: T::ExecutionScope,
+        codec: C,
+    ) -> Result<Response<Self::Body<B>>, Self::Error>
+    where
+        B: Body<Control = !, Error: Send + Sync> + Send,
+    {
+        let id = parse_procedure_id(&request)?;
+
+        match id {
+            meta::DataTypeProcedureId::GetDataTypes => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.get_data_types(scope, request).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left))
+            }
+            meta::DataTypeProcedureId::CreateDataTypes => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.create_data_types(scope, request).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left).map_body(Either::Right))
+            }
+            meta::DataTypeProcedureId::UpdateDataType => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.update_data_type(scope, request).await
+                })
+                .await
+                .map(|response| {
+                    response
+                        .map_body(Either::Left)
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                })
+            }
+            meta::DataTypeProcedureId::ArchiveDataType => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.archive_data_type(scope, request).await
+                })
+                .await
+                .map(|response| {
+                    response
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use core::marker::PhantomData;
+
+use error_stack::Report;
+use hash_graph_store::{
+    data_type::{ArchiveDataTypeParams, GetDataTypesParams},
+    entity::ValidateEntityParams,
+    entity_type::{ArchiveEntityTypeParams, GetEntityTypesParams},
+    property_type::{ArchivePropertyTypeParams, GetPropertyTypesParams},
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+
+use crate::rest::entity::GetEntitiesRequest;
+
+/// A request which borrows from the document it is deserialized from.
+///
+/// Filters and paths of queries borrow from the request, so they cannot be decoded by a procedure
+/// directly. The procedure receives a [`Document`] instead and parses the request from it.
+pub trait BorrowedRequest {
+    type Request<'d>: Deserialize<'d>;
+}
+
+impl BorrowedRequest for GetEntitiesRequest<'static, 'static, 'static> {
+    type Request<'d> = GetEntitiesRequest<'d, 'd, 'd>;
+}
+
+impl BorrowedRequest for ValidateEntityParams<'static> {
+    type Request<'d> = ValidateEntityParams<'d>;
+}
+
+impl BorrowedRequest for GetDataTypesParams<'static> {
+    type Request<'d> = GetDataTypesParams<'d>;
+}
+
+impl BorrowedRequest for ArchiveDataTypeParams<'static> {
+    type Request<'d> = ArchiveDataTypeParams<'d>;
+}
+
+impl BorrowedRequest for GetPropertyTypesParams<'static> {
+    type Request<'d> = GetPropertyTypesParams<'d>;
+}
+
+impl BorrowedRequest for ArchivePropertyTypeParams<'static> {
+    type Request<'d> = ArchivePropertyTypeParams<'d>;
+}
+
+impl BorrowedRequest for GetEntityTypesParams<'static> {
+    type Request<'d> = GetEntityTypesParams<'d>;
+}
+
+impl BorrowedRequest for ArchiveEntityTypeParams<'static> {
+    type Request<'d> = ArchiveEntityTypeParams<'d>;
+}
+
+/// JSON document of a [`BorrowedRequest`].
+///
+/// The document is only checked to be valid JSON when it is decoded, [`Document::parse`] checks
+/// that it is a valid request.
+#[derive_where::derive_where(Debug, Clone)]
+#[derive(Serialize, Deserialize)]
+#[serde(transparent, bound = "")]
+pub struct Document<T> {
+    value: JsonValue,
+    #[serde(skip)]
+    _request: PhantomData<fn() -> T>,
+}
+
+impl<T> Document<T>
+where
+    T: BorrowedRequest,
+{
+    #[must_use]
+    pub const fn new(value: JsonValue) -> Self {
+        Self {
+            value,
+            _request: PhantomData,
+        }
+    }
+
+    #[must_use]
+    pub const fn as_json(&self) -> &JsonValue {
+        &self.value
+    }
+
+    /// Parses the request from the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the document is not a valid request.
+    pub fn parse(&self) -> Result<T::Request<'_>, Report<serde_json::Error>> {
+        T::Request::deserialize(&self.value).map_err(Report::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hash_graph_store::data_type::ArchiveDataTypeParams;
+    use serde_json::json;
+
+    use super::Document;
+    use crate::rest::entity::GetEntitiesRequest;
+
+    #[test]
+    fn parse_borrowed_request() {
+        let document = serde_json::from_value::<
+            Document<GetEntitiesRequest<'static, 'static, 'static>>,
+        >(json!({
+            "filter": { "all": [] },
+            "temporalAxes": {
+                "pinned": { "axis": "transactionTime", "timestamp": null },
+                "variable": {
+                    "axis": "decisionTime",
+                    "interval": { "start": null, "end": null },
+                },
+            },
+            "includeDrafts": false,
+            "limit": 10,
+        }))
+        .expect("document should be valid JSON");
+
+        let request = document
+            .parse()
+            .expect("document should be a valid request");
+        assert_eq!(request.limit, Some(10));
+        assert!(!request.include_drafts);
+    }
+
+    #[test]
+    fn reject_invalid_request() {
+        let document = Document::<ArchiveDataTypeParams<'static>>::new(json!({
+            "dataTypeId": "https://example.com/@alice/types/data-type/length/v/1",
+            "unknown": true,
+        }));
+
+        document
+            .parse()
+            .expect_err("unknown fields should be rejected");
+    }
+
+    #[test]
+    fn serialize_transparently() {
+        let value = json!({ "filter": { "all": [] } });
+        let document =
+            Document::<GetEntitiesRequest<'static, 'static, 'static>>::new(value.clone());
+
+        assert_eq!(
+            serde_json::to_value(&document).expect("document should be serializable"),
+            value
+        );
+        assert_eq!(document.as_json(), &value);
+    }
+}
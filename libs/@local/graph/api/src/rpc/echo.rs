@@ -37,11 +37,12 @@ pub mod meta {
     use frunk::HList;
     use harpc_system::{
         Subsystem,
+        codegen::{DescribeProcedure, DescribeSubsystem, Type},
         procedure::{Procedure, ProcedureIdentifier},
     };
     use harpc_types::{procedure::ProcedureId, version::Version};
 
-    use crate::rpc::GraphSubsystemId;
+    use crate::rpc::{GraphSubsystemId, fallible};
 
     pub enum EchoProcedureId {
         Echo,
@@ -78,6 +79,10 @@ pub mod meta {
         };
     }
 
+    impl DescribeSubsystem for EchoSystem {
+        const NAME: &'static str = "Echo";
+    }
+
     pub struct ProcedureEcho;
 
     impl Procedure for ProcedureEcho {
@@ -85,6 +90,18 @@ pub mod meta {
 
         const ID: <Self::Subsystem as Subsystem>::ProcedureId = EchoProcedureId::Echo;
     }
+
+    impl DescribeProcedure for ProcedureEcho {
+        const NAME: &'static str = "echo";
+
+        fn request() -> Type {
+            Type::string()
+        }
+
+        fn response() -> Type {
+            fallible(Type::string())
+        }
+    }
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
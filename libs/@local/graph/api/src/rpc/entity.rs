@@ -0,0 +1,414 @@
+//! Subsystem to create, query, patch and validate entities.
+//!
+//! Queries are sent as [`Document`]s, as filters borrow from the request. Clients are generated
+//! from the [`client_schema`], which describes the procedures of this subsystem.
+//!
+//! [`client_schema`]: crate::rpc::client_schema
+use std::collections::HashMap;
+
+use error_stack::{Report, ResultExt as _};
+use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use harpc_server::{
+    error::DelegationError,
+    session::Session,
+    utils::{delegate_call_discrete, parse_procedure_id},
+};
+use harpc_system::delegate::SubsystemDelegate;
+use harpc_tower::{body::Body, either::Either, request::Request, response::Response};
+use harpc_types::response_kind::ResponseKind;
+use hash_graph_authorization::{AuthorizationApiPool, zanzibar::Consistency};
+use hash_graph_store::{
+    entity::{
+        CreateEntityRequest, EntityStore as _, EntityValidationReport, GetEntitiesResponse,
+        PatchEntityParams, ValidateEntityParams,
+    },
+    pool::StorePool,
+};
+use type_system::knowledge::Entity;
+
+use super::{Document, server::GraphServer, session::Account};
+use crate::{
+    operation,
+    rest::{OpenApiQuery, entity::GetEntitiesRequest},
+};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil entity request")]
+pub struct EntityError;
+
+pub trait EntitySystem {
+    type ExecutionScope;
+
+    async fn create_entity(
+        &self,
+        scope: Self::ExecutionScope,
+        request: CreateEntityRequest,
+    ) -> Result<Entity, Report<EntityError>>;
+
+    async fn get_entities(
+        &self,
+        scope: Self::ExecutionScope,
+        request: Document<GetEntitiesRequest<'static, 'static, 'static>>,
+    ) -> Result<GetEntitiesResponse<'static>, Report<EntityError>>;
+
+    async fn patch_entity(
+        &self,
+        scope: Self::ExecutionScope,
+        request: PatchEntityParams,
+    ) -> Result<Entity, Report<EntityError>>;
+
+    /// Returns the validation report of every component which failed to validate.
+    async fn validate_entity(
+        &self,
+        scope: Self::ExecutionScope,
+        request: Document<ValidateEntityParams<'static>>,
+    ) -> Result<HashMap<usize, EntityValidationReport>, Report<EntityError>>;
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+pub mod meta {
+    //! The `meta` module contains the metadata for the entity service.
+    //! In the future this will be automatically generated by the `harpc` crate.
+
+    use frunk::HList;
+    use harpc_system::{
+        Subsystem,
+        codegen::{DescribeProcedure, DescribeSubsystem, Type},
+        procedure::{Procedure, ProcedureIdentifier},
+    };
+    use harpc_types::{procedure::ProcedureId, version::Version};
+    use hash_graph_store::entity::{
+        EntityValidationReport, GetEntitiesResponse, PatchEntityParams, ValidateEntityParams,
+    };
+    use type_system::knowledge::Entity;
+
+    use crate::{
+        rest::entity::GetEntitiesRequest,
+        rpc::{GraphSubsystemId, fallible},
+    };
+
+    pub enum EntityProcedureId {
+        CreateEntity,
+        GetEntities,
+        PatchEntity,
+        ValidateEntity,
+    }
+
+    impl ProcedureIdentifier for EntityProcedureId {
+        type Subsystem = EntitySystem;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::CreateEntity),
+                0x01 => Some(Self::GetEntities),
+                0x02 => Some(Self::PatchEntity),
+                0x03 => Some(Self::ValidateEntity),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::CreateEntity => ProcedureId::new(0x00),
+                Self::GetEntities => ProcedureId::new(0x01),
+                Self::PatchEntity => ProcedureId::new(0x02),
+                Self::ValidateEntity => ProcedureId::new(0x03),
+            }
+        }
+    }
+
+    pub struct EntitySystem;
+
+    impl Subsystem for EntitySystem {
+        type ProcedureId = EntityProcedureId;
+        type Procedures = HList![
+            ProcedureCreateEntity,
+            ProcedureGetEntities,
+            ProcedurePatchEntity,
+            ProcedureValidateEntity
+        ];
+        type SubsystemId = GraphSubsystemId;
+
+        const ID: GraphSubsystemId = GraphSubsystemId::Entity;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x00,
+        };
+    }
+
+    impl DescribeSubsystem for EntitySystem {
+        const NAME: &'static str = "Entity";
+    }
+
+    pub struct ProcedureCreateEntity;
+
+    impl Procedure for ProcedureCreateEntity {
+        type Subsystem = EntitySystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId = EntityProcedureId::CreateEntity;
+    }
+
+    impl DescribeProcedure for ProcedureCreateEntity {
+        const NAME: &'static str = "create_entity";
+
+        fn request() -> Type {
+            // `CreateEntityRequest` is an alias of `CreateEntityParams`, whose schema has the name
+            // of the generic struct.
+            Type::named("CreateEntityRequest")
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<Entity>())
+        }
+    }
+
+    pub struct ProcedureGetEntities;
+
+    impl Procedure for ProcedureGetEntities {
+        type Subsystem = EntitySystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId = EntityProcedureId::GetEntities;
+    }
+
+    impl DescribeProcedure for ProcedureGetEntities {
+        const NAME: &'static str = "get_entities";
+
+        fn request() -> Type {
+            Type::of::<GetEntitiesRequest<'static, 'static, 'static>>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<GetEntitiesResponse<'static>>())
+        }
+    }
+
+    pub struct ProcedurePatchEntity;
+
+    impl Procedure for ProcedurePatchEntity {
+        type Subsystem = EntitySystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId = EntityProcedureId::PatchEntity;
+    }
+
+    impl DescribeProcedure for ProcedurePatchEntity {
+        const NAME: &'static str = "patch_entity";
+
+        fn request() -> Type {
+            Type::of::<PatchEntityParams>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<Entity>())
+        }
+    }
+
+    pub struct ProcedureValidateEntity;
+
+    impl Procedure for ProcedureValidateEntity {
+        type Subsystem = EntitySystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId = EntityProcedureId::ValidateEntity;
+    }
+
+    impl DescribeProcedure for ProcedureValidateEntity {
+        const NAME: &'static str = "validate_entity";
+
+        fn request() -> Type {
+            Type::of::<ValidateEntityParams<'static>>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::map(Type::of::<EntityValidationReport>()))
+        }
+    }
+}
+
+impl<S, A> EntitySystem for GraphServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn create_entity(
+        &self,
+        scope: Session<Account>,
+        request: CreateEntityRequest,
+    ) -> Result<Entity, Report<EntityError>> {
+        let actor_id = Self::actor(&scope, EntityError)?;
+
+        let mut store = self.store(EntityError).await?;
+
+        store
+            .create_entity(actor_id, request)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create entity");
+            })
+            .change_context(EntityError)
+    }
+
+    async fn get_entities(
+        &self,
+        scope: Session<Account>,
+        request: Document<GetEntitiesRequest<'static, 'static, 'static>>,
+    ) -> Result<GetEntitiesResponse<'static>, Report<EntityError>> {
+        let actor_id = Self::actor(&scope, EntityError)?;
+
+        let mut query_logger = self.query_logger.clone();
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.capture(actor_id, OpenApiQuery::GetEntities(request.as_json()));
+        }
+
+        let params = request.parse().change_context(EntityError)?;
+
+        let store = self.store(EntityError).await?;
+
+        let response = store
+            .get_entities(
+                actor_id,
+                operation::entity::get_entities_params(actor_id, params),
+            )
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not read entities");
+            })
+            .change_context(EntityError);
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.send().await.change_context(EntityError)?;
+        }
+        response
+    }
+
+    async fn patch_entity(
+        &self,
+        scope: Session<Account>,
+        request: PatchEntityParams,
+    ) -> Result<Entity, Report<EntityError>> {
+        let actor_id = Self::actor(&scope, EntityError)?;
+
+        let mut store = self.store(EntityError).await?;
+
+        store
+            .patch_entity(actor_id, request)
+            .await
+            .map_err(operation::entity::patch_entity_status)
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not patch entity");
+            })
+            .change_context(EntityError)
+    }
+
+    async fn validate_entity(
+        &self,
+        scope: Session<Account>,
+        request: Document<ValidateEntityParams<'static>>,
+    ) -> Result<HashMap<usize, EntityValidationReport>, Report<EntityError>> {
+        let actor_id = Self::actor(&scope, EntityError)?;
+
+        let mut query_logger = self.query_logger.clone();
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.capture(actor_id, OpenApiQuery::ValidateEntity(request.as_json()));
+        }
+
+        let params = request.parse().change_context(EntityError)?;
+
+        let store = self.store(EntityError).await?;
+
+        let report = store
+            .validate_entity(actor_id, Consistency::FullyConsistent, params)
+            .await;
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.send().await.change_context(EntityError)?;
+        }
+        Ok(report)
+    }
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EntityDelegate<T> {
+    inner: T,
+}
+
+impl<T> EntityDelegate<T> {
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, C> SubsystemDelegate<C> for EntityDelegate<T>
+where
+    T: EntitySystem<
+            create_entity(..): Send,
+            get_entities(..): Send,
+            patch_entity(..): Send,
+            validate_entity(..): Send,
+            ExecutionScope: Send,
+        > + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+{
+    type Error = Report<DelegationError>;
+    type ExecutionScope = T::ExecutionScope;
+    type Subsystem = meta::EntitySystem;
+
+    type Body<Source>
+        = impl Body<Control: AsRef<ResponseKind>, Error = <C as Encoder>::Error>
+    where
+        Source: Body<Control = !, Error: Send + Sync> + Send;
+
+    async fn call<B>(
+        self,
+        request: Request<B>,
+        scope: T::ExecutionScope,
+        codec: C,
+    ) -> Result<Response<Self::Body<B>>, Self::Error>
+    where
+        B: Body<Control = !, Error: Send + Sync> + Send,
+    {
+        let id = parse_procedure_id(&request)?;
+
+        match id {
+            meta::EntityProcedureId::CreateEntity => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.create_entity(scope, request).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left))
+            }
+            meta::EntityProcedureId::GetEntities => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.get_entities(scope, request).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left).map_body(Either::Right))
+            }
+            meta::EntityProcedureId::PatchEntity => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.patch_entity(scope, request).await
+                })
+                .await
+                .map(|response| {
+                    response
+                        .map_body(Either::Left)
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                })
+            }
+            meta::EntityProcedureId::ValidateEntity => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.validate_entity(scope, request).await
+                })
+                .await
+                .map(|response| {
+                    response
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                })
+            }
+        }
+    }
+}
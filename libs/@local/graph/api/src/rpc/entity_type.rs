@@ -0,0 +1,415 @@
+//! Subsystem to query, create, update and archive entity types.
+//!
+//! Errors are returned as reports, unlike `POST /entity-types` of the REST API, which describes a
+//! failure to create an entity type by a `Status` with the reason of the failure.
+
+use error_stack::{Report, ResultExt as _};
+use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use harpc_server::{
+    error::DelegationError,
+    session::Session,
+    utils::{delegate_call_discrete, parse_procedure_id},
+};
+use harpc_system::delegate::SubsystemDelegate;
+use harpc_tower::{body::Body, either::Either, request::Request, response::Response};
+use harpc_types::response_kind::ResponseKind;
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    entity_type::{
+        ArchiveEntityTypeParams, EntityTypeStore as _, GetEntityTypesParams, GetEntityTypesResponse,
+    },
+    pool::StorePool,
+};
+use type_system::ontology::{OntologyTemporalMetadata, entity_type::EntityTypeMetadata};
+
+use super::{Document, server::GraphServer, session::Account};
+use crate::{
+    operation,
+    rest::{
+        OpenApiQuery,
+        entity_type::{CreateEntityTypeRequest, UpdateEntityTypeRequest},
+    },
+};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil entity type request")]
+pub struct EntityTypeError;
+
+pub trait EntityTypeSystem {
+    type ExecutionScope;
+
+    async fn get_entity_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: Document<GetEntityTypesParams<'static>>,
+    ) -> Result<GetEntityTypesResponse, Report<EntityTypeError>>;
+
+    /// Creates the entity types of the request, the metadata is returned in the order of the
+    /// schemas.
+    async fn create_entity_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: CreateEntityTypeRequest,
+    ) -> Result<Vec<EntityTypeMetadata>, Report<EntityTypeError>>;
+
+    /// Creates the next version of the entity type to update.
+    async fn update_entity_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: UpdateEntityTypeRequest,
+    ) -> Result<EntityTypeMetadata, Report<EntityTypeError>>;
+
+    async fn archive_entity_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: Document<ArchiveEntityTypeParams<'static>>,
+    ) -> Result<OntologyTemporalMetadata, Report<EntityTypeError>>;
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+pub mod meta {
+    //! The `meta` module contains the metadata for the entity type service.
+    //! In the future this will be automatically generated by the `harpc` crate.
+
+    use frunk::HList;
+    use harpc_system::{
+        Subsystem,
+        codegen::{DescribeProcedure, DescribeSubsystem, Type},
+        procedure::{Procedure, ProcedureIdentifier},
+    };
+    use harpc_types::{procedure::ProcedureId, version::Version};
+    use hash_graph_store::entity_type::{
+        ArchiveEntityTypeParams, GetEntityTypesParams, GetEntityTypesResponse,
+    };
+    use type_system::ontology::{OntologyTemporalMetadata, entity_type::EntityTypeMetadata};
+
+    use crate::{
+        rest::entity_type::{CreateEntityTypeRequest, UpdateEntityTypeRequest},
+        rpc::{GraphSubsystemId, fallible},
+    };
+
+    pub enum EntityTypeProcedureId {
+        GetEntityTypes,
+        CreateEntityTypes,
+        UpdateEntityType,
+        ArchiveEntityType,
+    }
+
+    impl ProcedureIdentifier for EntityTypeProcedureId {
+        type Subsystem = EntityTypeSystem;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::GetEntityTypes),
+                0x01 => Some(Self::CreateEntityTypes),
+                0x02 => Some(Self::UpdateEntityType),
+                0x03 => Some(Self::ArchiveEntityType),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::GetEntityTypes => ProcedureId::new(0x00),
+                Self::CreateEntityTypes => ProcedureId::new(0x01),
+                Self::UpdateEntityType => ProcedureId::new(0x02),
+                Self::ArchiveEntityType => ProcedureId::new(0x03),
+            }
+        }
+    }
+
+    pub struct EntityTypeSystem;
+
+    impl Subsystem for EntityTypeSystem {
+        type ProcedureId = EntityTypeProcedureId;
+        type Procedures = HList![
+            ProcedureGetEntityTypes,
+            ProcedureCreateEntityTypes,
+            ProcedureUpdateEntityType,
+            ProcedureArchiveEntityType
+        ];
+        type SubsystemId = GraphSubsystemId;
+
+        const ID: GraphSubsystemId = GraphSubsystemId::EntityType;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x00,
+        };
+    }
+
+    impl DescribeSubsystem for EntityTypeSystem {
+        const NAME: &'static str = "EntityType";
+    }
+
+    pub struct ProcedureGetEntityTypes;
+
+    impl Procedure for ProcedureGetEntityTypes {
+        type Subsystem = EntityTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            EntityTypeProcedureId::GetEntityTypes;
+    }
+
+    impl DescribeProcedure for ProcedureGetEntityTypes {
+        const NAME: &'static str = "get_entity_types";
+
+        fn request() -> Type {
+            Type::of::<GetEntityTypesParams<'static>>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<GetEntityTypesResponse>())
+        }
+    }
+
+    pub struct ProcedureCreateEntityTypes;
+
+    impl Procedure for ProcedureCreateEntityTypes {
+        type Subsystem = EntityTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            EntityTypeProcedureId::CreateEntityTypes;
+    }
+
+    impl DescribeProcedure for ProcedureCreateEntityTypes {
+        const NAME: &'static str = "create_entity_types";
+
+        fn request() -> Type {
+            Type::of::<CreateEntityTypeRequest>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::list(Type::of::<EntityTypeMetadata>()))
+        }
+    }
+
+    pub struct ProcedureUpdateEntityType;
+
+    impl Procedure for ProcedureUpdateEntityType {
+        type Subsystem = EntityTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            EntityTypeProcedureId::UpdateEntityType;
+    }
+
+    impl DescribeProcedure for ProcedureUpdateEntityType {
+        const NAME: &'static str = "update_entity_type";
+
+        fn request() -> Type {
+            Type::of::<UpdateEntityTypeRequest>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<EntityTypeMetadata>())
+        }
+    }
+
+    pub struct ProcedureArchiveEntityType;
+
+    impl Procedure for ProcedureArchiveEntityType {
+        type Subsystem = EntityTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            EntityTypeProcedureId::ArchiveEntityType;
+    }
+
+    impl DescribeProcedure for ProcedureArchiveEntityType {
+        const NAME: &'static str = "archive_entity_type";
+
+        fn request() -> Type {
+            Type::of::<ArchiveEntityTypeParams<'static>>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<OntologyTemporalMetadata>())
+        }
+    }
+}
+
+impl<S, A> EntityTypeSystem for GraphServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn get_entity_types(
+        &self,
+        scope: Session<Account>,
+        request: Document<GetEntityTypesParams<'static>>,
+    ) -> Result<GetEntityTypesResponse, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope, EntityTypeError)?;
+
+        let mut query_logger = self.query_logger.clone();
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.capture(actor_id, OpenApiQuery::GetEntityTypes(request.as_json()));
+        }
+
+        let params = request.parse().change_context(EntityTypeError)?;
+
+        let store = self.store(EntityTypeError).await?;
+
+        let response = store
+            .get_entity_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not read entity types");
+            })
+            .change_context(EntityTypeError);
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.send().await.change_context(EntityTypeError)?;
+        }
+        response
+    }
+
+    async fn create_entity_types(
+        &self,
+        scope: Session<Account>,
+        request: CreateEntityTypeRequest,
+    ) -> Result<Vec<EntityTypeMetadata>, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope, EntityTypeError)?;
+
+        let params = operation::entity_type::create_params(request, &self.domain_validator)
+            .change_context(EntityTypeError)?;
+
+        let mut store = self.store(EntityTypeError).await?;
+
+        store
+            .create_entity_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create entity types");
+            })
+            .change_context(EntityTypeError)
+    }
+
+    async fn update_entity_type(
+        &self,
+        scope: Session<Account>,
+        request: UpdateEntityTypeRequest,
+    ) -> Result<EntityTypeMetadata, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope, EntityTypeError)?;
+
+        let params =
+            operation::entity_type::update_params(request).change_context(EntityTypeError)?;
+
+        let mut store = self.store(EntityTypeError).await?;
+
+        store
+            .update_entity_type(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not update entity type");
+            })
+            .change_context(EntityTypeError)
+    }
+
+    async fn archive_entity_type(
+        &self,
+        scope: Session<Account>,
+        request: Document<ArchiveEntityTypeParams<'static>>,
+    ) -> Result<OntologyTemporalMetadata, Report<EntityTypeError>> {
+        let actor_id = Self::actor(&scope, EntityTypeError)?;
+
+        let params = request.parse().change_context(EntityTypeError)?;
+
+        let mut store = self.store(EntityTypeError).await?;
+
+        store
+            .archive_entity_type(actor_id, params)
+            .await
+            .map_err(operation::archive_ontology_type_status)
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not archive entity type");
+            })
+            .change_context(EntityTypeError)
+    }
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EntityTypeDelegate<T> {
+    inner: T,
+}
+
+impl<T> EntityTypeDelegate<T> {
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, C> SubsystemDelegate<C> for EntityTypeDelegate<T>
+where
+    T: EntityTypeSystem<
+            get_entity_types(..): Send,
+            create_entity_types(..): Send,
+            update_entity_type(..): Send,
+            archive_entity_type(..): Send,
+            ExecutionScope: Send,
+        > + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+{
+    type Error = Report<DelegationError>;
+    type ExecutionScope = T::ExecutionScope;
+    type Subsystem = meta::EntityTypeSystem;
+
+    type Body<Source>
+        = impl Body<Control: AsRef<ResponseKind>, Error = <C as Encoder>::Error>
+    where
+        Source: Body<Control = !, Error: Send + Sync> + Send;
+
+    async fn call<B>(
+        self,
+        request: Request<B>,
+        scope: T::ExecutionScope,
+        codec: C,
+    ) -> Result<Response<Self::Body<B>>, Self::Error>
+    where
+        B: Body<Control = !, Error: Send + Sync> + Send,
+    {
+        let id = parse_procedure_id(&request)?;
+
+        match id {
+            meta::EntityTypeProcedureId::GetEntityTypes => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.get_entity_types(scope, request).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left))
+            }
+            meta::EntityTypeProcedureId::CreateEntityTypes => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.create_entity_types(scope, request).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left).map_body(Either::Right))
+            }
+            meta::EntityTypeProcedureId::UpdateEntityType => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.update_entity_type(scope, request).await
+                })
+                .await
+                .map(|response| {
+                    response
+                        .map_body(Either::Left)
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                })
+            }
+            meta::EntityTypeProcedureId::ArchiveEntityType => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.archive_entity_type(scope, request).await
+                })
+                .await
+                .map(|response| {
+                    response
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                })
+            }
+        }
+    }
+}
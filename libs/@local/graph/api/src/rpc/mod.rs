@@ -1,17 +1,28 @@
 pub mod account;
 pub mod auth;
+pub mod change_feed;
+pub mod data_type;
+mod document;
 pub mod echo;
+pub mod entity;
+pub mod entity_type;
+pub mod property_type;
+mod server;
 mod session;
 
 use alloc::sync::Arc;
 
 use harpc_codec::{decode::ReportDecoder, encode::ReportEncoder};
 use harpc_server::{
+    limit::{LimitKey, LimitLayer, Quota},
     route::Route,
     router::{Router, RouterBuilder},
-    session::Task,
+    session::{SessionId, Task},
+};
+use harpc_system::{
+    SubsystemIdentifier,
+    codegen::{ClientSchema, Type},
 };
-use harpc_system::SubsystemIdentifier;
 use harpc_tower::{
     body::server::request::RequestBody,
     layer::{body_report::HandleBodyReportLayer, report::HandleReportLayer},
@@ -20,19 +31,34 @@ use harpc_types::subsystem::SubsystemId;
 use hash_graph_authorization::AuthorizationApiPool;
 use hash_graph_store::pool::StorePool;
 use hash_temporal_client::TemporalClient;
+use type_system::ontology::json_schema::DomainValidator;
+use utoipa::OpenApi as _;
 
+pub use self::document::{BorrowedRequest, Document};
 use self::{
     account::{AccountDelegate, AccountServer},
     auth::{AuthenticationDelegate, AuthenticationServer},
+    change_feed::ChangeFeedDelegate,
+    data_type::DataTypeDelegate,
     echo::{EchoDelegate, EchoServer},
+    entity::EntityDelegate,
+    entity_type::EntityTypeDelegate,
+    property_type::PropertyTypeDelegate,
+    server::GraphServer,
     session::Account,
 };
+use crate::rest::{OpenApiDocumentation, QueryLogger};
 
 #[derive(Debug, Copy, Clone)]
 pub enum GraphSubsystemId {
     Echo,
     Authentication,
     Account,
+    Entity,
+    DataType,
+    PropertyType,
+    EntityType,
+    ChangeFeed,
 }
 
 impl SubsystemIdentifier for GraphSubsystemId {
@@ -44,6 +70,11 @@ impl SubsystemIdentifier for GraphSubsystemId {
             0x00 => Some(Self::Echo),
             0x01 => Some(Self::Authentication),
             0x02 => Some(Self::Account),
+            0x03 => Some(Self::Entity),
+            0x04 => Some(Self::DataType),
+            0x05 => Some(Self::PropertyType),
+            0x06 => Some(Self::EntityType),
+            0x07 => Some(Self::ChangeFeed),
             _ => None,
         }
     }
@@ -53,15 +84,37 @@ impl SubsystemIdentifier for GraphSubsystemId {
             Self::Echo => SubsystemId::new(0x00),
             Self::Authentication => SubsystemId::new(0x01),
             Self::Account => SubsystemId::new(0x02),
+            Self::Entity => SubsystemId::new(0x03),
+            Self::DataType => SubsystemId::new(0x04),
+            Self::PropertyType => SubsystemId::new(0x05),
+            Self::EntityType => SubsystemId::new(0x06),
+            Self::ChangeFeed => SubsystemId::new(0x07),
         }
     }
 }
 
+/// Quotas enforced by the RPC server.
+///
+/// Requests exceeding a quota are rejected with `TOO_MANY_REQUESTS`.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct Limits {
+    /// Quota enforced for every connected peer.
+    pub peer: Quota,
+    /// Quota enforced for every authenticated actor, regardless of the session or peer used.
+    ///
+    /// Sessions without an authenticated actor are limited individually.
+    pub actor: Quota,
+}
+
 pub struct Dependencies<S, A, C> {
     pub store: Arc<S>,
     pub authorization_api: Arc<A>,
     pub temporal_client: Option<TemporalClient>,
+    pub domain_validator: DomainValidator,
+    /// Logger for the queries sent to the graph, shared with the REST API.
+    pub query_logger: Option<QueryLogger>,
     pub codec: C,
+    pub limits: Limits,
 }
 
 #[expect(
@@ -80,9 +133,31 @@ where
     A: AuthorizationApiPool + Send + Sync + 'static,
     C: ReportEncoder + ReportDecoder + Clone + Send + Sync + 'static,
 {
-    let builder = RouterBuilder::new(dependencies.codec)
+    let temporal_client = dependencies.temporal_client.map(Arc::new);
+
+    let graph = GraphServer {
+        store_pool: Arc::clone(&dependencies.store),
+        authorization_api_pool: Arc::clone(&dependencies.authorization_api),
+        temporal_client: temporal_client.clone(),
+        domain_validator: dependencies.domain_validator,
+        query_logger: dependencies.query_logger,
+    };
+
+    let builder = RouterBuilder::new::<Account>(dependencies.codec);
+    let sessions = builder.session_storage();
+
+    let builder = builder
         .with_builder(|builder| {
             builder
+                .layer(LimitLayer::new(LimitKey::Peer, dependencies.limits.peer))
+                .layer(LimitLayer::actor(
+                    move |session: SessionId| {
+                        sessions
+                            .peek_with(session, |account| account.actor_id)
+                            .flatten()
+                    },
+                    dependencies.limits.actor,
+                ))
                 .layer(HandleReportLayer::new())
                 .layer(HandleBodyReportLayer::new())
         })
@@ -90,8 +165,13 @@ where
         .register(AccountDelegate::new(AccountServer {
             store_pool: dependencies.store,
             authorization_api_pool: dependencies.authorization_api,
-            temporal_client: dependencies.temporal_client.map(Arc::new),
+            temporal_client,
         }))
+        .register(EntityDelegate::new(graph.clone()))
+        .register(DataTypeDelegate::new(graph.clone()))
+        .register(PropertyTypeDelegate::new(graph.clone()))
+        .register(EntityTypeDelegate::new(graph.clone()))
+        .register(ChangeFeedDelegate::new(graph))
         .register(EchoDelegate::new(EchoServer));
 
     let task = builder.background_task(notifications);
@@ -100,3 +180,143 @@ where
 
     (router, task)
 }
+
+/// Response of a procedure which may fail.
+///
+/// Errors are serialized reports, which are not described any further.
+fn fallible(ok: Type) -> Type {
+    Type::result(ok, Type::unknown())
+}
+
+/// Describes every subsystem of the graph, used to generate client bindings.
+///
+/// Types are shared with the REST API, the components of the [`OpenApiDocumentation`] are used to
+/// resolve named types.
+#[must_use]
+pub fn client_schema() -> ClientSchema {
+    let components = OpenApiDocumentation::openapi()
+        .components
+        .map(|components| components.schemas)
+        .unwrap_or_default();
+
+    ClientSchema::new()
+        .subsystem::<echo::meta::EchoSystem>()
+        .subsystem::<auth::meta::AuthenticationSystem>()
+        .subsystem::<account::meta::AccountSystem>()
+        .subsystem::<entity::meta::EntitySystem>()
+        .subsystem::<data_type::meta::DataTypeSystem>()
+        .subsystem::<property_type::meta::PropertyTypeSystem>()
+        .subsystem::<entity_type::meta::EntityTypeSystem>()
+        .subsystem::<change_feed::meta::ChangeFeedSystem>()
+        .with_components(components)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use harpc_system::{SubsystemIdentifier as _, codegen::Type};
+    use harpc_types::subsystem::SubsystemId;
+    use serde_json::Value as JsonValue;
+
+    use super::{GraphSubsystemId, client_schema};
+
+    fn type_references(r#type: &Type, references: &mut Vec<String>) {
+        match r#type {
+            Type::Schema(schema) => schema_references(
+                &serde_json::to_value(schema).expect("schema should be serializable"),
+                references,
+            ),
+            Type::Tuple(items) => {
+                for item in items {
+                    type_references(item, references);
+                }
+            }
+            Type::List(item) | Type::Map(item) | Type::Option(item) | Type::Stream(item) => {
+                type_references(item, references);
+            }
+            Type::Result { ok, err } => {
+                type_references(ok, references);
+                type_references(err, references);
+            }
+            Type::Unit => {}
+        }
+    }
+
+    fn schema_references(schema: &JsonValue, references: &mut Vec<String>) {
+        match schema {
+            JsonValue::Object(object) => {
+                for (key, value) in object {
+                    match (key.as_str(), value) {
+                        ("$ref", JsonValue::String(reference)) => {
+                            references.push(reference.clone());
+                        }
+                        _ => schema_references(value, references),
+                    }
+                }
+            }
+            JsonValue::Array(items) => {
+                for item in items {
+                    schema_references(item, references);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    #[test]
+    fn every_subsystem_is_described() {
+        let schema = client_schema();
+
+        for id in (0..=u16::MAX).map(SubsystemId::new) {
+            if GraphSubsystemId::from_id(id).is_none() {
+                continue;
+            }
+
+            assert!(
+                schema
+                    .subsystems
+                    .iter()
+                    .any(|subsystem| subsystem.information.descriptor.id == id),
+                "subsystem {id} is not described"
+            );
+        }
+    }
+
+    #[test]
+    fn references_are_components() {
+        let schema = client_schema();
+
+        let mut references = Vec::new();
+        for procedure in schema
+            .subsystems
+            .iter()
+            .flat_map(|subsystem| &subsystem.procedures)
+        {
+            type_references(&procedure.request, &mut references);
+            type_references(&procedure.response, &mut references);
+        }
+
+        let mut visited = HashSet::new();
+        while let Some(reference) = references.pop() {
+            // Models are stored next to the `OpenAPI` specification and are not resolved
+            let Some(name) = reference.strip_prefix("#/components/schemas/") else {
+                continue;
+            };
+
+            if !visited.insert(name.to_owned()) {
+                continue;
+            }
+
+            let component = schema
+                .components
+                .get(name)
+                .unwrap_or_else(|| panic!("`{name}` is not a component of the REST API"));
+
+            schema_references(
+                &serde_json::to_value(component).expect("schema should be serializable"),
+                &mut references,
+            );
+        }
+    }
+}
@@ -0,0 +1,419 @@
+//! Subsystem to query, create, update and archive property types.
+//!
+//! Updating a property type never modifies an existing version, it creates the version following
+//! the one to update from the provided schema.
+
+use error_stack::{Report, ResultExt as _};
+use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use harpc_server::{
+    error::DelegationError,
+    session::Session,
+    utils::{delegate_call_discrete, parse_procedure_id},
+};
+use harpc_system::delegate::SubsystemDelegate;
+use harpc_tower::{body::Body, either::Either, request::Request, response::Response};
+use harpc_types::response_kind::ResponseKind;
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::{
+    pool::StorePool,
+    property_type::{
+        ArchivePropertyTypeParams, GetPropertyTypesParams, GetPropertyTypesResponse,
+        PropertyTypeStore as _,
+    },
+};
+use type_system::ontology::{OntologyTemporalMetadata, property_type::PropertyTypeMetadata};
+
+use super::{Document, server::GraphServer, session::Account};
+use crate::{
+    operation,
+    rest::{
+        OpenApiQuery,
+        property_type::{CreatePropertyTypeRequest, UpdatePropertyTypeRequest},
+    },
+};
+
+#[must_use]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, derive_more::Display, derive_more::Error)]
+#[display("unable to fullfil property type request")]
+pub struct PropertyTypeError;
+
+pub trait PropertyTypeSystem {
+    type ExecutionScope;
+
+    async fn get_property_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: Document<GetPropertyTypesParams<'static>>,
+    ) -> Result<GetPropertyTypesResponse, Report<PropertyTypeError>>;
+
+    /// Creates the property types of the request, the metadata is returned in the order of the
+    /// schemas.
+    async fn create_property_types(
+        &self,
+        scope: Self::ExecutionScope,
+        request: CreatePropertyTypeRequest,
+    ) -> Result<Vec<PropertyTypeMetadata>, Report<PropertyTypeError>>;
+
+    /// Creates the next version of the property type to update.
+    async fn update_property_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: UpdatePropertyTypeRequest,
+    ) -> Result<PropertyTypeMetadata, Report<PropertyTypeError>>;
+
+    async fn archive_property_type(
+        &self,
+        scope: Self::ExecutionScope,
+        request: Document<ArchivePropertyTypeParams<'static>>,
+    ) -> Result<OntologyTemporalMetadata, Report<PropertyTypeError>>;
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+pub mod meta {
+    //! The `meta` module contains the metadata for the property type service.
+    //! In the future this will be automatically generated by the `harpc` crate.
+
+    use frunk::HList;
+    use harpc_system::{
+        Subsystem,
+        codegen::{DescribeProcedure, DescribeSubsystem, Type},
+        procedure::{Procedure, ProcedureIdentifier},
+    };
+    use harpc_types::{procedure::ProcedureId, version::Version};
+    use hash_graph_store::property_type::{
+        ArchivePropertyTypeParams, GetPropertyTypesParams, GetPropertyTypesResponse,
+    };
+    use type_system::ontology::{OntologyTemporalMetadata, property_type::PropertyTypeMetadata};
+
+    use crate::{
+        rest::property_type::{CreatePropertyTypeRequest, UpdatePropertyTypeRequest},
+        rpc::{GraphSubsystemId, fallible},
+    };
+
+    pub enum PropertyTypeProcedureId {
+        GetPropertyTypes,
+        CreatePropertyTypes,
+        UpdatePropertyType,
+        ArchivePropertyType,
+    }
+
+    impl ProcedureIdentifier for PropertyTypeProcedureId {
+        type Subsystem = PropertyTypeSystem;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::GetPropertyTypes),
+                0x01 => Some(Self::CreatePropertyTypes),
+                0x02 => Some(Self::UpdatePropertyType),
+                0x03 => Some(Self::ArchivePropertyType),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::GetPropertyTypes => ProcedureId::new(0x00),
+                Self::CreatePropertyTypes => ProcedureId::new(0x01),
+                Self::UpdatePropertyType => ProcedureId::new(0x02),
+                Self::ArchivePropertyType => ProcedureId::new(0x03),
+            }
+        }
+    }
+
+    pub struct PropertyTypeSystem;
+
+    impl Subsystem for PropertyTypeSystem {
+        type ProcedureId = PropertyTypeProcedureId;
+        type Procedures = HList![
+            ProcedureGetPropertyTypes,
+            ProcedureCreatePropertyTypes,
+            ProcedureUpdatePropertyType,
+            ProcedureArchivePropertyType
+        ];
+        type SubsystemId = GraphSubsystemId;
+
+        const ID: GraphSubsystemId = GraphSubsystemId::PropertyType;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x00,
+        };
+    }
+
+    impl DescribeSubsystem for PropertyTypeSystem {
+        const NAME: &'static str = "PropertyType";
+    }
+
+    pub struct ProcedureGetPropertyTypes;
+
+    impl Procedure for ProcedureGetPropertyTypes {
+        type Subsystem = PropertyTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            PropertyTypeProcedureId::GetPropertyTypes;
+    }
+
+    impl DescribeProcedure for ProcedureGetPropertyTypes {
+        const NAME: &'static str = "get_property_types";
+
+        fn request() -> Type {
+            Type::of::<GetPropertyTypesParams<'static>>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<GetPropertyTypesResponse>())
+        }
+    }
+
+    pub struct ProcedureCreatePropertyTypes;
+
+    impl Procedure for ProcedureCreatePropertyTypes {
+        type Subsystem = PropertyTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            PropertyTypeProcedureId::CreatePropertyTypes;
+    }
+
+    impl DescribeProcedure for ProcedureCreatePropertyTypes {
+        const NAME: &'static str = "create_property_types";
+
+        fn request() -> Type {
+            Type::of::<CreatePropertyTypeRequest>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::list(Type::of::<PropertyTypeMetadata>()))
+        }
+    }
+
+    pub struct ProcedureUpdatePropertyType;
+
+    impl Procedure for ProcedureUpdatePropertyType {
+        type Subsystem = PropertyTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            PropertyTypeProcedureId::UpdatePropertyType;
+    }
+
+    impl DescribeProcedure for ProcedureUpdatePropertyType {
+        const NAME: &'static str = "update_property_type";
+
+        fn request() -> Type {
+            Type::of::<UpdatePropertyTypeRequest>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<PropertyTypeMetadata>())
+        }
+    }
+
+    pub struct ProcedureArchivePropertyType;
+
+    impl Procedure for ProcedureArchivePropertyType {
+        type Subsystem = PropertyTypeSystem;
+
+        const ID: <Self::Subsystem as Subsystem>::ProcedureId =
+            PropertyTypeProcedureId::ArchivePropertyType;
+    }
+
+    impl DescribeProcedure for ProcedureArchivePropertyType {
+        const NAME: &'static str = "archive_property_type";
+
+        fn request() -> Type {
+            Type::of::<ArchivePropertyTypeParams<'static>>()
+        }
+
+        fn response() -> Type {
+            fallible(Type::of::<OntologyTemporalMetadata>())
+        }
+    }
+}
+
+impl<S, A> PropertyTypeSystem for GraphServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    type ExecutionScope = Session<Account>;
+
+    async fn get_property_types(
+        &self,
+        scope: Session<Account>,
+        request: Document<GetPropertyTypesParams<'static>>,
+    ) -> Result<GetPropertyTypesResponse, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope, PropertyTypeError)?;
+
+        let mut query_logger = self.query_logger.clone();
+        if let Some(query_logger) = &mut query_logger {
+            query_logger.capture(actor_id, OpenApiQuery::GetPropertyTypes(request.as_json()));
+        }
+
+        let params = request.parse().change_context(PropertyTypeError)?;
+
+        let store = self.store(PropertyTypeError).await?;
+
+        let response = store
+            .get_property_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not read property types");
+            })
+            .change_context(PropertyTypeError);
+        if let Some(query_logger) = &mut query_logger {
+            query_logger
+                .send()
+                .await
+                .change_context(PropertyTypeError)?;
+        }
+        response
+    }
+
+    async fn create_property_types(
+        &self,
+        scope: Session<Account>,
+        request: CreatePropertyTypeRequest,
+    ) -> Result<Vec<PropertyTypeMetadata>, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope, PropertyTypeError)?;
+
+        let params = operation::property_type::create_params(request, &self.domain_validator)
+            .change_context(PropertyTypeError)?;
+
+        let mut store = self.store(PropertyTypeError).await?;
+
+        store
+            .create_property_types(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not create property types");
+            })
+            .change_context(PropertyTypeError)
+    }
+
+    async fn update_property_type(
+        &self,
+        scope: Session<Account>,
+        request: UpdatePropertyTypeRequest,
+    ) -> Result<PropertyTypeMetadata, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope, PropertyTypeError)?;
+
+        let params =
+            operation::property_type::update_params(request).change_context(PropertyTypeError)?;
+
+        let mut store = self.store(PropertyTypeError).await?;
+
+        store
+            .update_property_type(actor_id, params)
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not update property type");
+            })
+            .change_context(PropertyTypeError)
+    }
+
+    async fn archive_property_type(
+        &self,
+        scope: Session<Account>,
+        request: Document<ArchivePropertyTypeParams<'static>>,
+    ) -> Result<OntologyTemporalMetadata, Report<PropertyTypeError>> {
+        let actor_id = Self::actor(&scope, PropertyTypeError)?;
+
+        let params = request.parse().change_context(PropertyTypeError)?;
+
+        let mut store = self.store(PropertyTypeError).await?;
+
+        store
+            .archive_property_type(actor_id, params)
+            .await
+            .map_err(operation::archive_ontology_type_status)
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not archive property type");
+            })
+            .change_context(PropertyTypeError)
+    }
+}
+
+// TODO: this can be auto generated by the `harpc` crate
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PropertyTypeDelegate<T> {
+    inner: T,
+}
+
+impl<T> PropertyTypeDelegate<T> {
+    #[must_use]
+    pub const fn new(inner: T) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T, C> SubsystemDelegate<C> for PropertyTypeDelegate<T>
+where
+    T: PropertyTypeSystem<
+            get_property_types(..): Send,
+            create_property_types(..): Send,
+            update_property_type(..): Send,
+            archive_property_type(..): Send,
+            ExecutionScope: Send,
+        > + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+{
+    type Error = Report<DelegationError>;
+    type ExecutionScope = T::ExecutionScope;
+    type Subsystem = meta::PropertyTypeSystem;
+
+    type Body<Source>
+        = impl Body<Control: AsRef<ResponseKind>, Error = <C as Encoder>::Error>
+    where
+        Source: Body<Control = !, Error: Send + Sync> + Send;
+
+    async fn call<B>(
+        self,
+        request: Request<B>,
+        scope: T::ExecutionScope,
+        codec: C,
+    ) -> Result<Response<Self::Body<B>>, Self::Error>
+    where
+        B: Body<Control = !, Error: Send + Sync> + Send,
+    {
+        let id = parse_procedure_id(&request)?;
+
+        match id {
+            meta::PropertyTypeProcedureId::GetPropertyTypes => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.get_property_types(scope, request).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left))
+            }
+            meta::PropertyTypeProcedureId::CreatePropertyTypes => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.create_property_types(scope, request).await
+                })
+                .await
+                .map(|response| response.map_body(Either::Left).map_body(Either::Right))
+            }
+            meta::PropertyTypeProcedureId::UpdatePropertyType => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.update_property_type(scope, request).await
+                })
+                .await
+                .map(|response| {
+                    response
+                        .map_body(Either::Left)
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                })
+            }
+            meta::PropertyTypeProcedureId::ArchivePropertyType => {
+                delegate_call_discrete(request, codec, |request| async move {
+                    self.inner.archive_property_type(scope, request).await
+                })
+                .await
+                .map(|response| {
+                    response
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                        .map_body(Either::Right)
+                })
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use alloc::{borrow::Cow, sync::Arc};
+
+use error_stack::{Context, Report, ResultExt as _};
+use harpc_server::{error::Forbidden, session::Session};
+use hash_graph_authorization::AuthorizationApiPool;
+use hash_graph_store::pool::StorePool;
+use hash_temporal_client::TemporalClient;
+use type_system::{ontology::json_schema::DomainValidator, provenance::ActorEntityUuid};
+
+use super::session::Account;
+use crate::rest::QueryLogger;
+
+/// Server for the subsystems which operate on the knowledge graph and the ontology.
+///
+/// The same server is registered for the entity, data type, property type and entity type
+/// subsystems, as all of them require the same dependencies.
+#[derive_where::derive_where(Debug, Clone)]
+pub struct GraphServer<S, A> {
+    pub store_pool: Arc<S>,
+    pub authorization_api_pool: Arc<A>,
+    pub temporal_client: Option<Arc<TemporalClient>>,
+    #[derive_where(skip(Debug))]
+    pub domain_validator: DomainValidator,
+    pub query_logger: Option<QueryLogger>,
+}
+
+impl<S, A> GraphServer<S, A>
+where
+    S: StorePool + Send + Sync,
+    A: AuthorizationApiPool + Send + Sync,
+{
+    pub(super) async fn store<C>(&self, context: C) -> Result<S::Store<'_, A::Api<'_>>, Report<C>>
+    where
+        C: Context + Copy,
+    {
+        let authorization_api = self
+            .authorization_api_pool
+            .acquire()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(context)?;
+
+        self.store_pool
+            .acquire(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(context)
+    }
+
+    /// Acquires a store which does not borrow from the server, e.g. to be used by a stream.
+    pub(super) async fn store_owned<C>(
+        &self,
+        context: C,
+    ) -> Result<S::Store<'static, A::Api<'static>>, Report<C>>
+    where
+        C: Context + Copy,
+    {
+        let authorization_api = self
+            .authorization_api_pool
+            .acquire_owned()
+            .await
+            .inspect_err(|error| {
+                tracing::error!(?error, "Could not acquire access to the authorization API");
+            })
+            .change_context(context)?;
+
+        self.store_pool
+            .acquire_owned(authorization_api, self.temporal_client.clone())
+            .await
+            .inspect_err(|report| {
+                tracing::error!(error=?report, "Could not acquire store");
+            })
+            .change_context(context)
+    }
+
+    pub(super) fn actor<C>(
+        session: &Session<Account>,
+        context: C,
+    ) -> Result<ActorEntityUuid, Report<C>>
+    where
+        C: Context,
+    {
+        let &Account {
+            actor_id: Some(actor_id),
+        } = session.get()
+        else {
+            let request_info = session.request_info();
+
+            return Err(Report::new(Forbidden {
+                subsystem: request_info.subsystem,
+                procedure: request_info.procedure,
+                reason: Cow::Borrowed("user authentication required"),
+            })
+            .change_context(context));
+        };
+
+        Ok(actor_id)
+    }
+}
@@ -50,6 +50,7 @@ impl Relation<ActorGroupId> for AccountGroupResourceRelation {}
 pub enum AccountGroupPermission {
     AddMember,
     RemoveMember,
+    Member,
 }
 
 impl Permission<ActorGroupId> for AccountGroupPermission {}
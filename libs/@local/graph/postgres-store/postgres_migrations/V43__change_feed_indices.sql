@@ -0,0 +1,9 @@
+CREATE INDEX entity_temporal_metadata_transaction_time_idx
+ON entity_temporal_metadata (lower(transaction_time), entity_edition_id);
+
+CREATE INDEX ontology_temporal_metadata_created_idx
+ON ontology_temporal_metadata (lower(transaction_time), ontology_id);
+
+CREATE INDEX ontology_temporal_metadata_archived_idx
+ON ontology_temporal_metadata (upper(transaction_time), ontology_id)
+WHERE upper(transaction_time) IS NOT NULL;
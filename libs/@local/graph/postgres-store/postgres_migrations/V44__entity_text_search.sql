@@ -0,0 +1,71 @@
+CREATE TABLE "web_text_search_configs" (
+    "web_id" UUID      PRIMARY KEY REFERENCES "webs" ON DELETE CASCADE,
+    "config" REGCONFIG NOT NULL
+);
+
+CREATE TABLE "entity_edition_search" (
+    "entity_edition_id" UUID      PRIMARY KEY REFERENCES "entity_editions" ON DELETE CASCADE,
+    "config"            REGCONFIG NOT NULL,
+    "search_vector"     TSVECTOR  NOT NULL
+);
+
+CREATE INDEX "entity_edition_search_vector_idx"
+    ON "entity_edition_search" USING gin ("search_vector");
+
+-- Returns the text search configuration of a web. Webs without a configuration use `simple`,
+-- which does not apply any language specific stemming or stop words.
+CREATE FUNCTION web_text_search_config(_web_id UUID) RETURNS REGCONFIG AS
+$$
+    SELECT coalesce(
+        (SELECT config FROM web_text_search_configs WHERE web_id = _web_id),
+        'simple'::regconfig
+    );
+$$ STABLE LANGUAGE sql;
+
+-- The search vector only contains the string values of the properties. As editions are shared
+-- between the temporal rows of an entity, the vector is only calculated for the first row.
+CREATE FUNCTION insert_entity_edition_search_trigger() RETURNS TRIGGER AS
+$$
+DECLARE
+    _config REGCONFIG := web_text_search_config(NEW.web_id);
+BEGIN
+    INSERT INTO entity_edition_search (entity_edition_id, config, search_vector)
+    SELECT
+        entity_edition_id,
+        _config,
+        jsonb_to_tsvector(_config, coalesce(properties, '{}'), '["string"]')
+    FROM entity_editions
+    WHERE entity_edition_id = NEW.entity_edition_id
+    ON CONFLICT DO NOTHING;
+
+    RETURN NEW;
+END
+$$ VOLATILE LANGUAGE plpgsql;
+
+CREATE TRIGGER insert_entity_edition_search_trigger
+    AFTER INSERT ON "entity_temporal_metadata"
+    FOR EACH ROW EXECUTE PROCEDURE "insert_entity_edition_search_trigger"();
+
+CREATE FUNCTION update_entity_edition_search_trigger() RETURNS TRIGGER AS
+$$
+BEGIN
+    UPDATE entity_edition_search
+    SET search_vector = jsonb_to_tsvector(config, coalesce(NEW.properties, '{}'), '["string"]')
+    WHERE entity_edition_id = NEW.entity_edition_id;
+
+    RETURN NEW;
+END
+$$ VOLATILE LANGUAGE plpgsql;
+
+CREATE TRIGGER update_entity_edition_search_trigger
+    AFTER UPDATE OF "properties" ON "entity_editions"
+    FOR EACH ROW EXECUTE PROCEDURE "update_entity_edition_search_trigger"();
+
+INSERT INTO entity_edition_search (entity_edition_id, config, search_vector)
+SELECT DISTINCT ON (entity_editions.entity_edition_id)
+    entity_editions.entity_edition_id,
+    'simple'::regconfig,
+    jsonb_to_tsvector('simple', coalesce(entity_editions.properties, '{}'), '["string"]')
+FROM entity_editions
+INNER JOIN entity_temporal_metadata
+    ON entity_editions.entity_edition_id = entity_temporal_metadata.entity_edition_id;
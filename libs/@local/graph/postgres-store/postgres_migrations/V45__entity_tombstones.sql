@@ -0,0 +1,10 @@
+-- Entities which were permanently erased. All other data of an erased entity is removed, the
+-- tombstone only records who erased the entity, when, and why.
+CREATE TABLE "entity_tombstones" (
+    "web_id"                      UUID        NOT NULL,
+    "entity_uuid"                 UUID        NOT NULL,
+    "erased_by_id"                UUID        NOT NULL,
+    "erased_at_transaction_time"  TIMESTAMPTZ NOT NULL,
+    "reason"                      TEXT        NOT NULL,
+    PRIMARY KEY ("web_id", "entity_uuid")
+);
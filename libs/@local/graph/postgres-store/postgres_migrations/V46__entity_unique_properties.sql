@@ -0,0 +1,4 @@
+-- Supports looking up entities by top-level property values, which is used to enforce the
+-- `uniqueProperties` of entity types.
+CREATE INDEX entity_editions_properties_idx
+ON entity_editions USING gin (properties jsonb_path_ops);
@@ -0,0 +1,4 @@
+-- Records which types an erased entity had, so snapshots filtered by entity type can include the
+-- tombstone. The types of entities erased before are not known anymore.
+ALTER TABLE entity_tombstones
+    ADD COLUMN entity_type_ids TEXT[] NOT NULL DEFAULT '{}';
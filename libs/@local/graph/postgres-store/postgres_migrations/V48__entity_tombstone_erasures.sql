@@ -0,0 +1,5 @@
+-- An entity may be created again with the UUID of an erased entity, so it may be erased more than
+-- once. A tombstone is kept for every erasure.
+ALTER TABLE entity_tombstones
+    DROP CONSTRAINT entity_tombstones_pkey,
+    ADD PRIMARY KEY (web_id, entity_uuid, erased_at_transaction_time);
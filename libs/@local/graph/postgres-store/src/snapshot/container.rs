@@ -0,0 +1,527 @@
+use alloc::collections::{BTreeMap, btree_map::Entry};
+use core::{fmt, mem};
+use std::io::{SeekFrom, Write as _};
+
+use error_stack::{Report, ResultExt as _, ensure};
+use futures::{Stream, StreamExt as _, TryStreamExt as _, stream};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest as _, Sha256};
+use tokio::io::{
+    AsyncRead, AsyncReadExt as _, AsyncSeek, AsyncSeekExt as _, AsyncWrite, AsyncWriteExt as _,
+};
+
+use crate::snapshot::{SnapshotContainerError, SnapshotEntry, SnapshotEntryKind, SnapshotMetadata};
+
+/// The length of the manifest, its SHA-256 checksum and the magic bytes.
+const TRAILER_LENGTH: usize = 8 + 32 + SnapshotContainer::MAGIC.len();
+
+/// A chunk of compressed snapshot entries of a single kind.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotChunk {
+    pub kind: SnapshotEntryKind,
+    /// The position of the compressed chunk in the container.
+    pub offset: u64,
+    /// The length of the compressed chunk in bytes.
+    pub length: u64,
+    /// The number of entries in the chunk.
+    pub records: usize,
+    /// The hex encoded SHA-256 checksum of the compressed chunk.
+    pub sha256: String,
+}
+
+/// Lists the chunks contained in a [`SnapshotContainer`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotManifest {
+    pub format_version: u32,
+    pub chunks: Vec<SnapshotChunk>,
+}
+
+impl SnapshotManifest {
+    /// Returns the total number of entries in the container.
+    #[must_use]
+    pub fn records(&self) -> usize {
+        self.chunks.iter().map(|chunk| chunk.records).sum()
+    }
+}
+
+fn checksum(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// A seekable source of a [`SnapshotContainer`].
+trait ContainerReader: AsyncRead + AsyncSeek + Unpin + Send {}
+
+impl<R> ContainerReader for R where R: AsyncRead + AsyncSeek + Unpin + Send {}
+
+/// Reads `length` bytes starting at `offset`.
+async fn read_at(
+    reader: &mut (impl AsyncRead + AsyncSeek + Unpin),
+    offset: u64,
+    length: u64,
+) -> Result<Vec<u8>, Report<SnapshotContainerError>> {
+    reader
+        .seek(SeekFrom::Start(offset))
+        .await
+        .change_context(SnapshotContainerError::Read)?;
+    let mut bytes =
+        Vec::with_capacity(usize::try_from(length).change_context(SnapshotContainerError::Read)?);
+    reader
+        .take(length)
+        .read_to_end(&mut bytes)
+        .await
+        .change_context(SnapshotContainerError::Read)?;
+    ensure!(
+        bytes.len() as u64 == length,
+        SnapshotContainerError::Truncated
+    );
+    Ok(bytes)
+}
+
+/// Calculates the checksum of a chunk without reading it into memory at once.
+async fn chunk_checksum(
+    reader: &mut (impl AsyncRead + AsyncSeek + Unpin),
+    chunk: &SnapshotChunk,
+) -> Result<String, Report<SnapshotContainerError>> {
+    reader
+        .seek(SeekFrom::Start(chunk.offset))
+        .await
+        .change_context(SnapshotContainerError::Read)?;
+    let mut chunk_reader = reader.take(chunk.length);
+    let mut hasher = Sha256::new();
+    let mut buffer = [0; 8192];
+    let mut read = 0;
+    loop {
+        let length = chunk_reader
+            .read(&mut buffer)
+            .await
+            .change_context(SnapshotContainerError::Read)?;
+        if length == 0 {
+            break;
+        }
+        hasher.update(&buffer[..length]);
+        read += length as u64;
+    }
+    ensure!(read == chunk.length, SnapshotContainerError::Truncated);
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn decode_chunk(
+    bytes: &[u8],
+    chunk: &SnapshotChunk,
+) -> Result<Vec<SnapshotEntry>, Report<SnapshotContainerError>> {
+    let bytes = zstd::decode_all(bytes).change_context(SnapshotContainerError::Decode)?;
+    let entries = serde_json::Deserializer::from_slice(&bytes)
+        .into_iter()
+        .collect::<Result<Vec<SnapshotEntry>, _>>()
+        .change_context(SnapshotContainerError::Decode)?;
+    ensure!(
+        entries.len() == chunk.records,
+        SnapshotContainerError::Decode
+    );
+    Ok(entries)
+}
+
+/// A snapshot stored as zstd compressed chunks together with a manifest.
+///
+/// The container starts with [`Self::MAGIC`], followed by the chunks, each containing the
+/// newline-delimited JSON entries of a single [`SnapshotEntryKind`]. The JSON encoded
+/// [`SnapshotManifest`] follows the chunks. The container ends with the big-endian length of the
+/// manifest, its SHA-256 checksum and [`Self::MAGIC`] again, so a truncated container is detected
+/// before any entry is read.
+///
+/// The container is read from a seekable source, so only a single chunk is held in memory.
+pub struct SnapshotContainer {
+    manifest: SnapshotManifest,
+    reader: Box<dyn ContainerReader>,
+}
+
+impl fmt::Debug for SnapshotContainer {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SnapshotContainer")
+            .field("manifest", &self.manifest)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SnapshotContainer {
+    pub const MAGIC: [u8; 8] = *b"HASHSNAP";
+
+    /// Opens the container stored in `reader` and verifies its integrity.
+    ///
+    /// The checksums of all chunks are verified before this returns, without reading the chunks
+    /// into memory.
+    ///
+    /// # Errors
+    ///
+    /// - [`Unsupported`] if the data is not a container or the format version is not supported
+    /// - [`Truncated`] if the container is incomplete
+    /// - [`Checksum`] if the manifest or a chunk does not match its checksum
+    /// - [`Decode`] if the manifest could not be decoded
+    /// - [`Read`] if reading from `reader` fails
+    ///
+    /// [`Unsupported`]: SnapshotContainerError::Unsupported
+    /// [`Truncated`]: SnapshotContainerError::Truncated
+    /// [`Checksum`]: SnapshotContainerError::Checksum
+    /// [`Decode`]: SnapshotContainerError::Decode
+    /// [`Read`]: SnapshotContainerError::Read
+    #[expect(
+        clippy::big_endian_bytes,
+        reason = "The manifest length is stored in big-endian"
+    )]
+    pub async fn open(
+        mut reader: impl AsyncRead + AsyncSeek + Unpin + Send + 'static,
+    ) -> Result<Self, Report<SnapshotContainerError>> {
+        let magic_length = Self::MAGIC.len() as u64;
+        let length = reader
+            .seek(SeekFrom::End(0))
+            .await
+            .change_context(SnapshotContainerError::Read)?;
+        ensure!(
+            length >= magic_length && read_at(&mut reader, 0, magic_length).await? == Self::MAGIC,
+            SnapshotContainerError::Unsupported
+        );
+        ensure!(
+            length >= magic_length + TRAILER_LENGTH as u64,
+            SnapshotContainerError::Truncated
+        );
+
+        let trailer_start = length - TRAILER_LENGTH as u64;
+        let trailer = read_at(&mut reader, trailer_start, TRAILER_LENGTH as u64).await?;
+        ensure!(
+            trailer.ends_with(&Self::MAGIC),
+            SnapshotContainerError::Truncated
+        );
+        let manifest_length = u64::from_be_bytes(
+            trailer[..8]
+                .try_into()
+                .expect("slice should be 8 bytes long"),
+        );
+        let manifest_start = trailer_start
+            .checked_sub(manifest_length)
+            .filter(|manifest_start| *manifest_start >= magic_length)
+            .ok_or(SnapshotContainerError::Truncated)?;
+
+        let manifest_bytes = read_at(&mut reader, manifest_start, manifest_length).await?;
+        ensure!(
+            *Sha256::digest(&manifest_bytes) == trailer[8..40],
+            SnapshotContainerError::Checksum
+        );
+        let manifest: SnapshotManifest = serde_json::from_slice(&manifest_bytes)
+            .change_context(SnapshotContainerError::Decode)?;
+        ensure!(
+            manifest.format_version <= SnapshotMetadata::FORMAT_VERSION,
+            SnapshotContainerError::Unsupported
+        );
+
+        for (index, chunk) in manifest.chunks.iter().enumerate() {
+            let location = || format!("chunk {index} ({:?})", chunk.kind);
+            ensure!(
+                chunk.offset >= magic_length
+                    && chunk
+                        .offset
+                        .checked_add(chunk.length)
+                        .is_some_and(|end| end <= manifest_start),
+                Report::new(SnapshotContainerError::Truncated).attach_printable(location())
+            );
+            if chunk_checksum(&mut reader, chunk)
+                .await
+                .attach_printable_lazy(location)?
+                != chunk.sha256
+            {
+                return Err(
+                    Report::new(SnapshotContainerError::Checksum).attach_printable(location())
+                );
+            }
+        }
+
+        tracing::info!(
+            chunks = manifest.chunks.len(),
+            records = manifest.records(),
+            "verified snapshot container"
+        );
+
+        Ok(Self {
+            manifest,
+            reader: Box::new(reader),
+        })
+    }
+
+    #[must_use]
+    pub const fn manifest(&self) -> &SnapshotManifest {
+        &self.manifest
+    }
+
+    /// Returns the entries of the container, reading and decompressing one chunk at a time.
+    pub fn into_entries(
+        self,
+    ) -> impl Stream<Item = Result<SnapshotEntry, Report<SnapshotContainerError>>> + Send + 'static
+    {
+        let Self { manifest, reader } = self;
+        stream::try_unfold(
+            (reader, manifest.chunks.into_iter()),
+            |(mut reader, mut chunks)| async move {
+                let Some(chunk) = chunks.next() else {
+                    return Ok(None);
+                };
+                let bytes = read_at(&mut reader, chunk.offset, chunk.length).await?;
+                Ok(Some((decode_chunk(&bytes, &chunk)?, (reader, chunks))))
+            },
+        )
+        .map_ok(|entries| stream::iter(entries).map(Ok::<_, Report<SnapshotContainerError>>))
+        .try_flatten()
+    }
+}
+
+struct PendingChunk {
+    encoder: zstd::Encoder<'static, Vec<u8>>,
+    records: usize,
+}
+
+/// Writes a [`SnapshotContainer`].
+///
+/// Entries are grouped by their kind into chunks of up to `chunk_size` entries. The container is
+/// only complete after [`finish`] was called, otherwise it will be rejected when read.
+///
+/// [`finish`]: Self::finish
+pub struct SnapshotContainerWriter<W> {
+    writer: W,
+    chunk_size: usize,
+    offset: u64,
+    pending: BTreeMap<SnapshotEntryKind, PendingChunk>,
+    chunks: Vec<SnapshotChunk>,
+}
+
+impl<W> SnapshotContainerWriter<W>
+where
+    W: AsyncWrite + Unpin,
+{
+    /// Starts a new container.
+    ///
+    /// # Errors
+    ///
+    /// - If writing into `writer` fails
+    pub async fn new(
+        mut writer: W,
+        chunk_size: usize,
+    ) -> Result<Self, Report<SnapshotContainerError>> {
+        writer
+            .write_all(&SnapshotContainer::MAGIC)
+            .await
+            .change_context(SnapshotContainerError::Write)?;
+
+        Ok(Self {
+            writer,
+            chunk_size,
+            offset: SnapshotContainer::MAGIC.len() as u64,
+            pending: BTreeMap::new(),
+            chunks: Vec::new(),
+        })
+    }
+
+    /// Adds an entry to the container.
+    ///
+    /// # Errors
+    ///
+    /// - If the entry could not be compressed
+    /// - If writing a full chunk into the underlying writer fails
+    pub async fn write(
+        &mut self,
+        entry: &SnapshotEntry,
+    ) -> Result<(), Report<SnapshotContainerError>> {
+        let kind = entry.kind();
+        let chunk = match self.pending.entry(kind) {
+            Entry::Occupied(chunk) => chunk.into_mut(),
+            Entry::Vacant(chunk) => chunk.insert(PendingChunk {
+                encoder: zstd::Encoder::new(Vec::new(), zstd::DEFAULT_COMPRESSION_LEVEL)
+                    .change_context(SnapshotContainerError::Encode)?,
+                records: 0,
+            }),
+        };
+        serde_json::to_writer(&mut chunk.encoder, entry)
+            .change_context(SnapshotContainerError::Encode)?;
+        chunk
+            .encoder
+            .write_all(b"\n")
+            .change_context(SnapshotContainerError::Encode)?;
+        chunk.records += 1;
+
+        if chunk.records >= self.chunk_size {
+            let chunk = self.pending.remove(&kind).expect("chunk should be pending");
+            self.write_chunk(kind, chunk).await?;
+        }
+        Ok(())
+    }
+
+    async fn write_chunk(
+        &mut self,
+        kind: SnapshotEntryKind,
+        chunk: PendingChunk,
+    ) -> Result<(), Report<SnapshotContainerError>> {
+        let bytes = chunk
+            .encoder
+            .finish()
+            .change_context(SnapshotContainerError::Encode)?;
+        self.writer
+            .write_all(&bytes)
+            .await
+            .change_context(SnapshotContainerError::Write)?;
+
+        self.chunks.push(SnapshotChunk {
+            kind,
+            offset: self.offset,
+            length: bytes.len() as u64,
+            records: chunk.records,
+            sha256: checksum(&bytes),
+        });
+        self.offset += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Writes the remaining chunks and the manifest and flushes the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// - If a chunk could not be compressed
+    /// - If writing into the underlying writer fails
+    #[expect(
+        clippy::big_endian_bytes,
+        reason = "The manifest length is stored in big-endian"
+    )]
+    pub async fn finish(mut self) -> Result<SnapshotManifest, Report<SnapshotContainerError>> {
+        for (kind, chunk) in mem::take(&mut self.pending) {
+            self.write_chunk(kind, chunk).await?;
+        }
+
+        let manifest = SnapshotManifest {
+            format_version: SnapshotMetadata::FORMAT_VERSION,
+            chunks: mem::take(&mut self.chunks),
+        };
+        let manifest_bytes =
+            serde_json::to_vec(&manifest).change_context(SnapshotContainerError::Encode)?;
+
+        for bytes in [
+            manifest_bytes.as_slice(),
+            &(manifest_bytes.len() as u64).to_be_bytes(),
+            Sha256::digest(&manifest_bytes).as_slice(),
+            &SnapshotContainer::MAGIC,
+        ] {
+            self.writer
+                .write_all(bytes)
+                .await
+                .change_context(SnapshotContainerError::Write)?;
+        }
+        self.writer
+            .flush()
+            .await
+            .change_context(SnapshotContainerError::Write)?;
+
+        Ok(manifest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use type_system::{knowledge::entity::id::EntityUuid, provenance::ActorEntityUuid};
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::snapshot::{Account, BlockProtocolModuleVersions, CustomGlobalMetadata};
+
+    async fn write_container(accounts: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut writer = SnapshotContainerWriter::new(&mut data, 2)
+            .await
+            .expect("should be able to start the container");
+        writer
+            .write(&SnapshotEntry::Snapshot(SnapshotMetadata {
+                block_protocol_module_versions: BlockProtocolModuleVersions {
+                    graph: semver::Version::new(0, 3, 0),
+                },
+                format_version: SnapshotMetadata::FORMAT_VERSION,
+                since: None,
+                taken_at: None,
+                custom: CustomGlobalMetadata,
+            }))
+            .await
+            .expect("should be able to write metadata");
+        for _ in 0..accounts {
+            writer
+                .write(&SnapshotEntry::Account(Account {
+                    id: ActorEntityUuid::new(EntityUuid::new(Uuid::new_v4())),
+                }))
+                .await
+                .expect("should be able to write an account");
+        }
+
+        let manifest = writer
+            .finish()
+            .await
+            .expect("should be able to finish the container");
+        assert_eq!(manifest.records(), accounts + 1);
+        data
+    }
+
+    #[tokio::test]
+    async fn round_trip() {
+        let data = write_container(5).await;
+        let container = SnapshotContainer::open(Cursor::new(data))
+            .await
+            .expect("container should be valid");
+        assert_eq!(container.manifest().chunks.len(), 4);
+
+        let entries = container
+            .into_entries()
+            .try_collect::<Vec<_>>()
+            .await
+            .expect("should be able to read the entries");
+        assert_eq!(entries.len(), 6);
+        assert_eq!(
+            entries
+                .iter()
+                .filter(|entry| entry.kind() == SnapshotEntryKind::Account)
+                .count(),
+            5
+        );
+    }
+
+    #[tokio::test]
+    async fn truncated() {
+        let mut data = write_container(5).await;
+        data.truncate(data.len() - 1);
+        let report = SnapshotContainer::open(Cursor::new(data))
+            .await
+            .expect_err("container should be invalid");
+        assert!(matches!(
+            report.current_context(),
+            SnapshotContainerError::Truncated
+        ));
+    }
+
+    #[tokio::test]
+    async fn corrupted_chunk() {
+        let mut data = write_container(5).await;
+        data[SnapshotContainer::MAGIC.len()] ^= 0xFF;
+        let report = SnapshotContainer::open(Cursor::new(data))
+            .await
+            .expect_err("container should be invalid");
+        assert!(matches!(
+            report.current_context(),
+            SnapshotContainerError::Checksum
+        ));
+    }
+
+    #[tokio::test]
+    async fn not_a_container() {
+        let report = SnapshotContainer::open(Cursor::new(b"{\"type\":\"snapshot\"}\n".to_vec()))
+            .await
+            .expect_err("plain snapshots are not containers");
+        assert!(matches!(
+            report.current_context(),
+            SnapshotContainerError::Unsupported
+        ));
+    }
+}
@@ -4,11 +4,12 @@ use error_stack::{Report, ResultExt as _, ensure};
 use futures::{StreamExt as _, TryStreamExt as _, stream};
 use hash_graph_authorization::{
     AuthorizationApi, backend::ZanzibarBackend, schema::EntityRelationAndSubject,
+    zanzibar::types::RelationshipFilter,
 };
 use hash_graph_store::{
-    entity::{EntityStore as _, EntityValidationReport, ValidateEntityComponents},
+    entity::{EntityQueryPath, EntityStore as _, EntityValidationReport, ValidateEntityComponents},
     error::InsertionError,
-    filter::Filter,
+    filter::{Filter, FilterExpression, ParameterList},
     query::Read,
 };
 use hash_graph_types::{
@@ -17,7 +18,11 @@ use hash_graph_types::{
 use hash_graph_validation::{EntityPreprocessor, Validate as _};
 use tokio_postgres::GenericClient as _;
 use type_system::{
-    knowledge::{Entity, entity::id::EntityUuid, property::PropertyObjectWithMetadata},
+    knowledge::{
+        Entity,
+        entity::id::{EntityEditionId, EntityUuid},
+        property::PropertyObjectWithMetadata,
+    },
     ontology::entity_type::{ClosedEntityType, ClosedMultiEntityType},
 };
 
@@ -30,6 +35,7 @@ use crate::{
             query::rows::{
                 EntityDraftRow, EntityEditionRow, EntityEmbeddingRow, EntityHasLeftEntityRow,
                 EntityHasRightEntityRow, EntityIdRow, EntityIsOfTypeRow, EntityTemporalMetadataRow,
+                EntityTombstoneRow,
             },
         },
     },
@@ -45,6 +51,7 @@ pub enum EntityRowBatch {
     RightLinks(Vec<EntityHasRightEntityRow>),
     Embeddings(Vec<EntityEmbeddingRow>),
     Relations(Vec<(EntityUuid, EntityRelationAndSubject)>),
+    Tombstones(Vec<EntityTombstoneRow>),
 }
 
 impl<C, A> WriteBatch<C, A> for EntityRowBatch
@@ -91,6 +98,10 @@ where
                     CREATE TEMPORARY TABLE entity_embeddings_tmp
                         (LIKE entity_embeddings INCLUDING ALL)
                         ON COMMIT DROP;
+
+                    CREATE TEMPORARY TABLE entity_tombstones_tmp
+                        (LIKE entity_tombstones INCLUDING ALL)
+                        ON COMMIT DROP;
                 ",
             )
             .await
@@ -245,20 +256,287 @@ where
                     tracing::info!("Read {} entity embeddings", rows.len());
                 }
             }
+            Self::Tombstones(tombstones) => {
+                let rows = client
+                    .query(
+                        "
+                            INSERT INTO entity_tombstones_tmp
+                            SELECT DISTINCT * FROM UNNEST($1::entity_tombstones[])
+                            ON CONFLICT DO NOTHING
+                            RETURNING 1;
+                        ",
+                        &[&tombstones],
+                    )
+                    .await
+                    .change_context(InsertionError)?;
+                if !rows.is_empty() {
+                    tracing::info!("Read {} entity tombstones", rows.len());
+                }
+            }
         }
         Ok(())
     }
 
+    async fn prepare_incremental(
+        postgres_client: &mut PostgresStore<C, A>,
+    ) -> Result<(), Report<InsertionError>> {
+        // Entities erased since the base snapshot was taken are removed from the store. An entity
+        // may have been created again after it was erased, so it is only removed if nothing was
+        // recorded for it after the erasure.
+        postgres_client
+            .as_client()
+            .client()
+            .simple_query(
+                "
+                    CREATE TEMPORARY TABLE erased_entities_tmp ON COMMIT DROP AS
+                        SELECT DISTINCT web_id, entity_uuid FROM entity_tombstones_tmp
+                        WHERE NOT EXISTS (
+                            SELECT 1 FROM entity_temporal_metadata
+                            WHERE entity_temporal_metadata.web_id = entity_tombstones_tmp.web_id
+                              AND entity_temporal_metadata.entity_uuid
+                                  = entity_tombstones_tmp.entity_uuid
+                              AND lower(entity_temporal_metadata.transaction_time)
+                                  > entity_tombstones_tmp.erased_at_transaction_time
+                        );
+
+                    CREATE TEMPORARY TABLE erased_entity_editions_tmp ON COMMIT DROP AS
+                        SELECT entity_edition_id FROM entity_temporal_metadata
+                        WHERE (web_id, entity_uuid) IN (
+                            SELECT web_id, entity_uuid FROM erased_entities_tmp
+                        );
+
+                    DELETE FROM entity_embeddings
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_entities_tmp
+                    );
+
+                    DELETE FROM entity_has_left_entity
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_entities_tmp
+                    );
+
+                    DELETE FROM entity_has_right_entity
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_entities_tmp
+                    );
+
+                    DELETE FROM entity_temporal_metadata
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_entities_tmp
+                    );
+
+                    DELETE FROM entity_is_of_type
+                    WHERE entity_edition_id IN (
+                        SELECT entity_edition_id FROM erased_entity_editions_tmp
+                    );
+
+                    DELETE FROM entity_editions
+                    WHERE entity_edition_id IN (
+                        SELECT entity_edition_id FROM erased_entity_editions_tmp
+                    );
+
+                    DELETE FROM entity_drafts
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_entities_tmp
+                    );
+
+                    DELETE FROM entity_ids
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_entities_tmp
+                    );
+
+                    DELETE FROM entity_tombstones_tmp
+                    WHERE (web_id, entity_uuid, erased_at_transaction_time) IN (
+                        SELECT web_id, entity_uuid, erased_at_transaction_time
+                        FROM entity_tombstones
+                    );
+                ",
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        // The snapshot contains all temporal data of an entity which changed since the base
+        // snapshot was taken. It replaces the existing temporal data, e.g. to close the transaction
+        // time of an updated or archived edition. Editions themselves are immutable.
+        postgres_client
+            .as_client()
+            .client()
+            .simple_query(
+                "
+                    DELETE FROM entity_temporal_metadata
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM entity_temporal_metadata_tmp
+                    );
+
+                    DELETE FROM entity_has_left_entity
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM entity_has_left_entity_tmp
+                    );
+
+                    DELETE FROM entity_has_right_entity
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM entity_has_right_entity_tmp
+                    );
+
+                    DELETE FROM entity_embeddings
+                    USING entity_embeddings_tmp
+                    WHERE entity_embeddings.web_id = entity_embeddings_tmp.web_id
+                      AND entity_embeddings.entity_uuid = entity_embeddings_tmp.entity_uuid
+                      AND entity_embeddings.draft_id
+                          IS NOT DISTINCT FROM entity_embeddings_tmp.draft_id
+                      AND entity_embeddings.property
+                          IS NOT DISTINCT FROM entity_embeddings_tmp.property;
+
+                    DELETE FROM entity_ids_tmp
+                    WHERE (web_id, entity_uuid) IN (SELECT web_id, entity_uuid FROM entity_ids);
+
+                    DELETE FROM entity_drafts_tmp
+                    WHERE draft_id IN (SELECT draft_id FROM entity_drafts);
+
+                    DELETE FROM entity_editions_tmp
+                    WHERE entity_edition_id IN (SELECT entity_edition_id FROM entity_editions);
+
+                    DELETE FROM entity_is_of_type_tmp
+                    WHERE entity_edition_id IN (SELECT entity_edition_id FROM entity_is_of_type);
+                ",
+            )
+            .await
+            .change_context(InsertionError)?;
+        Ok(())
+    }
+
     #[expect(clippy::too_many_lines)]
     async fn commit(
         postgres_client: &mut PostgresStore<C, A>,
         ignore_validation_errors: bool,
+        incremental: bool,
     ) -> Result<(), Report<InsertionError>> {
+        // Erased entities may still be contained in the snapshot if it was taken before the
+        // entity was erased. Their data is dropped before it is inserted into the store, unless it
+        // was recorded after the erasure because the entity was created again.
         postgres_client
             .as_client()
             .client()
             .simple_query(
                 "
+                    CREATE TEMPORARY TABLE erased_snapshot_entities_tmp ON COMMIT DROP AS
+                        SELECT DISTINCT web_id, entity_uuid FROM entity_tombstones_tmp
+                        WHERE NOT EXISTS (
+                            SELECT 1 FROM entity_temporal_metadata_tmp
+                            WHERE entity_temporal_metadata_tmp.web_id
+                                  = entity_tombstones_tmp.web_id
+                              AND entity_temporal_metadata_tmp.entity_uuid
+                                  = entity_tombstones_tmp.entity_uuid
+                              AND lower(entity_temporal_metadata_tmp.transaction_time)
+                                  > entity_tombstones_tmp.erased_at_transaction_time
+                        );
+
+                    DELETE FROM entity_is_of_type_tmp
+                    WHERE entity_edition_id IN (
+                        SELECT entity_edition_id FROM entity_temporal_metadata_tmp
+                        WHERE (web_id, entity_uuid) IN (
+                            SELECT web_id, entity_uuid FROM erased_snapshot_entities_tmp
+                        )
+                    );
+
+                    DELETE FROM entity_editions_tmp
+                    WHERE entity_edition_id IN (
+                        SELECT entity_edition_id FROM entity_temporal_metadata_tmp
+                        WHERE (web_id, entity_uuid) IN (
+                            SELECT web_id, entity_uuid FROM erased_snapshot_entities_tmp
+                        )
+                    );
+
+                    DELETE FROM entity_temporal_metadata_tmp
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_snapshot_entities_tmp
+                    );
+
+                    DELETE FROM entity_has_left_entity_tmp
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_snapshot_entities_tmp
+                    );
+
+                    DELETE FROM entity_has_right_entity_tmp
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_snapshot_entities_tmp
+                    );
+
+                    DELETE FROM entity_embeddings_tmp
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_snapshot_entities_tmp
+                    );
+
+                    DELETE FROM entity_drafts_tmp
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_snapshot_entities_tmp
+                    );
+
+                    DELETE FROM entity_ids_tmp
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT web_id, entity_uuid FROM erased_snapshot_entities_tmp
+                    );
+                ",
+            )
+            .await
+            .change_context(InsertionError)?;
+
+        // The relationships were already written to the authorization backend when they were read.
+        // Entities which were created again after they were erased keep their relationships.
+        let erased_entities = postgres_client
+            .as_client()
+            .client()
+            .query(
+                "
+                    SELECT DISTINCT entity_uuid FROM entity_tombstones_tmp
+                    WHERE (web_id, entity_uuid) NOT IN (SELECT web_id, entity_uuid FROM entity_ids)
+                      AND (web_id, entity_uuid) NOT IN (
+                          SELECT web_id, entity_uuid FROM entity_ids_tmp
+                      );
+                ",
+                &[],
+            )
+            .await
+            .change_context(InsertionError)?;
+        for row in erased_entities {
+            postgres_client
+                .authorization_api
+                .delete_relations(RelationshipFilter::from_resource(
+                    row.get::<_, EntityUuid>(0),
+                ))
+                .await
+                .change_context(InsertionError)?;
+        }
+
+        // An incremental snapshot is applied on top of already validated entities, so only the
+        // entities contained in it are validated.
+        let restored_edition_ids = if incremental {
+            Some(
+                postgres_client
+                    .as_client()
+                    .client()
+                    .query(
+                        "SELECT DISTINCT entity_edition_id FROM entity_temporal_metadata_tmp",
+                        &[],
+                    )
+                    .await
+                    .change_context(InsertionError)?
+                    .into_iter()
+                    .map(|row| row.get::<_, EntityEditionId>(0))
+                    .collect::<Vec<_>>(),
+            )
+        } else {
+            None
+        };
+
+        postgres_client
+            .as_client()
+            .client()
+            .simple_query(
+                "
+                    INSERT INTO entity_tombstones
+                        SELECT * FROM entity_tombstones_tmp;
+
                     INSERT INTO entity_ids
                         SELECT * FROM entity_ids_tmp;
 
@@ -292,10 +570,24 @@ where
             .await
             .change_context(InsertionError)?;
 
-        let entities =
-            Read::<Entity>::read_vec(postgres_client, &Filter::All(Vec::new()), None, true)
-                .await
-                .change_context(InsertionError)?;
+        let entities = Read::<Entity>::read_vec(
+            postgres_client,
+            &restored_edition_ids.as_deref().map_or_else(
+                || Filter::All(Vec::new()),
+                |edition_ids| {
+                    Filter::In(
+                        FilterExpression::Path {
+                            path: EntityQueryPath::EditionId,
+                        },
+                        ParameterList::EntityEditionIds(edition_ids),
+                    )
+                },
+            ),
+            None,
+            true,
+        )
+        .await
+        .change_context(InsertionError)?;
 
         let validator_provider = StoreProvider {
             store: postgres_client,
@@ -371,6 +663,8 @@ where
                 }
             };
             validation_components.link_validation = postgres_client.settings.validate_links;
+            // Snapshots are restored as they were written, uniqueness is only enforced on writes.
+            validation_components.unique_properties = false;
 
             let validation_report = entity
                 .validate(&entity_type, validation_components, &validator_provider)
@@ -20,6 +20,7 @@ use crate::{
     store::postgres::query::rows::{
         EntityDraftRow, EntityEditionRow, EntityEmbeddingRow, EntityHasLeftEntityRow,
         EntityHasRightEntityRow, EntityIdRow, EntityIsOfTypeRow, EntityTemporalMetadataRow,
+        EntityTombstoneRow,
     },
 };
 
@@ -233,6 +234,7 @@ pub(crate) fn channel(
     chunk_size: usize,
     relation_rx: Receiver<(EntityUuid, EntityRelationAndSubject)>,
     embedding_rx: Receiver<EntityEmbeddingRow>,
+    tombstone_rx: Receiver<EntityTombstoneRow>,
 ) -> (EntitySender, EntityReceiver) {
     let (id_tx, id_rx) = mpsc::channel(chunk_size);
     let (draft_tx, draft_rx) = mpsc::channel(chunk_size);
@@ -290,6 +292,10 @@ pub(crate) fn channel(
                     .ready_chunks(chunk_size)
                     .map(EntityRowBatch::Embeddings)
                     .boxed(),
+                tombstone_rx
+                    .ready_chunks(chunk_size)
+                    .map(EntityRowBatch::Tombstones)
+                    .boxed(),
             ]),
         },
     )
@@ -43,3 +43,30 @@ impl fmt::Display for SnapshotRestoreError {
 }
 
 impl Error for SnapshotRestoreError {}
+
+#[derive(Debug)]
+pub enum SnapshotContainerError {
+    Read,
+    Write,
+    Encode,
+    Decode,
+    Truncated,
+    Checksum,
+    Unsupported,
+}
+
+impl fmt::Display for SnapshotContainerError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Read => write!(fmt, "could not read the snapshot container"),
+            Self::Write => write!(fmt, "could not write the snapshot container"),
+            Self::Encode => write!(fmt, "could not encode a snapshot entry"),
+            Self::Decode => write!(fmt, "could not decode a snapshot chunk"),
+            Self::Truncated => write!(fmt, "the snapshot container is truncated"),
+            Self::Checksum => write!(fmt, "the snapshot container failed the integrity check"),
+            Self::Unsupported => write!(fmt, "the snapshot container format is not supported"),
+        }
+    }
+}
+
+impl Error for SnapshotContainerError {}
@@ -0,0 +1,547 @@
+use core::future::ready;
+use std::collections::HashSet;
+
+use error_stack::{Report, ResultExt as _};
+use futures::{TryFutureExt as _, TryStreamExt as _};
+use hash_graph_authorization::{
+    NoAuthorization,
+    backend::ZanzibarBackend,
+    schema::{WebRelationAndSubject, WebSubject},
+    zanzibar::{
+        Consistency,
+        types::{Relationship as _, RelationshipFilter},
+    },
+};
+use hash_graph_store::{
+    data_type::DataTypeQueryPath,
+    entity::EntityQueryPath,
+    entity_type::EntityTypeQueryPath,
+    filter::{Filter, FilterExpression, ParameterList},
+    pool::StorePool as _,
+    property_type::PropertyTypeQueryPath,
+};
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
+use postgres_types::{FromSql, ToSql};
+use tokio_postgres::GenericClient;
+use type_system::{
+    knowledge::entity::{
+        Entity,
+        id::{EntityEditionId, EntityUuid},
+    },
+    ontology::{
+        VersionedUrl,
+        data_type::{DataTypeUuid, DataTypeWithMetadata},
+        entity_type::{EntityTypeUuid, EntityTypeWithMetadata},
+        property_type::{PropertyTypeUuid, PropertyTypeWithMetadata},
+    },
+    provenance::ActorEntityUuid,
+    web::{ActorGroupId, OwnedById},
+};
+
+use crate::{
+    snapshot::SnapshotDumpError,
+    store::postgres::{AsClient as _, PostgresStorePool},
+};
+
+/// Restricts a snapshot dump to a subset of the entities in the graph.
+///
+/// An empty list does not restrict the dump. If both lists are provided, an entity has to match
+/// both of them to be dumped.
+///
+/// The dump is closed under references, so it can be restored into an empty graph: link entities
+/// pull in their left and right entities, and the entities pull in their entity types together
+/// with all property types, data types and other entity types these reference. Only the webs
+/// owning a dumped entity or ontology type are dumped, together with the accounts and account
+/// groups referenced by the dumped records: the owners of the webs, the subjects of the web
+/// relations, and the actors in the provenance of the records and tombstones. Memberships of
+/// account groups are only dumped for these accounts.
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotDumpFilter {
+    /// Only dumps entities owned by one of these webs.
+    pub webs: Vec<OwnedById>,
+    /// Only dumps entities of one of these entity types, including types inheriting from them.
+    pub entity_types: Vec<VersionedUrl>,
+}
+
+/// The records which are part of a filtered or incremental snapshot dump.
+#[derive(Debug)]
+pub(crate) struct SnapshotClosure {
+    /// `None` if all webs are part of the dump.
+    pub webs: Option<Vec<OwnedById>>,
+    /// `None` if all accounts are part of the dump.
+    pub accounts: Option<Vec<ActorEntityUuid>>,
+    /// `None` if all account groups are part of the dump.
+    pub account_groups: Option<Vec<ActorGroupId>>,
+    /// Sorted, so it can be searched.
+    pub entity_uuids: Vec<EntityUuid>,
+    pub entity_edition_ids: Vec<EntityEditionId>,
+    pub entity_types: Vec<EntityTypeUuid>,
+    pub property_types: Vec<PropertyTypeUuid>,
+    pub data_types: Vec<DataTypeUuid>,
+}
+
+impl SnapshotClosure {
+    pub(crate) fn contains_entity(&self, entity_uuid: EntityUuid) -> bool {
+        self.entity_uuids.binary_search(&entity_uuid).is_ok()
+    }
+
+    pub(crate) fn entity_filter(&self) -> Filter<'_, Entity> {
+        Filter::In(
+            FilterExpression::Path {
+                path: EntityQueryPath::EditionId,
+            },
+            ParameterList::EntityEditionIds(&self.entity_edition_ids),
+        )
+    }
+
+    pub(crate) fn entity_type_filter(&self) -> Filter<'_, EntityTypeWithMetadata> {
+        Filter::In(
+            FilterExpression::Path {
+                path: EntityTypeQueryPath::OntologyId,
+            },
+            ParameterList::EntityTypeIds(&self.entity_types),
+        )
+    }
+
+    pub(crate) fn property_type_filter(&self) -> Filter<'_, PropertyTypeWithMetadata> {
+        Filter::In(
+            FilterExpression::Path {
+                path: PropertyTypeQueryPath::OntologyId,
+            },
+            ParameterList::PropertyTypeIds(&self.property_types),
+        )
+    }
+
+    pub(crate) fn data_type_filter(&self) -> Filter<'_, DataTypeWithMetadata> {
+        Filter::In(
+            FilterExpression::Path {
+                path: DataTypeQueryPath::OntologyId,
+            },
+            ParameterList::DataTypeIds(&self.data_types),
+        )
+    }
+}
+
+impl PostgresStorePool {
+    /// Resolves the records matching `filter` together with all records they reference.
+    #[expect(clippy::too_many_lines)]
+    pub(crate) async fn read_snapshot_closure(
+        &self,
+        filter: &SnapshotDumpFilter,
+        authorization_api: &(impl ZanzibarBackend + Sync),
+    ) -> Result<SnapshotClosure, Report<SnapshotDumpError>> {
+        let store = self
+            .acquire(NoAuthorization, None)
+            .await
+            .change_context(SnapshotDumpError::Query)?;
+        let client = store.as_client();
+
+        let filter_webs = (!filter.webs.is_empty()).then_some(filter.webs.as_slice());
+        let filter_entity_types = filter
+            .entity_types
+            .iter()
+            .map(EntityTypeUuid::from_url)
+            .collect::<Vec<_>>();
+
+        // Link entities reference their endpoints, which may be links themselves, so the
+        // endpoints are followed until no new entity is found.
+        let entity_rows = client
+            .query(
+                "
+                WITH RECURSIVE selected_entities AS (
+                    SELECT entity_ids.web_id, entity_ids.entity_uuid
+                      FROM entity_ids
+                     WHERE ($1::uuid[] IS NULL OR entity_ids.web_id = ANY($1))
+                       AND (cardinality($2::uuid[]) = 0 OR EXISTS (
+                           SELECT 1
+                             FROM entity_temporal_metadata
+                             JOIN entity_is_of_type USING (entity_edition_id)
+                            WHERE entity_temporal_metadata.web_id = entity_ids.web_id
+                              AND entity_temporal_metadata.entity_uuid = entity_ids.entity_uuid
+                              AND entity_is_of_type.entity_type_ontology_id = ANY($2)
+                       ))
+                    UNION
+                    SELECT link_endpoints.target_web_id, link_endpoints.target_entity_uuid
+                      FROM selected_entities
+                      JOIN (
+                          SELECT web_id,
+                                 entity_uuid,
+                                 left_web_id AS target_web_id,
+                                 left_entity_uuid AS target_entity_uuid
+                            FROM entity_has_left_entity
+                          UNION ALL
+                          SELECT web_id, entity_uuid, right_web_id, right_entity_uuid
+                            FROM entity_has_right_entity
+                      ) AS link_endpoints USING (web_id, entity_uuid)
+                )
+                SELECT selected_entities.web_id,
+                       selected_entities.entity_uuid,
+                       entity_temporal_metadata.entity_edition_id
+                  FROM selected_entities
+                  JOIN entity_temporal_metadata USING (web_id, entity_uuid)
+                ",
+                &[&filter_webs, &filter_entity_types],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?;
+
+        let mut webs = HashSet::new();
+        let mut entity_uuids = Vec::new();
+        let mut entity_edition_ids = Vec::with_capacity(entity_rows.len());
+        for row in entity_rows {
+            webs.insert(row.get::<_, OwnedById>(0));
+            entity_uuids.push(row.get::<_, EntityUuid>(1));
+            entity_edition_ids.push(row.get::<_, EntityEditionId>(2));
+        }
+        entity_uuids.sort_unstable();
+        entity_uuids.dedup();
+        entity_edition_ids.sort_unstable();
+        entity_edition_ids.dedup();
+
+        // The types filtered by are dumped even if no entity of them exists.
+        let entity_types = client
+            .query(
+                "
+                WITH RECURSIVE selected_entity_types AS (
+                    SELECT entity_type_ontology_id AS ontology_id
+                      FROM entity_is_of_type
+                     WHERE entity_edition_id = ANY($1)
+                    UNION
+                    SELECT unnest($2::uuid[])
+                    UNION
+                    SELECT entity_type_edges.target_entity_type_ontology_id
+                      FROM selected_entity_types
+                      JOIN (
+                          SELECT source_entity_type_ontology_id, target_entity_type_ontology_id
+                            FROM entity_type_inherits_from
+                          UNION ALL
+                          SELECT source_entity_type_ontology_id, target_entity_type_ontology_id
+                            FROM entity_type_constrains_links_on
+                          UNION ALL
+                          SELECT source_entity_type_ontology_id, target_entity_type_ontology_id
+                            FROM entity_type_constrains_link_destinations_on
+                      ) AS entity_type_edges
+                        ON entity_type_edges.source_entity_type_ontology_id
+                         = selected_entity_types.ontology_id
+                )
+                SELECT ontology_id FROM selected_entity_types
+                ",
+                &[&entity_edition_ids, &filter_entity_types],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .into_iter()
+            .map(|row| row.get::<_, EntityTypeUuid>(0))
+            .collect::<Vec<_>>();
+
+        let property_types = client
+            .query(
+                "
+                WITH RECURSIVE selected_property_types AS (
+                    SELECT target_property_type_ontology_id AS ontology_id
+                      FROM entity_type_constrains_properties_on
+                     WHERE source_entity_type_ontology_id = ANY($1)
+                    UNION
+                    SELECT property_type_constrains_properties_on.target_property_type_ontology_id
+                      FROM selected_property_types
+                      JOIN property_type_constrains_properties_on
+                        ON property_type_constrains_properties_on.source_property_type_ontology_id
+                         = selected_property_types.ontology_id
+                )
+                SELECT ontology_id FROM selected_property_types
+                ",
+                &[&entity_types],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .into_iter()
+            .map(|row| row.get::<_, PropertyTypeUuid>(0))
+            .collect::<Vec<_>>();
+
+        // Conversions reference the target data type by its base URL, so every version of it is
+        // required.
+        let data_types = client
+            .query(
+                "
+                WITH RECURSIVE selected_data_types AS (
+                    SELECT target_data_type_ontology_id AS ontology_id
+                      FROM property_type_constrains_values_on
+                     WHERE source_property_type_ontology_id = ANY($1)
+                    UNION
+                    SELECT data_type_edges.target_data_type_ontology_id
+                      FROM selected_data_types
+                      JOIN (
+                          SELECT source_data_type_ontology_id, target_data_type_ontology_id
+                            FROM data_type_inherits_from
+                          UNION ALL
+                          SELECT data_type_conversions.source_data_type_ontology_id,
+                                 ontology_ids.ontology_id
+                            FROM data_type_conversions
+                            JOIN ontology_ids
+                              ON ontology_ids.base_url
+                               = data_type_conversions.target_data_type_base_url
+                      ) AS data_type_edges
+                        ON data_type_edges.source_data_type_ontology_id
+                         = selected_data_types.ontology_id
+                )
+                SELECT ontology_id FROM selected_data_types
+                ",
+                &[&property_types],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .into_iter()
+            .map(|row| row.get::<_, DataTypeUuid>(0))
+            .collect::<Vec<_>>();
+
+        webs.extend(
+            client
+                .query(
+                    "
+                    SELECT web_id
+                      FROM ontology_owned_metadata
+                     WHERE ontology_id = ANY($1)
+                        OR ontology_id = ANY($2)
+                        OR ontology_id = ANY($3)
+                    ",
+                    &[&entity_types, &property_types, &data_types],
+                )
+                .await
+                .change_context(SnapshotDumpError::Query)?
+                .into_iter()
+                .map(|row| row.get::<_, OwnedById>(0)),
+        );
+
+        let webs = webs.into_iter().collect::<Vec<_>>();
+        let mut actors = webs
+            .iter()
+            .map(|web_id| web_id.into_uuid())
+            .collect::<Vec<_>>();
+        for web_id in &webs {
+            actors.extend(
+                authorization_api
+                    .read_relations::<(OwnedById, WebRelationAndSubject)>(
+                        RelationshipFilter::from_resource(*web_id),
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(SnapshotDumpError::Query)?
+                    .try_filter_map(|relationship| {
+                        ready(Ok(match relationship.to_parts().subject {
+                            WebSubject::Account(id) => Some(id.into_uuid()),
+                            WebSubject::AccountGroup(id) => Some(id.into_uuid()),
+                            WebSubject::Public => None,
+                        }))
+                    })
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .change_context(SnapshotDumpError::Query)?,
+            );
+        }
+
+        let filter_entity_type_ids =
+            (!filter.entity_types.is_empty()).then_some(filter.entity_types.as_slice());
+        let mut accounts = Vec::new();
+        let mut account_groups = Vec::new();
+        for row in client
+            .query(
+                "
+                WITH referenced_actors (actor_id) AS (
+                    SELECT unnest($1::uuid[])
+                    UNION
+                    SELECT (provenance ->> 'createdById')::uuid
+                      FROM entity_ids
+                     WHERE entity_uuid = ANY($2)
+                    UNION
+                    SELECT (provenance ->> 'createdById')::uuid
+                      FROM entity_editions
+                     WHERE entity_edition_id = ANY($3)
+                    UNION
+                    SELECT (provenance ->> 'archivedById')::uuid
+                      FROM entity_editions
+                     WHERE entity_edition_id = ANY($3)
+                    UNION
+                    SELECT (provenance ->> 'createdById')::uuid
+                      FROM ontology_temporal_metadata
+                     WHERE ontology_id = ANY($4) OR ontology_id = ANY($5) OR ontology_id = ANY($6)
+                    UNION
+                    SELECT (provenance ->> 'archivedById')::uuid
+                      FROM ontology_temporal_metadata
+                     WHERE ontology_id = ANY($4) OR ontology_id = ANY($5) OR ontology_id = ANY($6)
+                    UNION
+                    SELECT erased_by_id
+                      FROM entity_tombstones
+                     WHERE ($7::uuid[] IS NULL OR web_id = ANY($7))
+                       AND ($8::text[] IS NULL OR entity_type_ids && $8)
+                )
+                SELECT account_id, NULL::uuid
+                  FROM accounts
+                 WHERE account_id IN (SELECT actor_id FROM referenced_actors)
+                UNION ALL
+                SELECT NULL::uuid, account_group_id
+                  FROM account_groups
+                 WHERE account_group_id IN (SELECT actor_id FROM referenced_actors)
+                ",
+                &[
+                    &actors,
+                    &entity_uuids,
+                    &entity_edition_ids,
+                    &entity_types,
+                    &property_types,
+                    &data_types,
+                    &filter_webs,
+                    &filter_entity_type_ids,
+                ],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?
+        {
+            if let Some(account_id) = row.get::<_, Option<ActorEntityUuid>>(0) {
+                accounts.push(account_id);
+            }
+            if let Some(account_group_id) = row.get::<_, Option<ActorGroupId>>(1) {
+                account_groups.push(account_group_id);
+            }
+        }
+
+        tracing::info!(
+            webs = webs.len(),
+            accounts = accounts.len(),
+            account_groups = account_groups.len(),
+            entities = entity_uuids.len(),
+            entity_types = entity_types.len(),
+            property_types = property_types.len(),
+            data_types = data_types.len(),
+            "resolved snapshot closure"
+        );
+
+        Ok(SnapshotClosure {
+            webs: Some(webs),
+            accounts: Some(accounts),
+            account_groups: Some(account_groups),
+            entity_uuids,
+            entity_edition_ids,
+            entity_types,
+            property_types,
+            data_types,
+        })
+    }
+
+    /// Resolves the records which changed after `since`.
+    ///
+    /// An entity is changed if any of its temporal rows was created or closed after `since`. In
+    /// this case all temporal rows of the entity are part of the dump, so they can replace the
+    /// rows of the base snapshot. If `base` is provided, only records in it are considered.
+    pub(crate) async fn read_snapshot_delta(
+        &self,
+        since: Timestamp<TransactionTime>,
+        base: Option<&SnapshotClosure>,
+    ) -> Result<SnapshotClosure, Report<SnapshotDumpError>> {
+        let store = self
+            .acquire(NoAuthorization, None)
+            .await
+            .change_context(SnapshotDumpError::Query)?;
+        let client = store.as_client();
+
+        let entity_rows = client
+            .query(
+                "
+                SELECT web_id, entity_uuid, entity_edition_id
+                  FROM entity_temporal_metadata
+                 WHERE (web_id, entity_uuid) IN (
+                           SELECT web_id, entity_uuid
+                             FROM entity_temporal_metadata
+                            WHERE lower(transaction_time) > $1
+                               OR upper(transaction_time) > $1
+                       )
+                   AND ($2::uuid[] IS NULL OR entity_uuid = ANY($2))
+                ",
+                &[&since, &base.map(|base| base.entity_uuids.as_slice())],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?;
+
+        let mut entity_uuids = Vec::new();
+        let mut entity_edition_ids = Vec::with_capacity(entity_rows.len());
+        for row in entity_rows {
+            entity_uuids.push(row.get::<_, EntityUuid>(1));
+            entity_edition_ids.push(row.get::<_, EntityEditionId>(2));
+        }
+        entity_uuids.sort_unstable();
+        entity_uuids.dedup();
+        entity_edition_ids.sort_unstable();
+        entity_edition_ids.dedup();
+
+        let closure = SnapshotClosure {
+            webs: base.and_then(|base| base.webs.clone()),
+            accounts: base.and_then(|base| base.accounts.clone()),
+            account_groups: base.and_then(|base| base.account_groups.clone()),
+            entity_uuids,
+            entity_edition_ids,
+            entity_types: read_changed_ontology_types(
+                client,
+                "entity_types",
+                since,
+                base.map(|base| base.entity_types.as_slice()),
+            )
+            .await?,
+            property_types: read_changed_ontology_types(
+                client,
+                "property_types",
+                since,
+                base.map(|base| base.property_types.as_slice()),
+            )
+            .await?,
+            data_types: read_changed_ontology_types(
+                client,
+                "data_types",
+                since,
+                base.map(|base| base.data_types.as_slice()),
+            )
+            .await?,
+        };
+
+        tracing::info!(
+            %since,
+            entities = closure.entity_uuids.len(),
+            entity_types = closure.entity_types.len(),
+            property_types = closure.property_types.len(),
+            data_types = closure.data_types.len(),
+            "resolved snapshot delta"
+        );
+
+        Ok(closure)
+    }
+}
+
+/// Reads the ontology types stored in `table` which were created or archived after `since`.
+async fn read_changed_ontology_types<T>(
+    client: &(impl GenericClient + Sync),
+    table: &str,
+    since: Timestamp<TransactionTime>,
+    base: Option<&[T]>,
+) -> Result<Vec<T>, Report<SnapshotDumpError>>
+where
+    T: for<'a> FromSql<'a> + ToSql + Sync,
+{
+    client
+        .query(
+            &format!(
+                "
+                SELECT ontology_id
+                  FROM {table}
+                 WHERE ontology_id IN (
+                           SELECT ontology_id
+                             FROM ontology_temporal_metadata
+                            WHERE lower(transaction_time) > $1
+                               OR upper(transaction_time) > $1
+                       )
+                   AND ($2::uuid[] IS NULL OR ontology_id = ANY($2))
+                "
+            ),
+            &[&since, &base],
+        )
+        .map_ok(|rows| rows.into_iter().map(|row| row.get(0)).collect())
+        .await
+        .change_context(SnapshotDumpError::Query)
+}
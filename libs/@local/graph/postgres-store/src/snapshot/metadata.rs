@@ -1,13 +1,47 @@
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SnapshotMetadata {
     pub block_protocol_module_versions: BlockProtocolModuleVersions,
+    /// The version of the snapshot format.
+    ///
+    /// Snapshots taken before the format was versioned don't contain it and are read as version 1.
+    #[serde(default = "SnapshotMetadata::initial_format_version")]
+    pub format_version: u32,
+    /// If set, the snapshot is incremental and only contains the records which changed after
+    /// this point in time.
+    ///
+    /// An incremental snapshot is restored on top of a store containing the base snapshot, which
+    /// has to be taken at or after this point in time. Changes not reflected in the transaction
+    /// time, e.g. removed relations or discarded drafts, are not contained.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub since: Option<Timestamp<TransactionTime>>,
+    /// The transaction time at which the snapshot was taken.
+    ///
+    /// Snapshots are chained by passing this time as `since` to the next dump. The resulting
+    /// incremental snapshot contains every change not contained in this snapshot and is restored
+    /// on top of it. Records changed while this snapshot was taken may be contained in both.
+    ///
+    /// Snapshots taken before this was recorded don't contain it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taken_at: Option<Timestamp<TransactionTime>>,
     #[serde(default, skip_serializing_if = "CustomGlobalMetadata::is_empty")]
     pub custom: CustomGlobalMetadata,
 }
 
+impl SnapshotMetadata {
+    /// The version of the snapshot format written by this version of the Graph.
+    ///
+    /// Version 2 added the [compressed container](crate::snapshot::SnapshotContainer).
+    pub const FORMAT_VERSION: u32 = 2;
+
+    const fn initial_format_version() -> u32 {
+        1
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockProtocolModuleVersions {
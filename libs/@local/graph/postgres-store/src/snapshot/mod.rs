@@ -2,7 +2,9 @@ pub mod entity;
 pub mod owner;
 
 pub use self::{
-    error::{SnapshotDumpError, SnapshotRestoreError},
+    container::{SnapshotChunk, SnapshotContainer, SnapshotContainerWriter, SnapshotManifest},
+    error::{SnapshotContainerError, SnapshotDumpError, SnapshotRestoreError},
+    filter::SnapshotDumpFilter,
     metadata::{BlockProtocolModuleVersions, CustomGlobalMetadata},
     ontology::{
         DataTypeEmbeddingRecord, DataTypeSnapshotRecord, EntityTypeEmbeddingRecord,
@@ -12,7 +14,9 @@ pub use self::{
 };
 pub use crate::snapshot::metadata::SnapshotMetadata;
 
+mod container;
 mod error;
+mod filter;
 mod metadata;
 mod ontology;
 mod restore;
@@ -30,6 +34,7 @@ use hash_graph_authorization::{
     AuthorizationApi, NoAuthorization,
     backend::ZanzibarBackend,
     schema::{
+        AccountGroupAdministratorSubject, AccountGroupMemberSubject,
         AccountGroupRelationAndSubject, DataTypeRelationAndSubject, EntityNamespace,
         EntityRelationAndSubject, EntityTypeRelationAndSubject, PropertyTypeRelationAndSubject,
         WebRelationAndSubject,
@@ -40,11 +45,13 @@ use hash_graph_authorization::{
     },
 };
 use hash_graph_store::{
+    entity::EntityTombstone,
     error::InsertionError,
     filter::{Filter, QueryRecord},
     pool::StorePool,
     query::Read,
 };
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
 use hash_status::StatusCode;
 use postgres_types::ToSql;
 use serde::{Deserialize, Serialize};
@@ -65,7 +72,9 @@ use type_system::{
 };
 
 use crate::{
-    snapshot::{entity::EntityEmbeddingRecord, restore::SnapshotRecordBatch},
+    snapshot::{
+        entity::EntityEmbeddingRecord, filter::SnapshotClosure, restore::SnapshotRecordBatch,
+    },
     store::postgres::{AsClient, PostgresStore, PostgresStorePool},
 };
 
@@ -112,10 +121,51 @@ pub enum SnapshotEntry {
     EntityTypeEmbedding(EntityTypeEmbeddingRecord),
     Entity(Box<Entity>),
     EntityEmbedding(EntityEmbeddingRecord),
+    EntityTombstone(EntityTombstone),
     Relation(AuthorizationRelation),
 }
 
+/// The kind of a [`SnapshotEntry`], used to group entries in a [`SnapshotContainer`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SnapshotEntryKind {
+    Snapshot,
+    Account,
+    AccountGroup,
+    Web,
+    DataType,
+    DataTypeEmbedding,
+    PropertyType,
+    PropertyTypeEmbedding,
+    EntityType,
+    EntityTypeEmbedding,
+    Entity,
+    EntityEmbedding,
+    EntityTombstone,
+    Relation,
+}
+
 impl SnapshotEntry {
+    #[must_use]
+    pub const fn kind(&self) -> SnapshotEntryKind {
+        match self {
+            Self::Snapshot(_) => SnapshotEntryKind::Snapshot,
+            Self::Account(_) => SnapshotEntryKind::Account,
+            Self::AccountGroup(_) => SnapshotEntryKind::AccountGroup,
+            Self::Web(_) => SnapshotEntryKind::Web,
+            Self::DataType(_) => SnapshotEntryKind::DataType,
+            Self::DataTypeEmbedding(_) => SnapshotEntryKind::DataTypeEmbedding,
+            Self::PropertyType(_) => SnapshotEntryKind::PropertyType,
+            Self::PropertyTypeEmbedding(_) => SnapshotEntryKind::PropertyTypeEmbedding,
+            Self::EntityType(_) => SnapshotEntryKind::EntityType,
+            Self::EntityTypeEmbedding(_) => SnapshotEntryKind::EntityTypeEmbedding,
+            Self::Entity(_) => SnapshotEntryKind::Entity,
+            Self::EntityEmbedding(_) => SnapshotEntryKind::EntityEmbedding,
+            Self::EntityTombstone(_) => SnapshotEntryKind::EntityTombstone,
+            Self::Relation(_) => SnapshotEntryKind::Relation,
+        }
+    }
+
     #[expect(clippy::too_many_lines)]
     pub fn install_error_stack_hook() {
         error_stack::Report::install_debug_hook::<Self>(|entry, context| match entry {
@@ -229,6 +279,14 @@ impl SnapshotEntry {
                     }
                 }
             }
+            Self::EntityTombstone(tombstone) => {
+                context.push_body(format!("entity tombstone: {}", tombstone.entity_uuid));
+                if context.alternate() {
+                    if let Ok(json) = serde_json::to_string_pretty(tombstone) {
+                        context.push_appendix(format!("{}:\n{json}", tombstone.entity_uuid));
+                    }
+                }
+            }
         });
     }
 }
@@ -241,9 +299,22 @@ trait WriteBatch<C, A> {
         self,
         postgres_client: &mut PostgresStore<C, A>,
     ) -> impl Future<Output = Result<(), Report<InsertionError>>> + Send;
+    /// Prepares the records read from an incremental snapshot to be applied on top of the records
+    /// already in the store.
+    ///
+    /// Records which already exist in the store are removed from the temporary tables, records
+    /// which are replaced by the snapshot are removed from the store.
+    fn prepare_incremental(
+        postgres_client: &mut PostgresStore<C, A>,
+    ) -> impl Future<Output = Result<(), Report<InsertionError>>> + Send;
+    /// Inserts the records into the store.
+    ///
+    /// If `incremental` is set, the records were applied on top of the records already in the
+    /// store by [`prepare_incremental`](Self::prepare_incremental).
     fn commit(
         postgres_client: &mut PostgresStore<C, A>,
         ignore_validation_errors: bool,
+        incremental: bool,
     ) -> impl Future<Output = Result<(), Report<InsertionError>>> + Send;
 }
 
@@ -259,7 +330,7 @@ impl<C, A> SnapshotStore<C, A> {
     clippy::struct_excessive_bools,
     reason = "This is a configuration struct"
 )]
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Clone)]
 pub struct SnapshotDumpSettings {
     pub chunk_size: usize,
     pub dump_webs: bool,
@@ -271,11 +342,21 @@ pub struct SnapshotDumpSettings {
     pub dump_data_types: bool,
     pub dump_embeddings: bool,
     pub dump_relations: bool,
+    /// Only dumps the subset of the graph matching the filter, see [`SnapshotDumpFilter`].
+    pub filter: Option<SnapshotDumpFilter>,
+    /// Only dumps the records which changed after this transaction time.
+    ///
+    /// To chain snapshots, this is the [`taken_at`] time of the previous snapshot. The resulting
+    /// snapshot is restored on top of a graph which was restored from the previous snapshot.
+    ///
+    /// [`taken_at`]: SnapshotMetadata::taken_at
+    pub since: Option<Timestamp<TransactionTime>>,
 }
 
 impl PostgresStorePool {
     async fn read_accounts(
         &self,
+        accounts: Option<&[ActorEntityUuid]>,
     ) -> Result<
         impl Stream<Item = Result<Account, Report<SnapshotDumpError>>> + Send,
         Report<SnapshotDumpError>,
@@ -288,8 +369,8 @@ impl PostgresStorePool {
             .change_context(SnapshotDumpError::Query)?
             .as_client()
             .query_raw(
-                "SELECT account_id FROM accounts",
-                [] as [&(dyn ToSql + Sync); 0],
+                "SELECT account_id FROM accounts WHERE $1::uuid[] IS NULL OR account_id = ANY($1)",
+                [&accounts as &(dyn ToSql + Sync)],
             )
             .await
             .map_err(|error| Report::new(error).change_context(SnapshotDumpError::Query))?
@@ -297,9 +378,12 @@ impl PostgresStorePool {
             .map_err(|error| Report::new(error).change_context(SnapshotDumpError::Read)))
     }
 
+    /// Reads the account groups in `account_groups` together with the memberships of `accounts`.
     async fn read_account_groups<'a>(
         &'a self,
         authorization_api: &'a (impl ZanzibarBackend + Sync),
+        account_groups: Option<&[ActorGroupId]>,
+        accounts: Option<&'a [ActorEntityUuid]>,
     ) -> Result<
         impl Stream<Item = Result<AccountGroup, Report<SnapshotDumpError>>> + Send + 'a,
         Report<SnapshotDumpError>,
@@ -312,8 +396,12 @@ impl PostgresStorePool {
             .change_context(SnapshotDumpError::Query)?
             .as_client()
             .query_raw(
-                "SELECT account_group_id FROM account_groups",
-                [] as [&(dyn ToSql + Sync); 0],
+                "
+                SELECT account_group_id
+                  FROM account_groups
+                 WHERE $1::uuid[] IS NULL OR account_group_id = ANY($1)
+                ",
+                [&account_groups as &(dyn ToSql + Sync)],
             )
             .await
             .map_err(|error| Report::new(error).change_context(SnapshotDumpError::Query))?
@@ -330,6 +418,19 @@ impl PostgresStorePool {
                         .await
                         .change_context(SnapshotDumpError::Query)?
                         .map_ok(|(_group, relation)| relation)
+                        .try_filter(|relation| {
+                            let account_id = match relation {
+                                AccountGroupRelationAndSubject::Administrator {
+                                    subject: AccountGroupAdministratorSubject::Account { id },
+                                    ..
+                                }
+                                | AccountGroupRelationAndSubject::Member {
+                                    subject: AccountGroupMemberSubject::Account { id },
+                                    ..
+                                } => id,
+                            };
+                            ready(accounts.is_none_or(|accounts| accounts.contains(account_id)))
+                        })
                         .try_collect()
                         .await
                         .change_context(SnapshotDumpError::Query)?,
@@ -340,6 +441,7 @@ impl PostgresStorePool {
     async fn read_webs<'a>(
         &'a self,
         authorization_api: &'a (impl ZanzibarBackend + Sync),
+        webs: Option<&[OwnedById]>,
     ) -> Result<
         impl Stream<Item = Result<Web, Report<SnapshotDumpError>>> + Send + 'a,
         Report<SnapshotDumpError>,
@@ -349,7 +451,10 @@ impl PostgresStorePool {
             .await
             .change_context(SnapshotDumpError::Query)?
             .as_client()
-            .query_raw("SELECT web_id FROM webs", [] as [&(dyn ToSql + Sync); 0])
+            .query_raw(
+                "SELECT web_id FROM webs WHERE $1::uuid[] IS NULL OR web_id = ANY($1)",
+                [&webs as &(dyn ToSql + Sync)],
+            )
             .await
             .map_err(|error| Report::new(error).change_context(SnapshotDumpError::Query))?
             .map_err(|error| Report::new(error).change_context(SnapshotDumpError::Read))
@@ -375,6 +480,7 @@ impl PostgresStorePool {
     /// Convenience function to create a stream of snapshot entries.
     async fn create_dump_stream<'pool, T>(
         &'pool self,
+        filter: &Filter<'_, T>,
     ) -> Result<
         impl Stream<Item = Result<T, Report<SnapshotDumpError>>> + Send + 'pool,
         Report<SnapshotDumpError>,
@@ -388,7 +494,7 @@ impl PostgresStorePool {
                 .acquire(NoAuthorization, None)
                 .await
                 .change_context(SnapshotDumpError::Query)?,
-            &Filter::All(vec![]),
+            filter,
             None,
             true,
         )
@@ -399,6 +505,8 @@ impl PostgresStorePool {
 
     async fn create_data_type_embedding_stream(
         &self,
+        ontology_ids: Option<&[impl ToSql + Sync]>,
+        since: Option<Timestamp<TransactionTime>>,
     ) -> Result<
         impl Stream<Item = Result<SnapshotEntry, Report<SnapshotDumpError>>> + Send,
         Report<SnapshotDumpError>,
@@ -411,8 +519,13 @@ impl PostgresStorePool {
             .query_raw(
                 "SELECT base_url, version, embedding, updated_at_transaction_time
                  FROM data_type_embeddings
-                 JOIN ontology_ids USING (ontology_id)",
-                [] as [&(dyn ToSql + Sync); 0],
+                 JOIN ontology_ids USING (ontology_id)
+                 WHERE ($1::uuid[] IS NULL OR ontology_id = ANY($1))
+                   AND ($2::timestamptz IS NULL OR updated_at_transaction_time > $2)",
+                [
+                    &ontology_ids as &(dyn ToSql + Sync),
+                    &since as &(dyn ToSql + Sync),
+                ],
             )
             .await
             .change_context(SnapshotDumpError::Query)?
@@ -431,6 +544,8 @@ impl PostgresStorePool {
 
     async fn create_property_type_embedding_stream(
         &self,
+        ontology_ids: Option<&[impl ToSql + Sync]>,
+        since: Option<Timestamp<TransactionTime>>,
     ) -> Result<
         impl Stream<Item = Result<SnapshotEntry, Report<SnapshotDumpError>>> + Send,
         Report<SnapshotDumpError>,
@@ -443,8 +558,13 @@ impl PostgresStorePool {
             .query_raw(
                 "SELECT base_url, version, embedding, updated_at_transaction_time
                  FROM property_type_embeddings
-                 JOIN ontology_ids USING (ontology_id)",
-                [] as [&(dyn ToSql + Sync); 0],
+                 JOIN ontology_ids USING (ontology_id)
+                 WHERE ($1::uuid[] IS NULL OR ontology_id = ANY($1))
+                   AND ($2::timestamptz IS NULL OR updated_at_transaction_time > $2)",
+                [
+                    &ontology_ids as &(dyn ToSql + Sync),
+                    &since as &(dyn ToSql + Sync),
+                ],
             )
             .await
             .change_context(SnapshotDumpError::Query)?
@@ -463,6 +583,8 @@ impl PostgresStorePool {
 
     async fn create_entity_type_embedding_stream(
         &self,
+        ontology_ids: Option<&[impl ToSql + Sync]>,
+        since: Option<Timestamp<TransactionTime>>,
     ) -> Result<
         impl Stream<Item = Result<SnapshotEntry, Report<SnapshotDumpError>>> + Send,
         Report<SnapshotDumpError>,
@@ -475,8 +597,13 @@ impl PostgresStorePool {
             .query_raw(
                 "SELECT base_url, version, embedding, updated_at_transaction_time
                  FROM entity_type_embeddings
-                 JOIN ontology_ids USING (ontology_id)",
-                [] as [&(dyn ToSql + Sync); 0],
+                 JOIN ontology_ids USING (ontology_id)
+                 WHERE ($1::uuid[] IS NULL OR ontology_id = ANY($1))
+                   AND ($2::timestamptz IS NULL OR updated_at_transaction_time > $2)",
+                [
+                    &ontology_ids as &(dyn ToSql + Sync),
+                    &since as &(dyn ToSql + Sync),
+                ],
             )
             .await
             .change_context(SnapshotDumpError::Query)?
@@ -495,6 +622,8 @@ impl PostgresStorePool {
 
     async fn create_entity_embedding_stream(
         &self,
+        entity_uuids: Option<&[EntityUuid]>,
+        since: Option<Timestamp<TransactionTime>>,
     ) -> Result<
         impl Stream<Item = Result<SnapshotEntry, Report<SnapshotDumpError>>> + Send,
         Report<SnapshotDumpError>,
@@ -513,8 +642,13 @@ impl PostgresStorePool {
                     embedding,
                     updated_at_decision_time,
                     updated_at_transaction_time
-                 FROM entity_embeddings",
-                [] as [&(dyn ToSql + Sync); 0],
+                 FROM entity_embeddings
+                 WHERE ($1::uuid[] IS NULL OR entity_uuid = ANY($1))
+                   AND ($2::timestamptz IS NULL OR updated_at_transaction_time > $2)",
+                [
+                    &entity_uuids as &(dyn ToSql + Sync),
+                    &since as &(dyn ToSql + Sync),
+                ],
             )
             .await
             .change_context(SnapshotDumpError::Query)?
@@ -534,6 +668,58 @@ impl PostgresStorePool {
             }))
     }
 
+    /// Reads the tombstones of the entities erased in one of `webs` which had one of
+    /// `entity_types`.
+    ///
+    /// The types of a tombstone include the types inherited from, so a tombstone matches the same
+    /// filter as the entity it replaces.
+    async fn create_entity_tombstone_stream(
+        &self,
+        webs: Option<&[OwnedById]>,
+        entity_types: Option<&[VersionedUrl]>,
+        since: Option<Timestamp<TransactionTime>>,
+    ) -> Result<
+        impl Stream<Item = Result<SnapshotEntry, Report<SnapshotDumpError>>> + Send,
+        Report<SnapshotDumpError>,
+    > {
+        Ok(self
+            .acquire(NoAuthorization, None)
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .as_client()
+            .query_raw(
+                "SELECT
+                    web_id,
+                    entity_uuid,
+                    erased_by_id,
+                    erased_at_transaction_time,
+                    reason,
+                    entity_type_ids
+                 FROM entity_tombstones
+                 WHERE ($1::uuid[] IS NULL OR web_id = ANY($1))
+                   AND ($2::text[] IS NULL OR entity_type_ids && $2)
+                   AND ($3::timestamptz IS NULL OR erased_at_transaction_time > $3)",
+                [
+                    &webs as &(dyn ToSql + Sync),
+                    &entity_types as &(dyn ToSql + Sync),
+                    &since as &(dyn ToSql + Sync),
+                ],
+            )
+            .await
+            .change_context(SnapshotDumpError::Query)?
+            .map(|result| result.change_context(SnapshotDumpError::Query))
+            .map_ok(|row| {
+                SnapshotEntry::EntityTombstone(EntityTombstone {
+                    owned_by_id: row.get(0),
+                    entity_uuid: row.get(1),
+                    erased_by_id: row.get(2),
+                    erased_at_transaction_time: row.get(3),
+                    reason: row.get(4),
+                    entity_type_ids: row.get(5),
+                })
+            }))
+    }
+
     /// Reads the snapshot from the store into the given sink.
     ///
     /// The sink is expected to be a `futures::Sink` that can be used to write the snapshot entries
@@ -552,6 +738,77 @@ impl PostgresStorePool {
         authorization_api: &(impl ZanzibarBackend + Sync),
         settings: SnapshotDumpSettings,
     ) -> Result<(), Report<SnapshotDumpError>> {
+        let mut taken_at = None;
+        let mut snapshot_closure = None;
+        let mut snapshot_delta = None;
+        let ((), results) = TokioScope::scope_and_block(|scope| {
+            scope.spawn(async {
+                // Read before any record, so a dump `since` this time contains every change which
+                // is not part of this dump.
+                taken_at = Some(
+                    self.acquire(NoAuthorization, None)
+                        .await
+                        .change_context(SnapshotDumpError::Query)?
+                        .as_client()
+                        .query_one("SELECT now()", &[])
+                        .await
+                        .change_context(SnapshotDumpError::Query)?
+                        .get(0),
+                );
+                if let Some(filter) = &settings.filter {
+                    snapshot_closure = Some(
+                        self.read_snapshot_closure(filter, authorization_api)
+                            .await?,
+                    );
+                }
+                if let Some(since) = settings.since {
+                    snapshot_delta = Some(
+                        self.read_snapshot_delta(since, snapshot_closure.as_ref())
+                            .await?,
+                    );
+                }
+                Ok::<_, Report<SnapshotDumpError>>(())
+            });
+        });
+        for result in results {
+            result.change_context(SnapshotDumpError::Query)??;
+        }
+        // Embeddings and tombstones are restricted by the closure and their own transaction time,
+        // all other records by the delta.
+        let embedding_closure = snapshot_closure.as_ref();
+        let snapshot_closure = snapshot_delta.as_ref().or(snapshot_closure.as_ref());
+
+        let data_type_filter = snapshot_closure.map_or_else(
+            || Filter::All(Vec::new()),
+            SnapshotClosure::data_type_filter,
+        );
+        let property_type_filter = snapshot_closure.map_or_else(
+            || Filter::All(Vec::new()),
+            SnapshotClosure::property_type_filter,
+        );
+        let entity_type_filter = snapshot_closure.map_or_else(
+            || Filter::All(Vec::new()),
+            SnapshotClosure::entity_type_filter,
+        );
+        let entity_filter = snapshot_closure
+            .map_or_else(|| Filter::All(Vec::new()), SnapshotClosure::entity_filter);
+        let webs = snapshot_closure.and_then(|snapshot_closure| snapshot_closure.webs.as_deref());
+        let accounts =
+            snapshot_closure.and_then(|snapshot_closure| snapshot_closure.accounts.as_deref());
+        let account_groups = snapshot_closure
+            .and_then(|snapshot_closure| snapshot_closure.account_groups.as_deref());
+        // Tombstones are not part of the closure, so they are filtered like the entities.
+        let tombstone_webs = settings
+            .filter
+            .as_ref()
+            .map(|filter| filter.webs.as_slice())
+            .filter(|webs| !webs.is_empty());
+        let tombstone_entity_types = settings
+            .filter
+            .as_ref()
+            .map(|filter| filter.entity_types.as_slice())
+            .filter(|entity_types| !entity_types.is_empty());
+
         let (snapshot_record_tx, snapshot_record_rx) = mpsc::channel(settings.chunk_size);
         let snapshot_record_tx = snapshot_record_tx
             .sink_map_err(|error| Report::new(error).change_context(SnapshotDumpError::Write));
@@ -566,14 +823,17 @@ impl PostgresStorePool {
                     block_protocol_module_versions: BlockProtocolModuleVersions {
                         graph: semver::Version::new(0, 3, 0),
                     },
+                    format_version: SnapshotMetadata::FORMAT_VERSION,
                     custom: CustomGlobalMetadata,
+                    since: settings.since,
+                    taken_at,
                 }))))
                 .forward(snapshot_record_tx.clone()),
             );
 
             if settings.dump_webs {
                 scope.spawn(
-                    self.read_webs(authorization_api)
+                    self.read_webs(authorization_api, webs)
                         .try_flatten_stream()
                         .map_ok(SnapshotEntry::Web)
                         .forward(snapshot_record_tx.clone()),
@@ -582,7 +842,7 @@ impl PostgresStorePool {
 
             if settings.dump_accounts {
                 scope.spawn(
-                    self.read_accounts()
+                    self.read_accounts(accounts)
                         .try_flatten_stream()
                         .map_ok(SnapshotEntry::Account)
                         .forward(snapshot_record_tx.clone()),
@@ -591,7 +851,7 @@ impl PostgresStorePool {
 
             if settings.dump_account_groups {
                 scope.spawn(
-                    self.read_account_groups(authorization_api)
+                    self.read_account_groups(authorization_api, account_groups, accounts)
                         .try_flatten_stream()
                         .map_ok(SnapshotEntry::AccountGroup)
                         .forward(snapshot_record_tx.clone()),
@@ -600,7 +860,7 @@ impl PostgresStorePool {
 
             if settings.dump_data_types {
                 scope.spawn(
-                    self.create_dump_stream::<DataTypeWithMetadata>()
+                    self.create_dump_stream::<DataTypeWithMetadata>(&data_type_filter)
                         .try_flatten_stream()
                         .and_then(move |record| async move {
                             Ok(SnapshotEntry::DataType(Box::new(DataTypeSnapshotRecord {
@@ -627,7 +887,7 @@ impl PostgresStorePool {
 
             if settings.dump_property_types {
                 scope.spawn(
-                    self.create_dump_stream::<PropertyTypeWithMetadata>()
+                    self.create_dump_stream::<PropertyTypeWithMetadata>(&property_type_filter)
                         .try_flatten_stream()
                         .and_then(move |record| async move {
                             Ok(
@@ -671,7 +931,7 @@ impl PostgresStorePool {
 
             if settings.dump_entity_types {
                 scope.spawn(
-                        self.create_dump_stream::<EntityTypeWithMetadata>()
+                        self.create_dump_stream::<EntityTypeWithMetadata>(&entity_type_filter)
                             .try_flatten_stream()
                             .and_then(move |record| async move {
                                 Ok(SnapshotEntry::EntityType(Box::new(EntityTypeSnapshotRecord {
@@ -698,42 +958,67 @@ impl PostgresStorePool {
 
             if settings.dump_entities {
                 scope.spawn(
-                    self.create_dump_stream::<Entity>()
+                    self.create_dump_stream::<Entity>(&entity_filter)
                         .try_flatten_stream()
                         .map_ok(|entity| SnapshotEntry::Entity(Box::new(entity)))
                         .forward(snapshot_record_tx.clone()),
                 );
+                scope.spawn(
+                    self.create_entity_tombstone_stream(
+                        tombstone_webs,
+                        tombstone_entity_types,
+                        settings.since,
+                    )
+                    .try_flatten_stream()
+                    .forward(snapshot_record_tx.clone()),
+                );
             }
 
             if settings.dump_data_types && settings.dump_embeddings {
                 scope.spawn(
-                    self.create_data_type_embedding_stream()
-                        .try_flatten_stream()
-                        .forward(snapshot_record_tx.clone()),
+                    self.create_data_type_embedding_stream(
+                        embedding_closure
+                            .map(|embedding_closure| embedding_closure.data_types.as_slice()),
+                        settings.since,
+                    )
+                    .try_flatten_stream()
+                    .forward(snapshot_record_tx.clone()),
                 );
             }
 
             if settings.dump_property_types && settings.dump_embeddings {
                 scope.spawn(
-                    self.create_property_type_embedding_stream()
-                        .try_flatten_stream()
-                        .forward(snapshot_record_tx.clone()),
+                    self.create_property_type_embedding_stream(
+                        embedding_closure
+                            .map(|embedding_closure| embedding_closure.property_types.as_slice()),
+                        settings.since,
+                    )
+                    .try_flatten_stream()
+                    .forward(snapshot_record_tx.clone()),
                 );
             }
 
             if settings.dump_entity_types && settings.dump_embeddings {
                 scope.spawn(
-                    self.create_entity_type_embedding_stream()
-                        .try_flatten_stream()
-                        .forward(snapshot_record_tx.clone()),
+                    self.create_entity_type_embedding_stream(
+                        embedding_closure
+                            .map(|embedding_closure| embedding_closure.entity_types.as_slice()),
+                        settings.since,
+                    )
+                    .try_flatten_stream()
+                    .forward(snapshot_record_tx.clone()),
                 );
             }
 
             if settings.dump_entities && settings.dump_embeddings {
                 scope.spawn(
-                    self.create_entity_embedding_stream()
-                        .try_flatten_stream()
-                        .forward(snapshot_record_tx.clone()),
+                    self.create_entity_embedding_stream(
+                        embedding_closure
+                            .map(|embedding_closure| embedding_closure.entity_uuids.as_slice()),
+                        settings.since,
+                    )
+                    .try_flatten_stream()
+                    .forward(snapshot_record_tx.clone()),
                 );
             }
 
@@ -748,6 +1033,11 @@ impl PostgresStorePool {
                         )
                         .try_flatten_stream()
                         .map(|result| result.change_context(SnapshotDumpError::Query))
+                        .try_filter(move |(id, _)| {
+                            ready(snapshot_closure.is_none_or(|snapshot_closure| {
+                                snapshot_closure.contains_entity(*id)
+                            }))
+                        })
                         .map_ok(|(id, relation)| {
                             SnapshotEntry::Relation(AuthorizationRelation::Entity {
                                 object: id,
@@ -796,6 +1086,11 @@ where
     ///      this stage might fail. In this case, the transaction is rolled back and the error is
     ///      returned.
     ///
+    /// If the snapshot is incremental (see [`SnapshotMetadata::since`]), it is applied on top of
+    /// the data in the store, which is expected to be restored from the base snapshot. Before
+    /// committing, records already present in the store are skipped and the temporal data of
+    /// entities and ontology types contained in the snapshot replaces the existing one.
+    ///
     /// If the input stream contains an `Err` value, the snapshot restore is aborted and the error
     /// is returned.
     ///
@@ -854,48 +1149,63 @@ where
             .await
             .change_context(SnapshotRestoreError::Read)??;
 
-        SnapshotRecordBatch::commit(&mut client, ignore_validation_errors)
-            .await
-            .change_context(SnapshotRestoreError::Write)
-            .map_err(|report| {
-                if let Some(error) = report
-                    .downcast_ref()
-                    .and_then(tokio_postgres::Error::as_db_error)
-                {
-                    match *error.code() {
-                        SqlState::FOREIGN_KEY_VIOLATION => {
-                            report.attach_printable(StatusCode::NotFound)
-                        }
-                        SqlState::UNIQUE_VIOLATION => {
-                            report.attach_printable(StatusCode::AlreadyExists)
-                        }
-                        _ => report,
-                    }
-                } else {
-                    report
-                }
-            })?;
-
-        client
-            .commit()
-            .await
-            .change_context(SnapshotRestoreError::Write)
-            .attach_printable("unable to commit snapshot to the store")?;
-
-        let mut found_metadata = false;
-        for metadata in metadata_rx.collect::<Vec<SnapshotMetadata>>().await {
-            if found_metadata {
+        let mut metadata = None;
+        for snapshot_metadata in metadata_rx.collect::<Vec<SnapshotMetadata>>().await {
+            if metadata.is_some() {
                 tracing::warn!("found more than one metadata record in the snapshot");
             }
-            found_metadata = true;
 
             ensure!(
-                metadata.block_protocol_module_versions.graph == semver::Version::new(0, 3, 0),
+                snapshot_metadata.block_protocol_module_versions.graph
+                    == semver::Version::new(0, 3, 0),
                 SnapshotRestoreError::Unsupported
             );
+            ensure!(
+                snapshot_metadata.format_version <= SnapshotMetadata::FORMAT_VERSION,
+                SnapshotRestoreError::Unsupported
+            );
+            metadata = Some(snapshot_metadata);
+        }
+        let metadata = metadata.ok_or(SnapshotRestoreError::MissingMetadata)?;
+
+        if let Some(since) = metadata.since {
+            tracing::info!(%since, "applying incremental snapshot");
+            SnapshotRecordBatch::prepare_incremental(&mut client)
+                .await
+                .change_context(SnapshotRestoreError::Write)?;
         }
 
-        ensure!(found_metadata, SnapshotRestoreError::MissingMetadata);
+        SnapshotRecordBatch::commit(
+            &mut client,
+            ignore_validation_errors,
+            metadata.since.is_some(),
+        )
+        .await
+        .change_context(SnapshotRestoreError::Write)
+        .map_err(|report| {
+            if let Some(error) = report
+                .downcast_ref()
+                .and_then(tokio_postgres::Error::as_db_error)
+            {
+                match *error.code() {
+                    SqlState::FOREIGN_KEY_VIOLATION => {
+                        report.attach_printable(StatusCode::NotFound)
+                    }
+                    SqlState::UNIQUE_VIOLATION => {
+                        report.attach_printable(StatusCode::AlreadyExists)
+                    }
+                    _ => report,
+                }
+            } else {
+                report
+            }
+        })?;
+
+        client
+            .commit()
+            .await
+            .change_context(SnapshotRestoreError::Write)
+            .attach_printable("unable to commit snapshot to the store")?;
 
         tracing::info!("snapshot restore finished");
 
@@ -131,9 +131,33 @@ where
         Ok(())
     }
 
+    async fn prepare_incremental(
+        postgres_client: &mut PostgresStore<C, A>,
+    ) -> Result<(), Report<InsertionError>> {
+        postgres_client
+            .as_client()
+            .client()
+            .simple_query(
+                "
+                    DELETE FROM data_type_conversions_tmp
+                    WHERE source_data_type_ontology_id IN (SELECT ontology_id FROM data_types);
+
+                    DELETE FROM data_types_tmp
+                    WHERE ontology_id IN (SELECT ontology_id FROM data_types);
+
+                    DELETE FROM data_type_embeddings
+                    WHERE ontology_id IN (SELECT ontology_id FROM data_type_embeddings_tmp);
+                ",
+            )
+            .await
+            .change_context(InsertionError)?;
+        Ok(())
+    }
+
     async fn commit(
         postgres_client: &mut PostgresStore<C, A>,
         _ignore_validation_errors: bool,
+        _incremental: bool,
     ) -> Result<(), Report<InsertionError>> {
         postgres_client
             .as_client()
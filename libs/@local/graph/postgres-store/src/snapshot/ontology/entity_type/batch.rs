@@ -110,9 +110,30 @@ where
         Ok(())
     }
 
+    async fn prepare_incremental(
+        postgres_client: &mut PostgresStore<C, A>,
+    ) -> Result<(), Report<InsertionError>> {
+        postgres_client
+            .as_client()
+            .client()
+            .simple_query(
+                "
+                    DELETE FROM entity_types_tmp
+                    WHERE ontology_id IN (SELECT ontology_id FROM entity_types);
+
+                    DELETE FROM entity_type_embeddings
+                    WHERE ontology_id IN (SELECT ontology_id FROM entity_type_embeddings_tmp);
+                ",
+            )
+            .await
+            .change_context(InsertionError)?;
+        Ok(())
+    }
+
     async fn commit(
         postgres_client: &mut PostgresStore<C, A>,
         _ignore_validation_errors: bool,
+        _incremental: bool,
     ) -> Result<(), Report<InsertionError>> {
         postgres_client
             .as_client()
@@ -107,6 +107,7 @@ impl Sink<EntityTypeSnapshotRecord> for EntityTypeSender {
                         links: HashMap::new(),
                     },
                     all_of: Vec::new(),
+                    unique_properties: HashMap::new(),
                 }),
                 schema,
             })
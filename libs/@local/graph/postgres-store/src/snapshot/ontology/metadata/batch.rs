@@ -133,9 +133,38 @@ where
         Ok(())
     }
 
+    async fn prepare_incremental(
+        postgres_client: &mut PostgresStore<C, A>,
+    ) -> Result<(), Report<InsertionError>> {
+        // Ontology types are immutable, only their temporal metadata changes when they are
+        // archived.
+        postgres_client
+            .as_client()
+            .client()
+            .simple_query(
+                "
+                    DELETE FROM ontology_temporal_metadata
+                    WHERE ontology_id IN (SELECT ontology_id FROM ontology_temporal_metadata_tmp);
+
+                    DELETE FROM ontology_owned_metadata_tmp
+                    WHERE ontology_id IN (SELECT ontology_id FROM ontology_owned_metadata);
+
+                    DELETE FROM ontology_external_metadata_tmp
+                    WHERE ontology_id IN (SELECT ontology_id FROM ontology_external_metadata);
+
+                    DELETE FROM ontology_ids_tmp
+                    WHERE ontology_id IN (SELECT ontology_id FROM ontology_ids);
+                ",
+            )
+            .await
+            .change_context(InsertionError)?;
+        Ok(())
+    }
+
     async fn commit(
         postgres_client: &mut PostgresStore<C, A>,
         _ignore_validation_errors: bool,
+        _incremental: bool,
     ) -> Result<(), Report<InsertionError>> {
         postgres_client
             .as_client()
@@ -143,7 +172,8 @@ where
             .simple_query(
                 "
                     INSERT INTO base_urls
-                        SELECT DISTINCT base_url FROM ontology_ids_tmp;
+                        SELECT DISTINCT base_url FROM ontology_ids_tmp
+                        ON CONFLICT DO NOTHING;
                     INSERT INTO ontology_ids
                         SELECT * FROM ontology_ids_tmp;
                     INSERT INTO ontology_temporal_metadata
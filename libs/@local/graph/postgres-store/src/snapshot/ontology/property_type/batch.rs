@@ -155,9 +155,40 @@ where
         Ok(())
     }
 
+    async fn prepare_incremental(
+        postgres_client: &mut PostgresStore<C, A>,
+    ) -> Result<(), Report<InsertionError>> {
+        postgres_client
+            .as_client()
+            .client()
+            .simple_query(
+                "
+                    DELETE FROM property_type_constrains_values_on_tmp
+                    WHERE source_property_type_ontology_id IN (
+                        SELECT ontology_id FROM property_types
+                    );
+
+                    DELETE FROM property_type_constrains_properties_on_tmp
+                    WHERE source_property_type_ontology_id IN (
+                        SELECT ontology_id FROM property_types
+                    );
+
+                    DELETE FROM property_types_tmp
+                    WHERE ontology_id IN (SELECT ontology_id FROM property_types);
+
+                    DELETE FROM property_type_embeddings
+                    WHERE ontology_id IN (SELECT ontology_id FROM property_type_embeddings_tmp);
+                ",
+            )
+            .await
+            .change_context(InsertionError)?;
+        Ok(())
+    }
+
     async fn commit(
         postgres_client: &mut PostgresStore<C, A>,
         _ignore_validation_errors: bool,
+        _incremental: bool,
     ) -> Result<(), Report<InsertionError>> {
         postgres_client
             .as_client()
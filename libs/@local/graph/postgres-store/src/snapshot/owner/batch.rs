@@ -96,9 +96,30 @@ where
         Ok(())
     }
 
+    async fn prepare_incremental(
+        postgres_client: &mut PostgresStore<C, A>,
+    ) -> Result<(), Report<InsertionError>> {
+        postgres_client
+            .as_client()
+            .client()
+            .simple_query(
+                "
+                    DELETE FROM accounts_tmp
+                    WHERE account_id IN (SELECT account_id FROM accounts);
+
+                    DELETE FROM account_groups_tmp
+                    WHERE account_group_id IN (SELECT account_group_id FROM account_groups);
+                ",
+            )
+            .await
+            .change_context(InsertionError)?;
+        Ok(())
+    }
+
     async fn commit(
         postgres_client: &mut PostgresStore<C, A>,
         _ignore_validation_errors: bool,
+        _incremental: bool,
     ) -> Result<(), Report<InsertionError>> {
         postgres_client
             .as_client()
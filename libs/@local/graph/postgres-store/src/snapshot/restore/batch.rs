@@ -59,17 +59,37 @@ where
         }
     }
 
+    async fn prepare_incremental(
+        postgres_client: &mut PostgresStore<C, A>,
+    ) -> Result<(), Report<InsertionError>> {
+        AccountRowBatch::prepare_incremental(postgres_client).await?;
+        WebBatch::prepare_incremental(postgres_client).await?;
+        OntologyTypeMetadataRowBatch::prepare_incremental(postgres_client).await?;
+        DataTypeRowBatch::prepare_incremental(postgres_client).await?;
+        PropertyTypeRowBatch::prepare_incremental(postgres_client).await?;
+        EntityTypeRowBatch::prepare_incremental(postgres_client).await?;
+        EntityRowBatch::prepare_incremental(postgres_client).await?;
+        Ok(())
+    }
+
     async fn commit(
         postgres_client: &mut PostgresStore<C, A>,
         ignore_validation_errors: bool,
+        incremental: bool,
     ) -> Result<(), Report<InsertionError>> {
-        AccountRowBatch::commit(postgres_client, ignore_validation_errors).await?;
-        WebBatch::commit(postgres_client, ignore_validation_errors).await?;
-        OntologyTypeMetadataRowBatch::commit(postgres_client, ignore_validation_errors).await?;
-        DataTypeRowBatch::commit(postgres_client, ignore_validation_errors).await?;
-        PropertyTypeRowBatch::commit(postgres_client, ignore_validation_errors).await?;
-        EntityTypeRowBatch::commit(postgres_client, ignore_validation_errors).await?;
-        EntityRowBatch::commit(postgres_client, ignore_validation_errors).await?;
+        AccountRowBatch::commit(postgres_client, ignore_validation_errors, incremental).await?;
+        WebBatch::commit(postgres_client, ignore_validation_errors, incremental).await?;
+        OntologyTypeMetadataRowBatch::commit(
+            postgres_client,
+            ignore_validation_errors,
+            incremental,
+        )
+        .await?;
+        DataTypeRowBatch::commit(postgres_client, ignore_validation_errors, incremental).await?;
+        PropertyTypeRowBatch::commit(postgres_client, ignore_validation_errors, incremental)
+            .await?;
+        EntityTypeRowBatch::commit(postgres_client, ignore_validation_errors, incremental).await?;
+        EntityRowBatch::commit(postgres_client, ignore_validation_errors, incremental).await?;
         Ok(())
     }
 }
@@ -30,7 +30,8 @@ use crate::{
         web::WebSender,
     },
     store::postgres::query::rows::{
-        DataTypeEmbeddingRow, EntityEmbeddingRow, EntityTypeEmbeddingRow, PropertyTypeEmbeddingRow,
+        DataTypeEmbeddingRow, EntityEmbeddingRow, EntityTombstoneRow, EntityTypeEmbeddingRow,
+        PropertyTypeEmbeddingRow,
     },
 };
 
@@ -48,6 +49,7 @@ pub struct SnapshotRecordSender {
     entity: EntitySender,
     entity_embedding: Sender<EntityEmbeddingRow>,
     entity_relation: Sender<(EntityUuid, EntityRelationAndSubject)>,
+    entity_tombstone: Sender<EntityTombstoneRow>,
 }
 
 impl Sink<SnapshotEntry> for SnapshotRecordSender {
@@ -87,6 +89,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.entity_embedding.poll_ready_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not poll entity embedding sender")?;
+        ready!(self.entity_tombstone.poll_ready_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not poll entity tombstone sender")?;
 
         Poll::Ready(Ok(()))
     }
@@ -175,6 +180,18 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
                 })
                 .change_context(SnapshotRestoreError::Read)
                 .attach_printable("could not send entity embedding"),
+            SnapshotEntry::EntityTombstone(tombstone) => self
+                .entity_tombstone
+                .start_send_unpin(EntityTombstoneRow {
+                    web_id: tombstone.owned_by_id,
+                    entity_uuid: tombstone.entity_uuid,
+                    erased_by_id: tombstone.erased_by_id,
+                    erased_at_transaction_time: tombstone.erased_at_transaction_time,
+                    reason: tombstone.reason,
+                    entity_type_ids: tombstone.entity_type_ids,
+                })
+                .change_context(SnapshotRestoreError::Read)
+                .attach_printable("could not send entity tombstone"),
         }
     }
 
@@ -212,6 +229,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.entity_embedding.poll_flush_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not flush entity embedding sender")?;
+        ready!(self.entity_tombstone.poll_flush_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not flush entity tombstone sender")?;
 
         Poll::Ready(Ok(()))
     }
@@ -250,6 +270,9 @@ impl Sink<SnapshotEntry> for SnapshotRecordSender {
         ready!(self.entity_embedding.poll_close_unpin(cx))
             .change_context(SnapshotRestoreError::Read)
             .attach_printable("could not close entity embedding sender")?;
+        ready!(self.entity_tombstone.poll_close_unpin(cx))
+            .change_context(SnapshotRestoreError::Read)
+            .attach_printable("could not close entity tombstone sender")?;
 
         Poll::Ready(Ok(()))
     }
@@ -296,8 +319,13 @@ pub(crate) fn channel(
         ontology::entity_type_channel(chunk_size, ontology_metadata_tx, entity_type_embedding_rx);
     let (entity_relation_tx, entity_relation_rx) = mpsc::channel(chunk_size);
     let (entity_embedding_tx, entity_embedding_rx) = mpsc::channel(chunk_size);
-    let (entity_tx, entity_rx) =
-        entity::channel(chunk_size, entity_relation_rx, entity_embedding_rx);
+    let (entity_tombstone_tx, entity_tombstone_rx) = mpsc::channel(chunk_size);
+    let (entity_tx, entity_rx) = entity::channel(
+        chunk_size,
+        entity_relation_rx,
+        entity_embedding_rx,
+        entity_tombstone_rx,
+    );
 
     (
         SnapshotRecordSender {
@@ -313,6 +341,7 @@ pub(crate) fn channel(
             entity: entity_tx,
             entity_relation: entity_relation_tx,
             entity_embedding: entity_embedding_tx,
+            entity_tombstone: entity_tombstone_tx,
         },
         SnapshotRecordReceiver {
             stream: select_all(vec![
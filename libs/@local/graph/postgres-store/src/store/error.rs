@@ -59,6 +59,18 @@ impl fmt::Display for RaceConditionOnUpdate {
 
 impl Error for RaceConditionOnUpdate {}
 
+#[derive(Debug)]
+#[must_use]
+pub struct EntityEditionMismatch;
+
+impl fmt::Display for EntityEditionMismatch {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.write_str("The current edition of the entity does not match the expected edition")
+    }
+}
+
+impl Error for EntityEditionMismatch {}
+
 #[derive(Debug)]
 #[must_use]
 pub struct VersionedUrlAlreadyExists;
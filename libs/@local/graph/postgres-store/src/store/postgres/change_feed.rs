@@ -0,0 +1,368 @@
+use std::collections::HashSet;
+
+use error_stack::{Report, ResultExt as _};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    schema::{DataTypePermission, EntityPermission, EntityTypePermission, PropertyTypePermission},
+    zanzibar::Consistency,
+};
+use hash_graph_store::{
+    change_feed::{
+        Change, ChangeFeedCursor, ChangeFeedRecord, ChangeFeedStore, OntologyTypeKind,
+        ReadChangesParams, ReadChangesResponse,
+    },
+    error::QueryError,
+};
+use hash_graph_temporal_versioning::Timestamp;
+use tokio_postgres::Row;
+use type_system::{
+    knowledge::entity::id::EntityId,
+    ontology::{
+        data_type::DataTypeUuid,
+        entity_type::EntityTypeUuid,
+        id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+        property_type::PropertyTypeUuid,
+    },
+    provenance::ActorEntityUuid,
+};
+use uuid::Uuid;
+
+use crate::store::{AsClient, PostgresStore};
+
+/// Sequence of an inserted entity edition.
+const ENTITY_EDITION_SEQUENCE: i16 = 0;
+/// Sequence of an ontology type which was created or unarchived.
+const ONTOLOGY_TYPE_INSERTED_SEQUENCE: i16 = 1;
+/// Sequence of an ontology type which was archived.
+const ONTOLOGY_TYPE_ARCHIVED_SEQUENCE: i16 = 2;
+
+/// Reads the changes after the cursor `($1, $2, $3)` and before `$4`, limited to `$5` changes.
+///
+/// The parameters `$6`, `$7`, and `$8` are the sequences of the different kinds of changes.
+///
+/// The update trigger on `entity_temporal_metadata` splits the history of an entity into multiple
+/// rows, so an entity edition is only reported at the earliest transaction time it appears in.
+/// Ontology types are reported when their temporal metadata row starts and, if they were
+/// archived, when it ends.
+const READ_CHANGES_QUERY: &str = "
+    WITH changes AS (
+        (
+            SELECT DISTINCT
+                lower(entity_temporal_metadata.transaction_time) AS transaction_time,
+                $6::int2 AS sequence,
+                entity_temporal_metadata.entity_edition_id AS id,
+                entity_temporal_metadata.web_id,
+                entity_temporal_metadata.entity_uuid,
+                entity_temporal_metadata.draft_id,
+                entity_editions.archived,
+                NULL::text AS ontology_type,
+                NULL::boolean AS first_edition
+            FROM entity_temporal_metadata
+            JOIN entity_editions
+              ON entity_editions.entity_edition_id = entity_temporal_metadata.entity_edition_id
+            WHERE (
+                    lower(entity_temporal_metadata.transaction_time),
+                    $6::int2,
+                    entity_temporal_metadata.entity_edition_id
+                  ) > ($1, $2, $3)
+              AND ($4::timestamptz IS NULL
+                   OR lower(entity_temporal_metadata.transaction_time) < $4)
+              AND NOT EXISTS (
+                  SELECT 1
+                  FROM entity_temporal_metadata AS earlier
+                  WHERE earlier.entity_edition_id = entity_temporal_metadata.entity_edition_id
+                    AND lower(earlier.transaction_time)
+                        < lower(entity_temporal_metadata.transaction_time)
+              )
+            ORDER BY 1, 2, 3
+            LIMIT $5
+        ) UNION ALL (
+            SELECT
+                lower(ontology_temporal_metadata.transaction_time) AS transaction_time,
+                $7::int2 AS sequence,
+                ontology_temporal_metadata.ontology_id AS id,
+                NULL::uuid,
+                NULL::uuid,
+                NULL::uuid,
+                NULL::boolean,
+                NULL::text,
+                NOT EXISTS (
+                    SELECT 1
+                    FROM ontology_temporal_metadata AS earlier
+                    WHERE earlier.ontology_id = ontology_temporal_metadata.ontology_id
+                      AND lower(earlier.transaction_time)
+                          < lower(ontology_temporal_metadata.transaction_time)
+                )
+            FROM ontology_temporal_metadata
+            WHERE (
+                    lower(ontology_temporal_metadata.transaction_time),
+                    $7::int2,
+                    ontology_temporal_metadata.ontology_id
+                  ) > ($1, $2, $3)
+              AND ($4::timestamptz IS NULL
+                   OR lower(ontology_temporal_metadata.transaction_time) < $4)
+            ORDER BY 1, 2, 3
+            LIMIT $5
+        ) UNION ALL (
+            SELECT
+                upper(ontology_temporal_metadata.transaction_time) AS transaction_time,
+                $8::int2 AS sequence,
+                ontology_temporal_metadata.ontology_id AS id,
+                NULL::uuid,
+                NULL::uuid,
+                NULL::uuid,
+                NULL::boolean,
+                NULL::text,
+                NULL::boolean
+            FROM ontology_temporal_metadata
+            WHERE upper(ontology_temporal_metadata.transaction_time) IS NOT NULL
+              AND (
+                    upper(ontology_temporal_metadata.transaction_time),
+                    $8::int2,
+                    ontology_temporal_metadata.ontology_id
+                  ) > ($1, $2, $3)
+              AND ($4::timestamptz IS NULL
+                   OR upper(ontology_temporal_metadata.transaction_time) < $4)
+            ORDER BY 1, 2, 3
+            LIMIT $5
+        )
+    )
+    SELECT
+        changes.transaction_time,
+        changes.sequence,
+        changes.id,
+        changes.web_id,
+        changes.entity_uuid,
+        changes.draft_id,
+        changes.archived,
+        ontology_ids.base_url,
+        ontology_ids.version,
+        CASE
+            WHEN data_types.ontology_id IS NOT NULL THEN 'dataType'
+            WHEN property_types.ontology_id IS NOT NULL THEN 'propertyType'
+            WHEN entity_types.ontology_id IS NOT NULL THEN 'entityType'
+        END,
+        changes.first_edition
+    FROM changes
+    LEFT JOIN ontology_ids ON ontology_ids.ontology_id = changes.id AND changes.sequence <> $6
+    LEFT JOIN data_types ON data_types.ontology_id = ontology_ids.ontology_id
+    LEFT JOIN property_types ON property_types.ontology_id = ontology_ids.ontology_id
+    LEFT JOIN entity_types ON entity_types.ontology_id = ontology_ids.ontology_id
+    ORDER BY changes.transaction_time, changes.sequence, changes.id
+    LIMIT $5;
+";
+
+fn change_from_row(row: &Row) -> Result<ChangeFeedRecord, Report<QueryError>> {
+    let cursor = ChangeFeedCursor {
+        transaction_time: row.get(0),
+        sequence: row.get(1),
+        id: row.get(2),
+    };
+
+    let change = if cursor.sequence == ENTITY_EDITION_SEQUENCE {
+        let entity_id = EntityId {
+            owned_by_id: row.get(3),
+            entity_uuid: row.get(4),
+            draft_id: row.get(5),
+        };
+        let entity_edition_id = row.get(2);
+
+        if row.get(6) {
+            Change::EntityArchived {
+                entity_id,
+                entity_edition_id,
+            }
+        } else {
+            Change::EntityEditionCreated {
+                entity_id,
+                entity_edition_id,
+            }
+        }
+    } else {
+        let ontology_type = match row.get::<_, &str>(9) {
+            "dataType" => OntologyTypeKind::DataType,
+            "propertyType" => OntologyTypeKind::PropertyType,
+            "entityType" => OntologyTypeKind::EntityType,
+            kind => {
+                return Err(Report::new(QueryError)
+                    .attach_printable(format!("Unknown ontology type kind: {kind}")));
+            }
+        };
+        let ontology_id = row.get(2);
+        let ontology_type_id = VersionedUrl {
+            base_url: row.get::<_, BaseUrl>(7),
+            version: row.get::<_, OntologyTypeVersion>(8),
+        };
+
+        if cursor.sequence == ONTOLOGY_TYPE_ARCHIVED_SEQUENCE {
+            Change::OntologyTypeArchived {
+                ontology_type,
+                ontology_id,
+                ontology_type_id,
+            }
+        } else if row.get(10) {
+            Change::OntologyTypeCreated {
+                ontology_type,
+                ontology_id,
+                ontology_type_id,
+            }
+        } else {
+            Change::OntologyTypeUnarchived {
+                ontology_type,
+                ontology_id,
+                ontology_type_id,
+            }
+        }
+    };
+
+    Ok(ChangeFeedRecord { cursor, change })
+}
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    /// Removes the changes of records the actor is not allowed to view.
+    async fn filter_viewable_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        changes: Vec<ChangeFeedRecord>,
+    ) -> Result<Vec<ChangeFeedRecord>, Report<QueryError>> {
+        let mut entity_ids = HashSet::<EntityId>::new();
+        let mut data_type_ids = HashSet::<DataTypeUuid>::new();
+        let mut property_type_ids = HashSet::<PropertyTypeUuid>::new();
+        let mut entity_type_ids = HashSet::<EntityTypeUuid>::new();
+        for record in &changes {
+            match (record.change.entity_id(), record.change.ontology_type()) {
+                (Some(entity_id), _) => {
+                    entity_ids.insert(entity_id);
+                }
+                (None, Some((OntologyTypeKind::DataType, ontology_id))) => {
+                    data_type_ids.insert(ontology_id.into());
+                }
+                (None, Some((OntologyTypeKind::PropertyType, ontology_id))) => {
+                    property_type_ids.insert(ontology_id.into());
+                }
+                (None, Some((OntologyTypeKind::EntityType, ontology_id))) => {
+                    entity_type_ids.insert(ontology_id.into());
+                }
+                (None, None) => {}
+            }
+        }
+
+        let (entity_permissions, _) = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                entity_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+        let (data_type_permissions, _) = self
+            .authorization_api
+            .check_data_types_permission(
+                actor_id,
+                DataTypePermission::View,
+                data_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+        let (property_type_permissions, _) = self
+            .authorization_api
+            .check_property_types_permission(
+                actor_id,
+                PropertyTypePermission::View,
+                property_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+        let (entity_type_permissions, _) = self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::View,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        Ok(changes
+            .into_iter()
+            .filter(|record| {
+                let permitted = match (record.change.entity_id(), record.change.ontology_type()) {
+                    (Some(entity_id), _) => entity_permissions.get(&entity_id.entity_uuid),
+                    (None, Some((OntologyTypeKind::DataType, ontology_id))) => {
+                        data_type_permissions.get(&DataTypeUuid::from(ontology_id))
+                    }
+                    (None, Some((OntologyTypeKind::PropertyType, ontology_id))) => {
+                        property_type_permissions.get(&PropertyTypeUuid::from(ontology_id))
+                    }
+                    (None, Some((OntologyTypeKind::EntityType, ontology_id))) => {
+                        entity_type_permissions.get(&EntityTypeUuid::from(ontology_id))
+                    }
+                    (None, None) => None,
+                };
+                permitted.copied().unwrap_or(false)
+            })
+            .collect())
+    }
+}
+
+impl<C, A> ChangeFeedStore for PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn read_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, Report<QueryError>> {
+        let after = params.after.unwrap_or(ChangeFeedCursor {
+            transaction_time: Timestamp::UNIX_EPOCH,
+            sequence: i16::MIN,
+            id: Uuid::nil(),
+        });
+        let requested = params.capped_limit();
+        // One additional change is read to find out if there are more changes.
+        let limit = i64::try_from(requested + 1).unwrap_or(i64::MAX);
+
+        let mut changes = self
+            .as_client()
+            .query(
+                READ_CHANGES_QUERY,
+                &[
+                    &after.transaction_time,
+                    &after.sequence,
+                    &after.id,
+                    &params.before,
+                    &limit,
+                    &ENTITY_EDITION_SEQUENCE,
+                    &ONTOLOGY_TYPE_INSERTED_SEQUENCE,
+                    &ONTOLOGY_TYPE_ARCHIVED_SEQUENCE,
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .iter()
+            .map(change_from_row)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let has_more = changes.len() > requested;
+        changes.truncate(requested);
+        let cursor = changes.last().map(|record| record.cursor).or(params.after);
+
+        Ok(ReadChangesResponse {
+            changes: self.filter_viewable_changes(actor_id, changes).await?,
+            cursor,
+            has_more,
+        })
+    }
+}
@@ -1,19 +1,26 @@
-use core::marker::PhantomData;
+use core::{fmt, marker::PhantomData};
 
 use error_stack::{Report, ResultExt as _};
 use futures::{Stream, StreamExt as _, TryStreamExt as _};
+use hash_graph_authorization::{AuthorizationApi, zanzibar::Consistency};
 use hash_graph_store::{
     error::QueryError,
     filter::Filter,
-    query::{QueryResult, Read, ReadPaginated, Sorting},
+    query::{
+        ExplainQuery, JoinedTable, QueryExplanation, QueryResult, Read, ReadPaginated, Sorting,
+    },
     subgraph::temporal_axes::QueryTemporalAxes,
 };
 use tokio_postgres::{GenericClient as _, Row};
 use tracing::Instrument as _;
+use type_system::provenance::ActorEntityUuid;
 
 use crate::store::{
-    AsClient, PostgresStore,
-    postgres::query::{PostgresQueryPath, PostgresRecord, PostgresSorting, SelectCompiler},
+    AsClient, PostgresStore, StoreCache,
+    postgres::query::{
+        PostgresQueryPath, PostgresRecord, PostgresSorting, SelectCompiler, Transpile as _,
+    },
+    validation::StoreProvider,
 };
 
 pub struct QueryIndices<R: QueryRecordDecode, S: QueryRecordDecode> {
@@ -90,19 +97,14 @@ where
         let cursor_parameters = sorting.encode().change_context(QueryError)?;
 
         let mut compiler = SelectCompiler::new(temporal_axes, include_drafts);
-        if let Some(limit) = limit {
-            compiler.set_limit(limit);
-        }
-
-        compiler.add_filter(filter).change_context(QueryError)?;
-
-        let cursor_indices = sorting
-            .compile(
-                &mut compiler,
-                cursor_parameters.as_ref(),
-                temporal_axes.expect("To use a cursor, temporal axes has to be specified"),
-            )
-            .change_context(QueryError)?;
+        let cursor_indices = compile_paginated(
+            &mut compiler,
+            filter,
+            temporal_axes,
+            sorting,
+            cursor_parameters.as_ref(),
+            limit,
+        )?;
 
         let record_artifacts = R::parameters();
         let record_indices = R::compile(&mut compiler, &record_artifacts);
@@ -127,6 +129,36 @@ where
     }
 }
 
+/// Adds the filter, the sorting, and the limit of a paginated read to the `compiler`.
+///
+/// Returns the indices of the cursor columns.
+fn compile_paginated<'p, 'q: 'p, R, S>(
+    compiler: &mut SelectCompiler<'p, 'q, R>,
+    filter: &'p Filter<'q, R>,
+    temporal_axes: Option<&QueryTemporalAxes>,
+    sorting: &'p S,
+    cursor_parameters: Option<&'p <S as PostgresSorting<'q, R>>::CompilationParameters>,
+    limit: Option<usize>,
+) -> Result<<S as QueryRecordDecode>::Indices, Report<QueryError>>
+where
+    R: PostgresRecord<QueryPath<'q>: PostgresQueryPath>,
+    S: PostgresSorting<'q, R>,
+{
+    if let Some(limit) = limit {
+        compiler.set_limit(limit);
+    }
+
+    compiler.add_filter(filter).change_context(QueryError)?;
+
+    sorting
+        .compile(
+            compiler,
+            cursor_parameters,
+            temporal_axes.expect("To use a cursor, temporal axes has to be specified"),
+        )
+        .change_context(QueryError)
+}
+
 impl<Cl, A, R> Read<R> for PostgresStore<Cl, A>
 where
     Cl: AsClient,
@@ -191,3 +223,75 @@ where
         }
     }
 }
+
+impl<Cl, A, R, S> ExplainQuery<R, S> for PostgresStore<Cl, A>
+where
+    Cl: AsClient,
+    for<'c> R: PostgresRecord<QueryPath<'c>: PostgresQueryPath + fmt::Display>,
+    for<'s> S: PostgresSorting<'s, R> + Sync,
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, filter, sorting))]
+    async fn explain_query(
+        &self,
+        actor_id: ActorEntityUuid,
+        mut filter: Filter<'_, R>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        sorting: &S,
+        limit: Option<usize>,
+        include_drafts: bool,
+    ) -> Result<QueryExplanation, Report<QueryError>> {
+        filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let cursor_parameters = sorting.encode().change_context(QueryError)?;
+
+        let mut compiler = SelectCompiler::new(temporal_axes, include_drafts);
+        compile_paginated(
+            &mut compiler,
+            &filter,
+            temporal_axes,
+            sorting,
+            cursor_parameters.as_ref(),
+            limit,
+        )?;
+
+        let record_artifacts = R::parameters();
+        R::compile(&mut compiler, &record_artifacts);
+
+        let (statement, parameters) = compiler.compile();
+
+        let plan = self
+            .as_client()
+            .query_one(
+                &format!("EXPLAIN (ANALYZE, FORMAT JSON) {statement}"),
+                parameters,
+            )
+            .instrument(tracing::trace_span!("query"))
+            .await
+            .change_context(QueryError)?
+            .get(0);
+
+        Ok(QueryExplanation {
+            parameters: parameters
+                .iter()
+                .map(|parameter| format!("{parameter:?}"))
+                .collect(),
+            joined_tables: compiler
+                .joined_tables()
+                .map(|table| JoinedTable {
+                    table: table.table.as_str().to_owned(),
+                    alias: table.transpile_to_string().trim_matches('"').to_owned(),
+                })
+                .collect(),
+            statement,
+            plan,
+        })
+    }
+}
@@ -0,0 +1,284 @@
+//! Aggregation of entities in batches.
+//!
+//! Permissions cannot be checked in the database, so the entities matching a filter are aggregated
+//! per entity. The partial aggregations are authorized in batches and combined per group
+//! afterwards.
+
+use core::cmp::Ordering;
+use std::collections::HashMap;
+
+use error_stack::{Report, bail};
+use hash_graph_store::{
+    entity::{AggregateEntitiesParams, EntityAggregation, EntityAggregationGroup, EntityQueryPath},
+    query::AggregationFunction,
+    subgraph::temporal_axes::QueryTemporalAxes,
+};
+use serde_json::Value;
+use tokio_postgres::Row;
+use type_system::knowledge::Entity;
+
+use crate::store::postgres::query::{SelectCompiler, SelectCompilerError};
+
+/// The number of partial aggregations which are authorized at once.
+pub(super) const AGGREGATION_BATCH_SIZE: usize = 10_000;
+
+/// A query which groups entities and selects the partial aggregations of each group.
+pub(super) struct AggregationQuery<'p, 'q: 'p> {
+    pub(super) compiler: SelectCompiler<'p, 'q, Entity>,
+    /// The indices of the selected group keys.
+    pub(super) key_indices: Vec<usize>,
+    pub(super) aggregations: Vec<PartialAggregation>,
+}
+
+impl<'p, 'q: 'p> AggregationQuery<'p, 'q> {
+    pub(super) fn new(
+        temporal_axes: &'p QueryTemporalAxes,
+        params: &'p AggregateEntitiesParams<'q>,
+    ) -> Result<Self, Report<SelectCompilerError>> {
+        let mut compiler = SelectCompiler::new(Some(temporal_axes), params.include_drafts);
+        let key_indices = params
+            .group_by
+            .iter()
+            .map(|path| compiler.add_group_by_path(path))
+            .collect();
+        let aggregations = params
+            .aggregations
+            .iter()
+            .map(|aggregation| PartialAggregation::select(&mut compiler, aggregation))
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            compiler,
+            key_indices,
+            aggregations,
+        })
+    }
+}
+
+/// The selected columns of an aggregation, from which the aggregation can be combined across
+/// entities.
+#[derive(Debug, Copy, Clone)]
+pub(super) enum PartialAggregation {
+    Count(usize),
+    Sum(usize),
+    Min(usize),
+    Max(usize),
+    Avg { sum: usize, count: usize },
+}
+
+impl PartialAggregation {
+    /// Adds the columns required to compute `aggregation` to the selection.
+    ///
+    /// Counting entities without a path counts their editions, so every entity is counted once per
+    /// group, even if it is joined with multiple rows.
+    fn select<'p, 'q: 'p>(
+        compiler: &mut SelectCompiler<'p, 'q, Entity>,
+        aggregation: &'p EntityAggregation<'q>,
+    ) -> Result<Self, Report<SelectCompilerError>> {
+        let path = aggregation.path.as_ref();
+        Ok(match (aggregation.function, path) {
+            (AggregationFunction::Count, None) => {
+                Self::Count(compiler.add_distinct_count(&EntityQueryPath::EditionId))
+            }
+            (AggregationFunction::Count, Some(_)) => {
+                Self::Count(compiler.add_aggregation(AggregationFunction::Count, path)?)
+            }
+            (AggregationFunction::Sum, _) => {
+                Self::Sum(compiler.add_aggregation(AggregationFunction::Sum, path)?)
+            }
+            (AggregationFunction::Min, _) => {
+                Self::Min(compiler.add_aggregation(AggregationFunction::Min, path)?)
+            }
+            (AggregationFunction::Max, _) => {
+                Self::Max(compiler.add_aggregation(AggregationFunction::Max, path)?)
+            }
+            (AggregationFunction::Avg, Some(path)) => Self::Avg {
+                sum: compiler.add_aggregation(AggregationFunction::Sum, Some(path))?,
+                count: compiler.add_value_count(AggregationFunction::Avg, path)?,
+            },
+            (AggregationFunction::Avg, None) => {
+                bail!(SelectCompilerError::MissingAggregationPath {
+                    function: AggregationFunction::Avg
+                })
+            }
+        })
+    }
+
+    fn accumulate(self, accumulator: &mut Accumulator, row: &Row) {
+        match self {
+            Self::Count(index) => accumulator.count += row.get::<_, f64>(index),
+            Self::Sum(index) => {
+                accumulator.value =
+                    combine(accumulator.value, row.get(index), |lhs, rhs| lhs + rhs);
+            }
+            Self::Min(index) => {
+                accumulator.value = combine(accumulator.value, row.get(index), f64::min);
+            }
+            Self::Max(index) => {
+                accumulator.value = combine(accumulator.value, row.get(index), f64::max);
+            }
+            Self::Avg { sum, count } => {
+                accumulator.value = combine(accumulator.value, row.get(sum), |lhs, rhs| lhs + rhs);
+                accumulator.count += row.get::<_, f64>(count);
+            }
+        }
+    }
+
+    fn finish(self, accumulator: Accumulator) -> Option<f64> {
+        match self {
+            Self::Count(_) => Some(accumulator.count),
+            Self::Sum(_) | Self::Min(_) | Self::Max(_) => accumulator.value,
+            Self::Avg { .. } => accumulator.value.map(|sum| sum / accumulator.count),
+        }
+    }
+}
+
+fn combine(lhs: Option<f64>, rhs: Option<f64>, op: impl FnOnce(f64, f64) -> f64) -> Option<f64> {
+    match (lhs, rhs) {
+        (Some(lhs), Some(rhs)) => Some(op(lhs, rhs)),
+        (value, None) | (None, value) => value,
+    }
+}
+
+/// The combined result of an aggregation over the partial aggregations read so far.
+#[derive(Debug, Copy, Clone, Default)]
+struct Accumulator {
+    value: Option<f64>,
+    count: f64,
+}
+
+/// Combines the groups of aggregations computed per entity.
+#[derive(Debug)]
+pub(super) struct AggregationGroups {
+    aggregations: Vec<PartialAggregation>,
+    grouped: bool,
+    indices: HashMap<String, usize>,
+    groups: Vec<(Vec<Value>, Vec<Accumulator>)>,
+}
+
+impl AggregationGroups {
+    /// Creates an empty set of groups for the given aggregations.
+    ///
+    /// If the entities are not `grouped`, a single group is returned even if no entity was
+    /// aggregated.
+    pub(super) fn new(aggregations: Vec<PartialAggregation>, grouped: bool) -> Self {
+        Self {
+            aggregations,
+            grouped,
+            indices: HashMap::new(),
+            groups: Vec::new(),
+        }
+    }
+
+    /// Adds the aggregations of a group for a single entity.
+    pub(super) fn accumulate(&mut self, key: Vec<Value>, row: &Row) {
+        let index = *self
+            .indices
+            .entry(Value::from(key.clone()).to_string())
+            .or_insert_with(|| {
+                self.groups
+                    .push((key, vec![Accumulator::default(); self.aggregations.len()]));
+                self.groups.len() - 1
+            });
+
+        for (aggregation, accumulator) in self.aggregations.iter().zip(&mut self.groups[index].1) {
+            aggregation.accumulate(accumulator, row);
+        }
+    }
+
+    /// Returns the combined groups ordered by their key.
+    ///
+    /// Like in the database, `null` values are ordered last. Strings are compared by their bytes.
+    pub(super) fn finish(mut self) -> Vec<EntityAggregationGroup> {
+        if !self.grouped && self.groups.is_empty() {
+            self.groups.push((
+                Vec::new(),
+                vec![Accumulator::default(); self.aggregations.len()],
+            ));
+        }
+
+        self.groups
+            .sort_by(|(lhs, _), (rhs, _)| compare_keys(lhs, rhs));
+        self.groups
+            .into_iter()
+            .map(|(key, accumulators)| EntityAggregationGroup {
+                key,
+                values: self
+                    .aggregations
+                    .iter()
+                    .zip(accumulators)
+                    .map(|(aggregation, accumulator)| aggregation.finish(accumulator))
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+fn compare_keys(lhs: &[Value], rhs: &[Value]) -> Ordering {
+    lhs.iter()
+        .zip(rhs)
+        .map(|(lhs, rhs)| compare_values(lhs, rhs))
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or(Ordering::Equal)
+}
+
+const fn type_rank(value: &Value) -> u8 {
+    match value {
+        Value::Bool(_) => 0,
+        Value::Number(_) => 1,
+        Value::String(_) => 2,
+        Value::Array(_) => 3,
+        Value::Object(_) => 4,
+        Value::Null => 5,
+    }
+}
+
+fn compare_values(lhs: &Value, rhs: &Value) -> Ordering {
+    match (lhs, rhs) {
+        (Value::Bool(lhs), Value::Bool(rhs)) => lhs.cmp(rhs),
+        (Value::Number(lhs), Value::Number(rhs)) => lhs
+            .as_f64()
+            .unwrap_or(f64::NAN)
+            .total_cmp(&rhs.as_f64().unwrap_or(f64::NAN)),
+        (Value::String(lhs), Value::String(rhs)) => lhs.cmp(rhs),
+        _ => type_rank(lhs)
+            .cmp(&type_rank(rhs))
+            .then_with(|| lhs.to_string().cmp(&rhs.to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn keys_are_ordered_like_the_database() {
+        let mut keys = [
+            vec![json!(null)],
+            vec![json!("b")],
+            vec![json!(10)],
+            vec![json!("a")],
+            vec![json!(9.5)],
+        ];
+        keys.sort_by(|lhs, rhs| compare_keys(lhs, rhs));
+        assert_eq!(
+            keys,
+            [
+                vec![json!(9.5)],
+                vec![json!(10)],
+                vec![json!("a")],
+                vec![json!("b")],
+                vec![json!(null)],
+            ]
+        );
+    }
+
+    #[test]
+    fn missing_values_are_ignored_when_combining() {
+        assert_eq!(combine(None, None, f64::min), None);
+        assert_eq!(combine(Some(1.0), None, f64::min), Some(1.0));
+        assert_eq!(combine(None, Some(2.0), f64::min), Some(2.0));
+        assert_eq!(combine(Some(1.0), Some(2.0), f64::max), Some(2.0));
+    }
+}
@@ -1,7 +1,9 @@
+mod aggregation;
+mod path;
 mod query;
 mod read;
 use alloc::borrow::Cow;
-use core::{borrow::Borrow as _, iter::once, mem};
+use core::{borrow::Borrow as _, iter::once, mem, pin::pin};
 use std::collections::{HashMap, HashSet};
 
 use error_stack::{FutureExt as _, Report, ResultExt as _, TryReportStreamExt as _, bail, ensure};
@@ -17,14 +19,19 @@ use hash_graph_authorization::{
 };
 use hash_graph_store::{
     entity::{
-        CountEntitiesParams, CreateEntityParams, EmptyEntityTypes, EntityQueryPath,
-        EntityQuerySorting, EntityStore, EntityTypeRetrieval, EntityTypesError,
-        EntityValidationReport, EntityValidationType, GetEntitiesParams, GetEntitiesResponse,
-        GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams, QueryConversion,
-        UpdateEntityEmbeddingsParams, ValidateEntityComponents, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EmptyEntityTypes, EntityBatchOperation, EntityBatchOperationResult,
+        EntityHistoryEdition, EntityQueryPath, EntityQuerySorting, EntityStore, EntityTombstone,
+        EntityTypeRetrieval, EntityTypesError, EntityUpgradeFailure, EntityValidationReport,
+        EntityValidationType, EraseEntityParams, FindEntityPathsParams, FindEntityPathsResponse,
+        GetEntitiesParams, GetEntitiesResponse, GetEntityHistoryParams, GetEntityHistoryResponse,
+        GetEntitySubgraphParams, GetEntitySubgraphResponse, MergeEntitiesParams,
+        MergeEntitiesResponse, PatchEntityParams, QueryConversion, UpdateEntityEmbeddingsParams,
+        UpgradeEntityTypeParams, UpgradeEntityTypeResponse, ValidateEntityComponents,
+        ValidateEntityParams,
     },
     entity_type::{EntityTypeQueryPath, EntityTypeStore as _, IncludeEntityTypeOption},
-    error::{InsertionError, QueryError, UpdateError},
+    error::{DeletionError as StoreDeletionError, InsertionError, QueryError, UpdateError},
     filter::{Filter, FilterExpression, Parameter, ParameterList},
     query::{QueryResult as _, Read, ReadPaginated, Sorting as _},
     subgraph::{
@@ -57,14 +64,14 @@ use type_system::{
     knowledge::{
         Confidence, Entity, Property, PropertyValue,
         entity::{
-            EntityMetadata, EntityProvenance,
+            EntityMetadata, EntityProvenance, LinkData,
             id::{DraftId, EntityEditionId, EntityId, EntityRecordId, EntityUuid},
             metadata::EntityTemporalMetadata,
             provenance::{EntityEditionProvenance, InferredEntityProvenance},
         },
         property::{
-            PropertyObject, PropertyObjectWithMetadata, PropertyPath, PropertyPathError,
-            PropertyValueWithMetadata, PropertyWithMetadata,
+            PropertyDiff, PropertyObject, PropertyObjectWithMetadata, PropertyPatchOperation,
+            PropertyPath, PropertyPathError, PropertyValueWithMetadata, PropertyWithMetadata,
             metadata::{PropertyMetadata, PropertyObjectMetadata},
         },
     },
@@ -83,15 +90,18 @@ use uuid::Uuid;
 
 use crate::store::{
     AsClient, PostgresStore, StoreCache,
-    error::{DeletionError, EntityDoesNotExist, RaceConditionOnUpdate},
+    error::{DeletionError, EntityDoesNotExist, EntityEditionMismatch, RaceConditionOnUpdate},
     postgres::{
         ResponseCountMap, TraversalContext,
-        knowledge::entity::read::EntityEdgeTraversalData,
+        knowledge::entity::{
+            aggregation::{AGGREGATION_BATCH_SIZE, AggregationGroups, AggregationQuery},
+            read::EntityEdgeTraversalData,
+        },
         query::{
             InsertStatementBuilder, ReferenceTable, SelectCompiler, Table,
             rows::{
                 EntityDraftRow, EntityEditionRow, EntityHasLeftEntityRow, EntityHasRightEntityRow,
-                EntityIdRow, EntityIsOfTypeRow, EntityTemporalMetadataRow,
+                EntityIdRow, EntityIsOfTypeRow, EntityTemporalMetadataRow, EntityTombstoneRow,
             },
         },
     },
@@ -325,10 +335,12 @@ where
                     DELETE FROM entity_has_right_entity;
                     DELETE FROM entity_is_of_type;
                     DELETE FROM entity_temporal_metadata;
+                    DELETE FROM entity_edition_search;
                     DELETE FROM entity_editions;
                     DELETE FROM entity_embeddings;
                     DELETE FROM entity_drafts;
                     DELETE FROM entity_ids;
+                    DELETE FROM entity_tombstones;
                 ",
             )
             .await
@@ -764,193 +776,739 @@ where
             latest_zookie.into_owned(),
         ))
     }
-}
 
-impl<C, A> EntityStore for PostgresStore<C, A>
-where
-    C: AsClient,
-    A: AuthorizationApi,
-{
-    #[tracing::instrument(level = "info", skip(self, params))]
-    async fn create_entities<R>(
+    /// Executes a single operation of an entity batch.
+    ///
+    /// Relationship modifications are only checked for permission and added to `relationships`,
+    /// so they can be applied after all other operations of the batch succeeded.
+    async fn execute_entity_batch_operation(
         &mut self,
         actor_id: ActorEntityUuid,
-        params: Vec<CreateEntityParams<R>>,
-    ) -> Result<Vec<Entity>, Report<InsertionError>>
-    where
-        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
-    {
-        let transaction_time = Timestamp::<TransactionTime>::now().remove_nanosecond();
-        let mut relationships = Vec::with_capacity(params.len());
-        let mut entity_type_ids = HashMap::new();
-        let mut checked_web_ids = HashSet::new();
-        let mut entity_edition_ids = Vec::with_capacity(params.len());
-
-        let mut entity_id_rows = Vec::with_capacity(params.len());
-        let mut entity_draft_rows = Vec::new();
-        let mut entity_edition_rows = Vec::with_capacity(params.len());
-        let mut entity_temporal_metadata_rows = Vec::with_capacity(params.len());
-        let mut entity_is_of_type_rows = Vec::with_capacity(params.len());
-        let mut entity_has_left_entity_rows = Vec::new();
-        let mut entity_has_right_entity_rows = Vec::new();
-
-        let mut entities = Vec::with_capacity(params.len());
-        // TODO: There are expected to be duplicates but we currently don't have a way to identify
-        //       multi-type entity types. We need a way to speed this up.
-        let mut validation_params = Vec::with_capacity(params.len());
-
-        let validator_provider = StoreProvider {
-            store: self,
-            cache: StoreCache::default(),
-            authorization: Some((actor_id, Consistency::FullyConsistent)),
-        };
-
-        let mut validation_reports = HashMap::<usize, EntityValidationReport>::new();
-        for (index, mut params) in params.into_iter().enumerate() {
-            let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
-                stream::iter(&params.entity_type_ids)
-                    .then(|entity_type_url| async {
-                        OntologyTypeProvider::<ClosedEntityType>::provide_type(
-                            &validator_provider,
-                            entity_type_url,
-                        )
-                        .await
-                        .map(|entity_type| (*entity_type).clone())
-                    })
-                    .try_collect::<Vec<ClosedEntityType>>()
+        operation: EntityBatchOperation,
+        created: &[Option<EntityId>],
+        relationships: &mut Vec<(
+            ModifyRelationshipOperation,
+            EntityId,
+            EntityRelationAndSubject,
+        )>,
+    ) -> Result<EntityBatchOperationResult, Report<UpdateError>> {
+        match operation {
+            EntityBatchOperation::CreateEntity(operation) => {
+                let params = (*operation)
+                    .resolve(created)
+                    .attach(StatusCode::InvalidArgument)
+                    .change_context(UpdateError)?;
+                let entity = self
+                    .create_entity(actor_id, params)
                     .await
-                    .change_context(InsertionError)?,
-            )
-            .change_context(InsertionError)?;
+                    .change_context(UpdateError)?;
+                Ok(EntityBatchOperationResult::CreateEntity { entity })
+            }
+            EntityBatchOperation::PatchEntity(operation) => {
+                let params = operation
+                    .resolve(created)
+                    .attach(StatusCode::InvalidArgument)
+                    .change_context(UpdateError)?;
+                let entity = self.patch_entity(actor_id, params).await?;
+                Ok(EntityBatchOperationResult::PatchEntity { entity })
+            }
+            EntityBatchOperation::ArchiveEntity(operation) => {
+                let params = operation
+                    .resolve(created)
+                    .attach(StatusCode::InvalidArgument)
+                    .change_context(UpdateError)?;
+                let entity = self.patch_entity(actor_id, params).await?;
+                Ok(EntityBatchOperationResult::ArchiveEntity { entity })
+            }
+            EntityBatchOperation::ModifyRelationship(operation) => {
+                let entity_id = operation
+                    .resource
+                    .resolve(created)
+                    .attach(StatusCode::InvalidArgument)
+                    .change_context(UpdateError)?;
+                self.authorization_api
+                    .check_entity_permission(
+                        actor_id,
+                        EntityPermission::Update,
+                        entity_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(UpdateError)?
+                    .assert_permission()
+                    .change_context(UpdateError)?;
+                relationships.push((operation.operation, entity_id, operation.relation_subject));
+                Ok(EntityBatchOperationResult::ModifyRelationship)
+            }
+        }
+    }
 
-            let mut preprocessor = EntityPreprocessor {
-                components: if params.draft {
-                    ValidateEntityComponents::draft()
-                } else {
-                    ValidateEntityComponents::full()
-                },
-            };
-            preprocessor.components.link_validation = self.settings.validate_links;
+    /// Reverts the authorization relationships written by a failed entity batch.
+    ///
+    /// All relationships of the `created` entities are removed and the `modified` relationships of
+    /// other entities are reverted. Touched relationships are kept, as it's unknown whether they
+    /// existed before.
+    async fn revert_entity_batch(
+        &mut self,
+        created: &[Option<EntityId>],
+        modified: &[(
+            ModifyRelationshipOperation,
+            EntityId,
+            EntityRelationAndSubject,
+        )],
+        error: Report<UpdateError>,
+    ) -> Report<UpdateError> {
+        let mut error = error.expand();
+
+        let created = created.iter().flatten().copied().collect::<HashSet<_>>();
+        let mut reverted = modified
+            .iter()
+            .filter(|(_, entity_id, _)| !created.contains(entity_id))
+            .filter_map(|&(operation, entity_id, relation)| match operation {
+                ModifyRelationshipOperation::Create => {
+                    Some((ModifyRelationshipOperation::Delete, entity_id, relation))
+                }
+                ModifyRelationshipOperation::Delete => {
+                    Some((ModifyRelationshipOperation::Touch, entity_id, relation))
+                }
+                ModifyRelationshipOperation::Touch => None,
+            })
+            .collect::<Vec<_>>();
 
-            if let Err(property_validation) = preprocessor
-                .visit_object(&entity_type, &mut params.properties, &validator_provider)
+        for entity_id in created {
+            match self
+                .authorization_api
+                .get_entity_relations(entity_id, Consistency::FullyConsistent)
                 .await
             {
-                validation_reports.entry(index).or_default().properties =
-                    property_validation.properties;
+                Ok(relations) => reverted.extend(
+                    relations
+                        .into_iter()
+                        .map(|relation| (ModifyRelationshipOperation::Delete, entity_id, relation)),
+                ),
+                Err(read_error) => error.push(read_error.change_context(UpdateError)),
             }
+        }
 
-            let (properties, property_metadata) = params.properties.into_parts();
-
-            let decision_time = params
-                .decision_time
-                .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
-            let entity_id = EntityId {
-                owned_by_id: params.owned_by_id,
-                entity_uuid: params
-                    .entity_uuid
-                    .unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
-                draft_id: params.draft.then(|| DraftId::new(Uuid::new_v4())),
-            };
-
-            if entity_id.entity_uuid.as_uuid() != entity_id.owned_by_id.as_uuid() {
-                checked_web_ids.insert(entity_id.owned_by_id);
-            }
+        if let Err(modify_error) = self
+            .authorization_api
+            .modify_entity_relations(reverted)
+            .await
+        {
+            error.push(modify_error.change_context(UpdateError));
+        }
 
-            let entity_provenance = EntityProvenance {
-                inferred: InferredEntityProvenance {
-                    created_by_id: actor_id,
-                    created_at_transaction_time: transaction_time,
-                    created_at_decision_time: decision_time,
-                    first_non_draft_created_at_transaction_time: entity_id
-                        .draft_id
-                        .is_none()
-                        .then_some(transaction_time),
-                    first_non_draft_created_at_decision_time: entity_id
-                        .draft_id
-                        .is_none()
-                        .then_some(decision_time),
-                },
-                edition: EntityEditionProvenance {
-                    created_by_id: actor_id,
-                    archived_by_id: None,
-                    provided: params.provenance,
-                },
-            };
-            entity_id_rows.push(EntityIdRow {
-                web_id: entity_id.owned_by_id,
-                entity_uuid: entity_id.entity_uuid,
-                provenance: entity_provenance.inferred.clone(),
-            });
-            if let Some(draft_id) = entity_id.draft_id {
-                entity_draft_rows.push(EntityDraftRow {
-                    web_id: entity_id.owned_by_id,
-                    entity_uuid: entity_id.entity_uuid,
-                    draft_id,
-                });
-            }
+        error.change_context(UpdateError)
+    }
 
-            let entity_edition_id = EntityEditionId::new(Uuid::new_v4());
-            entity_edition_rows.push(EntityEditionRow {
-                entity_edition_id,
-                properties: properties.clone(),
-                archived: false,
-                confidence: params.confidence,
-                provenance: entity_provenance.edition.clone(),
-                property_metadata: property_metadata.clone(),
-            });
-            entity_edition_ids.push(entity_edition_id);
+    /// Patches an entity, see [`EntityStore::patch_entity`].
+    ///
+    /// If the entity was merged into another entity, `merged_into` is recorded in the provenance
+    /// of the new edition.
+    #[expect(
+        clippy::significant_drop_tightening,
+        reason = "The connection is required to borrow the client"
+    )]
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn patch_entity_impl(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        mut params: PatchEntityParams,
+        merged_into: Option<EntityId>,
+    ) -> Result<Entity, Report<UpdateError>> {
+        let transaction_time = Timestamp::now().remove_nanosecond();
+        let decision_time = params
+            .decision_time
+            .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
+        let entity_type_ids = params
+            .entity_type_ids
+            .iter()
+            .map(EntityTypeUuid::from_url)
+            .collect::<Vec<_>>();
 
-            let temporal_versioning = EntityTemporalMetadata {
-                decision_time: LeftClosedTemporalInterval::new(
-                    ClosedTemporalBound::Inclusive(decision_time),
-                    OpenTemporalBound::Unbounded,
-                ),
-                transaction_time: LeftClosedTemporalInterval::new(
-                    ClosedTemporalBound::Inclusive(transaction_time),
-                    OpenTemporalBound::Unbounded,
-                ),
-            };
-            entity_temporal_metadata_rows.push(EntityTemporalMetadataRow {
-                web_id: entity_id.owned_by_id,
-                entity_uuid: entity_id.entity_uuid,
-                draft_id: entity_id.draft_id,
-                entity_edition_id,
-                decision_time: temporal_versioning.decision_time,
-                transaction_time: temporal_versioning.transaction_time,
-            });
+        if !self
+            .authorization_api
+            .check_entity_types_permission(
+                actor_id,
+                EntityTypePermission::Instantiate,
+                entity_type_ids,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .0
+            .into_iter()
+            .all(|(_, permission)| permission)
+        {
+            bail!(Report::new(UpdateError).attach(StatusCode::PermissionDenied));
+        }
 
-            for entity_type in &entity_type.all_of {
-                let entity_type_id = EntityTypeUuid::from_url(&entity_type.id);
-                entity_type_ids.insert(entity_type_id, entity_type.id.clone());
-                entity_is_of_type_rows.push(EntityIsOfTypeRow {
-                    entity_edition_id,
-                    entity_type_ontology_id: entity_type_id,
-                    inheritance_depth: InheritanceDepth::new(0),
-                });
-            }
+        self.authorization_api
+            .check_entity_permission(
+                actor_id,
+                EntityPermission::Update,
+                params.entity_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(UpdateError)?
+            .assert_permission()
+            .change_context(UpdateError)?;
 
-            let link_data = params.link_data.inspect(|link_data| {
-                entity_has_left_entity_rows.push(EntityHasLeftEntityRow {
-                    web_id: entity_id.owned_by_id,
-                    entity_uuid: entity_id.entity_uuid,
-                    left_web_id: link_data.left_entity_id.owned_by_id,
-                    left_entity_uuid: link_data.left_entity_id.entity_uuid,
-                    confidence: link_data.left_entity_confidence,
-                    provenance: link_data.left_entity_provenance.clone(),
-                });
-                entity_has_right_entity_rows.push(EntityHasRightEntityRow {
-                    web_id: entity_id.owned_by_id,
-                    entity_uuid: entity_id.entity_uuid,
-                    right_web_id: link_data.right_entity_id.owned_by_id,
-                    right_entity_uuid: link_data.right_entity_id.entity_uuid,
-                    confidence: link_data.right_entity_confidence,
-                    provenance: link_data.right_entity_provenance.clone(),
-                });
-            });
+        let transaction = self.transaction().await.change_context(UpdateError)?;
 
-            entities.push(Entity {
-                properties,
+        let locked_row = transaction
+            .lock_entity_edition(
+                params.entity_id,
+                transaction_time,
+                decision_time,
+                params.expected_edition_id,
+            )
+            .await?
+            .ok_or_else(|| {
+                Report::new(EntityDoesNotExist)
+                    .attach(StatusCode::NotFound)
+                    .attach_printable(params.entity_id)
+                    .change_context(UpdateError)
+            })?;
+        let ClosedTemporalBound::Inclusive(locked_transaction_time) =
+            *locked_row.transaction_time.start();
+        let ClosedTemporalBound::Inclusive(locked_decision_time) =
+            *locked_row.decision_time.start();
+        let previous_entity = Read::<Entity>::read_one(
+            &transaction,
+            &Filter::Equal(
+                Some(FilterExpression::Path {
+                    path: EntityQueryPath::EditionId,
+                }),
+                Some(FilterExpression::Parameter {
+                    parameter: Parameter::Uuid(locked_row.entity_edition_id.into_uuid()),
+                    convert: None,
+                }),
+            ),
+            Some(&QueryTemporalAxes::DecisionTime {
+                pinned: PinnedTemporalAxis::new(locked_transaction_time),
+                variable: VariableTemporalAxis::new(
+                    TemporalBound::Inclusive(locked_decision_time),
+                    LimitedTemporalBound::Inclusive(locked_decision_time),
+                ),
+            }),
+            true,
+        )
+        .await
+        .change_context(EntityDoesNotExist)
+        .attach(params.entity_id)
+        .change_context(UpdateError)?;
+
+        let mut first_non_draft_created_at_decision_time = previous_entity
+            .metadata
+            .provenance
+            .inferred
+            .first_non_draft_created_at_decision_time;
+        let mut first_non_draft_created_at_transaction_time = previous_entity
+            .metadata
+            .provenance
+            .inferred
+            .first_non_draft_created_at_transaction_time;
+
+        let was_draft_before = previous_entity
+            .metadata
+            .record_id
+            .entity_id
+            .draft_id
+            .is_some();
+        let draft = params.draft.unwrap_or(was_draft_before);
+        let archived = params.archived.unwrap_or(previous_entity.metadata.archived);
+        let (entity_type_ids, entity_types_updated) = if params.entity_type_ids.is_empty() {
+            (previous_entity.metadata.entity_type_ids, false)
+        } else {
+            let added_types = previous_entity
+                .metadata
+                .entity_type_ids
+                .difference(&params.entity_type_ids);
+            let removed_types = params
+                .entity_type_ids
+                .difference(&previous_entity.metadata.entity_type_ids);
+
+            let mut has_changed = false;
+            for entity_type_id in added_types.chain(removed_types) {
+                has_changed = true;
+
+                let entity_type_id = EntityTypeUuid::from_url(entity_type_id);
+                transaction
+                    .authorization_api
+                    .check_entity_type_permission(
+                        actor_id,
+                        EntityTypePermission::Instantiate,
+                        entity_type_id,
+                        Consistency::FullyConsistent,
+                    )
+                    .await
+                    .change_context(UpdateError)?
+                    .assert_permission()
+                    .change_context(UpdateError)
+                    .attach(StatusCode::PermissionDenied)?;
+            }
+
+            (params.entity_type_ids, has_changed)
+        };
+
+        let previous_properties = previous_entity.properties.clone();
+        let previous_property_metadata = previous_entity.metadata.properties.clone();
+
+        let mut properties_with_metadata = PropertyWithMetadata::from_parts(
+            Property::Object(previous_entity.properties),
+            Some(PropertyMetadata::Object(PropertyObjectMetadata {
+                value: previous_entity.metadata.properties.value,
+                metadata: previous_entity.metadata.properties.metadata,
+            })),
+        )
+        .change_context(UpdateError)?;
+        properties_with_metadata
+            .patch(params.properties)
+            .change_context(UpdateError)?;
+
+        let validator_provider = StoreProvider {
+            store: &transaction,
+            cache: StoreCache::default(),
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
+            stream::iter(&entity_type_ids)
+                .then(|entity_type_url| async {
+                    OntologyTypeProvider::<ClosedEntityType>::provide_type(
+                        &validator_provider,
+                        entity_type_url,
+                    )
+                    .await
+                    .map(|entity_type| (*entity_type).clone())
+                })
+                .try_collect::<Vec<ClosedEntityType>>()
+                .await
+                .change_context(UpdateError)?,
+        )
+        .change_context(UpdateError)?;
+
+        let mut validation_components = if draft {
+            ValidateEntityComponents::draft()
+        } else {
+            ValidateEntityComponents::full()
+        };
+        validation_components.link_validation = transaction.settings.validate_links;
+
+        let mut validation_report = EntityValidationReport::default();
+        let (properties, property_metadata) =
+            if let PropertyWithMetadata::Object(mut object) = properties_with_metadata {
+                let mut preprocessor = EntityPreprocessor {
+                    components: validation_components,
+                };
+                if let Err(property_validation) = preprocessor
+                    .visit_object(&entity_type, &mut object, &validator_provider)
+                    .await
+                {
+                    validation_report.properties = property_validation.properties;
+                }
+
+                let (properties, property_metadata) = object.into_parts();
+                (properties, property_metadata)
+            } else {
+                unreachable!("patching should not change the property type");
+            };
+        // We move out the cache, so we can re-use `&mut self` later.
+        let store_cache = validator_provider.cache;
+
+        #[expect(clippy::needless_collect, reason = "Will be used later")]
+        let diff = previous_properties
+            .diff(&properties, &mut PropertyPath::default())
+            .collect::<Vec<_>>();
+
+        if diff.is_empty()
+            && was_draft_before == draft
+            && archived == previous_entity.metadata.archived
+            && !entity_types_updated
+            && previous_property_metadata == property_metadata
+            && params.confidence == previous_entity.metadata.confidence
+            && merged_into.is_none()
+        {
+            // No changes were made to the entity.
+            return Ok(Entity {
+                properties: previous_properties,
+                link_data: previous_entity.link_data,
+                metadata: EntityMetadata {
+                    record_id: previous_entity.metadata.record_id,
+                    temporal_versioning: previous_entity.metadata.temporal_versioning,
+                    entity_type_ids,
+                    provenance: previous_entity.metadata.provenance,
+                    archived,
+                    confidence: previous_entity.metadata.confidence,
+                    properties: property_metadata,
+                },
+            });
+        }
+
+        let link_data = previous_entity.link_data;
+
+        let edition_provenance = EntityEditionProvenance {
+            created_by_id: actor_id,
+            archived_by_id: None,
+            merged_into,
+            provided: params.provenance,
+        };
+        let edition_id = transaction
+            .insert_entity_edition(
+                archived,
+                &entity_type_ids,
+                &properties,
+                params.confidence,
+                &edition_provenance,
+                &property_metadata,
+            )
+            .await
+            .change_context(UpdateError)?;
+
+        let temporal_versioning = match (was_draft_before, draft) {
+            (true, true) | (false, false) => {
+                // regular update
+                transaction
+                    .update_temporal_metadata(
+                        locked_row,
+                        transaction_time,
+                        decision_time,
+                        edition_id,
+                        false,
+                    )
+                    .await?
+            }
+            (false, true) => {
+                let draft_id = DraftId::new(Uuid::new_v4());
+                transaction
+                    .as_client()
+                    .query(
+                        "
+                        INSERT INTO entity_drafts (
+                            web_id,
+                            entity_uuid,
+                            draft_id
+                        ) VALUES ($1, $2, $3);",
+                        &[
+                            &params.entity_id.owned_by_id,
+                            &params.entity_id.entity_uuid,
+                            &draft_id,
+                        ],
+                    )
+                    .await
+                    .change_context(UpdateError)?;
+                params.entity_id.draft_id = Some(draft_id);
+                transaction
+                    .insert_temporal_metadata(
+                        params.entity_id,
+                        edition_id,
+                        transaction_time,
+                        decision_time,
+                    )
+                    .await
+                    .change_context(UpdateError)?
+            }
+            (true, false) => {
+                // Publish a draft
+                params.entity_id.draft_id = None;
+
+                if first_non_draft_created_at_decision_time.is_none() {
+                    transaction
+                        .as_client()
+                        .query(
+                            "
+                            UPDATE entity_ids
+                            SET provenance = provenance || JSONB_BUILD_OBJECT(
+                                'firstNonDraftCreatedAtTransactionTime', $1::TIMESTAMPTZ,
+                                'firstNonDraftCreatedAtDecisionTime', $2::TIMESTAMPTZ
+                            )
+                            WHERE web_id = $3
+                              AND entity_uuid = $4;
+                            ",
+                            &[
+                                &transaction_time,
+                                &decision_time,
+                                &params.entity_id.owned_by_id,
+                                &params.entity_id.entity_uuid,
+                            ],
+                        )
+                        .await
+                        .change_context(UpdateError)?;
+
+                    first_non_draft_created_at_transaction_time = Some(transaction_time);
+                    first_non_draft_created_at_decision_time = Some(decision_time);
+                }
+
+                if let Some(previous_live_entity) = transaction
+                    .lock_entity_edition(params.entity_id, transaction_time, decision_time, None)
+                    .await?
+                {
+                    transaction
+                        .archive_entity(
+                            actor_id,
+                            previous_live_entity,
+                            transaction_time,
+                            decision_time,
+                        )
+                        .await?;
+                }
+                transaction
+                    .update_temporal_metadata(
+                        locked_row,
+                        transaction_time,
+                        decision_time,
+                        edition_id,
+                        true,
+                    )
+                    .await?
+            }
+        };
+
+        let entity_metadata = EntityMetadata {
+            record_id: EntityRecordId {
+                entity_id: params.entity_id,
+                edition_id,
+            },
+            temporal_versioning,
+            entity_type_ids,
+            provenance: EntityProvenance {
+                inferred: InferredEntityProvenance {
+                    first_non_draft_created_at_transaction_time,
+                    first_non_draft_created_at_decision_time,
+                    ..previous_entity.metadata.provenance.inferred
+                },
+                edition: edition_provenance,
+            },
+            confidence: params.confidence,
+            properties: property_metadata,
+            archived,
+        };
+        let entities = [Entity {
+            properties,
+            link_data,
+            metadata: entity_metadata.clone(),
+        }];
+
+        let validator_provider = StoreProvider {
+            store: &transaction,
+            cache: store_cache,
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+        let post_validation_report = entities[0]
+            .validate(&entity_type, validation_components, &validator_provider)
+            .await;
+        validation_report.link = post_validation_report.link;
+        validation_report.metadata.properties = post_validation_report.property_metadata;
+        validation_report.unique_properties = post_validation_report.unique_properties;
+
+        ensure!(
+            validation_report.is_valid(),
+            Report::new(UpdateError).attach(HashMap::from([(
+                entities[0].metadata.record_id.entity_id,
+                validation_report
+            )]))
+        );
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        if let Some(temporal_client) = &self.temporal_client {
+            temporal_client
+                .start_update_entity_embeddings_workflow(actor_id, &entities)
+                .await
+                .change_context(UpdateError)?;
+        }
+        let [entity] = entities;
+        Ok(entity)
+    }
+}
+
+impl<C, A> EntityStore for PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn create_entities<R>(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: Vec<CreateEntityParams<R>>,
+    ) -> Result<Vec<Entity>, Report<InsertionError>>
+    where
+        R: IntoIterator<Item = EntityRelationAndSubject> + Send,
+    {
+        let transaction_time = Timestamp::<TransactionTime>::now().remove_nanosecond();
+        let mut relationships = Vec::with_capacity(params.len());
+        let mut entity_type_ids = HashMap::new();
+        let mut checked_web_ids = HashSet::new();
+        let mut entity_edition_ids = Vec::with_capacity(params.len());
+
+        let mut entity_id_rows = Vec::with_capacity(params.len());
+        let mut entity_draft_rows = Vec::new();
+        let mut entity_edition_rows = Vec::with_capacity(params.len());
+        let mut entity_temporal_metadata_rows = Vec::with_capacity(params.len());
+        let mut entity_is_of_type_rows = Vec::with_capacity(params.len());
+        let mut entity_has_left_entity_rows = Vec::new();
+        let mut entity_has_right_entity_rows = Vec::new();
+
+        let mut entities = Vec::with_capacity(params.len());
+        // TODO: There are expected to be duplicates but we currently don't have a way to identify
+        //       multi-type entity types. We need a way to speed this up.
+        let mut validation_params = Vec::with_capacity(params.len());
+
+        let validator_provider = StoreProvider {
+            store: self,
+            cache: StoreCache::default(),
+            authorization: Some((actor_id, Consistency::FullyConsistent)),
+        };
+
+        let mut validation_reports = HashMap::<usize, EntityValidationReport>::new();
+        for (index, mut params) in params.into_iter().enumerate() {
+            let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
+                stream::iter(&params.entity_type_ids)
+                    .then(|entity_type_url| async {
+                        OntologyTypeProvider::<ClosedEntityType>::provide_type(
+                            &validator_provider,
+                            entity_type_url,
+                        )
+                        .await
+                        .map(|entity_type| (*entity_type).clone())
+                    })
+                    .try_collect::<Vec<ClosedEntityType>>()
+                    .await
+                    .change_context(InsertionError)?,
+            )
+            .change_context(InsertionError)?;
+
+            let mut preprocessor = EntityPreprocessor {
+                components: if params.draft {
+                    ValidateEntityComponents::draft()
+                } else {
+                    ValidateEntityComponents::full()
+                },
+            };
+            preprocessor.components.link_validation = self.settings.validate_links;
+
+            if let Err(property_validation) = preprocessor
+                .visit_object(&entity_type, &mut params.properties, &validator_provider)
+                .await
+            {
+                validation_reports.entry(index).or_default().properties =
+                    property_validation.properties;
+            }
+
+            let (properties, property_metadata) = params.properties.into_parts();
+
+            let decision_time = params
+                .decision_time
+                .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
+            let entity_id = EntityId {
+                owned_by_id: params.owned_by_id,
+                entity_uuid: params
+                    .entity_uuid
+                    .unwrap_or_else(|| EntityUuid::new(Uuid::new_v4())),
+                draft_id: params.draft.then(|| DraftId::new(Uuid::new_v4())),
+            };
+
+            if entity_id.entity_uuid.as_uuid() != entity_id.owned_by_id.as_uuid() {
+                checked_web_ids.insert(entity_id.owned_by_id);
+            }
+
+            let entity_provenance = EntityProvenance {
+                inferred: InferredEntityProvenance {
+                    created_by_id: actor_id,
+                    created_at_transaction_time: transaction_time,
+                    created_at_decision_time: decision_time,
+                    first_non_draft_created_at_transaction_time: entity_id
+                        .draft_id
+                        .is_none()
+                        .then_some(transaction_time),
+                    first_non_draft_created_at_decision_time: entity_id
+                        .draft_id
+                        .is_none()
+                        .then_some(decision_time),
+                },
+                edition: EntityEditionProvenance {
+                    created_by_id: actor_id,
+                    archived_by_id: None,
+                    merged_into: None,
+                    provided: params.provenance,
+                },
+            };
+            entity_id_rows.push(EntityIdRow {
+                web_id: entity_id.owned_by_id,
+                entity_uuid: entity_id.entity_uuid,
+                provenance: entity_provenance.inferred.clone(),
+            });
+            if let Some(draft_id) = entity_id.draft_id {
+                entity_draft_rows.push(EntityDraftRow {
+                    web_id: entity_id.owned_by_id,
+                    entity_uuid: entity_id.entity_uuid,
+                    draft_id,
+                });
+            }
+
+            let entity_edition_id = EntityEditionId::new(Uuid::new_v4());
+            entity_edition_rows.push(EntityEditionRow {
+                entity_edition_id,
+                properties: properties.clone(),
+                archived: false,
+                confidence: params.confidence,
+                provenance: entity_provenance.edition.clone(),
+                property_metadata: property_metadata.clone(),
+            });
+            entity_edition_ids.push(entity_edition_id);
+
+            let temporal_versioning = EntityTemporalMetadata {
+                decision_time: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(decision_time),
+                    OpenTemporalBound::Unbounded,
+                ),
+                transaction_time: LeftClosedTemporalInterval::new(
+                    ClosedTemporalBound::Inclusive(transaction_time),
+                    OpenTemporalBound::Unbounded,
+                ),
+            };
+            entity_temporal_metadata_rows.push(EntityTemporalMetadataRow {
+                web_id: entity_id.owned_by_id,
+                entity_uuid: entity_id.entity_uuid,
+                draft_id: entity_id.draft_id,
+                entity_edition_id,
+                decision_time: temporal_versioning.decision_time,
+                transaction_time: temporal_versioning.transaction_time,
+            });
+
+            for entity_type in &entity_type.all_of {
+                let entity_type_id = EntityTypeUuid::from_url(&entity_type.id);
+                entity_type_ids.insert(entity_type_id, entity_type.id.clone());
+                entity_is_of_type_rows.push(EntityIsOfTypeRow {
+                    entity_edition_id,
+                    entity_type_ontology_id: entity_type_id,
+                    inheritance_depth: InheritanceDepth::new(0),
+                });
+            }
+
+            let link_data = params.link_data.inspect(|link_data| {
+                entity_has_left_entity_rows.push(EntityHasLeftEntityRow {
+                    web_id: entity_id.owned_by_id,
+                    entity_uuid: entity_id.entity_uuid,
+                    left_web_id: link_data.left_entity_id.owned_by_id,
+                    left_entity_uuid: link_data.left_entity_id.entity_uuid,
+                    confidence: link_data.left_entity_confidence,
+                    provenance: link_data.left_entity_provenance.clone(),
+                });
+                entity_has_right_entity_rows.push(EntityHasRightEntityRow {
+                    web_id: entity_id.owned_by_id,
+                    entity_uuid: entity_id.entity_uuid,
+                    right_web_id: link_data.right_entity_id.owned_by_id,
+                    right_entity_uuid: link_data.right_entity_id.entity_uuid,
+                    confidence: link_data.right_entity_confidence,
+                    provenance: link_data.right_entity_provenance.clone(),
+                });
+            });
+
+            entities.push(Entity {
+                properties,
                 link_data,
                 metadata: EntityMetadata {
                     record_id: EntityRecordId {
@@ -1139,6 +1697,7 @@ where
                 let report = validation_reports.entry(index).or_default();
                 report.link = validation_report.link;
                 report.metadata.properties = validation_report.property_metadata;
+                report.unique_properties = validation_report.unique_properties;
             }
         }
 
@@ -1511,6 +2070,15 @@ where
         .await
     }
 
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn find_entity_paths(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: FindEntityPathsParams,
+    ) -> Result<FindEntityPathsResponse, Report<QueryError>> {
+        self.find_entity_paths_impl(actor_id, params).await
+    }
+
     async fn count_entities(
         &self,
         actor_id: ActorEntityUuid,
@@ -1561,6 +2129,97 @@ where
             .count())
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: ActorEntityUuid,
+        mut params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        params
+            .filter
+            .convert_parameters(&StoreProvider {
+                store: self,
+                cache: StoreCache::default(),
+                authorization: Some((actor_id, Consistency::FullyConsistent)),
+            })
+            .await
+            .change_context(QueryError)?;
+
+        let temporal_axes = params.temporal_axes.resolve();
+
+        // Authorization cannot be checked in the database, so the aggregations are additionally
+        // grouped by entity. The partial aggregations are streamed, authorized in batches and
+        // combined per group afterwards.
+        let AggregationQuery {
+            mut compiler,
+            key_indices,
+            aggregations,
+        } = AggregationQuery::new(&temporal_axes, &params)
+            .change_context(QueryError)
+            .attach(StatusCode::InvalidArgument)?;
+        let web_id_idx = compiler.add_grouped_selection_path(&EntityQueryPath::OwnedById);
+        let entity_uuid_idx = compiler.add_grouped_selection_path(&EntityQueryPath::Uuid);
+        let draft_id_idx = compiler.add_grouped_selection_path(&EntityQueryPath::DraftId);
+        compiler
+            .add_filter(&params.filter)
+            .change_context(QueryError)?;
+
+        let (statement, parameters) = compiler.compile();
+        let mut batches = pin!(
+            self.as_client()
+                .query_raw(&statement, parameters.iter().copied())
+                .instrument(tracing::trace_span!("aggregate"))
+                .await
+                .change_context(QueryError)?
+                .try_chunks(AGGREGATION_BATCH_SIZE)
+        );
+
+        let mut groups = AggregationGroups::new(aggregations, !params.group_by.is_empty());
+        while let Some(rows) = batches
+            .try_next()
+            .await
+            .map_err(|error| error.1)
+            .change_context(QueryError)?
+        {
+            let permitted_ids = self
+                .authorization_api
+                .check_entities_permission(
+                    actor_id,
+                    EntityPermission::View,
+                    rows.iter().map(|row| EntityId {
+                        owned_by_id: row.get(web_id_idx),
+                        entity_uuid: row.get(entity_uuid_idx),
+                        draft_id: row.get(draft_id_idx),
+                    }),
+                    Consistency::FullyConsistent,
+                )
+                .instrument(tracing::trace_span!("post_filter_entities"))
+                .await
+                .change_context(QueryError)?
+                .0
+                .into_iter()
+                .filter_map(|(entity_uuid, has_permission)| has_permission.then_some(entity_uuid))
+                .collect::<HashSet<_>>();
+
+            for row in rows {
+                if !permitted_ids.contains(&row.get::<_, EntityUuid>(entity_uuid_idx)) {
+                    continue;
+                }
+                let key = key_indices
+                    .iter()
+                    .map(|&index| {
+                        row.get::<_, Option<serde_json::Value>>(index)
+                            .unwrap_or(serde_json::Value::Null)
+                    })
+                    .collect();
+                groups.accumulate(key, &row);
+            }
+        }
+
+        Ok(AggregateEntitiesResponse {
+            groups: groups.finish(),
+        })
+    }
+
     async fn get_entity_by_id(
         &self,
         actor_id: ActorEntityUuid,
@@ -1598,412 +2257,828 @@ where
         .await
     }
 
-    #[expect(
-        clippy::significant_drop_tightening,
-        reason = "The connection is required to borrow the client"
-    )]
-    #[tracing::instrument(level = "info", skip(self, params))]
-    async fn patch_entity(
-        &mut self,
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn get_entity_history(
+        &self,
         actor_id: ActorEntityUuid,
-        mut params: PatchEntityParams,
-    ) -> Result<Entity, Report<UpdateError>> {
-        let transaction_time = Timestamp::now().remove_nanosecond();
-        let decision_time = params
-            .decision_time
-            .map_or_else(|| transaction_time.cast(), Timestamp::remove_nanosecond);
-        let entity_type_ids = params
-            .entity_type_ids
-            .iter()
-            .map(EntityTypeUuid::from_url)
-            .collect::<Vec<_>>();
-
-        if !self
-            .authorization_api
-            .check_entity_types_permission(
-                actor_id,
-                EntityTypePermission::Instantiate,
-                entity_type_ids,
-                Consistency::FullyConsistent,
-            )
-            .await
-            .change_context(UpdateError)?
-            .0
-            .into_iter()
-            .all(|(_, permission)| permission)
-        {
-            bail!(Report::new(UpdateError).attach(StatusCode::PermissionDenied));
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse<'static>, Report<QueryError>> {
+        struct HistoryEdition {
+            edition_id: EntityEditionId,
+            temporal_versioning: Vec<EntityTemporalMetadata>,
+            provenance: EntityEditionProvenance,
+            archived: bool,
+            properties: PropertyObject,
         }
 
         self.authorization_api
             .check_entity_permission(
                 actor_id,
-                EntityPermission::Update,
+                EntityPermission::View,
                 params.entity_id,
                 Consistency::FullyConsistent,
             )
             .await
-            .change_context(UpdateError)?
+            .change_context(QueryError)?
             .assert_permission()
-            .change_context(UpdateError)?;
-
-        let transaction = self.transaction().await.change_context(UpdateError)?;
-
-        let locked_row = transaction
-            .lock_entity_edition(params.entity_id, transaction_time, decision_time)
-            .await?
-            .ok_or_else(|| {
-                Report::new(EntityDoesNotExist)
-                    .attach(StatusCode::NotFound)
-                    .attach_printable(params.entity_id)
-                    .change_context(UpdateError)
-            })?;
-        let ClosedTemporalBound::Inclusive(locked_transaction_time) =
-            *locked_row.transaction_time.start();
-        let ClosedTemporalBound::Inclusive(locked_decision_time) =
-            *locked_row.decision_time.start();
-        let previous_entity = Read::<Entity>::read_one(
-            &transaction,
-            &Filter::Equal(
-                Some(FilterExpression::Path {
-                    path: EntityQueryPath::EditionId,
-                }),
-                Some(FilterExpression::Parameter {
-                    parameter: Parameter::Uuid(locked_row.entity_edition_id.into_uuid()),
-                    convert: None,
-                }),
-            ),
-            Some(&QueryTemporalAxes::DecisionTime {
-                pinned: PinnedTemporalAxis::new(locked_transaction_time),
-                variable: VariableTemporalAxis::new(
-                    TemporalBound::Inclusive(locked_decision_time),
-                    LimitedTemporalBound::Inclusive(locked_decision_time),
-                ),
-            }),
-            true,
-        )
-        .await
-        .change_context(EntityDoesNotExist)
-        .attach(params.entity_id)
-        .change_context(UpdateError)?;
-
-        let mut first_non_draft_created_at_decision_time = previous_entity
-            .metadata
-            .provenance
-            .inferred
-            .first_non_draft_created_at_decision_time;
-        let mut first_non_draft_created_at_transaction_time = previous_entity
-            .metadata
-            .provenance
-            .inferred
-            .first_non_draft_created_at_transaction_time;
-
-        let was_draft_before = previous_entity
-            .metadata
-            .record_id
-            .entity_id
-            .draft_id
-            .is_some();
-        let draft = params.draft.unwrap_or(was_draft_before);
-        let archived = params.archived.unwrap_or(previous_entity.metadata.archived);
-        let (entity_type_ids, entity_types_updated) = if params.entity_type_ids.is_empty() {
-            (previous_entity.metadata.entity_type_ids, false)
-        } else {
-            let added_types = previous_entity
-                .metadata
-                .entity_type_ids
-                .difference(&params.entity_type_ids);
-            let removed_types = params
-                .entity_type_ids
-                .difference(&previous_entity.metadata.entity_type_ids);
+            .change_context(QueryError)?;
 
-            let mut has_changed = false;
-            for entity_type_id in added_types.chain(removed_types) {
-                has_changed = true;
+        // The update trigger splits the temporal metadata of an edition into multiple rows, so the
+        // rows are grouped by their edition in the order the editions were first recorded.
+        let rows = self
+            .as_client()
+            .query(
+                "
+                    SELECT
+                        entity_temporal_metadata.entity_edition_id,
+                        entity_temporal_metadata.decision_time,
+                        entity_temporal_metadata.transaction_time,
+                        entity_editions.provenance,
+                        entity_editions.archived,
+                        entity_editions.properties
+                    FROM entity_temporal_metadata
+                    JOIN entity_editions
+                      ON entity_editions.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                    WHERE entity_temporal_metadata.web_id = $1
+                      AND entity_temporal_metadata.entity_uuid = $2
+                      AND entity_temporal_metadata.draft_id IS NOT DISTINCT FROM $3
+                    ORDER BY lower(entity_temporal_metadata.transaction_time),
+                             lower(entity_temporal_metadata.decision_time);
+                ",
+                &[
+                    &params.entity_id.owned_by_id,
+                    &params.entity_id.entity_uuid,
+                    &params.entity_id.draft_id,
+                ],
+            )
+            .await
+            .change_context(QueryError)?;
 
-                let entity_type_id = EntityTypeUuid::from_url(entity_type_id);
-                transaction
-                    .authorization_api
-                    .check_entity_type_permission(
-                        actor_id,
-                        EntityTypePermission::Instantiate,
-                        entity_type_id,
-                        Consistency::FullyConsistent,
-                    )
-                    .await
-                    .change_context(UpdateError)?
-                    .assert_permission()
-                    .change_context(UpdateError)
-                    .attach(StatusCode::PermissionDenied)?;
+        if rows.is_empty() {
+            return Err(Report::new(EntityDoesNotExist)
+                .attach(StatusCode::NotFound)
+                .attach_printable(params.entity_id)
+                .change_context(QueryError));
+        }
+
+        let mut editions = Vec::<HistoryEdition>::new();
+        let mut edition_indices = HashMap::new();
+        for row in rows {
+            let edition_id: EntityEditionId = row.get(0);
+            let temporal_versioning = EntityTemporalMetadata {
+                decision_time: row.get(1),
+                transaction_time: row.get(2),
+            };
+            if let Some(&index) = edition_indices.get(&edition_id) {
+                editions[index]
+                    .temporal_versioning
+                    .push(temporal_versioning);
+            } else {
+                edition_indices.insert(edition_id, editions.len());
+                editions.push(HistoryEdition {
+                    edition_id,
+                    temporal_versioning: vec![temporal_versioning],
+                    provenance: row.get(3),
+                    archived: row.get(4),
+                    properties: row.get(5),
+                });
             }
+        }
 
-            (params.entity_type_ids, has_changed)
-        };
+        let empty_properties = PropertyObject::empty();
+        let mut previous_properties = &empty_properties;
+        let mut history = Vec::with_capacity(editions.len());
+        for edition in &editions {
+            let properties = previous_properties
+                .diff(&edition.properties, &mut PropertyPath::default())
+                .map(PropertyDiff::into_owned)
+                .collect();
+            previous_properties = &edition.properties;
 
-        let previous_properties = previous_entity.properties.clone();
-        let previous_property_metadata = previous_entity.metadata.properties.clone();
+            // The diff is always calculated against the previous edition, even if the previous
+            // edition is outside of the requested interval.
+            if params.interval.as_ref().is_some_and(|interval| {
+                !edition
+                    .temporal_versioning
+                    .iter()
+                    .any(|temporal_versioning| {
+                        temporal_versioning.transaction_time.overlaps(interval)
+                    })
+            }) {
+                continue;
+            }
 
-        let mut properties_with_metadata = PropertyWithMetadata::from_parts(
-            Property::Object(previous_entity.properties),
-            Some(PropertyMetadata::Object(PropertyObjectMetadata {
-                value: previous_entity.metadata.properties.value,
-                metadata: previous_entity.metadata.properties.metadata,
-            })),
-        )
-        .change_context(UpdateError)?;
-        properties_with_metadata
-            .patch(params.properties)
-            .change_context(UpdateError)?;
+            history.push(EntityHistoryEdition {
+                edition_id: edition.edition_id,
+                temporal_versioning: edition.temporal_versioning.clone(),
+                provenance: edition.provenance.clone(),
+                archived: edition.archived,
+                properties,
+            });
+        }
 
-        let validator_provider = StoreProvider {
-            store: &transaction,
-            cache: StoreCache::default(),
-            authorization: Some((actor_id, Consistency::FullyConsistent)),
-        };
-        let entity_type = ClosedMultiEntityType::from_multi_type_closed_schema(
-            stream::iter(&entity_type_ids)
-                .then(|entity_type_url| async {
-                    OntologyTypeProvider::<ClosedEntityType>::provide_type(
-                        &validator_provider,
-                        entity_type_url,
+        Ok(GetEntityHistoryResponse { editions: history })
+    }
+
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn erase_entity(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: EraseEntityParams,
+    ) -> Result<Vec<EntityTombstone>, Report<StoreDeletionError>> {
+        let transaction_time = Timestamp::<TransactionTime>::now().remove_nanosecond();
+        let transaction = self
+            .transaction()
+            .await
+            .change_context(StoreDeletionError)?;
+
+        // Link entities cannot exist without their endpoints, so they are erased together with the
+        // entity. As links may connect other links, this includes all links which transitively
+        // depend on the entity.
+        let (web_ids, entity_uuids): (Vec<OwnedById>, Vec<EntityUuid>) = transaction
+            .as_client()
+            .query(
+                "
+                    WITH RECURSIVE erased (web_id, entity_uuid) AS (
+                        SELECT web_id, entity_uuid
+                        FROM entity_ids
+                        WHERE web_id = $1 AND entity_uuid = $2
+                        UNION
+                        SELECT links.web_id, links.entity_uuid
+                        FROM erased
+                        JOIN (
+                            SELECT web_id, entity_uuid, left_web_id, left_entity_uuid
+                            FROM entity_has_left_entity
+                            UNION ALL
+                            SELECT web_id, entity_uuid, right_web_id, right_entity_uuid
+                            FROM entity_has_right_entity
+                        ) AS links (web_id, entity_uuid, endpoint_web_id, endpoint_entity_uuid)
+                          ON links.endpoint_web_id = erased.web_id
+                         AND links.endpoint_entity_uuid = erased.entity_uuid
                     )
-                    .await
-                    .map(|entity_type| (*entity_type).clone())
-                })
-                .try_collect::<Vec<ClosedEntityType>>()
+                    SELECT web_id, entity_uuid FROM erased;
+                ",
+                &[&params.entity_id.owned_by_id, &params.entity_id.entity_uuid],
+            )
+            .await
+            .change_context(StoreDeletionError)?
+            .into_iter()
+            .map(|row| (row.get::<_, OwnedById>(0), row.get::<_, EntityUuid>(1)))
+            .unzip();
+
+        if entity_uuids.is_empty() {
+            return Err(Report::new(EntityDoesNotExist)
+                .attach(StatusCode::NotFound)
+                .attach_printable(params.entity_id)
+                .change_context(StoreDeletionError));
+        }
+
+        let mut entity_type_ids = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT entity_temporal_metadata.entity_uuid,
+                           array_agg(DISTINCT ontology_ids.base_url || 'v/' || \
+                 ontology_ids.version)
+                      FROM entity_temporal_metadata
+                      JOIN entity_is_of_type
+                        ON entity_is_of_type.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                      JOIN ontology_ids
+                        ON ontology_ids.ontology_id = entity_is_of_type.entity_type_ontology_id
+                     WHERE (entity_temporal_metadata.web_id, entity_temporal_metadata.entity_uuid)
+                        IN (SELECT * FROM unnest($1::uuid[], $2::uuid[]))
+                     GROUP BY entity_temporal_metadata.entity_uuid;
+                ",
+                &[&web_ids, &entity_uuids],
+            )
+            .await
+            .change_context(StoreDeletionError)?
+            .into_iter()
+            .map(|row| {
+                (
+                    row.get::<_, EntityUuid>(0),
+                    row.get::<_, Vec<VersionedUrl>>(1),
+                )
+            })
+            .collect::<HashMap<_, _>>();
+
+        for statement in [
+            "DELETE FROM entity_embeddings
+             WHERE (web_id, entity_uuid) IN (SELECT * FROM unnest($1::uuid[], $2::uuid[]));",
+            "DELETE FROM entity_has_left_entity
+             WHERE (web_id, entity_uuid) IN (SELECT * FROM unnest($1::uuid[], $2::uuid[]));",
+            "DELETE FROM entity_has_right_entity
+             WHERE (web_id, entity_uuid) IN (SELECT * FROM unnest($1::uuid[], $2::uuid[]));",
+        ] {
+            transaction
+                .as_client()
+                .execute(statement, &[&web_ids, &entity_uuids])
                 .await
-                .change_context(UpdateError)?,
-        )
-        .change_context(UpdateError)?;
+                .change_context(StoreDeletionError)?;
+        }
 
-        let mut validation_components = if draft {
-            ValidateEntityComponents::draft()
-        } else {
-            ValidateEntityComponents::full()
-        };
-        validation_components.link_validation = transaction.settings.validate_links;
+        let edition_ids = transaction
+            .as_client()
+            .query(
+                "
+                    DELETE FROM entity_temporal_metadata
+                    WHERE (web_id, entity_uuid) IN (
+                        SELECT * FROM unnest($1::uuid[], $2::uuid[])
+                    )
+                    RETURNING entity_edition_id;
+                ",
+                &[&web_ids, &entity_uuids],
+            )
+            .await
+            .change_context(StoreDeletionError)?
+            .into_iter()
+            .map(|row| row.get::<_, EntityEditionId>(0))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
 
-        let mut validation_report = EntityValidationReport::default();
-        let (properties, property_metadata) =
-            if let PropertyWithMetadata::Object(mut object) = properties_with_metadata {
-                let mut preprocessor = EntityPreprocessor {
-                    components: validation_components,
-                };
-                if let Err(property_validation) = preprocessor
-                    .visit_object(&entity_type, &mut object, &validator_provider)
-                    .await
-                {
-                    validation_report.properties = property_validation.properties;
-                }
+        for statement in [
+            "DELETE FROM entity_is_of_type WHERE entity_edition_id = ANY($1);",
+            "DELETE FROM entity_editions WHERE entity_edition_id = ANY($1);",
+        ] {
+            transaction
+                .as_client()
+                .execute(statement, &[&edition_ids])
+                .await
+                .change_context(StoreDeletionError)?;
+        }
 
-                let (properties, property_metadata) = object.into_parts();
-                (properties, property_metadata)
-            } else {
-                unreachable!("patching should not change the property type");
-            };
-        // We move out the cache, so we can re-use `&mut self` later.
-        let store_cache = validator_provider.cache;
+        for statement in [
+            "DELETE FROM entity_drafts
+             WHERE (web_id, entity_uuid) IN (SELECT * FROM unnest($1::uuid[], $2::uuid[]));",
+            "DELETE FROM entity_ids
+             WHERE (web_id, entity_uuid) IN (SELECT * FROM unnest($1::uuid[], $2::uuid[]));",
+        ] {
+            transaction
+                .as_client()
+                .execute(statement, &[&web_ids, &entity_uuids])
+                .await
+                .change_context(StoreDeletionError)?;
+        }
 
-        #[expect(clippy::needless_collect, reason = "Will be used later")]
-        let diff = previous_properties
-            .diff(&properties, &mut PropertyPath::default())
+        let tombstones = web_ids
+            .into_iter()
+            .zip(entity_uuids)
+            .map(|(owned_by_id, entity_uuid)| EntityTombstone {
+                owned_by_id,
+                entity_uuid,
+                erased_by_id: actor_id,
+                erased_at_transaction_time: transaction_time,
+                reason: params.reason.clone(),
+                entity_type_ids: entity_type_ids.remove(&entity_uuid).unwrap_or_default(),
+            })
             .collect::<Vec<_>>();
+        transaction
+            .as_client()
+            .query(
+                "
+                    INSERT INTO entity_tombstones
+                    SELECT * FROM UNNEST($1::entity_tombstones[]);
+                ",
+                &[&tombstones
+                    .iter()
+                    .map(|tombstone| EntityTombstoneRow {
+                        web_id: tombstone.owned_by_id,
+                        entity_uuid: tombstone.entity_uuid,
+                        erased_by_id: tombstone.erased_by_id,
+                        erased_at_transaction_time: tombstone.erased_at_transaction_time,
+                        reason: tombstone.reason.clone(),
+                        entity_type_ids: tombstone.entity_type_ids.clone(),
+                    })
+                    .collect::<Vec<_>>()],
+            )
+            .await
+            .change_context(StoreDeletionError)?;
 
-        if diff.is_empty()
-            && was_draft_before == draft
-            && archived == previous_entity.metadata.archived
-            && !entity_types_updated
-            && previous_property_metadata == property_metadata
-            && params.confidence == previous_entity.metadata.confidence
-        {
-            // No changes were made to the entity.
-            return Ok(Entity {
-                properties: previous_properties,
-                link_data: previous_entity.link_data,
-                metadata: EntityMetadata {
-                    record_id: previous_entity.metadata.record_id,
-                    temporal_versioning: previous_entity.metadata.temporal_versioning,
-                    entity_type_ids,
-                    provenance: previous_entity.metadata.provenance,
-                    archived,
-                    confidence: previous_entity.metadata.confidence,
-                    properties: property_metadata,
+        let mut relationships = Vec::new();
+        for tombstone in &tombstones {
+            let entity_id = EntityId {
+                owned_by_id: tombstone.owned_by_id,
+                entity_uuid: tombstone.entity_uuid,
+                draft_id: None,
+            };
+            relationships.extend(
+                transaction
+                    .authorization_api
+                    .get_entity_relations(entity_id, Consistency::FullyConsistent)
+                    .await
+                    .change_context(StoreDeletionError)?
+                    .into_iter()
+                    .map(|relation_and_subject| (entity_id, relation_and_subject)),
+            );
+        }
+
+        transaction
+            .authorization_api
+            .modify_entity_relations(relationships.iter().copied().map(
+                |(entity_id, relation_and_subject)| {
+                    (
+                        ModifyRelationshipOperation::Delete,
+                        entity_id,
+                        relation_and_subject,
+                    )
                 },
-            });
+            ))
+            .await
+            .change_context(StoreDeletionError)?;
+
+        if let Err(error) = transaction
+            .commit()
+            .await
+            .change_context(StoreDeletionError)
+        {
+            let mut error = error.expand();
+
+            if let Err(auth_error) = self
+                .authorization_api
+                .modify_entity_relations(relationships.into_iter().map(
+                    |(entity_id, relation_and_subject)| {
+                        (
+                            ModifyRelationshipOperation::Create,
+                            entity_id,
+                            relation_and_subject,
+                        )
+                    },
+                ))
+                .await
+                .change_context(StoreDeletionError)
+            {
+                error.push(auth_error);
+            }
+
+            Err(error.change_context(StoreDeletionError))
+        } else {
+            Ok(tombstones)
         }
+    }
 
-        let link_data = previous_entity.link_data;
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn merge_entities(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: MergeEntitiesParams,
+    ) -> Result<MergeEntitiesResponse, Report<UpdateError>> {
+        let target_entity_id = params.target_entity_id;
+        let source_entity_id = params.source_entity_id;
+        ensure!(
+            (target_entity_id.owned_by_id, target_entity_id.entity_uuid)
+                != (source_entity_id.owned_by_id, source_entity_id.entity_uuid),
+            Report::new(UpdateError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable("An entity cannot be merged into itself")
+        );
 
-        let edition_provenance = EntityEditionProvenance {
-            created_by_id: actor_id,
-            archived_by_id: None,
-            provided: params.provenance,
-        };
-        let edition_id = transaction
-            .insert_entity_edition(
-                archived,
-                &entity_type_ids,
-                &properties,
-                params.confidence,
-                &edition_provenance,
-                &property_metadata,
+        let mut transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let target = transaction
+            .get_entity_by_id(actor_id, target_entity_id, None, None)
+            .await
+            .change_context(UpdateError)?;
+        let source = transaction
+            .get_entity_by_id(actor_id, source_entity_id, None, None)
+            .await
+            .change_context(UpdateError)?;
+
+        let properties = params.merge_properties(
+            PropertyObjectWithMetadata::from_parts(
+                target.properties,
+                Some(target.metadata.properties),
+            )
+            .change_context(UpdateError)?,
+            PropertyObjectWithMetadata::from_parts(
+                source.properties,
+                Some(source.metadata.properties),
+            )
+            .change_context(UpdateError)?,
+        );
+
+        let entity = transaction
+            .patch_entity(
+                actor_id,
+                PatchEntityParams {
+                    entity_id: target_entity_id,
+                    decision_time: None,
+                    expected_edition_id: Some(target.metadata.record_id.edition_id),
+                    entity_type_ids: HashSet::new(),
+                    properties: vec![PropertyPatchOperation::Replace {
+                        path: PropertyPath::default(),
+                        property: PropertyWithMetadata::Object(properties),
+                    }],
+                    draft: None,
+                    archived: None,
+                    confidence: target.metadata.confidence,
+                    provenance: params.provenance.clone(),
+                },
+            )
+            .await?;
+
+        // The endpoints of a link are not versioned, so each link of the source is archived and
+        // replaced by a new link to the target. The drafts of a link are archived as well and added
+        // as drafts to the new link.
+        let link_versions = transaction
+            .as_client()
+            .query(
+                "
+                    SELECT DISTINCT
+                        links.web_id,
+                        links.entity_uuid,
+                        entity_temporal_metadata.draft_id
+                    FROM (
+                        SELECT web_id, entity_uuid
+                        FROM entity_has_left_entity
+                        WHERE left_web_id = $1 AND left_entity_uuid = $2
+                        UNION
+                        SELECT web_id, entity_uuid
+                        FROM entity_has_right_entity
+                        WHERE right_web_id = $1 AND right_entity_uuid = $2
+                    ) AS links
+                    JOIN entity_temporal_metadata
+                      ON entity_temporal_metadata.web_id = links.web_id
+                     AND entity_temporal_metadata.entity_uuid = links.entity_uuid
+                    WHERE upper_inf(entity_temporal_metadata.transaction_time)
+                    ORDER BY links.web_id, links.entity_uuid, entity_temporal_metadata.draft_id
+                        NULLS FIRST;
+                ",
+                &[&source_entity_id.owned_by_id, &source_entity_id.entity_uuid],
             )
             .await
-            .change_context(UpdateError)?;
+            .change_context(UpdateError)?
+            .into_iter()
+            .map(|row| EntityId {
+                owned_by_id: row.get(0),
+                entity_uuid: row.get(1),
+                draft_id: row.get(2),
+            })
+            .collect::<Vec<_>>();
 
-        let temporal_versioning = match (was_draft_before, draft) {
-            (true, true) | (false, false) => {
-                // regular update
-                transaction
-                    .update_temporal_metadata(
-                        locked_row,
-                        transaction_time,
-                        decision_time,
-                        edition_id,
-                        false,
-                    )
-                    .await?
+        let is_entity = |entity_id: EntityId, other: EntityId| {
+            (entity_id.owned_by_id, entity_id.entity_uuid) == (other.owned_by_id, other.entity_uuid)
+        };
+        let replace_endpoint = |entity_id: EntityId| {
+            if is_entity(entity_id, source_entity_id) {
+                target_entity_id
+            } else {
+                entity_id
             }
-            (false, true) => {
-                let draft_id = DraftId::new(Uuid::new_v4());
-                transaction
-                    .as_client()
-                    .query(
-                        "
-                        INSERT INTO entity_drafts (
-                            web_id,
-                            entity_uuid,
-                            draft_id
-                        ) VALUES ($1, $2, $3);",
-                        &[
-                            &params.entity_id.owned_by_id,
-                            &params.entity_id.entity_uuid,
-                            &draft_id,
-                        ],
-                    )
+        };
+
+        let mut links = Vec::new();
+        for versions in link_versions.chunk_by(|lhs, rhs| is_entity(*lhs, *rhs)) {
+            let mut replaced_versions = Vec::with_capacity(versions.len());
+            for &link_id in versions {
+                let link = transaction
+                    .get_entity_by_id(actor_id, link_id, None, None)
                     .await
                     .change_context(UpdateError)?;
-                params.entity_id.draft_id = Some(draft_id);
+                if link.link_data.is_none() || link.metadata.archived {
+                    continue;
+                }
+
+                // Archiving the link requires the actor to be allowed to update it.
                 transaction
-                    .insert_temporal_metadata(
-                        params.entity_id,
-                        edition_id,
-                        transaction_time,
-                        decision_time,
+                    .patch_entity(
+                        actor_id,
+                        PatchEntityParams {
+                            entity_id: link_id,
+                            decision_time: None,
+                            expected_edition_id: Some(link.metadata.record_id.edition_id),
+                            entity_type_ids: HashSet::new(),
+                            properties: Vec::new(),
+                            draft: None,
+                            archived: Some(true),
+                            confidence: link.metadata.confidence,
+                            provenance: params.provenance.clone(),
+                        },
                     )
-                    .await
-                    .change_context(UpdateError)?
+                    .await?;
+                replaced_versions.push(link);
             }
-            (true, false) => {
-                // Publish a draft
-                params.entity_id.draft_id = None;
 
-                if first_non_draft_created_at_decision_time.is_none() {
+            let mut replaced_versions = replaced_versions.into_iter();
+            let Some(link) = replaced_versions.next() else {
+                continue;
+            };
+            let Some(link_data) = link.link_data else {
+                continue;
+            };
+
+            // A link between the source and the target would link the target to itself after the
+            // merge, so it is not replaced.
+            let (left_entity_id, right_entity_id) =
+                (link_data.left_entity_id, link_data.right_entity_id);
+            if (is_entity(left_entity_id, source_entity_id)
+                && is_entity(right_entity_id, target_entity_id))
+                || (is_entity(left_entity_id, target_entity_id)
+                    && is_entity(right_entity_id, source_entity_id))
+            {
+                continue;
+            }
+
+            let relationships = transaction
+                .authorization_api
+                .get_entity_relations(
+                    link.metadata.record_id.entity_id,
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .change_context(UpdateError)?;
+            let replacement = transaction
+                .create_entity(
+                    actor_id,
+                    CreateEntityParams {
+                        owned_by_id: link.metadata.record_id.entity_id.owned_by_id,
+                        entity_uuid: None,
+                        decision_time: None,
+                        entity_type_ids: link.metadata.entity_type_ids,
+                        properties: PropertyObjectWithMetadata::from_parts(
+                            link.properties,
+                            Some(link.metadata.properties),
+                        )
+                        .change_context(UpdateError)?,
+                        confidence: link.metadata.confidence,
+                        link_data: Some(LinkData {
+                            left_entity_id: replace_endpoint(left_entity_id),
+                            right_entity_id: replace_endpoint(right_entity_id),
+                            ..link_data
+                        }),
+                        draft: link.metadata.record_id.entity_id.draft_id.is_some(),
+                        relationships,
+                        provenance: params.provenance.clone(),
+                    },
+                )
+                .await
+                .change_context(UpdateError)?;
+            let replacement_id = replacement.metadata.record_id.entity_id;
+            links.push(replacement);
+
+            // The first version is the published link if there is one, the remaining versions are
+            // its drafts.
+            for draft in replaced_versions {
+                links.push(
                     transaction
-                        .as_client()
-                        .query(
-                            "
-                            UPDATE entity_ids
-                            SET provenance = provenance || JSONB_BUILD_OBJECT(
-                                'firstNonDraftCreatedAtTransactionTime', $1::TIMESTAMPTZ,
-                                'firstNonDraftCreatedAtDecisionTime', $2::TIMESTAMPTZ
-                            )
-                            WHERE web_id = $3
-                              AND entity_uuid = $4;
-                            ",
-                            &[
-                                &transaction_time,
-                                &decision_time,
-                                &params.entity_id.owned_by_id,
-                                &params.entity_id.entity_uuid,
-                            ],
+                        .patch_entity(
+                            actor_id,
+                            PatchEntityParams {
+                                entity_id: replacement_id,
+                                decision_time: None,
+                                expected_edition_id: None,
+                                entity_type_ids: draft.metadata.entity_type_ids,
+                                properties: vec![PropertyPatchOperation::Replace {
+                                    path: PropertyPath::default(),
+                                    property: PropertyWithMetadata::Object(
+                                        PropertyObjectWithMetadata::from_parts(
+                                            draft.properties,
+                                            Some(draft.metadata.properties),
+                                        )
+                                        .change_context(UpdateError)?,
+                                    ),
+                                }],
+                                draft: Some(true),
+                                archived: None,
+                                confidence: draft.metadata.confidence,
+                                provenance: params.provenance.clone(),
+                            },
                         )
+                        .await?,
+                );
+            }
+        }
+
+        let merged_entity = transaction
+            .patch_entity_impl(
+                actor_id,
+                PatchEntityParams {
+                    entity_id: source_entity_id,
+                    decision_time: None,
+                    expected_edition_id: Some(source.metadata.record_id.edition_id),
+                    entity_type_ids: HashSet::new(),
+                    properties: Vec::new(),
+                    draft: None,
+                    archived: Some(true),
+                    confidence: source.metadata.confidence,
+                    provenance: params.provenance,
+                },
+                Some(target_entity_id),
+            )
+            .await?;
+
+        transaction.commit().await.change_context(UpdateError)?;
+
+        Ok(MergeEntitiesResponse {
+            entity,
+            merged_entity,
+            links,
+        })
+    }
+
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn upgrade_entity_type(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: UpgradeEntityTypeParams,
+    ) -> Result<UpgradeEntityTypeResponse, Report<UpdateError>> {
+        const DEFAULT_BATCH_SIZE: usize = 100;
+
+        let batch_size = params.batch_size.unwrap_or(DEFAULT_BATCH_SIZE);
+        ensure!(
+            batch_size > 0,
+            Report::new(UpdateError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable("The batch size must be greater than zero")
+        );
+
+        let entity_ids = self
+            .as_client()
+            .query(
+                "
+                    SELECT DISTINCT
+                        entity_temporal_metadata.web_id,
+                        entity_temporal_metadata.entity_uuid,
+                        entity_temporal_metadata.draft_id
+                    FROM entity_temporal_metadata
+                    JOIN entity_is_of_type
+                      ON entity_is_of_type.entity_edition_id
+                       = entity_temporal_metadata.entity_edition_id
+                    WHERE entity_is_of_type.entity_type_ontology_id = $1
+                      AND entity_temporal_metadata.transaction_time @> now()
+                      AND entity_temporal_metadata.decision_time @> now();
+                ",
+                &[&EntityTypeUuid::from_url(&params.source_entity_type_id)],
+            )
+            .await
+            .change_context(UpdateError)?
+            .into_iter()
+            .map(|row| EntityId {
+                owned_by_id: row.get(0),
+                entity_uuid: row.get(1),
+                draft_id: row.get(2),
+            })
+            .collect::<Vec<_>>();
+
+        let mut upgraded = Vec::new();
+        let mut failures = Vec::new();
+        for batch in entity_ids.chunks(batch_size) {
+            // Entities the actor is not allowed to update are skipped, so the response does not
+            // reveal entities of other webs.
+            let permitted_ids = self
+                .authorization_api
+                .check_entities_permission(
+                    actor_id,
+                    EntityPermission::Update,
+                    batch.iter().copied(),
+                    Consistency::FullyConsistent,
+                )
+                .await
+                .change_context(UpdateError)?
+                .0
+                .into_iter()
+                .filter_map(|(entity_uuid, has_permission)| has_permission.then_some(entity_uuid))
+                .collect::<HashSet<_>>();
+
+            let mut transaction = self.transaction().await.change_context(UpdateError)?;
+
+            for &entity_id in batch
+                .iter()
+                .filter(|entity_id| permitted_ids.contains(&entity_id.entity_uuid))
+            {
+                let result = async {
+                    let entity = transaction
+                        .get_entity_by_id(actor_id, entity_id, None, None)
                         .await
                         .change_context(UpdateError)?;
 
-                    first_non_draft_created_at_transaction_time = Some(transaction_time);
-                    first_non_draft_created_at_decision_time = Some(decision_time);
-                }
+                    let mut properties = PropertyObjectWithMetadata::from_parts(
+                        entity.properties,
+                        Some(entity.metadata.properties),
+                    )
+                    .change_context(UpdateError)?;
+                    for operation in &params.mapping {
+                        operation.apply(&mut properties);
+                    }
 
-                if let Some(previous_live_entity) = transaction
-                    .lock_entity_edition(params.entity_id, transaction_time, decision_time)
-                    .await?
-                {
+                    let mut entity_type_ids = entity.metadata.entity_type_ids;
+                    entity_type_ids.remove(&params.source_entity_type_id);
+                    entity_type_ids.insert(params.target_entity_type_id.clone());
+
+                    // Each entity is patched in its own savepoint, so a failed entity does not
+                    // affect the other entities of the batch.
                     transaction
-                        .archive_entity(
+                        .patch_entity(
                             actor_id,
-                            previous_live_entity,
-                            transaction_time,
-                            decision_time,
+                            PatchEntityParams {
+                                entity_id,
+                                decision_time: None,
+                                expected_edition_id: Some(entity.metadata.record_id.edition_id),
+                                entity_type_ids,
+                                properties: vec![PropertyPatchOperation::Replace {
+                                    path: PropertyPath::default(),
+                                    property: PropertyWithMetadata::Object(properties),
+                                }],
+                                draft: None,
+                                archived: None,
+                                confidence: entity.metadata.confidence,
+                                provenance: params.provenance.clone(),
+                            },
                         )
-                        .await?;
+                        .await
                 }
-                transaction
-                    .update_temporal_metadata(
-                        locked_row,
-                        transaction_time,
-                        decision_time,
-                        edition_id,
-                        true,
-                    )
-                    .await?
-            }
-        };
+                .await;
 
-        let entity_metadata = EntityMetadata {
-            record_id: EntityRecordId {
-                entity_id: params.entity_id,
-                edition_id,
-            },
-            temporal_versioning,
-            entity_type_ids,
-            provenance: EntityProvenance {
-                inferred: InferredEntityProvenance {
-                    first_non_draft_created_at_transaction_time,
-                    first_non_draft_created_at_decision_time,
-                    ..previous_entity.metadata.provenance.inferred
-                },
-                edition: edition_provenance,
-            },
-            confidence: params.confidence,
-            properties: property_metadata,
-            archived,
-        };
-        let entities = [Entity {
-            properties,
-            link_data,
-            metadata: entity_metadata.clone(),
-        }];
+                match result {
+                    Ok(_) => upgraded.push(entity_id),
+                    Err(mut report) => {
+                        tracing::warn!("Could not upgrade entity `{entity_id}`: {report:?}");
+
+                        let validation = report
+                            .downcast_mut::<HashMap<EntityId, EntityValidationReport>>()
+                            .and_then(|reports| reports.remove(&entity_id));
+                        // The report may contain details of other entities, so only a summary is
+                        // returned.
+                        let message = if validation.is_some() {
+                            "The upgraded entity is not valid"
+                        } else if report.contains::<EntityEditionMismatch>() {
+                            "The entity was updated while it was upgraded"
+                        } else {
+                            "The entity could not be upgraded"
+                        };
+                        failures.push(EntityUpgradeFailure {
+                            entity_id,
+                            validation,
+                            message: message.to_owned(),
+                        });
+                    }
+                }
+            }
 
-        let validator_provider = StoreProvider {
-            store: &transaction,
-            cache: store_cache,
-            authorization: Some((actor_id, Consistency::FullyConsistent)),
-        };
-        let post_validation_report = entities[0]
-            .validate(&entity_type, validation_components, &validator_provider)
-            .await;
-        validation_report.link = post_validation_report.link;
-        validation_report.metadata.properties = post_validation_report.property_metadata;
+            transaction.commit().await.change_context(UpdateError)?;
+        }
 
-        ensure!(
-            validation_report.is_valid(),
-            Report::new(UpdateError).attach(HashMap::from([(
-                entities[0].metadata.record_id.entity_id,
-                validation_report
-            )]))
-        );
+        Ok(UpgradeEntityTypeResponse { upgraded, failures })
+    }
 
-        transaction.commit().await.change_context(UpdateError)?;
+    #[tracing::instrument(level = "info", skip(self, params))]
+    async fn patch_entity(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: PatchEntityParams,
+    ) -> Result<Entity, Report<UpdateError>> {
+        self.patch_entity_impl(actor_id, params, None).await
+    }
 
-        if let Some(temporal_client) = &self.temporal_client {
-            temporal_client
-                .start_update_entity_embeddings_workflow(actor_id, &entities)
+    #[tracing::instrument(level = "info", skip(self, operations))]
+    async fn execute_entity_batch(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        operations: Vec<EntityBatchOperation>,
+    ) -> Result<Vec<EntityBatchOperationResult>, Report<UpdateError>> {
+        let mut transaction = self.transaction().await.change_context(UpdateError)?;
+
+        let mut created = Vec::with_capacity(operations.len());
+        let mut results = Vec::with_capacity(operations.len());
+        let mut relationships = Vec::new();
+        for (index, operation) in operations.into_iter().enumerate() {
+            match transaction
+                .execute_entity_batch_operation(actor_id, operation, &created, &mut relationships)
                 .await
-                .change_context(UpdateError)?;
+            {
+                Ok(result) => {
+                    created.push(result.created_entity_id());
+                    results.push(result);
+                }
+                Err(error) => {
+                    drop(transaction);
+                    let error = error.attach_printable(format!("batch operation {index} failed"));
+                    return Err(self.revert_entity_batch(&created, &[], error).await);
+                }
+            }
         }
-        let [entity] = entities;
-        Ok(entity)
+
+        // Relationships are not part of the database transaction, so they are only modified after
+        // all other operations succeeded.
+        if let Err(error) = transaction
+            .authorization_api
+            .modify_entity_relations(relationships.iter().copied())
+            .await
+            .change_context(UpdateError)
+        {
+            drop(transaction);
+            return Err(self.revert_entity_batch(&created, &[], error).await);
+        }
+
+        if let Err(error) = transaction.commit().await.change_context(UpdateError) {
+            return Err(self
+                .revert_entity_batch(&created, &relationships, error)
+                .await);
+        }
+
+        Ok(results)
     }
 
     #[tracing::instrument(level = "info", skip(self, params))]
@@ -2240,12 +3315,18 @@ where
         Ok(edition_id)
     }
 
+    /// Locks the edition of the entity which is live at the specified times.
+    ///
+    /// If an `expected_edition_id` is provided and the locked edition differs from it, an
+    /// [`EntityEditionMismatch`] is returned. As the edition is locked until the transaction ends,
+    /// the edition cannot change between the check and the update.
     #[tracing::instrument(level = "trace", skip(self))]
     async fn lock_entity_edition(
         &self,
         entity_id: EntityId,
         transaction_time: Timestamp<TransactionTime>,
         decision_time: Timestamp<DecisionTime>,
+        expected_edition_id: Option<EntityEditionId>,
     ) -> Result<Option<LockedEntityEdition>, Report<UpdateError>> {
         let current_data = if let Some(draft_id) = entity_id.draft_id {
             self.as_client()
@@ -2296,7 +3377,7 @@ where
                 .await
         };
 
-        current_data
+        let locked_edition = current_data
             .map(|row| {
                 row.map(|row| LockedEntityEdition {
                     entity_id,
@@ -2310,7 +3391,25 @@ where
                     .attach(entity_id)
                     .change_context(UpdateError),
                 _ => Report::new(error).change_context(UpdateError),
-            })
+            })?;
+
+        if let Some((expected_edition_id, locked_edition)) =
+            expected_edition_id.zip(locked_edition.as_ref())
+        {
+            ensure!(
+                locked_edition.entity_edition_id == expected_edition_id,
+                Report::new(EntityEditionMismatch)
+                    .attach(StatusCode::Aborted)
+                    .attach_printable(format!(
+                        "expected edition `{}` but the current edition is `{}`",
+                        expected_edition_id.as_uuid(),
+                        locked_edition.entity_edition_id.as_uuid()
+                    ))
+                    .change_context(UpdateError)
+            );
+        }
+
+        Ok(locked_edition)
     }
 
     #[tracing::instrument(level = "trace", skip(self))]
@@ -0,0 +1,466 @@
+use std::collections::{HashMap, HashSet};
+
+use error_stack::{Report, ResultExt as _, ensure};
+use hash_graph_authorization::{
+    AuthorizationApi,
+    schema::EntityPermission,
+    zanzibar::{Consistency, Zookie},
+};
+use hash_graph_store::{
+    entity::{
+        EntityPath, EntityPathHop, EntityQuerySorting, FindEntityPathsParams,
+        FindEntityPathsResponse, GetEntitiesResponse, LinkDirection,
+    },
+    error::QueryError,
+    filter::Filter,
+    subgraph::{
+        Subgraph, SubgraphRecord as _,
+        edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind},
+        identifier::{EntityIdWithInterval, EntityVertexId},
+        temporal_axes::{QueryTemporalAxes, VariableAxis},
+    },
+};
+use hash_graph_temporal_versioning::{LeftClosedTemporalInterval, RightBoundedTemporalInterval};
+use hash_status::StatusCode;
+use type_system::{
+    knowledge::entity::id::EntityEditionId, ontology::VersionedUrl, provenance::ActorEntityUuid,
+};
+
+use super::GetEntitiesImplParams;
+use crate::store::postgres::{
+    AsClient, PostgresStore, TraversalContext, knowledge::entity::read::EntityEdgeTraversalData,
+    query::ReferenceTable,
+};
+
+/// A link entity followed from an entity together with the entity on the other end of the link.
+#[derive(Debug, Copy, Clone)]
+struct LinkHop {
+    direction: LinkDirection,
+    source: EntityVertexId,
+    link: EntityVertexId,
+    link_edition_id: EntityEditionId,
+    link_interval: LeftClosedTemporalInterval<VariableAxis>,
+    target: EntityVertexId,
+    target_edition_id: EntityEditionId,
+    target_interval: LeftClosedTemporalInterval<VariableAxis>,
+    traversal_interval: RightBoundedTemporalInterval<VariableAxis>,
+}
+
+impl LinkHop {
+    fn to_path_hop(self) -> EntityPathHop {
+        EntityPathHop {
+            link_entity_id: self.link.base_id,
+            direction: self.direction,
+            entity_id: self.target.base_id,
+        }
+    }
+}
+
+/// Returns the edges to get from an entity to a link entity and from the link entity to the
+/// entity on its other end.
+const fn link_edges(direction: LinkDirection) -> [(KnowledgeGraphEdgeKind, ReferenceTable); 2] {
+    match direction {
+        LinkDirection::Outgoing => [
+            (
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                ReferenceTable::EntityHasLeftEntity,
+            ),
+            (
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                ReferenceTable::EntityHasRightEntity,
+            ),
+        ],
+        LinkDirection::Incoming => [
+            (
+                KnowledgeGraphEdgeKind::HasRightEntity,
+                ReferenceTable::EntityHasRightEntity,
+            ),
+            (
+                KnowledgeGraphEdgeKind::HasLeftEntity,
+                ReferenceTable::EntityHasLeftEntity,
+            ),
+        ],
+    }
+}
+
+fn traversal_data(
+    temporal_axes: &QueryTemporalAxes,
+    vertices: impl IntoIterator<Item = (EntityVertexId, RightBoundedTemporalInterval<VariableAxis>)>,
+) -> EntityEdgeTraversalData {
+    let mut traversal_data = EntityEdgeTraversalData::new(
+        temporal_axes.pinned_timestamp(),
+        temporal_axes.variable_time_axis(),
+    );
+    for (vertex_id, interval) in vertices {
+        traversal_data.push(vertex_id, interval, GraphResolveDepths::default());
+    }
+    traversal_data
+}
+
+impl<C, A> PostgresStore<C, A>
+where
+    C: AsClient,
+    A: AuthorizationApi,
+{
+    /// Reads the link entities attached to `vertices` in the given direction together with the
+    /// entities on the other end of the links.
+    ///
+    /// Link entities which are not of one of the `link_entity_type_ids` and entities the actor is
+    /// not allowed to view are skipped.
+    async fn read_link_hops(
+        &self,
+        vertices: &[(EntityVertexId, RightBoundedTemporalInterval<VariableAxis>)],
+        direction: LinkDirection,
+        link_entity_type_ids: Option<&HashSet<VersionedUrl>>,
+        temporal_axes: &QueryTemporalAxes,
+        actor_id: ActorEntityUuid,
+        zookie: &Zookie<'static>,
+    ) -> Result<Vec<LinkHop>, Report<QueryError>> {
+        let [(_, link_table), (_, target_table)] = link_edges(direction);
+        let mut links = self
+            .read_knowledge_edges(
+                &traversal_data(temporal_axes, vertices.iter().copied()),
+                link_table,
+                EdgeDirection::Incoming,
+            )
+            .await?
+            .map(|(_, edge)| edge)
+            .collect::<Vec<_>>();
+
+        if let Some(link_entity_type_ids) = link_entity_type_ids {
+            // `entity_is_of_type` contains the inherited types as well, so we don't have to
+            // resolve the parents of the types here.
+            let link_type_data = traversal_data(
+                temporal_axes,
+                links
+                    .iter()
+                    .map(|link| (link.right_endpoint, link.traversal_interval)),
+            );
+            let permitted_links = self
+                .read_shared_edges(&link_type_data, None)
+                .await?
+                .filter(|(_, edge)| {
+                    link_entity_type_ids.contains(&VersionedUrl {
+                        base_url: edge.right_endpoint.base_id.clone(),
+                        version: edge.right_endpoint.revision_id,
+                    })
+                })
+                .map(|(_, edge)| edge.left_endpoint)
+                .collect::<HashSet<_>>();
+            links.retain(|link| permitted_links.contains(&link.right_endpoint));
+        }
+
+        if links.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let targets = self
+            .read_knowledge_edges(
+                &traversal_data(
+                    temporal_axes,
+                    links
+                        .iter()
+                        .map(|link| (link.right_endpoint, link.traversal_interval)),
+                ),
+                target_table,
+                EdgeDirection::Outgoing,
+            )
+            .await?
+            .map(|(_, edge)| edge)
+            .collect::<Vec<_>>();
+
+        let permissions = self
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                links
+                    .iter()
+                    .map(|link| link.right_endpoint.base_id)
+                    .chain(targets.iter().map(|target| target.right_endpoint.base_id))
+                    .collect::<HashSet<_>>(),
+                Consistency::AtExactSnapshot(zookie),
+            )
+            .await
+            .change_context(QueryError)?
+            .0;
+        let is_permitted = |vertex_id: EntityVertexId| {
+            permissions
+                .get(&vertex_id.base_id.entity_uuid)
+                .copied()
+                .unwrap_or(true)
+        };
+
+        let mut hops = Vec::new();
+        let mut seen = HashSet::new();
+        for target in targets {
+            if !is_permitted(target.right_endpoint) {
+                continue;
+            }
+            for link in &links {
+                if link.right_endpoint != target.left_endpoint || !is_permitted(link.right_endpoint)
+                {
+                    continue;
+                }
+                let Some(traversal_interval) =
+                    link.traversal_interval.intersect(target.traversal_interval)
+                else {
+                    continue;
+                };
+                // Several editions of the same entities may be reached within the traversal
+                // interval, but a path only needs to contain them once.
+                if !seen.insert((
+                    link.left_endpoint.base_id,
+                    link.right_endpoint.base_id,
+                    target.right_endpoint.base_id,
+                )) {
+                    continue;
+                }
+
+                hops.push(LinkHop {
+                    direction,
+                    source: link.left_endpoint,
+                    link: link.right_endpoint,
+                    link_edition_id: link.right_endpoint_edition_id,
+                    link_interval: link.edge_interval,
+                    target: target.right_endpoint,
+                    target_edition_id: target.right_endpoint_edition_id,
+                    target_interval: target.edge_interval,
+                    traversal_interval,
+                });
+            }
+        }
+
+        Ok(hops)
+    }
+
+    /// Searches the paths between two entities breadth-first, one link per iteration.
+    ///
+    /// To bound the search in densely linked graphs, every entity is expanded at most `limit`
+    /// times.
+    #[expect(clippy::too_many_lines)]
+    pub(super) async fn find_entity_paths_impl(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: FindEntityPathsParams,
+    ) -> Result<FindEntityPathsResponse, Report<QueryError>> {
+        ensure!(
+            params.max_depth <= FindEntityPathsParams::MAX_DEPTH,
+            Report::new(QueryError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable(format!(
+                    "`maxDepth` must not exceed {}",
+                    FindEntityPathsParams::MAX_DEPTH
+                ))
+        );
+        ensure!(
+            params.limit <= FindEntityPathsParams::MAX_LIMIT,
+            Report::new(QueryError)
+                .attach(StatusCode::InvalidArgument)
+                .attach_printable(format!(
+                    "`limit` must not exceed {}",
+                    FindEntityPathsParams::MAX_LIMIT
+                ))
+        );
+
+        let unresolved_temporal_axes = params.temporal_axes;
+        let temporal_axes = unresolved_temporal_axes.clone().resolve();
+        let time_axis = temporal_axes.variable_time_axis();
+
+        let (
+            GetEntitiesResponse {
+                entities: endpoints,
+                ..
+            },
+            zookie,
+        ) = self
+            .get_entities_impl(
+                actor_id,
+                GetEntitiesImplParams {
+                    filter: Filter::Any(vec![
+                        Filter::for_entity_by_entity_id(params.source_entity_id),
+                        Filter::for_entity_by_entity_id(params.target_entity_id),
+                    ]),
+                    sorting: EntityQuerySorting {
+                        paths: Vec::new(),
+                        cursor: None,
+                    },
+                    limit: None,
+                    include_drafts: params.include_drafts,
+                    include_count: false,
+                    include_entity_types: None,
+                    include_web_ids: false,
+                    include_created_by_ids: false,
+                    include_edition_created_by_ids: false,
+                    include_type_ids: false,
+                    include_type_titles: false,
+                },
+                &temporal_axes,
+            )
+            .await?;
+
+        let mut subgraph = Subgraph::new(
+            params.graph_resolve_depths,
+            unresolved_temporal_axes,
+            temporal_axes,
+        );
+        let variable_interval = subgraph.temporal_axes.resolved.variable_interval();
+
+        let mut found_paths = Vec::<Vec<LinkHop>>::new();
+        let mut partial_paths = Vec::new();
+        for entity in endpoints {
+            let vertex_id = entity.vertex_id(time_axis);
+            if entity.metadata.record_id.entity_id == params.source_entity_id {
+                if params.source_entity_id == params.target_entity_id {
+                    found_paths.push(Vec::new());
+                } else {
+                    partial_paths.push((vertex_id, variable_interval, Vec::<LinkHop>::new()));
+                }
+            }
+            subgraph.roots.push(vertex_id.into());
+            subgraph.insert_vertex(vertex_id, entity);
+        }
+
+        let directions = params
+            .directions
+            .unwrap_or_else(|| vec![LinkDirection::Outgoing, LinkDirection::Incoming])
+            .into_iter()
+            .collect::<HashSet<_>>();
+        let link_entity_type_ids = params
+            .link_entity_type_ids
+            .map(|ids| ids.into_iter().collect::<HashSet<_>>());
+
+        let mut expansions = HashMap::new();
+        for _ in 0..params.max_depth {
+            if partial_paths.is_empty() || found_paths.len() >= params.limit {
+                break;
+            }
+
+            let frontier = partial_paths
+                .iter()
+                .map(|(vertex_id, interval, _)| (*vertex_id, *interval))
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>();
+            let mut hops = HashMap::<_, Vec<_>>::new();
+            for &direction in &directions {
+                for hop in self
+                    .read_link_hops(
+                        &frontier,
+                        direction,
+                        link_entity_type_ids.as_ref(),
+                        &subgraph.temporal_axes.resolved,
+                        actor_id,
+                        &zookie,
+                    )
+                    .await?
+                {
+                    hops.entry(hop.source).or_default().push(hop);
+                }
+            }
+
+            let mut next_partial_paths = Vec::new();
+            for (vertex_id, interval, path) in partial_paths {
+                for hop in hops.get(&vertex_id).into_iter().flatten() {
+                    let entity_id = hop.target.base_id;
+                    if entity_id == params.source_entity_id
+                        || path.iter().any(|previous| {
+                            previous.target.base_id == entity_id
+                                || previous.link.base_id == hop.link.base_id
+                        })
+                    {
+                        continue;
+                    }
+                    let Some(interval) = interval.intersect(hop.traversal_interval) else {
+                        continue;
+                    };
+
+                    let mut path = path.clone();
+                    path.push(*hop);
+                    if entity_id == params.target_entity_id {
+                        found_paths.push(path);
+                    } else {
+                        let expansion_count = expansions.entry(entity_id).or_insert(0);
+                        if *expansion_count < params.limit {
+                            *expansion_count += 1;
+                            next_partial_paths.push((hop.target, interval, path));
+                        }
+                    }
+                }
+            }
+            partial_paths = next_partial_paths;
+        }
+
+        // Paths are found in order of their length, so the first ones are the shortest.
+        let mut paths = Vec::new();
+        let mut path_hops = Vec::new();
+        for hops in found_paths {
+            let path = EntityPath {
+                hops: hops.iter().copied().map(LinkHop::to_path_hop).collect(),
+            };
+            if paths.len() < params.limit && !paths.contains(&path) {
+                paths.push(path);
+                path_hops.extend(hops);
+            }
+        }
+
+        let mut traversal_context = TraversalContext::default();
+        let mut traversal_queue = subgraph
+            .vertices
+            .entities
+            .keys()
+            .map(|vertex_id| (*vertex_id, subgraph.depths, variable_interval))
+            .collect::<Vec<_>>();
+        for hop in path_hops {
+            let [(link_edge_kind, _), (target_edge_kind, _)] = link_edges(hop.direction);
+            subgraph.insert_edge(
+                &hop.source,
+                link_edge_kind,
+                EdgeDirection::Incoming,
+                EntityIdWithInterval {
+                    entity_id: hop.link.base_id,
+                    interval: hop.link_interval,
+                },
+            );
+            subgraph.insert_edge(
+                &hop.link,
+                target_edge_kind,
+                EdgeDirection::Outgoing,
+                EntityIdWithInterval {
+                    entity_id: hop.target.base_id,
+                    interval: hop.target_interval,
+                },
+            );
+
+            traversal_queue.extend(
+                traversal_context
+                    .add_entity_id(hop.link_edition_id, subgraph.depths, hop.traversal_interval)
+                    .map(move |(_, depths, interval)| (hop.link, depths, interval))
+                    .chain(
+                        traversal_context
+                            .add_entity_id(
+                                hop.target_edition_id,
+                                subgraph.depths,
+                                hop.traversal_interval,
+                            )
+                            .map(move |(_, depths, interval)| (hop.target, depths, interval)),
+                    ),
+            );
+        }
+
+        self.traverse_entities(
+            traversal_queue,
+            &mut traversal_context,
+            actor_id,
+            &zookie,
+            &mut subgraph,
+        )
+        .await?;
+
+        traversal_context
+            .read_traversed_vertices(self, &mut subgraph, params.include_drafts)
+            .await?;
+
+        Ok(FindEntityPathsResponse { paths, subgraph })
+    }
+}
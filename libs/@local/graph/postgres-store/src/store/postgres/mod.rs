@@ -1,3 +1,4 @@
+mod change_feed;
 mod crud;
 mod knowledge;
 mod migration;
@@ -17,14 +18,16 @@ use hash_graph_authorization::{
     schema::{
         AccountGroupAdministratorSubject, AccountGroupRelationAndSubject, WebDataTypeViewerSubject,
         WebEntityCreatorSubject, WebEntityEditorSubject, WebEntityTypeViewerSubject,
-        WebOwnerSubject, WebPropertyTypeViewerSubject, WebRelationAndSubject, WebSubjectSet,
+        WebOwnerSubject, WebPermission, WebPropertyTypeViewerSubject, WebRelationAndSubject,
+        WebSubjectSet,
     },
+    zanzibar::Consistency,
 };
 use hash_graph_store::{
     account::{
         AccountGroupInsertionError, AccountInsertionError, AccountStore,
         InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams, QueryWebError,
-        WebInsertionError,
+        UpdateWebTextSearchConfigParams, WebInsertionError, WebUpdateError,
     },
     error::{InsertionError, QueryError, UpdateError},
     query::ConflictBehavior,
@@ -1199,6 +1202,74 @@ impl<C: AsClient, A: AuthorizationApi> AccountStore for PostgresStore<C, A> {
         }
     }
 
+    #[tracing::instrument(level = "info", skip(self))]
+    async fn update_web_text_search_config(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: UpdateWebTextSearchConfigParams,
+    ) -> Result<(), Report<WebUpdateError>> {
+        self.authorization_api
+            .check_web_permission(
+                actor_id,
+                WebPermission::ChangePermission,
+                params.owned_by_id,
+                Consistency::FullyConsistent,
+            )
+            .await
+            .change_context(WebUpdateError)?
+            .assert_permission()
+            .change_context(WebUpdateError)?;
+
+        let transaction = self.transaction().await.change_context(WebUpdateError)?;
+
+        transaction
+            .as_client()
+            .query(
+                "
+                    INSERT INTO web_text_search_configs (web_id, config)
+                    VALUES ($1, $2::text::regconfig)
+                    ON CONFLICT (web_id) DO UPDATE SET config = EXCLUDED.config;
+                ",
+                &[&params.owned_by_id, &params.config],
+            )
+            .await
+            .map_err(Report::new)
+            .map_err(|report| match report.current_context().code() {
+                Some(&SqlState::UNDEFINED_OBJECT) => report
+                    .change_context(WebUpdateError)
+                    .attach(hash_status::StatusCode::InvalidArgument)
+                    .attach_printable(params.config.clone()),
+                _ => report.change_context(WebUpdateError),
+            })?;
+
+        transaction
+            .as_client()
+            .query(
+                "
+                    UPDATE entity_edition_search
+                    SET
+                        config = $2::text::regconfig,
+                        search_vector = jsonb_to_tsvector(
+                            $2::text::regconfig,
+                            coalesce(editions.properties, '{}'),
+                            '[\"string\"]'
+                        )
+                    FROM entity_editions AS editions
+                    WHERE editions.entity_edition_id = entity_edition_search.entity_edition_id
+                      AND editions.entity_edition_id IN (
+                        SELECT entity_edition_id
+                        FROM entity_temporal_metadata
+                        WHERE web_id = $1
+                      );
+                ",
+                &[&params.owned_by_id, &params.config],
+            )
+            .await
+            .change_context(WebUpdateError)?;
+
+        transaction.commit().await.change_context(WebUpdateError)
+    }
+
     #[tracing::instrument(level = "info", skip(self))]
     async fn identify_owned_by_id(
         &self,
@@ -7,24 +7,26 @@ use hash_graph_store::{
     filter::{
         Filter, FilterExpression, Parameter, ParameterList, ParameterType, PathToken, QueryRecord,
     },
-    query::{NullOrdering, Ordering},
+    query::{AggregationFunction, NullOrdering, Ordering},
     subgraph::temporal_axes::QueryTemporalAxes,
 };
 use hash_graph_temporal_versioning::TimeAxis;
+use hash_status::StatusCode;
 use postgres_types::ToSql;
 use tracing::instrument;
 
 use super::expression::JoinType;
 use crate::store::postgres::query::{
-    Alias, AliasedTable, Column, Condition, Distinctness, EqualityOperator, Expression, Function,
-    JoinExpression, OrderByExpression, PostgresQueryPath, PostgresRecord, SelectExpression,
-    SelectStatement, Table, Transpile as _, WhereExpression, WindowStatement, WithExpression,
+    Alias, AliasedTable, Column, Condition, Constant, Distinctness, EqualityOperator, Expression,
+    Function, JoinExpression, OrderByExpression, PostgresQueryPath, PostgresRecord,
+    SelectExpression, SelectStatement, Table, Transpile as _, WhereExpression, WindowStatement,
+    WithExpression,
     expression::{GroupByExpression, PostgresType},
     statement::FromItem,
     table::{
-        DataTypeEmbeddings, DatabaseColumn as _, EntityEmbeddings, EntityTemporalMetadata,
-        EntityTypeEmbeddings, EntityTypes, JsonField, OntologyIds, OntologyTemporalMetadata,
-        PropertyTypeEmbeddings,
+        DataTypeEmbeddings, DatabaseColumn as _, EntityEditionSearch, EntityEmbeddings,
+        EntityTemporalMetadata, EntityTypeEmbeddings, EntityTypes, JsonField, OntologyIds,
+        OntologyTemporalMetadata, ParameterizedJsonField, PropertyTypeEmbeddings,
     },
 };
 
@@ -82,8 +84,29 @@ pub enum SelectCompilerError {
     UnsupportedDistanceExpression,
     #[display("Cannot add a cursor: {reason}")]
     CursorDisallowed { reason: &'static str },
+    #[display("The aggregation function `{function:?}` requires a path")]
+    MissingAggregationPath { function: AggregationFunction },
+    #[display("Only numeric and JSON values can be aggregated by `{function:?}`")]
+    UnsupportedAggregationPath { function: AggregationFunction },
+    #[display(
+        "Full-text search is only supported with exactly one search vector `path` and one \
+         `parameter` expression."
+    )]
+    UnsupportedFullTextSearchExpression,
+    #[display(
+        "Contains is only supported with exactly one list `path` and one `parameter` expression."
+    )]
+    UnsupportedContainsExpression,
+    #[display("The regular expression has {length} bytes, but at most {maximum} bytes are allowed")]
+    RegexPatternTooLong { length: usize, maximum: usize },
 }
 
+/// The maximum length in bytes of a regular expression passed to [`Filter::Regex`].
+///
+/// Matching a regular expression cannot be interrupted by the database, so the complexity of a
+/// pattern is limited by its length.
+const MAX_REGEX_PATTERN_LENGTH: usize = 1_000;
+
 impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
     /// Creates a new, empty compiler.
     pub fn new(temporal_axes: Option<&'p QueryTemporalAxes>, include_drafts: bool) -> Self {
@@ -394,6 +417,155 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         ))
     }
 
+    /// Groups the selection by the path and adds its value as JSON to the selection.
+    ///
+    /// The groups are ordered by the value of the path.
+    pub fn add_group_by_path(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let expression = self.compile_path_column(path);
+        self.statement
+            .group_by_expression
+            .expressions
+            .push(expression.clone());
+        self.statement
+            .order_by_expression
+            .push(expression.clone(), Ordering::Ascending, None);
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Function(Function::ToJson(Box::new(expression))),
+            None,
+        ));
+        self.statement.selects.len() - 1
+    }
+
+    /// Groups the selection by the path and adds its value to the selection.
+    ///
+    /// Unlike [`Self::add_group_by_path`], the value is neither converted to JSON nor are the
+    /// groups ordered by it.
+    pub fn add_grouped_selection_path(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let expression = self.compile_path_column(path);
+        self.statement
+            .group_by_expression
+            .expressions
+            .push(expression.clone());
+        self.statement
+            .selects
+            .push(SelectExpression::new(expression, None));
+        self.statement.selects.len() - 1
+    }
+
+    /// Adds an aggregation over the grouped rows to the selection.
+    ///
+    /// Numeric aggregations ignore values which are not numeric. The result is selected as
+    /// `double precision`.
+    ///
+    /// If rows are joined one-to-many, [`AggregationFunction::Count`] without a path counts a
+    /// record once for every joined row. Use [`Self::add_distinct_count`] to count records.
+    pub fn add_aggregation(
+        &mut self,
+        function: AggregationFunction,
+        path: Option<&'p R::QueryPath<'q>>,
+    ) -> Result<usize, Report<SelectCompilerError>>
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let aggregation = Box::new(self.aggregation_input(function, path)?);
+        let aggregation = match function {
+            AggregationFunction::Count => Function::Count(aggregation),
+            AggregationFunction::Sum => Function::Sum(aggregation),
+            AggregationFunction::Min => Function::Min(aggregation),
+            AggregationFunction::Max => Function::Max(aggregation),
+            AggregationFunction::Avg => Function::Avg(aggregation),
+        };
+        Ok(self.add_aggregation_selection(aggregation))
+    }
+
+    /// Adds the number of values an aggregation over `path` takes into account to the selection.
+    ///
+    /// Together with the sum of the values, this allows averages of several groups to be combined.
+    pub fn add_value_count(
+        &mut self,
+        function: AggregationFunction,
+        path: &'p R::QueryPath<'q>,
+    ) -> Result<usize, Report<SelectCompilerError>>
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let expression = self.aggregation_input(function, Some(path))?;
+        Ok(self.add_aggregation_selection(Function::Count(Box::new(expression))))
+    }
+
+    /// Adds the number of distinct values at `path` in the grouped rows to the selection.
+    ///
+    /// When `path` identifies a record, this counts every record once, even if it was joined
+    /// with multiple rows.
+    pub fn add_distinct_count(&mut self, path: &'p R::QueryPath<'q>) -> usize
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let expression = self.compile_path_column(path);
+        self.add_aggregation_selection(Function::CountDistinct(Box::new(expression)))
+    }
+
+    fn aggregation_input(
+        &mut self,
+        function: AggregationFunction,
+        path: Option<&'p R::QueryPath<'q>>,
+    ) -> Result<Expression, Report<SelectCompilerError>>
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        Ok(match path {
+            Some(path) if function.is_numeric() => {
+                let (column, json_field) = path.terminating_column();
+                let parameter_type = if let Some(JsonField::StaticText(_)) = json_field {
+                    ParameterType::Text
+                } else {
+                    column.parameter_type()
+                };
+                let expression = self.compile_path_column(path);
+                match parameter_type {
+                    ParameterType::Any => Expression::Cast(
+                        Box::new(Expression::Function(Function::JsonPathQueryFirst(
+                            Box::new(expression),
+                            Box::new(Expression::Cast(
+                                Box::new(Expression::Constant(Constant::String(
+                                    r#"$ ? (@.type() == "number")"#,
+                                ))),
+                                PostgresType::JsonPath,
+                            )),
+                        ))),
+                        PostgresType::DoublePrecision,
+                    ),
+                    ParameterType::Integer
+                    | ParameterType::Decimal
+                    | ParameterType::OntologyTypeVersion => {
+                        Expression::Cast(Box::new(expression), PostgresType::DoublePrecision)
+                    }
+                    _ => bail!(SelectCompilerError::UnsupportedAggregationPath { function }),
+                }
+            }
+            Some(path) => self.compile_path_column(path),
+            None if function == AggregationFunction::Count => Expression::Asterisk,
+            None => bail!(SelectCompilerError::MissingAggregationPath { function }),
+        })
+    }
+
+    fn add_aggregation_selection(&mut self, aggregation: Function) -> usize {
+        self.statement.selects.push(SelectExpression::new(
+            Expression::Cast(
+                Box::new(Expression::Function(aggregation)),
+                PostgresType::DoublePrecision,
+            ),
+            None,
+        ));
+        self.statement.selects.len() - 1
+    }
+
     /// Adds a new filter to the selection.
     pub fn add_filter(
         &mut self,
@@ -417,6 +589,11 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         )
     }
 
+    /// Returns the tables which are joined to the base table of the statement.
+    pub fn joined_tables(&self) -> impl Iterator<Item = AliasedTable> {
+        self.statement.joins.iter().map(|join| join.table)
+    }
+
     /// Compiles a [`Filter`] to a `Condition`.
     #[expect(clippy::too_many_lines)]
     pub fn compile_filter(
@@ -616,61 +793,113 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
                 }
                 _ => bail!(SelectCompilerError::UnsupportedDistanceExpression),
             },
-            Filter::In(lhs, rhs) => Condition::In(
-                self.compile_filter_expression(lhs).0,
-                self.compile_parameter_list(rhs).0,
+            Filter::In(lhs, rhs) => {
+                let lhs = self.compile_filter_expression(lhs).0;
+                self.compile_parameter_list(lhs, rhs)
+            }
+            Filter::StartsWith(lhs, rhs) => Condition::StartsWith(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
             ),
-            Filter::StartsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
-
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+            Filter::EndsWith(lhs, rhs) => Condition::EndsWith(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::ContainsSegment(lhs, rhs) => Condition::ContainsSegment(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::ILike(lhs, rhs) => Condition::ILike(
+                self.compile_text_filter_expression(lhs),
+                self.compile_text_filter_expression(rhs),
+            ),
+            Filter::Regex(lhs, rhs) => {
+                if let FilterExpression::Parameter {
+                    parameter: Parameter::Text(pattern),
+                    convert: _,
+                } = rhs
+                {
+                    ensure!(
+                        pattern.len() <= MAX_REGEX_PATTERN_LENGTH,
+                        Report::new(SelectCompilerError::RegexPatternTooLong {
+                            length: pattern.len(),
+                            maximum: MAX_REGEX_PATTERN_LENGTH,
+                        })
+                        .attach(StatusCode::InvalidArgument)
+                    );
+                }
 
-                Condition::StartsWith(left_filter, right_filter)
+                Condition::Regex(
+                    self.compile_text_filter_expression(lhs),
+                    self.compile_text_filter_expression(rhs),
+                )
             }
-            Filter::EndsWith(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
-
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
-
-                Condition::EndsWith(left_filter, right_filter)
+            Filter::Contains(lhs, rhs) => match (lhs, rhs) {
+                (
+                    FilterExpression::Path { path },
+                    FilterExpression::Parameter {
+                        parameter,
+                        convert: _,
+                    },
+                ) => match path.expected_type() {
+                    ParameterType::Vector(element_type)
+                        if *element_type != ParameterType::Decimal =>
+                    {
+                        let list_expression = self.compile_path_column(path);
+                        let value_expression = self.compile_parameter(parameter).0;
+                        Condition::In(value_expression, list_expression)
+                    }
+                    ParameterType::Any => {
+                        // Wrapping the value into an array ensures that arrays are matched
+                        // element-wise instead of being compared as sub-arrays.
+                        let list_expression = self.compile_path_column(path);
+                        let value_expression = self.compile_parameter(parameter).0;
+                        Condition::Contains(
+                            list_expression,
+                            Expression::Function(Function::JsonBuildArray(vec![value_expression])),
+                        )
+                    }
+                    _ => bail!(SelectCompilerError::UnsupportedContainsExpression),
+                },
+                _ => bail!(SelectCompilerError::UnsupportedContainsExpression),
+            },
+            Filter::Exists(expression) => {
+                Condition::NotEqual(Some(self.compile_filter_expression(expression).0), None)
             }
-            Filter::ContainsSegment(lhs, rhs) => {
-                let (left_filter, left_parameter) = self.compile_filter_expression(lhs);
-                let left_filter = if left_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(left_filter)))
-                } else {
-                    left_filter
-                };
-
-                let (right_filter, right_parameter) = self.compile_filter_expression(rhs);
-                let right_filter = if right_parameter == ParameterType::Any {
-                    Expression::Function(Function::JsonExtractText(Box::new(right_filter)))
-                } else {
-                    right_filter
-                };
+            Filter::FullTextMatch(lhs, rhs) => match (lhs, rhs) {
+                (
+                    FilterExpression::Path { path },
+                    FilterExpression::Parameter {
+                        parameter,
+                        convert: _,
+                    },
+                )
+                | (
+                    FilterExpression::Parameter {
+                        parameter,
+                        convert: _,
+                    },
+                    FilterExpression::Path { path },
+                ) => {
+                    let Expression::ColumnReference {
+                        column: Column::EntityEditionSearch(EntityEditionSearch::SearchVector),
+                        table_alias,
+                    } = self.compile_path_column(path)
+                    else {
+                        bail!(SelectCompilerError::UnsupportedFullTextSearchExpression);
+                    };
+                    let query_expression = self.compile_parameter(parameter).0;
 
-                Condition::ContainsSegment(left_filter, right_filter)
-            }
+                    Condition::FullTextMatch(
+                        Expression::ColumnReference {
+                            column: Column::EntityEditionSearch(EntityEditionSearch::SearchVector),
+                            table_alias,
+                        },
+                        Self::web_search_query(table_alias, query_expression),
+                    )
+                }
+                _ => bail!(SelectCompilerError::UnsupportedFullTextSearchExpression),
+            },
         })
     }
 
@@ -808,7 +1037,7 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
     {
         let (column, json_field) = path.terminating_column();
         let parameter = json_field.map(|field| {
-            let (field, parameter) = field.into_owned(self.artifacts.parameters.len() + 1);
+            let (field, parameter) = field.parameterize(self.artifacts.parameters.len() + 1);
             if let Some(parameter) = parameter {
                 self.artifacts.parameters.push(parameter);
             }
@@ -828,10 +1057,7 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
 
         match parameter {
             None => column_expression,
-            Some(JsonField::JsonPath(path)) => {
-                unreachable!("JsonPath `{path}` should be handled by now")
-            }
-            Some(JsonField::JsonPathParameter(index)) => {
+            Some(ParameterizedJsonField::JsonPath(index)) => {
                 Expression::Function(Function::JsonPathQueryFirst(
                     Box::new(column_expression),
                     Box::new(Expression::Cast(
@@ -843,13 +1069,23 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
                     )),
                 ))
             }
-            Some(JsonField::StaticText(field)) => {
+            Some(ParameterizedJsonField::SearchRank(index)) => Expression::Cast(
+                Box::new(Expression::Function(Function::TsRank(
+                    Box::new(column_expression),
+                    Box::new(Self::web_search_query(
+                        Some(alias),
+                        Expression::Parameter(index),
+                    )),
+                ))),
+                PostgresType::DoublePrecision,
+            ),
+            Some(ParameterizedJsonField::StaticText(field)) => {
                 Expression::Function(Function::JsonExtractAsText(
                     Box::new(column_expression),
                     PathToken::Field(Cow::Borrowed(field)),
                 ))
             }
-            Some(JsonField::Label { inheritance_depth }) => {
+            Some(ParameterizedJsonField::Label { inheritance_depth }) => {
                 if let Some(label_path) =
                     <R as QueryRecord>::QueryPath::label_property_path(inheritance_depth)
                 {
@@ -870,6 +1106,17 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         }
     }
 
+    /// Parses the query with the text search configuration stored next to the search vector.
+    fn web_search_query(search_alias: Option<Alias>, query: Expression) -> Expression {
+        Expression::Function(Function::WebSearchToTsQuery(
+            Box::new(Expression::ColumnReference {
+                column: Column::EntityEditionSearch(EntityEditionSearch::Config),
+                table_alias: search_alias,
+            }),
+            Box::new(query),
+        ))
+    }
+
     pub fn add_parameter(&mut self, parameter: &'p (dyn ToSql + Sync)) -> Expression {
         self.artifacts.parameters.push(parameter);
         Expression::Parameter(self.artifacts.parameters.len())
@@ -950,32 +1197,52 @@ impl<'p, 'q: 'p, R: PostgresRecord> SelectCompiler<'p, 'q, R> {
         }
     }
 
+    /// Compiles the `expression` to a text, JSON values are extracted as text.
+    fn compile_text_filter_expression(
+        &mut self,
+        expression: &'p FilterExpression<'q, R>,
+    ) -> Expression
+    where
+        R::QueryPath<'q>: PostgresQueryPath,
+    {
+        let (expression, parameter_type) = self.compile_filter_expression(expression);
+        if parameter_type == ParameterType::Any {
+            Expression::Function(Function::JsonExtractText(Box::new(expression)))
+        } else {
+            expression
+        }
+    }
+
+    /// Compiles a check whether `lhs` is contained in the `parameters`.
+    ///
+    /// Lists of IDs are passed as a single array parameter, while lists of arbitrary parameters
+    /// are compared one by one as they don't share a common Postgres type.
     pub fn compile_parameter_list<'f: 'p>(
         &mut self,
+        lhs: Expression,
         parameters: &'p ParameterList<'f>,
-    ) -> (Expression, ParameterType) {
-        let parameter_type = match parameters {
-            ParameterList::DataTypeIds(uuids) => {
-                self.artifacts.parameters.push(uuids);
-                ParameterType::Uuid
-            }
-            ParameterList::PropertyTypeIds(uuids) => {
-                self.artifacts.parameters.push(uuids);
-                ParameterType::Uuid
-            }
-            ParameterList::EntityTypeIds(uuids) => {
-                self.artifacts.parameters.push(uuids);
-                ParameterType::Uuid
-            }
-            ParameterList::EntityEditionIds(uuids) => {
-                self.artifacts.parameters.push(uuids);
-                ParameterType::Uuid
+    ) -> Condition {
+        match parameters {
+            ParameterList::DataTypeIds(uuids) => self.artifacts.parameters.push(uuids),
+            ParameterList::PropertyTypeIds(uuids) => self.artifacts.parameters.push(uuids),
+            ParameterList::EntityTypeIds(uuids) => self.artifacts.parameters.push(uuids),
+            ParameterList::EntityEditionIds(uuids) => self.artifacts.parameters.push(uuids),
+            ParameterList::Parameters(parameters) => {
+                return Condition::Any(
+                    parameters
+                        .iter()
+                        .map(|parameter| {
+                            Condition::Equal(
+                                Some(lhs.clone()),
+                                Some(self.compile_parameter(parameter).0),
+                            )
+                        })
+                        .collect(),
+                );
             }
-        };
-        (
-            Expression::Parameter(self.artifacts.parameters.len()),
-            parameter_type,
-        )
+        }
+
+        Condition::In(lhs, Expression::Parameter(self.artifacts.parameters.len()))
     }
 
     /// Joins a chain of [`Relation`]s and returns the table name of the last joined table.
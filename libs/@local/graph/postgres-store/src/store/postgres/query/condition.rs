@@ -22,6 +22,10 @@ pub enum Condition {
     StartsWith(Expression, Expression),
     EndsWith(Expression, Expression),
     ContainsSegment(Expression, Expression),
+    ILike(Expression, Expression),
+    Regex(Expression, Expression),
+    Contains(Expression, Expression),
+    FullTextMatch(Expression, Expression),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
@@ -141,6 +145,26 @@ impl Transpile for Condition {
                 rhs.transpile(fmt)?;
                 fmt.write_str(" || '%'")
             }
+            Self::ILike(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ILIKE ")?;
+                rhs.transpile(fmt)
+            }
+            Self::Regex(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" ~ ")?;
+                rhs.transpile(fmt)
+            }
+            Self::Contains(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" @> ")?;
+                rhs.transpile(fmt)
+            }
+            Self::FullTextMatch(lhs, rhs) => {
+                lhs.transpile(fmt)?;
+                fmt.write_str(" @@ ")?;
+                rhs.transpile(fmt)
+            }
         }
     }
 }
@@ -9,9 +9,9 @@ use hash_graph_store::{
 use crate::store::postgres::query::{
     PostgresQueryPath,
     table::{
-        Column, EntityEditions, EntityEmbeddings, EntityHasLeftEntity, EntityHasRightEntity,
-        EntityIds, EntityIsOfTypeIds, EntityTemporalMetadata, JsonField, LabelForEntity,
-        ReferenceTable, Relation, TypeTitleForEntity,
+        Column, EntityEditionSearch, EntityEditions, EntityEmbeddings, EntityHasLeftEntity,
+        EntityHasRightEntity, EntityIds, EntityIsOfTypeIds, EntityTemporalMetadata, JsonField,
+        LabelForEntity, ReferenceTable, Relation, TypeTitleForEntity,
     },
 };
 
@@ -28,6 +28,7 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
                 vec![Relation::EntityIds]
             }
             Self::Embedding => vec![Relation::EntityEmbeddings],
+            Self::SearchVector | Self::SearchRank(_) => vec![Relation::EntityEditionSearch],
             Self::LeftEntityConfidence | Self::LeftEntityProvenance => vec![Relation::LeftEntity],
             Self::RightEntityConfidence | Self::RightEntityProvenance => {
                 vec![Relation::RightEntity]
@@ -123,6 +124,14 @@ impl PostgresQueryPath for EntityQueryPath<'_> {
             ),
             Self::Archived => (Column::EntityEditions(EntityEditions::Archived), None),
             Self::Embedding => (Column::EntityEmbeddings(EntityEmbeddings::Embedding), None),
+            Self::SearchVector => (
+                Column::EntityEditionSearch(EntityEditionSearch::SearchVector),
+                None,
+            ),
+            Self::SearchRank(query) => (
+                Column::EntityEditionSearch(EntityEditionSearch::SearchVector),
+                Some(JsonField::SearchRank(query)),
+            ),
             Self::TypeBaseUrls => (Column::EntityIsOfTypeIds(EntityIsOfTypeIds::BaseUrls), None),
             Self::TypeVersions => (Column::EntityIsOfTypeIds(EntityIsOfTypeIds::Versions), None),
             Self::EntityTypeEdge { path, .. } => path.terminating_column(),
@@ -13,6 +13,11 @@ use crate::store::postgres::query::{
 pub enum Function {
     Min(Box<Expression>),
     Max(Box<Expression>),
+    Sum(Box<Expression>),
+    Avg(Box<Expression>),
+    Count(Box<Expression>),
+    CountDistinct(Box<Expression>),
+    ToJson(Box<Expression>),
     JsonExtractText(Box<Expression>),
     JsonExtractAsText(Box<Expression>, PathToken<'static>),
     JsonExtractPath(Vec<Expression>),
@@ -20,6 +25,8 @@ pub enum Function {
     JsonBuildArray(Vec<Expression>),
     JsonBuildObject(Vec<(Expression, Expression)>),
     JsonPathQueryFirst(Box<Expression>, Box<Expression>),
+    WebSearchToTsQuery(Box<Expression>, Box<Expression>),
+    TsRank(Box<Expression>, Box<Expression>),
     Lower(Box<Expression>),
     Upper(Box<Expression>),
     Unnest(Box<Expression>),
@@ -39,6 +46,31 @@ impl Transpile for Function {
                 expression.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::Sum(expression) => {
+                fmt.write_str("SUM(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Avg(expression) => {
+                fmt.write_str("AVG(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::Count(expression) => {
+                fmt.write_str("COUNT(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::CountDistinct(expression) => {
+                fmt.write_str("COUNT(DISTINCT ")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::ToJson(expression) => {
+                fmt.write_str("to_jsonb(")?;
+                expression.transpile(fmt)?;
+                fmt.write_char(')')
+            }
             Self::JsonExtractPath(paths) => {
                 fmt.write_str("jsonb_extract_path(")?;
                 for (i, expression) in paths.iter().enumerate() {
@@ -113,6 +145,20 @@ impl Transpile for Function {
                 path.transpile(fmt)?;
                 fmt.write_char(')')
             }
+            Self::WebSearchToTsQuery(config, query) => {
+                fmt.write_str("websearch_to_tsquery(")?;
+                config.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                query.transpile(fmt)?;
+                fmt.write_char(')')
+            }
+            Self::TsRank(vector, query) => {
+                fmt.write_str("ts_rank(")?;
+                vector.transpile(fmt)?;
+                fmt.write_str(", ")?;
+                query.transpile(fmt)?;
+                fmt.write_char(')')
+            }
         }
     }
 }
@@ -140,6 +186,7 @@ pub enum PostgresType {
     Row(Table),
     Text,
     JsonPath,
+    DoublePrecision,
 }
 
 impl Transpile for PostgresType {
@@ -152,6 +199,7 @@ impl Transpile for PostgresType {
             Self::Row(table) => table.transpile(fmt),
             Self::Text => fmt.write_str("text"),
             Self::JsonPath => fmt.write_str("jsonpath"),
+            Self::DoublePrecision => fmt.write_str("double precision"),
         }
     }
 }
@@ -21,10 +21,11 @@ use type_system::{
         InheritanceDepth,
         data_type::{ClosedDataType, ConversionDefinition, DataType, DataTypeUuid},
         entity_type::{ClosedEntityType, EntityType, EntityTypeUuid},
-        id::{BaseUrl, OntologyTypeUuid, OntologyTypeVersion},
+        id::{BaseUrl, OntologyTypeUuid, OntologyTypeVersion, VersionedUrl},
         property_type::{PropertyType, PropertyTypeUuid},
         provenance::OntologyEditionProvenance,
     },
+    provenance::ActorEntityUuid,
     web::{ActorGroupId, OwnedById},
 };
 
@@ -169,6 +170,17 @@ pub struct EntityTemporalMetadataRow {
     pub transaction_time: LeftClosedTemporalInterval<TransactionTime>,
 }
 
+#[derive(Debug, ToSql)]
+#[postgres(name = "entity_tombstones")]
+pub struct EntityTombstoneRow {
+    pub web_id: OwnedById,
+    pub entity_uuid: EntityUuid,
+    pub erased_by_id: ActorEntityUuid,
+    pub erased_at_transaction_time: Timestamp<TransactionTime>,
+    pub reason: String,
+    pub entity_type_ids: Vec<VersionedUrl>,
+}
+
 #[derive(Debug, ToSql)]
 #[postgres(name = "entity_type_constrains_link_destinations_on")]
 pub struct EntityTypeConstrainsLinkDestinationsOnRow {
@@ -92,14 +92,14 @@ impl Transpile for SelectStatement {
             self.where_expression.transpile(fmt)?;
         }
 
-        if !self.order_by_expression.is_empty() {
+        if !self.group_by_expression.expressions.is_empty() {
             fmt.write_char('\n')?;
-            self.order_by_expression.transpile(fmt)?;
+            self.group_by_expression.transpile(fmt)?;
         }
 
-        if !self.group_by_expression.expressions.is_empty() {
+        if !self.order_by_expression.is_empty() {
             fmt.write_char('\n')?;
-            self.group_by_expression.transpile(fmt)?;
+            self.order_by_expression.transpile(fmt)?;
         }
 
         if let Some(limit) = self.limit {
@@ -120,9 +120,9 @@ mod tests {
         data_type::DataTypeQueryPath,
         entity::EntityQueryPath,
         entity_type::EntityTypeQueryPath,
-        filter::{Filter, FilterExpression, JsonPath, Parameter, PathToken},
+        filter::{Filter, FilterExpression, JsonPath, Parameter, ParameterList, PathToken},
         property_type::PropertyTypeQueryPath,
-        query::{NullOrdering, Ordering},
+        query::{AggregationFunction, NullOrdering, Ordering},
         subgraph::{
             edges::{EdgeDirection, KnowledgeGraphEdgeKind, OntologyEdgeKind, SharedEdgeKind},
             temporal_axes::QueryTemporalAxesUnresolved,
@@ -1202,6 +1202,200 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filter_full_text_match() {
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(None, false);
+
+        let rank_path = EntityQueryPath::SearchRank(Cow::Borrowed("alice"));
+        compiler.add_distinct_selection_with_ordering(
+            &rank_path,
+            Distinctness::Distinct,
+            Some((Ordering::Descending, None)),
+        );
+
+        let filter = Filter::FullTextMatch(
+            FilterExpression::Path {
+                path: EntityQueryPath::SearchVector,
+            },
+            FilterExpression::Parameter {
+                parameter: Parameter::Text(Cow::Borrowed("alice")),
+                convert: None,
+            },
+        );
+        compiler.add_filter(&filter).expect("Failed to add filter");
+
+        test_compilation(
+            &compiler,
+            r#"
+              SELECT
+                DISTINCT ON((ts_rank("entity_edition_search_0_1_0"."search_vector", websearch_to_tsquery("entity_edition_search_0_1_0"."config", $1))::double precision))
+                *,
+                (ts_rank("entity_edition_search_0_1_0"."search_vector", websearch_to_tsquery("entity_edition_search_0_1_0"."config", $1))::double precision)
+              FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+              LEFT OUTER JOIN "entity_edition_search" AS "entity_edition_search_0_1_0"
+                ON "entity_edition_search_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+              WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                AND "entity_edition_search_0_1_0"."search_vector" @@ websearch_to_tsquery("entity_edition_search_0_1_0"."config", $2)
+              ORDER BY (ts_rank("entity_edition_search_0_1_0"."search_vector", websearch_to_tsquery("entity_edition_search_0_1_0"."config", $1))::double precision) DESC
+            "#,
+            &[&"alice", &"alice"],
+        );
+    }
+
+    #[test]
+    fn full_text_match_requires_search_vector() {
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(None, false);
+
+        let filter = Filter::FullTextMatch(
+            FilterExpression::Path {
+                path: EntityQueryPath::Properties(None),
+            },
+            FilterExpression::Parameter {
+                parameter: Parameter::Text(Cow::Borrowed("alice")),
+                convert: None,
+            },
+        );
+        compiler
+            .add_filter(&filter)
+            .expect_err("Full-text search on properties should fail");
+    }
+
+    #[test]
+    fn filter_contains_type_base_url() {
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(None, false);
+
+        let filter = Filter::Contains(
+            FilterExpression::Path {
+                path: EntityQueryPath::TypeBaseUrls,
+            },
+            FilterExpression::Parameter {
+                parameter: Parameter::Text(Cow::Borrowed(
+                    "https://blockprotocol.org/@alice/types/entity-type/person/",
+                )),
+                convert: None,
+            },
+        );
+        compiler.add_filter(&filter).expect("Failed to add filter");
+
+        test_compilation(
+            &compiler,
+            r#"
+              SELECT *
+              FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+              INNER JOIN "entity_is_of_type_ids" AS "entity_is_of_type_ids_0_1_0"
+                ON "entity_is_of_type_ids_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+              WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                AND $1 = ANY("entity_is_of_type_ids_0_1_0"."base_urls")
+            "#,
+            &[&"https://blockprotocol.org/@alice/types/entity-type/person/"],
+        );
+    }
+
+    #[test]
+    fn filter_in_parameters() {
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(None, false);
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/name/""#,
+        ))]);
+
+        let filter = Filter::All(vec![
+            Filter::In(
+                FilterExpression::Path {
+                    path: EntityQueryPath::Properties(Some(json_path.clone())),
+                },
+                ParameterList::Parameters(vec![
+                    Parameter::Text(Cow::Borrowed("Alice")),
+                    Parameter::Text(Cow::Borrowed("Bob")),
+                ]),
+            ),
+            Filter::ILike(
+                FilterExpression::Path {
+                    path: EntityQueryPath::Properties(Some(json_path.clone())),
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("a%")),
+                    convert: None,
+                },
+            ),
+        ]);
+        compiler.add_filter(&filter).expect("Failed to add filter");
+
+        test_compilation(
+            &compiler,
+            r#"
+              SELECT *
+              FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+              INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+                ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+              WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                AND (((jsonb_path_query_first("entity_editions_0_1_0"."properties", (($1::text)::jsonpath)) = $2)
+                  OR (jsonb_path_query_first("entity_editions_0_1_0"."properties", (($1::text)::jsonpath)) = $3)))
+                AND (((jsonb_path_query_first("entity_editions_0_1_0"."properties", (($4::text)::jsonpath))) #>> '{}'::text[]) ILIKE $5)
+            "#,
+            &[&json_path, &"Alice", &"Bob", &json_path, &"a%"],
+        );
+    }
+
+    #[test]
+    fn filter_regex_and_exists() {
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(None, false);
+        let name_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/name/""#,
+        ))]);
+        let age_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            r#"$."https://blockprotocol.org/@alice/types/property-type/age/""#,
+        ))]);
+
+        let filter = Filter::All(vec![
+            Filter::Regex(
+                FilterExpression::Path {
+                    path: EntityQueryPath::Properties(Some(name_path.clone())),
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Borrowed("^A.*e$")),
+                    convert: None,
+                },
+            ),
+            Filter::Exists(FilterExpression::Path {
+                path: EntityQueryPath::Properties(Some(age_path.clone())),
+            }),
+        ]);
+        compiler.add_filter(&filter).expect("Failed to add filter");
+
+        test_compilation(
+            &compiler,
+            r#"
+              SELECT *
+              FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+              INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+                ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+              WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+                AND (((jsonb_path_query_first("entity_editions_0_1_0"."properties", (($1::text)::jsonpath))) #>> '{}'::text[]) ~ $2)
+                AND (jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath)) IS NOT NULL)
+            "#,
+            &[&name_path, &"^A.*e$", &age_path],
+        );
+    }
+
+    #[test]
+    fn regex_pattern_length_is_limited() {
+        let mut compiler = SelectCompiler::<Entity>::with_asterisk(None, false);
+        let pattern = "a".repeat(1_001);
+
+        let filter = Filter::Regex(
+            FilterExpression::Path {
+                path: EntityQueryPath::Properties(None),
+            },
+            FilterExpression::Parameter {
+                parameter: Parameter::Text(Cow::Borrowed(&pattern)),
+                convert: None,
+            },
+        );
+        compiler
+            .add_filter(&filter)
+            .expect_err("Long regular expressions should be rejected");
+    }
+
     mod predefined {
         use type_system::{
             knowledge::entity::id::{EntityId, EntityUuid},
@@ -1278,4 +1472,96 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn entity_aggregation() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            "https://blockprotocol.org/@alice/types/property-type/age/",
+        ))]);
+        let age_path = EntityQueryPath::Properties(Some(json_path.clone()));
+
+        compiler.add_group_by_path(&EntityQueryPath::OwnedById);
+        compiler
+            .add_aggregation(AggregationFunction::Count, None)
+            .expect("count should not require a path");
+        compiler
+            .add_aggregation(AggregationFunction::Sum, Some(&age_path))
+            .expect("properties should be aggregatable");
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                to_jsonb("entity_temporal_metadata_0_0_0"."web_id"),
+                (COUNT(*)::double precision),
+                (SUM((jsonb_path_query_first(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath)), ('$ ? (@.type() == "number")'::jsonpath))::double precision))::double precision)
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+            GROUP BY "entity_temporal_metadata_0_0_0"."web_id"
+            ORDER BY "entity_temporal_metadata_0_0_0"."web_id" ASC
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json_path,
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_partial_aggregation() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let pinned_timestamp = temporal_axes.pinned_timestamp();
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+        let json_path = JsonPath::from_path_tokens(vec![PathToken::Field(Cow::Borrowed(
+            "https://blockprotocol.org/@alice/types/property-type/age/",
+        ))]);
+        let age_path = EntityQueryPath::Properties(Some(json_path.clone()));
+
+        compiler.add_group_by_path(&EntityQueryPath::OwnedById);
+        compiler.add_distinct_count(&EntityQueryPath::EditionId);
+        compiler
+            .add_value_count(AggregationFunction::Avg, &age_path)
+            .expect("properties should be aggregatable");
+
+        test_compilation(
+            &compiler,
+            r#"
+            SELECT
+                to_jsonb("entity_temporal_metadata_0_0_0"."web_id"),
+                (COUNT(DISTINCT "entity_temporal_metadata_0_0_0"."entity_edition_id")::double precision),
+                (COUNT((jsonb_path_query_first(jsonb_path_query_first("entity_editions_0_1_0"."properties", (($3::text)::jsonpath)), ('$ ? (@.type() == "number")'::jsonpath))::double precision))::double precision)
+            FROM "entity_temporal_metadata" AS "entity_temporal_metadata_0_0_0"
+            INNER JOIN "entity_editions" AS "entity_editions_0_1_0"
+              ON "entity_editions_0_1_0"."entity_edition_id" = "entity_temporal_metadata_0_0_0"."entity_edition_id"
+            WHERE "entity_temporal_metadata_0_0_0"."draft_id" IS NULL
+              AND "entity_temporal_metadata_0_0_0"."transaction_time" @> $1::TIMESTAMPTZ
+              AND "entity_temporal_metadata_0_0_0"."decision_time" && $2
+            GROUP BY "entity_temporal_metadata_0_0_0"."web_id"
+            ORDER BY "entity_temporal_metadata_0_0_0"."web_id" ASC
+            "#,
+            &[
+                &pinned_timestamp,
+                &temporal_axes.variable_interval(),
+                &json_path,
+            ],
+        );
+    }
+
+    #[test]
+    fn entity_aggregation_requires_path() {
+        let temporal_axes = QueryTemporalAxesUnresolved::default().resolve();
+        let mut compiler = SelectCompiler::<Entity>::new(Some(&temporal_axes), false);
+
+        compiler
+            .add_aggregation(AggregationFunction::Avg, None)
+            .expect_err("average should require a path");
+    }
 }
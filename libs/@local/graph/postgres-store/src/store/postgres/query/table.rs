@@ -1,3 +1,4 @@
+use alloc::borrow::Cow;
 use core::{
     fmt::{self, Debug, Formatter},
     hash::Hash,
@@ -40,6 +41,7 @@ pub enum Table {
     EntityTemporalMetadata,
     EntityEditions,
     EntityEmbeddings,
+    EntityEditionSearch,
     EntityIsOfType,
     EntityIsOfTypeIds,
     EntityHasLeftEntity,
@@ -340,6 +342,7 @@ impl Table {
             Self::EntityTemporalMetadata => "entity_temporal_metadata",
             Self::EntityEditions => "entity_editions",
             Self::EntityEmbeddings => "entity_embeddings",
+            Self::EntityEditionSearch => "entity_edition_search",
             Self::EntityIsOfType => "entity_is_of_type",
             Self::EntityIsOfTypeIds => "entity_is_of_type_ids",
             Self::EntityHasLeftEntity => "entity_has_left_entity",
@@ -358,24 +361,41 @@ impl Transpile for Table {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum JsonField<'p> {
     JsonPath(&'p JsonPath<'p>),
-    JsonPathParameter(usize),
     StaticText(&'static str),
     Label { inheritance_depth: Option<u32> },
+    SearchRank(&'p Cow<'p, str>),
+}
+
+/// A [`JsonField`] where user provided values are replaced by the index of a parameter.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParameterizedJsonField {
+    JsonPath(usize),
+    StaticText(&'static str),
+    Label { inheritance_depth: Option<u32> },
+    SearchRank(usize),
 }
 
 impl<'p> JsonField<'p> {
-    pub const fn into_owned(
+    /// Replaces the user provided value of the field by the parameter at `parameter_index`.
+    ///
+    /// Returns the value which has to be bound to the parameter, if the field has one.
+    pub const fn parameterize(
         self,
-        current_parameter_index: usize,
-    ) -> (JsonField<'static>, Option<&'p (dyn ToSql + Sync)>) {
+        parameter_index: usize,
+    ) -> (ParameterizedJsonField, Option<&'p (dyn ToSql + Sync)>) {
         match self {
             Self::JsonPath(path) => (
-                JsonField::JsonPathParameter(current_parameter_index),
+                ParameterizedJsonField::JsonPath(parameter_index),
                 Some(path),
             ),
-            Self::JsonPathParameter(index) => (JsonField::JsonPathParameter(index), None),
-            Self::StaticText(text) => (JsonField::StaticText(text), None),
-            Self::Label { inheritance_depth } => (JsonField::Label { inheritance_depth }, None),
+            Self::StaticText(text) => (ParameterizedJsonField::StaticText(text), None),
+            Self::Label { inheritance_depth } => {
+                (ParameterizedJsonField::Label { inheritance_depth }, None)
+            }
+            Self::SearchRank(query) => (
+                ParameterizedJsonField::SearchRank(parameter_index),
+                Some(query),
+            ),
         }
     }
 }
@@ -1031,6 +1051,34 @@ impl DatabaseColumn for EntityEmbeddings {
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum EntityEditionSearch {
+    EditionId,
+    Config,
+    SearchVector,
+}
+
+impl DatabaseColumn for EntityEditionSearch {
+    fn parameter_type(self) -> ParameterType {
+        match self {
+            Self::EditionId => ParameterType::Uuid,
+            Self::Config | Self::SearchVector => ParameterType::Text,
+        }
+    }
+
+    fn nullable(self) -> bool {
+        false
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::EditionId => "entity_edition_id",
+            Self::Config => "config",
+            Self::SearchVector => "search_vector",
+        }
+    }
+}
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
 pub enum EntityEditions {
     EditionId,
@@ -1410,6 +1458,7 @@ pub enum Column {
     FirstTitleForEntity(TypeTitleForEntity),
     LastTitleForEntity(TypeTitleForEntity),
     EntityEmbeddings(EntityEmbeddings),
+    EntityEditionSearch(EntityEditionSearch),
     PropertyTypeConstrainsValuesOn(PropertyTypeConstrainsValuesOn),
     PropertyTypeConstrainsPropertiesOn(PropertyTypeConstrainsPropertiesOn),
     EntityTypeConstrainsPropertiesOn(EntityTypeConstrainsPropertiesOn, Option<u32>),
@@ -1512,6 +1561,12 @@ impl From<EntityEmbeddings> for Column {
     }
 }
 
+impl From<EntityEditionSearch> for Column {
+    fn from(column: EntityEditionSearch) -> Self {
+        Self::EntityEditionSearch(column)
+    }
+}
+
 impl From<PropertyTypeConstrainsValuesOn> for Column {
     fn from(column: PropertyTypeConstrainsValuesOn) -> Self {
         Self::PropertyTypeConstrainsValuesOn(column)
@@ -1596,6 +1651,7 @@ impl Column {
             Self::FirstTitleForEntity(_) => Table::FirstTitleForEntity,
             Self::LastTitleForEntity(_) => Table::LastTitleForEntity,
             Self::EntityEmbeddings(_) => Table::EntityEmbeddings,
+            Self::EntityEditionSearch(_) => Table::EntityEditionSearch,
             Self::DataTypeInheritsFrom(_, inheritance_depth) => {
                 Table::Reference(ReferenceTable::DataTypeInheritsFrom { inheritance_depth })
             }
@@ -1677,6 +1733,7 @@ impl DatabaseColumn for Column {
                 column.parameter_type()
             }
             Self::EntityEmbeddings(column) => column.parameter_type(),
+            Self::EntityEditionSearch(column) => column.parameter_type(),
             Self::PropertyTypeConstrainsValuesOn(column) => column.parameter_type(),
             Self::PropertyTypeConstrainsPropertiesOn(column) => column.parameter_type(),
             Self::EntityTypeConstrainsPropertiesOn(column, _) => column.parameter_type(),
@@ -1716,6 +1773,7 @@ impl DatabaseColumn for Column {
                 column.nullable()
             }
             Self::EntityEmbeddings(column) => column.nullable(),
+            Self::EntityEditionSearch(column) => column.nullable(),
             Self::PropertyTypeConstrainsValuesOn(column) => column.nullable(),
             Self::PropertyTypeConstrainsPropertiesOn(column) => column.nullable(),
             Self::EntityTypeConstrainsPropertiesOn(column, _) => column.nullable(),
@@ -1751,6 +1809,7 @@ impl DatabaseColumn for Column {
             Self::FirstLabelForEntity(column) | Self::LastLabelForEntity(column) => column.as_str(),
             Self::FirstTitleForEntity(column) | Self::LastTitleForEntity(column) => column.as_str(),
             Self::EntityEmbeddings(column) => column.as_str(),
+            Self::EntityEditionSearch(column) => column.as_str(),
             Self::PropertyTypeConstrainsValuesOn(column) => column.as_str(),
             Self::PropertyTypeConstrainsPropertiesOn(column) => column.as_str(),
             Self::EntityTypeConstrainsPropertiesOn(column, _) => column.as_str(),
@@ -1858,6 +1917,7 @@ pub enum Relation {
     PropertyTypeEmbeddings,
     EntityTypeEmbeddings,
     EntityEmbeddings,
+    EntityEditionSearch,
     LeftEntity,
     RightEntity,
     Reference {
@@ -2074,6 +2134,13 @@ impl Relation {
                 ],
                 join_type: JoinType::LeftOuter,
             }),
+            Self::EntityEditionSearch => {
+                ForeignKeyJoin::from_reference(ForeignKeyReference::Single {
+                    on: Column::EntityTemporalMetadata(EntityTemporalMetadata::EditionId),
+                    join: Column::EntityEditionSearch(EntityEditionSearch::EditionId),
+                    join_type: JoinType::LeftOuter,
+                })
+            }
             Self::LeftEntity => ForeignKeyJoin::from_reference(ForeignKeyReference::Double {
                 on: [
                     Column::EntityTemporalMetadata(EntityTemporalMetadata::WebId),
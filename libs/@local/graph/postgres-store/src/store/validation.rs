@@ -19,12 +19,13 @@ use hash_graph_store::{
     },
 };
 use hash_graph_types::ontology::{DataTypeLookup, OntologyTypeProvider};
-use hash_graph_validation::EntityProvider;
+use hash_graph_validation::{ConflictingEntities, EntityProvider};
+use postgres_types::Json;
 use tokio::sync::RwLock;
 use tokio_postgres::GenericClient as _;
 use type_system::{
     Valid,
-    knowledge::{Entity, entity::EntityId},
+    knowledge::{Entity, entity::EntityId, property::Property},
     ontology::{
         BaseUrl, DataTypeWithMetadata, EntityTypeWithMetadata, PropertyTypeWithMetadata,
         VersionedUrl,
@@ -547,4 +548,112 @@ where
             .await?;
         Ok(self.cache.entities.grant(entity_id, entity).await)
     }
+
+    #[expect(refining_impl_trait)]
+    async fn provide_entities_with_property_value(
+        &self,
+        entity_id: EntityId,
+        entity_type_id: &VersionedUrl,
+        property: &BaseUrl,
+        value: &Property,
+    ) -> Result<ConflictingEntities, Report<QueryError>> {
+        let client = self.store.as_client();
+
+        // Concurrent transactions inserting the same value would not see each other's entities,
+        // so the check is serialized per web, entity type, property, and value until the end of
+        // the transaction.
+        client
+            .execute(
+                "
+                    SELECT pg_advisory_xact_lock(hashtextextended(
+                        $1::uuid::text || $2::text || $3::text || $4::jsonb::text, 0
+                    ));
+                ",
+                &[
+                    &entity_id.owned_by_id,
+                    &entity_type_id.base_url.as_str(),
+                    &property.as_str(),
+                    &Json(value),
+                ],
+            )
+            .await
+            .change_context(QueryError)?;
+
+        // `@>` is supported by the index on the properties, `->` compares the values exactly, so
+        // e.g. an array value does not match an array containing it.
+        let conflicting_entities = client
+            .query(
+                "
+                    SELECT DISTINCT entity_temporal_metadata.entity_uuid
+                      FROM entity_temporal_metadata
+                      JOIN entity_editions
+                        ON entity_editions.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                      JOIN entity_is_of_type
+                        ON entity_is_of_type.entity_edition_id
+                         = entity_temporal_metadata.entity_edition_id
+                      JOIN ontology_ids
+                        ON ontology_ids.ontology_id = entity_is_of_type.entity_type_ontology_id
+                     WHERE entity_temporal_metadata.web_id = $1
+                       AND entity_temporal_metadata.entity_uuid != $2
+                       AND entity_temporal_metadata.draft_id IS NULL
+                       AND upper_inf(entity_temporal_metadata.transaction_time)
+                       AND upper_inf(entity_temporal_metadata.decision_time)
+                       AND ontology_ids.base_url = $3
+                       AND NOT entity_editions.archived
+                       AND entity_editions.properties @> jsonb_build_object($4::text, $5::jsonb)
+                       AND entity_editions.properties -> $4 = $5::jsonb;
+                ",
+                &[
+                    &entity_id.owned_by_id,
+                    &entity_id.entity_uuid,
+                    &entity_type_id.base_url.as_str(),
+                    &property.as_str(),
+                    &Json(value),
+                ],
+            )
+            .await
+            .change_context(QueryError)?
+            .into_iter()
+            .map(|row| EntityId {
+                owned_by_id: entity_id.owned_by_id,
+                entity_uuid: row.get(0),
+                draft_id: None,
+            })
+            .collect::<Vec<_>>();
+
+        let Some((actor_id, consistency)) = self.authorization else {
+            return Ok(ConflictingEntities {
+                visible: conflicting_entities,
+                hidden: 0,
+            });
+        };
+        if conflicting_entities.is_empty() {
+            return Ok(ConflictingEntities::default());
+        }
+
+        let (permissions, _) = self
+            .store
+            .authorization_api
+            .check_entities_permission(
+                actor_id,
+                EntityPermission::View,
+                conflicting_entities.iter().copied(),
+                consistency,
+            )
+            .await
+            .change_context(QueryError)?;
+
+        let (visible, hidden): (Vec<_>, Vec<_>) =
+            conflicting_entities.into_iter().partition(|entity_id| {
+                permissions
+                    .get(&entity_id.entity_uuid)
+                    .copied()
+                    .unwrap_or(false)
+            });
+        Ok(ConflictingEntities {
+            visible,
+            hidden: hidden.len(),
+        })
+    }
 }
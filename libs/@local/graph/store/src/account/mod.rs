@@ -53,6 +53,19 @@ pub struct InsertWebIdParams {
     pub owner: WebOwnerSubject,
 }
 
+#[derive(Debug, Error)]
+#[error("Could not update web")]
+pub struct WebUpdateError;
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpdateWebTextSearchConfigParams {
+    pub owned_by_id: OwnedById,
+    /// The name of the text search configuration, e.g. `english` or `simple`.
+    pub config: String,
+}
+
 #[derive(Debug, Error)]
 #[error("Could not query web")]
 pub struct QueryWebError;
@@ -92,6 +105,21 @@ pub trait AccountStore {
         params: InsertWebIdParams,
     ) -> impl Future<Output = Result<(), Report<WebInsertionError>>> + Send;
 
+    /// Sets the text search configuration used for full-text search on the entities of a web.
+    ///
+    /// The search vectors of the entities already stored in the web are rebuilt with the new
+    /// configuration.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to change the web
+    /// - if the text search configuration does not exist
+    fn update_web_text_search_config(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: UpdateWebTextSearchConfigParams,
+    ) -> impl Future<Output = Result<(), Report<WebUpdateError>>> + Send;
+
     /// Returns either an [`ActorEntityUuid`] or an [`ActorGroupId`] for the specified
     /// [`OwnedById`].
     ///
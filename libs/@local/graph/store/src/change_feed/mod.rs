@@ -0,0 +1,219 @@
+//! Feed of the changes made to the graph in transaction-time order.
+//!
+//! The feed is derived from the temporal metadata of entities and ontology types. Every change is
+//! associated with a [`ChangeFeedCursor`], which can be used to resume reading the feed after that
+//! change.
+
+use error_stack::Report;
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
+use serde::{Deserialize, Serialize};
+use type_system::{
+    knowledge::entity::id::{EntityEditionId, EntityId},
+    ontology::id::{OntologyTypeUuid, VersionedUrl},
+    provenance::ActorEntityUuid,
+};
+use uuid::Uuid;
+
+use crate::error::QueryError;
+
+/// Position of a change in the change feed.
+///
+/// Changes are ordered by the transaction time at which they happened. Changes which happened at
+/// the same transaction time are ordered by their kind and the ID of the changed record.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ChangeFeedCursor {
+    pub transaction_time: Timestamp<TransactionTime>,
+    pub sequence: i16,
+    pub id: Uuid,
+}
+
+/// The kind of an ontology type in the change feed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum OntologyTypeKind {
+    DataType,
+    PropertyType,
+    EntityType,
+}
+
+/// A single change to the graph.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Change {
+    /// A new edition of an entity was inserted.
+    #[serde(rename_all = "camelCase")]
+    EntityEditionCreated {
+        entity_id: EntityId,
+        entity_edition_id: EntityEditionId,
+    },
+    /// An entity was archived by inserting an archived edition.
+    #[serde(rename_all = "camelCase")]
+    EntityArchived {
+        entity_id: EntityId,
+        entity_edition_id: EntityEditionId,
+    },
+    /// A new ontology type was created.
+    #[serde(rename_all = "camelCase")]
+    OntologyTypeCreated {
+        ontology_type: OntologyTypeKind,
+        ontology_id: OntologyTypeUuid,
+        ontology_type_id: VersionedUrl,
+    },
+    /// A previously archived ontology type was unarchived.
+    #[serde(rename_all = "camelCase")]
+    OntologyTypeUnarchived {
+        ontology_type: OntologyTypeKind,
+        ontology_id: OntologyTypeUuid,
+        ontology_type_id: VersionedUrl,
+    },
+    /// An ontology type was archived.
+    #[serde(rename_all = "camelCase")]
+    OntologyTypeArchived {
+        ontology_type: OntologyTypeKind,
+        ontology_id: OntologyTypeUuid,
+        ontology_type_id: VersionedUrl,
+    },
+}
+
+impl Change {
+    /// Returns the ID of the changed entity, if an entity was changed.
+    #[must_use]
+    pub const fn entity_id(&self) -> Option<EntityId> {
+        match self {
+            Self::EntityEditionCreated { entity_id, .. }
+            | Self::EntityArchived { entity_id, .. } => Some(*entity_id),
+            Self::OntologyTypeCreated { .. }
+            | Self::OntologyTypeUnarchived { .. }
+            | Self::OntologyTypeArchived { .. } => None,
+        }
+    }
+
+    /// Returns the kind and the ID of the changed ontology type, if an ontology type was changed.
+    #[must_use]
+    pub const fn ontology_type(&self) -> Option<(OntologyTypeKind, OntologyTypeUuid)> {
+        match self {
+            Self::EntityEditionCreated { .. } | Self::EntityArchived { .. } => None,
+            Self::OntologyTypeCreated {
+                ontology_type,
+                ontology_id,
+                ..
+            }
+            | Self::OntologyTypeUnarchived {
+                ontology_type,
+                ontology_id,
+                ..
+            }
+            | Self::OntologyTypeArchived {
+                ontology_type,
+                ontology_id,
+                ..
+            } => Some((*ontology_type, *ontology_id)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeFeedRecord {
+    pub cursor: ChangeFeedCursor,
+    pub change: Change,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ReadChangesParams {
+    /// Only changes after this cursor are returned. If omitted, the feed is read from the start.
+    #[serde(default)]
+    pub after: Option<ChangeFeedCursor>,
+    /// Only changes before this transaction time are returned.
+    #[serde(default)]
+    pub before: Option<Timestamp<TransactionTime>>,
+    /// The maximum number of changes to return.
+    ///
+    /// Limits above 1000 are reduced to 1000, `hasMore` indicates if there are more changes to
+    /// read.
+    pub limit: usize,
+}
+
+impl ReadChangesParams {
+    /// The maximum number of changes returned by a single read.
+    pub const MAX_LIMIT: usize = 1000;
+
+    /// The number of changes to read, which is [`limit`] capped at [`MAX_LIMIT`].
+    ///
+    /// [`limit`]: Self::limit
+    /// [`MAX_LIMIT`]: Self::MAX_LIMIT
+    #[must_use]
+    pub const fn capped_limit(&self) -> usize {
+        if self.limit > Self::MAX_LIMIT {
+            Self::MAX_LIMIT
+        } else {
+            self.limit
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct ReadChangesResponse {
+    /// The changes the actor is allowed to view.
+    pub changes: Vec<ChangeFeedRecord>,
+    /// The cursor to continue reading the feed at.
+    ///
+    /// Changes the actor is not allowed to view are skipped, so this may be after the last
+    /// returned change. If no change was read, this is the cursor the read started at.
+    pub cursor: Option<ChangeFeedCursor>,
+    /// `true` if the feed contained more changes than requested.
+    pub has_more: bool,
+}
+
+/// Describes the API of a store implementation to read the change feed.
+pub trait ChangeFeedStore {
+    /// Reads the changes made to the graph after the specified cursor.
+    ///
+    /// Changes are returned in transaction-time order. As the transaction time is set when the
+    /// transaction starts, a change may become visible after changes with a later transaction
+    /// time were read. Consumers which must not miss a change should therefore only read changes
+    /// which are older than the longest expected transaction by setting
+    /// [`ReadChangesParams::before`].
+    ///
+    /// # Errors
+    ///
+    /// - if reading the changes failed
+    fn read_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: ReadChangesParams,
+    ) -> impl Future<Output = Result<ReadChangesResponse, Report<QueryError>>> + Send;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ReadChangesParams;
+
+    #[test]
+    fn limit_is_capped() {
+        let params = |limit| ReadChangesParams {
+            after: None,
+            before: None,
+            limit,
+        };
+
+        assert_eq!(params(10).capped_limit(), 10);
+        assert_eq!(
+            params(ReadChangesParams::MAX_LIMIT).capped_limit(),
+            ReadChangesParams::MAX_LIMIT
+        );
+        assert_eq!(
+            params(usize::MAX).capped_limit(),
+            ReadChangesParams::MAX_LIMIT
+        );
+    }
+}
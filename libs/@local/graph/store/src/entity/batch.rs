@@ -0,0 +1,363 @@
+use std::collections::HashSet;
+
+use error_stack::Report;
+use hash_graph_authorization::{
+    backend::ModifyRelationshipOperation, schema::EntityRelationAndSubject,
+};
+use hash_graph_temporal_versioning::{DecisionTime, Timestamp};
+use serde::{Deserialize, Serialize};
+use type_system::{
+    knowledge::{
+        Confidence,
+        entity::{
+            Entity, LinkData,
+            id::{EntityEditionId, EntityId, EntityUuid},
+            provenance::ProvidedEntityEditionProvenance,
+        },
+        property::{
+            PropertyObjectWithMetadata, PropertyPatchOperation, metadata::PropertyProvenance,
+        },
+    },
+    ontology::VersionedUrl,
+    web::OwnedById,
+};
+
+use crate::entity::{CreateEntityParams, PatchEntityParams};
+
+/// The reference of a batch operation could not be resolved.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
+#[display("operation {operation} does not refer to an entity created earlier in the batch")]
+#[must_use]
+pub struct InvalidEntityBatchReference {
+    pub operation: usize,
+}
+
+/// Refers to an entity from within an [`EntityBatchOperation`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(untagged)]
+pub enum EntityBatchReference {
+    /// An entity which already exists.
+    Id(EntityId),
+    /// The entity created by an earlier operation of the same batch.
+    Operation { operation: usize },
+}
+
+impl EntityBatchReference {
+    /// Resolves the reference to the [`EntityId`] it refers to.
+    ///
+    /// `created` contains the [`EntityId`] of every operation executed so far, or `None` if the
+    /// operation did not create an entity.
+    ///
+    /// # Errors
+    ///
+    /// - if the referenced operation has not been executed yet or did not create an entity
+    pub fn resolve(
+        self,
+        created: &[Option<EntityId>],
+    ) -> Result<EntityId, Report<InvalidEntityBatchReference>> {
+        match self {
+            Self::Id(entity_id) => Ok(entity_id),
+            Self::Operation { operation } => created
+                .get(operation)
+                .copied()
+                .flatten()
+                .ok_or_else(|| Report::new(InvalidEntityBatchReference { operation })),
+        }
+    }
+}
+
+/// [`LinkData`] which may refer to entities created earlier in the batch.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityBatchLinkData {
+    pub left_entity_id: EntityBatchReference,
+    pub right_entity_id: EntityBatchReference,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default)]
+    pub left_entity_confidence: Option<Confidence>,
+    #[serde(default)]
+    pub left_entity_provenance: PropertyProvenance,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default)]
+    pub right_entity_confidence: Option<Confidence>,
+    #[serde(default)]
+    pub right_entity_provenance: PropertyProvenance,
+}
+
+impl EntityBatchLinkData {
+    /// Resolves the referenced entities to [`LinkData`].
+    ///
+    /// # Errors
+    ///
+    /// - if any of the references could not be resolved
+    pub fn resolve(
+        self,
+        created: &[Option<EntityId>],
+    ) -> Result<LinkData, Report<InvalidEntityBatchReference>> {
+        Ok(LinkData {
+            left_entity_id: self.left_entity_id.resolve(created)?,
+            right_entity_id: self.right_entity_id.resolve(created)?,
+            left_entity_confidence: self.left_entity_confidence,
+            left_entity_provenance: self.left_entity_provenance,
+            right_entity_confidence: self.right_entity_confidence,
+            right_entity_provenance: self.right_entity_provenance,
+        })
+    }
+}
+
+/// Creates an entity, see [`CreateEntityParams`].
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct CreateEntityBatchOperation {
+    pub owned_by_id: OwnedById,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub entity_uuid: Option<EntityUuid>,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub decision_time: Option<Timestamp<DecisionTime>>,
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<VersionedUrl>))]
+    pub entity_type_ids: HashSet<VersionedUrl>,
+    pub properties: PropertyObjectWithMetadata,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default)]
+    pub confidence: Option<Confidence>,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub link_data: Option<EntityBatchLinkData>,
+    pub draft: bool,
+    pub relationships: Vec<EntityRelationAndSubject>,
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+impl CreateEntityBatchOperation {
+    /// Resolves the referenced entities to [`CreateEntityParams`].
+    ///
+    /// # Errors
+    ///
+    /// - if any of the references could not be resolved
+    pub fn resolve(
+        self,
+        created: &[Option<EntityId>],
+    ) -> Result<
+        CreateEntityParams<Vec<EntityRelationAndSubject>>,
+        Report<InvalidEntityBatchReference>,
+    > {
+        Ok(CreateEntityParams {
+            owned_by_id: self.owned_by_id,
+            entity_uuid: self.entity_uuid,
+            decision_time: self.decision_time,
+            entity_type_ids: self.entity_type_ids,
+            properties: self.properties,
+            confidence: self.confidence,
+            link_data: self
+                .link_data
+                .map(|link_data| link_data.resolve(created))
+                .transpose()?,
+            draft: self.draft,
+            relationships: self.relationships,
+            provenance: self.provenance,
+        })
+    }
+}
+
+/// Patches an entity, see [`PatchEntityParams`].
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct PatchEntityBatchOperation {
+    pub entity_id: EntityBatchReference,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub decision_time: Option<Timestamp<DecisionTime>>,
+    /// If provided, the patch is only applied if this is the current edition of the entity.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub expected_edition_id: Option<EntityEditionId>,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<VersionedUrl>))]
+    pub entity_type_ids: HashSet<VersionedUrl>,
+    #[serde(default)]
+    pub properties: Vec<PropertyPatchOperation>,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub draft: Option<bool>,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub confidence: Option<Confidence>,
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+impl PatchEntityBatchOperation {
+    /// Resolves the referenced entity to [`PatchEntityParams`].
+    ///
+    /// # Errors
+    ///
+    /// - if the reference could not be resolved
+    pub fn resolve(
+        self,
+        created: &[Option<EntityId>],
+    ) -> Result<PatchEntityParams, Report<InvalidEntityBatchReference>> {
+        Ok(PatchEntityParams {
+            entity_id: self.entity_id.resolve(created)?,
+            decision_time: self.decision_time,
+            expected_edition_id: self.expected_edition_id,
+            entity_type_ids: self.entity_type_ids,
+            properties: self.properties,
+            draft: self.draft,
+            archived: None,
+            confidence: self.confidence,
+            provenance: self.provenance,
+        })
+    }
+}
+
+/// Archives an entity.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ArchiveEntityBatchOperation {
+    pub entity_id: EntityBatchReference,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub decision_time: Option<Timestamp<DecisionTime>>,
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+impl ArchiveEntityBatchOperation {
+    /// Resolves the referenced entity to [`PatchEntityParams`] archiving the entity.
+    ///
+    /// # Errors
+    ///
+    /// - if the reference could not be resolved
+    pub fn resolve(
+        self,
+        created: &[Option<EntityId>],
+    ) -> Result<PatchEntityParams, Report<InvalidEntityBatchReference>> {
+        Ok(PatchEntityParams {
+            entity_id: self.entity_id.resolve(created)?,
+            decision_time: self.decision_time,
+            expected_edition_id: None,
+            entity_type_ids: HashSet::new(),
+            properties: Vec::new(),
+            draft: None,
+            archived: Some(true),
+            confidence: None,
+            provenance: self.provenance,
+        })
+    }
+}
+
+/// Modifies an authorization relationship of an entity.
+///
+/// Requires the actor to have [`Update`] permission on the entity.
+///
+/// [`Update`]: hash_graph_authorization::schema::EntityPermission::Update
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModifyEntityRelationshipBatchOperation {
+    pub operation: ModifyRelationshipOperation,
+    pub resource: EntityBatchReference,
+    pub relation_subject: EntityRelationAndSubject,
+}
+
+/// A single operation of an entity batch.
+///
+/// Later operations can refer to entities created by earlier operations using
+/// [`EntityBatchReference::Operation`].
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum EntityBatchOperation {
+    CreateEntity(Box<CreateEntityBatchOperation>),
+    PatchEntity(PatchEntityBatchOperation),
+    ArchiveEntity(ArchiveEntityBatchOperation),
+    ModifyRelationship(ModifyEntityRelationshipBatchOperation),
+}
+
+/// The result of a single [`EntityBatchOperation`].
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum EntityBatchOperationResult {
+    CreateEntity { entity: Entity },
+    PatchEntity { entity: Entity },
+    ArchiveEntity { entity: Entity },
+    ModifyRelationship,
+}
+
+impl EntityBatchOperationResult {
+    /// The entity created by the operation, if any.
+    #[must_use]
+    pub const fn created_entity_id(&self) -> Option<EntityId> {
+        match self {
+            Self::CreateEntity { entity } => Some(entity.metadata.record_id.entity_id),
+            Self::PatchEntity { .. } | Self::ArchiveEntity { .. } | Self::ModifyRelationship => {
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use type_system::{
+        knowledge::entity::id::{EntityId, EntityUuid},
+        web::OwnedById,
+    };
+    use uuid::Uuid;
+
+    use super::{EntityBatchReference, InvalidEntityBatchReference};
+
+    #[test]
+    fn deserialize_reference() {
+        let entity_id = EntityId {
+            owned_by_id: OwnedById::new(Uuid::new_v4()),
+            entity_uuid: EntityUuid::new(Uuid::new_v4()),
+            draft_id: None,
+        };
+
+        assert_eq!(
+            serde_json::from_value::<EntityBatchReference>(serde_json::json!(
+                entity_id.to_string()
+            ))
+            .expect("should deserialize entity id"),
+            EntityBatchReference::Id(entity_id)
+        );
+        assert_eq!(
+            serde_json::from_value::<EntityBatchReference>(serde_json::json!({ "operation": 2 }))
+                .expect("should deserialize operation reference"),
+            EntityBatchReference::Operation { operation: 2 }
+        );
+    }
+
+    #[test]
+    fn resolve_reference() {
+        let entity_id = EntityId {
+            owned_by_id: OwnedById::new(Uuid::new_v4()),
+            entity_uuid: EntityUuid::new(Uuid::new_v4()),
+            draft_id: None,
+        };
+        let created = [Some(entity_id), None];
+
+        assert_eq!(
+            EntityBatchReference::Operation { operation: 0 }
+                .resolve(&created)
+                .expect("should resolve reference"),
+            entity_id
+        );
+        for operation in [1, 2] {
+            let report = EntityBatchReference::Operation { operation }
+                .resolve(&created)
+                .expect_err("should not resolve reference");
+            assert_eq!(
+                *report.current_context(),
+                InvalidEntityBatchReference { operation }
+            );
+        }
+    }
+}
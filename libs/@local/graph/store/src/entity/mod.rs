@@ -1,26 +1,39 @@
 #[cfg(feature = "utoipa")]
 pub use self::store::CreateEntityRequest;
 pub use self::{
+    batch::{
+        ArchiveEntityBatchOperation, CreateEntityBatchOperation, EntityBatchLinkData,
+        EntityBatchOperation, EntityBatchOperationResult, EntityBatchReference,
+        InvalidEntityBatchReference, ModifyEntityRelationshipBatchOperation,
+        PatchEntityBatchOperation,
+    },
     query::{
         EntityQueryCursor, EntityQueryPath, EntityQuerySorting, EntityQuerySortingRecord,
         EntityQuerySortingToken, EntityQueryToken,
     },
     store::{
-        ClosedMultiEntityTypeMap, CountEntitiesParams, CreateEntityParams, DiffEntityParams,
-        DiffEntityResult, EntityStore, EntityValidationType, GetEntitiesParams,
-        GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse, PatchEntityParams,
-        QueryConversion, UpdateEntityEmbeddingsParams, ValidateEntityComponents,
-        ValidateEntityError, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, ClosedMultiEntityTypeMap,
+        CountEntitiesParams, CreateEntityParams, DiffEntityParams, DiffEntityResult,
+        EntityAggregation, EntityAggregationGroup, EntityHistoryEdition, EntityPath, EntityPathHop,
+        EntityStore, EntityTombstone, EntityUpgradeFailure, EntityValidationType,
+        EraseEntityParams, FindEntityPathsParams, FindEntityPathsResponse, GetEntitiesParams,
+        GetEntitiesResponse, GetEntityHistoryParams, GetEntityHistoryResponse,
+        GetEntitySubgraphParams, GetEntitySubgraphResponse, LinkDirection, MergeEntitiesParams,
+        MergeEntitiesResponse, PatchEntityParams, PropertyMergeStrategy, PropertyUpgradeOperation,
+        QueryConversion, UpdateEntityEmbeddingsParams, UpgradeEntityTypeParams,
+        UpgradeEntityTypeResponse, ValidateEntityComponents, ValidateEntityError,
+        ValidateEntityParams,
     },
     validation_report::{
-        EmptyEntityTypes, EntityRetrieval, EntityTypeRetrieval, EntityTypesError,
-        EntityValidationReport, LinkDataStateError, LinkDataValidationReport, LinkError,
-        LinkTargetError, LinkValidationReport, LinkedEntityError, MetadataValidationReport,
-        MissingLinkData, PropertyMetadataValidationReport, UnexpectedEntityType,
-        UnexpectedLinkData,
+        DuplicatePropertyValue, EmptyEntityTypes, EntityRetrieval, EntityTypeRetrieval,
+        EntityTypesError, EntityValidationReport, LinkDataStateError, LinkDataValidationReport,
+        LinkError, LinkTargetError, LinkValidationReport, LinkedEntityError,
+        MetadataValidationReport, MissingLinkData, PropertyMetadataValidationReport,
+        UnexpectedEntityType, UnexpectedLinkData, UniquePropertyError, UniquePropertyRetrieval,
     },
 };
 
+mod batch;
 mod query;
 mod store;
 mod validation_report;
@@ -459,6 +459,32 @@ pub enum EntityQueryPath<'p> {
     /// # Ok::<(), serde_json::Error>(())
     /// ```
     Embedding,
+    /// The full-text search vector of the string values in the properties of the [`Entity`].
+    ///
+    /// The vector is built with the text search configuration of the web the [`Entity`] belongs
+    /// to and can be matched with [`Filter::FullTextMatch`].
+    ///
+    /// Deserializes from `["searchVector"]`:
+    ///
+    /// ```rust
+    /// # use serde::Deserialize;
+    /// # use serde_json::json;
+    /// # use hash_graph_store::entity::EntityQueryPath;
+    /// let path = EntityQueryPath::deserialize(json!(["searchVector"]))?;
+    /// assert_eq!(path, EntityQueryPath::SearchVector);
+    /// # Ok::<(), serde_json::Error>(())
+    /// ```
+    ///
+    /// [`Entity`]: type_system::knowledge::Entity
+    /// [`Filter::FullTextMatch`]: crate::filter::Filter::FullTextMatch
+    SearchVector,
+    /// The relevance of the [`Entity`] for the contained web search query.
+    ///
+    /// It's currently not possible to query for the relevance directly, it can only be used for
+    /// sorting.
+    ///
+    /// [`Entity`]: type_system::knowledge::Entity
+    SearchRank(Cow<'p, str>),
     /// Corresponds to the title of the [`Entity`]'s first [`EntityType`].
     ///
     /// It's currently not possible to query for the first title directly.
@@ -511,6 +537,8 @@ impl fmt::Display for EntityQueryPath<'_> {
             Self::PropertyMetadata(Some(path)) => write!(fmt, "propertyMetadata.{path}"),
             Self::PropertyMetadata(None) => fmt.write_str("propertyMetadata"),
             Self::Embedding => fmt.write_str("embedding"),
+            Self::SearchVector => fmt.write_str("searchVector"),
+            Self::SearchRank(_) => fmt.write_str("searchRank"),
             Self::EntityTypeEdge {
                 edge_kind: SharedEdgeKind::IsOfType,
                 path,
@@ -574,6 +602,8 @@ impl QueryPath for EntityQueryPath<'_> {
                 ParameterType::Decimal
             }
             Self::Embedding => ParameterType::Vector(Box::new(ParameterType::Decimal)),
+            Self::SearchVector => ParameterType::Text,
+            Self::SearchRank(_) => ParameterType::Decimal,
             Self::Archived => ParameterType::Boolean,
             Self::EntityTypeEdge { path, .. } => path.expected_type(),
             Self::EntityEdge { path, .. } => path.expected_type(),
@@ -601,6 +631,7 @@ pub enum EntityQueryToken {
     Provenance,
     EditionProvenance,
     Embedding,
+    SearchVector,
     IncomingLinks,
     OutgoingLinks,
     LeftEntity,
@@ -616,8 +647,8 @@ pub(crate) struct EntityQueryPathVisitor {
 impl EntityQueryPathVisitor {
     pub(crate) const EXPECTING: &'static str =
         "one of `uuid`, `editionId`, `draftId`, `archived`, `ownedById`, `type`, `properties`, \
-         `label`, `provenance`, `editionProvenance`, `embedding`, `incomingLinks`, \
-         `outgoingLinks`, `leftEntity`, `rightEntity`";
+         `label`, `provenance`, `editionProvenance`, `embedding`, `searchVector`, \
+         `incomingLinks`, `outgoingLinks`, `leftEntity`, `rightEntity`";
 
     #[must_use]
     pub(crate) const fn new(position: usize) -> Self {
@@ -729,6 +760,7 @@ impl<'de> Visitor<'de> for EntityQueryPathVisitor {
             EntityQueryToken::DraftId => EntityQueryPath::DraftId,
             EntityQueryToken::Archived => EntityQueryPath::Archived,
             EntityQueryToken::Embedding => EntityQueryPath::Embedding,
+            EntityQueryToken::SearchVector => EntityQueryPath::SearchVector,
             EntityQueryToken::Type => EntityQueryPath::EntityTypeEdge {
                 edge_kind: SharedEdgeKind::IsOfType,
                 path: EntityTypeQueryPathVisitor::new(self.position).visit_seq(seq)?,
@@ -832,6 +864,7 @@ pub enum EntityQuerySortingToken {
     CreatedAtTransactionTime,
     CreatedAtDecisionTime,
     TypeTitle,
+    SearchRank,
 }
 
 /// Deserializes an [`EntityQueryPath`] from a string sequence.
@@ -844,7 +877,7 @@ impl EntityQuerySortingVisitor {
     pub(crate) const EXPECTING: &'static str =
         "one of `uuid`, `archived`, `properties`, `label`, `recordCreatedAtTransactionTime`, \
          `recordCreatedAtDecisionTime`, `createdAtTransactionTime`, `createdAtDecisionTime`, \
-         `typeTitle`";
+         `typeTitle`, `searchRank`";
 
     #[must_use]
     pub(crate) const fn new(position: usize) -> Self {
@@ -893,6 +926,10 @@ impl<'de> Visitor<'de> for EntityQuerySortingVisitor {
                 position: self.position,
             }
             .visit_seq(seq)?,
+            EntityQuerySortingToken::SearchRank => EntityQueryPath::SearchRank(
+                seq.next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(self.position, &self))?,
+            ),
         })
     }
 }
@@ -943,6 +980,8 @@ impl<'de: 'p, 'p> EntityQueryPath<'p> {
             Self::Properties(path) => EntityQueryPath::Properties(path.map(JsonPath::into_owned)),
             Self::Label { inheritance_depth } => EntityQueryPath::Label { inheritance_depth },
             Self::Embedding => EntityQueryPath::Embedding,
+            Self::SearchVector => EntityQueryPath::SearchVector,
+            Self::SearchRank(query) => EntityQueryPath::SearchRank(Cow::Owned(query.into_owned())),
             Self::EntityConfidence => EntityQueryPath::EntityConfidence,
             Self::LeftEntityConfidence => EntityQueryPath::LeftEntityConfidence,
             Self::LeftEntityProvenance => EntityQueryPath::LeftEntityProvenance,
@@ -4,7 +4,9 @@ use std::collections::{HashMap, HashSet};
 use error_stack::Report;
 use futures::TryFutureExt as _;
 use hash_graph_authorization::{schema::EntityRelationAndSubject, zanzibar::Consistency};
-use hash_graph_temporal_versioning::{DecisionTime, Timestamp, TransactionTime};
+use hash_graph_temporal_versioning::{
+    DecisionTime, RightBoundedTemporalInterval, Timestamp, TransactionTime,
+};
 use hash_graph_types::knowledge::entity::EntityEmbedding;
 use serde::{Deserialize, Serialize};
 use type_system::{
@@ -12,15 +14,16 @@ use type_system::{
         Confidence,
         entity::{
             Entity, LinkData,
-            id::{EntityId, EntityUuid},
-            metadata::EntityTypeIdDiff,
-            provenance::ProvidedEntityEditionProvenance,
+            id::{EntityEditionId, EntityId, EntityUuid},
+            metadata::{EntityTemporalMetadata, EntityTypeIdDiff},
+            provenance::{EntityEditionProvenance, ProvidedEntityEditionProvenance},
         },
         property::{
             PropertyDiff, PropertyObjectWithMetadata, PropertyPatchOperation, PropertyPath,
+            PropertyWithMetadata,
         },
     },
-    ontology::{VersionedUrl, entity_type::ClosedMultiEntityType},
+    ontology::{BaseUrl, VersionedUrl, entity_type::ClosedMultiEntityType},
     provenance::ActorEntityUuid,
     web::OwnedById,
 };
@@ -31,10 +34,14 @@ use utoipa::{
 };
 
 use crate::{
-    entity::{EntityQueryCursor, EntityQuerySorting, EntityValidationReport},
+    entity::{
+        EntityBatchOperation, EntityBatchOperationResult, EntityQueryCursor, EntityQueryPath,
+        EntityQuerySorting, EntityValidationReport,
+    },
     entity_type::{EntityTypeResolveDefinitions, IncludeEntityTypeOption},
-    error::{InsertionError, QueryError, UpdateError},
+    error::{DeletionError, InsertionError, QueryError, UpdateError},
     filter::Filter,
+    query::AggregationFunction,
     subgraph::{Subgraph, edges::GraphResolveDepths, temporal_axes::QueryTemporalAxesUnresolved},
 };
 
@@ -87,6 +94,9 @@ pub struct ValidateEntityComponents {
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     #[serde(default = "default_true")]
     pub link_validation: bool,
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    #[serde(default = "default_true")]
+    pub unique_properties: bool,
 }
 
 impl ValidateEntityComponents {
@@ -97,6 +107,7 @@ impl ValidateEntityComponents {
             required_properties: true,
             num_items: true,
             link_validation: true,
+            unique_properties: true,
         }
     }
 
@@ -105,6 +116,7 @@ impl ValidateEntityComponents {
         Self {
             num_items: false,
             required_properties: false,
+            unique_properties: false,
             ..Self::full()
         }
     }
@@ -277,6 +289,96 @@ pub struct GetEntitySubgraphParams<'a> {
     pub include_type_titles: bool,
 }
 
+/// The direction in which a link entity is followed on an [`EntityPath`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum LinkDirection {
+    /// The link points from the previous entity on the path (its left entity) to the next one
+    /// (its right entity).
+    Outgoing,
+    /// The link points from the next entity on the path (its left entity) to the previous one
+    /// (its right entity).
+    Incoming,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct FindEntityPathsParams {
+    pub source_entity_id: EntityId,
+    pub target_entity_id: EntityId,
+    /// The maximum number of links on a path.
+    ///
+    /// Must not exceed [`Self::MAX_DEPTH`].
+    pub max_depth: u8,
+    /// The maximum number of paths to return.
+    ///
+    /// Paths are returned shortest first, so a limit of `1` returns a single shortest path. Must
+    /// not exceed [`Self::MAX_LIMIT`].
+    #[serde(default = "default_path_limit")]
+    pub limit: usize,
+    /// Only follows link entities of one of these types, including types inheriting from them.
+    ///
+    /// If omitted, link entities of any type are followed.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub link_entity_type_ids: Option<Vec<VersionedUrl>>,
+    /// The directions in which links may be followed.
+    ///
+    /// If omitted, links are followed in both directions.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub directions: Option<Vec<LinkDirection>>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    /// Additionally resolves the entities on the paths to the given depths.
+    #[serde(default)]
+    pub graph_resolve_depths: GraphResolveDepths,
+    #[serde(default)]
+    pub include_drafts: bool,
+}
+
+impl FindEntityPathsParams {
+    /// The highest `max_depth` accepted by the store.
+    pub const MAX_DEPTH: u8 = 8;
+    /// The highest `limit` accepted by the store.
+    pub const MAX_LIMIT: usize = 100;
+}
+
+const fn default_path_limit() -> usize {
+    1
+}
+
+/// A single link on an [`EntityPath`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityPathHop {
+    pub link_entity_id: EntityId,
+    pub direction: LinkDirection,
+    /// The entity reached by following the link.
+    pub entity_id: EntityId,
+}
+
+/// A path from the source entity to the target entity through link entities.
+///
+/// An entity appears at most once on a path.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityPath {
+    pub hops: Vec<EntityPathHop>,
+}
+
+#[derive(Debug)]
+pub struct FindEntityPathsResponse {
+    /// The paths found, shortest first.
+    pub paths: Vec<EntityPath>,
+    /// The subgraph containing the entities and link entities on the paths, rooted at the source
+    /// and target entities.
+    pub subgraph: Subgraph,
+}
+
 #[derive(Debug)]
 pub struct GetEntitySubgraphResponse<'r> {
     pub subgraph: Subgraph,
@@ -301,6 +403,58 @@ pub struct CountEntitiesParams<'a> {
     pub include_drafts: bool,
 }
 
+/// An aggregation which is computed for every group of entities.
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityAggregation<'p> {
+    pub function: AggregationFunction,
+    /// The path to aggregate the values of.
+    ///
+    /// Only [`AggregationFunction::Count`] can be used without a path.
+    #[serde(borrow, default)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Option<EntityQueryPath>))]
+    pub path: Option<EntityQueryPath<'p>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AggregateEntitiesParams<'a> {
+    #[serde(borrow)]
+    pub filter: Filter<'a, Entity>,
+    pub temporal_axes: QueryTemporalAxesUnresolved,
+    pub include_drafts: bool,
+    /// The paths to group the entities by.
+    ///
+    /// If no path is provided, all entities are aggregated into a single group.
+    #[serde(borrow, default)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = Vec<EntityQueryPath>))]
+    pub group_by: Vec<EntityQueryPath<'a>>,
+    #[serde(borrow)]
+    pub aggregations: Vec<EntityAggregation<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityAggregationGroup {
+    /// The values of the group-by paths in the order they were requested.
+    pub key: Vec<serde_json::Value>,
+    /// The results of the aggregations in the order they were requested.
+    ///
+    /// The result is `null` if the group does not contain a value to aggregate.
+    pub values: Vec<Option<f64>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct AggregateEntitiesResponse {
+    /// The groups ordered by their key.
+    pub groups: Vec<EntityAggregationGroup>,
+}
+
 #[derive(Debug, Deserialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
@@ -309,6 +463,10 @@ pub struct PatchEntityParams {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     #[cfg_attr(feature = "utoipa", schema(nullable = false))]
     pub decision_time: Option<Timestamp<DecisionTime>>,
+    /// If provided, the patch is only applied if this is the current edition of the entity.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub expected_edition_id: Option<EntityEditionId>,
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     #[cfg_attr(feature = "utoipa", schema(value_type = Vec<VersionedUrl>))]
     pub entity_type_ids: HashSet<VersionedUrl>,
@@ -370,6 +528,293 @@ pub struct DiffEntityResult<'e> {
     pub draft_state: Option<bool>,
 }
 
+/// Parameters to read the history of an entity.
+///
+/// The history is the sequence of editions as they were recorded in transaction time, every
+/// edition is compared to the edition recorded before it. Decision time is not taken into account,
+/// so an edition which was recorded later but with an earlier decision time is still compared to
+/// the edition recorded directly before it.
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct GetEntityHistoryParams {
+    pub entity_id: EntityId,
+    /// Restricts the history to editions which were recorded during the given transaction time
+    /// interval.
+    ///
+    /// There is no restriction on decision time, the decision time intervals of every returned
+    /// edition are reported in its temporal versioning. If omitted, all editions of the entity are
+    /// returned.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub interval: Option<RightBoundedTemporalInterval<TransactionTime>>,
+}
+
+/// A single edition in the history of an entity.
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityHistoryEdition<'e> {
+    pub edition_id: EntityEditionId,
+    /// The time intervals in which the edition is part of the history of the entity.
+    ///
+    /// An edition may be valid for more than one pair of intervals if a later edition was
+    /// created with a decision time before the decision time of this edition.
+    pub temporal_versioning: Vec<EntityTemporalMetadata>,
+    pub provenance: EntityEditionProvenance,
+    pub archived: bool,
+    /// The changes to the properties compared to the previous edition.
+    ///
+    /// For the first edition of the entity, all properties are reported as added.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub properties: Vec<PropertyDiff<'e>>,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct GetEntityHistoryResponse<'e> {
+    /// The editions ordered by the transaction time they were first recorded at.
+    pub editions: Vec<EntityHistoryEdition<'e>>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EraseEntityParams {
+    /// The entity to erase.
+    ///
+    /// All drafts of the entity are erased as well, so the draft ID is ignored.
+    pub entity_id: EntityId,
+    /// Why the entity is erased, e.g. a reference to the request of the data subject.
+    pub reason: String,
+}
+
+/// The record which is kept after an entity was permanently erased.
+///
+/// If an entity is created again with the same ID and erased again, a record is kept for every
+/// erasure.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EntityTombstone {
+    pub owned_by_id: OwnedById,
+    pub entity_uuid: EntityUuid,
+    pub erased_by_id: ActorEntityUuid,
+    pub erased_at_transaction_time: Timestamp<TransactionTime>,
+    pub reason: String,
+    /// The types the entity had in any of its editions, including the types they inherit from.
+    pub entity_type_ids: Vec<VersionedUrl>,
+}
+
+/// Determines which value is kept when both merged entities have the same property.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum PropertyMergeStrategy {
+    /// Keeps the value of the surviving entity.
+    #[default]
+    KeepTarget,
+    /// Keeps the value of the entity which is merged into the surviving entity.
+    KeepSource,
+    /// Keeps the value with the higher [`Confidence`].
+    ///
+    /// A value without a confidence is considered less confident than any value with a confidence.
+    /// If both values are equally confident, the value of the surviving entity is kept.
+    HigherConfidence,
+}
+
+impl PropertyMergeStrategy {
+    #[must_use]
+    pub fn merge(
+        self,
+        target: PropertyWithMetadata,
+        source: PropertyWithMetadata,
+    ) -> PropertyWithMetadata {
+        const fn confidence(property: &PropertyWithMetadata) -> Option<Confidence> {
+            match property {
+                PropertyWithMetadata::Array(array) => array.metadata.confidence,
+                PropertyWithMetadata::Object(object) => object.metadata.confidence,
+                PropertyWithMetadata::Value(value) => value.metadata.confidence,
+            }
+        }
+
+        match self {
+            Self::KeepTarget => target,
+            Self::KeepSource => source,
+            Self::HigherConfidence => match (confidence(&target), confidence(&source)) {
+                (None, Some(_)) => source,
+                (Some(target_confidence), Some(source_confidence))
+                    if source_confidence > target_confidence =>
+                {
+                    source
+                }
+                _ => target,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct MergeEntitiesParams {
+    /// The entity which survives the merge.
+    pub target_entity_id: EntityId,
+    /// The entity which is merged into the target and archived afterwards.
+    pub source_entity_id: EntityId,
+    /// The strategy used for properties which are not listed in `property_strategies`.
+    #[serde(default)]
+    pub default_strategy: PropertyMergeStrategy,
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(value_type = HashMap<String, PropertyMergeStrategy>))]
+    pub property_strategies: HashMap<BaseUrl, PropertyMergeStrategy>,
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+impl MergeEntitiesParams {
+    /// Merges the properties of the source entity into the properties of the target entity.
+    ///
+    /// Properties which are only set on one of the entities are always kept.
+    #[must_use]
+    pub fn merge_properties(
+        &self,
+        mut target: PropertyObjectWithMetadata,
+        source: PropertyObjectWithMetadata,
+    ) -> PropertyObjectWithMetadata {
+        for (base_url, source_property) in source.value {
+            let strategy = self
+                .property_strategies
+                .get(&base_url)
+                .copied()
+                .unwrap_or(self.default_strategy);
+            let property = match target.value.remove(&base_url) {
+                Some(target_property) => strategy.merge(target_property, source_property),
+                None => source_property,
+            };
+            target.value.insert(base_url, property);
+        }
+        target
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MergeEntitiesResponse {
+    /// The surviving entity after the properties were merged.
+    pub entity: Entity,
+    /// The archived edition of the merged entity.
+    pub merged_entity: Entity,
+    /// The links which replace the links of the merged entity, each followed by its drafts.
+    ///
+    /// Links between the merged entity and the surviving entity are archived without being
+    /// replaced.
+    pub links: Vec<Entity>,
+}
+
+/// A change applied to the properties of an entity when upgrading its entity type.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", tag = "op", deny_unknown_fields)]
+pub enum PropertyUpgradeOperation {
+    /// Moves the property to a different base URL.
+    Rename { from: BaseUrl, to: BaseUrl },
+    /// Removes the property from the entity.
+    Remove { property: BaseUrl },
+    /// Converts the value of the property to the specified data type.
+    ///
+    /// The value is converted using the conversions of the data types when the entity is
+    /// validated. If the property is an array, all values of the array are converted.
+    #[serde(rename_all = "camelCase")]
+    Convert {
+        property: BaseUrl,
+        data_type_id: VersionedUrl,
+    },
+}
+
+impl PropertyUpgradeOperation {
+    pub fn apply(&self, properties: &mut PropertyObjectWithMetadata) {
+        fn convert(property: &mut PropertyWithMetadata, data_type_id: &VersionedUrl) {
+            match property {
+                PropertyWithMetadata::Value(value) => {
+                    value
+                        .metadata
+                        .original_data_type_id
+                        .clone_from(&value.metadata.data_type_id);
+                    value.metadata.data_type_id = Some(data_type_id.clone());
+                }
+                PropertyWithMetadata::Array(array) => {
+                    for element in &mut array.value {
+                        convert(element, data_type_id);
+                    }
+                }
+                PropertyWithMetadata::Object(_) => {}
+            }
+        }
+
+        match self {
+            Self::Rename { from, to } => {
+                if let Some(property) = properties.value.remove(from) {
+                    properties.value.insert(to.clone(), property);
+                }
+            }
+            Self::Remove { property } => {
+                properties.value.remove(property);
+            }
+            Self::Convert {
+                property,
+                data_type_id,
+            } => {
+                if let Some(property) = properties.value.get_mut(property) {
+                    convert(property, data_type_id);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct UpgradeEntityTypeParams {
+    /// The entity type version the entities are currently of.
+    pub source_entity_type_id: VersionedUrl,
+    /// The entity type version the entities are upgraded to.
+    pub target_entity_type_id: VersionedUrl,
+    /// The operations applied to the properties of each entity, in order.
+    #[serde(default)]
+    pub mapping: Vec<PropertyUpgradeOperation>,
+    /// The number of entities which are upgraded in a single transaction.
+    #[serde(default)]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub batch_size: Option<usize>,
+    pub provenance: ProvidedEntityEditionProvenance,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct EntityUpgradeFailure {
+    pub entity_id: EntityId,
+    /// The validation report, if the upgraded entity was not valid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[cfg_attr(feature = "utoipa", schema(nullable = false))]
+    pub validation: Option<EntityValidationReport>,
+    /// A summary of why the entity could not be upgraded.
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct UpgradeEntityTypeResponse {
+    /// The entities which were upgraded to the target entity type.
+    pub upgraded: Vec<EntityId>,
+    /// The entities which could not be upgraded and still are of the source entity type.
+    pub failures: Vec<EntityUpgradeFailure>,
+}
+
 /// Describes the API of a store implementation for [Entities].
 ///
 /// [Entities]: Entity
@@ -458,6 +903,19 @@ pub trait EntityStore {
         params: GetEntitySubgraphParams<'_>,
     ) -> impl Future<Output = Result<GetEntitySubgraphResponse<'static>, Report<QueryError>>> + Send;
 
+    /// Finds paths between two entities through link entities.
+    ///
+    /// Only entities and link entities the actor is allowed to view are traversed.
+    ///
+    /// # Errors
+    ///
+    /// - if the entities on the paths cannot be retrieved
+    fn find_entity_paths(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: FindEntityPathsParams,
+    ) -> impl Future<Output = Result<FindEntityPathsResponse, Report<QueryError>>> + Send;
+
     /// Count the number of entities that would be returned in [`get_entity`].
     ///
     /// # Errors
@@ -471,6 +929,20 @@ pub trait EntityStore {
         params: CountEntitiesParams<'_>,
     ) -> impl Future<Output = Result<usize, Report<QueryError>>> + Send;
 
+    /// Aggregates the entities matching the filter of the [`AggregateEntitiesParams`].
+    ///
+    /// Only entities the actor is allowed to view are taken into account.
+    ///
+    /// # Errors
+    ///
+    /// - if an aggregation requires a path but none was provided
+    /// - if the request to the database fails
+    fn aggregate_entities(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: AggregateEntitiesParams<'_>,
+    ) -> impl Future<Output = Result<AggregateEntitiesResponse, Report<QueryError>>> + Send;
+
     fn get_entity_by_id(
         &self,
         actor_id: ActorEntityUuid,
@@ -485,6 +957,25 @@ pub trait EntityStore {
         params: PatchEntityParams,
     ) -> impl Future<Output = Result<Entity, Report<UpdateError>>> + Send;
 
+    /// Executes the [`EntityBatchOperation`]s in order, with all-or-nothing semantics.
+    ///
+    /// Operations can refer to entities created by earlier operations of the same batch. Either all
+    /// operations are applied or none of them, the returned results are in the same order as the
+    /// operations.
+    ///
+    /// # Errors
+    ///
+    /// - if any of the operations fails, the error has the index of the failed operation attached
+    /// - if an operation refers to an entity which was not created earlier in the batch, the error
+    ///   contains an [`InvalidEntityBatchReference`]
+    ///
+    /// [`InvalidEntityBatchReference`]: crate::entity::InvalidEntityBatchReference
+    fn execute_entity_batch(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        operations: Vec<EntityBatchOperation>,
+    ) -> impl Future<Output = Result<Vec<EntityBatchOperationResult>, Report<UpdateError>>> + Send;
+
     fn diff_entity(
         &self,
         actor_id: ActorEntityUuid,
@@ -550,6 +1041,80 @@ pub trait EntityStore {
         }
     }
 
+    /// Returns the editions of an entity across both temporal axes.
+    ///
+    /// Every edition is returned with its decision time and transaction time intervals, its
+    /// provenance, and the changes to the properties compared to the previous edition.
+    ///
+    /// # Errors
+    ///
+    /// - if the actor is not allowed to view the entity
+    /// - if the entity does not exist
+    /// - if the request to the database fails
+    fn get_entity_history(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: GetEntityHistoryParams,
+    ) -> impl Future<Output = Result<GetEntityHistoryResponse<'static>, Report<QueryError>>> + Send;
+
+    /// Permanently erases an entity.
+    ///
+    /// Removes all editions of the entity, its temporal metadata, embeddings, drafts, and
+    /// authorization relationships. Link entities which have the erased entity as an endpoint are
+    /// erased as well, including links whose endpoints are such link entities, as are the links of
+    /// the entity itself. For every erased entity a [`EntityTombstone`] is recorded and returned.
+    ///
+    /// This operation cannot be undone. The store does not check any permissions, so the caller
+    /// has to ensure that the actor is allowed to erase entities.
+    ///
+    /// # Errors
+    ///
+    /// - if the entity does not exist
+    /// - if removing the data or the authorization relationships fails
+    fn erase_entity(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: EraseEntityParams,
+    ) -> impl Future<Output = Result<Vec<EntityTombstone>, Report<DeletionError>>> + Send;
+
+    /// Merges the source entity into the target entity.
+    ///
+    /// The properties of both entities are merged according to the [`PropertyMergeStrategy`]s, the
+    /// entity types of the target are kept. Links which have the source as an endpoint are
+    /// archived and replaced by new links to the target, so the previous links are kept in the
+    /// history. Afterwards, the source is archived and its provenance records the entity it was
+    /// merged into.
+    ///
+    /// # Errors
+    ///
+    /// - if either entity does not exist
+    /// - if the source and the target are the same entity
+    /// - if the actor is not allowed to update both entities or the links of the source
+    /// - if the merged properties are not valid for the entity types of the target
+    fn merge_entities(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: MergeEntitiesParams,
+    ) -> impl Future<Output = Result<MergeEntitiesResponse, Report<UpdateError>>> + Send;
+
+    /// Upgrades all entities of the source entity type to the target entity type.
+    ///
+    /// The [`PropertyUpgradeOperation`]s are applied to the properties of every entity before it
+    /// is validated against its new entity types. Entities are updated in batches, each batch in
+    /// its own transaction. An entity which cannot be upgraded is reported in the response and
+    /// does not prevent other entities from being upgraded. Entities the actor is not allowed to
+    /// update are neither upgraded nor reported.
+    ///
+    /// # Errors
+    ///
+    /// - if the entities of the source entity type cannot be read
+    /// - if a batch cannot be committed
+    fn upgrade_entity_type(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: UpgradeEntityTypeParams,
+    ) -> impl Future<Output = Result<UpgradeEntityTypeResponse, Report<UpdateError>>> + Send;
+
     fn update_entity_embeddings(
         &mut self,
         actor_id: ActorEntityUuid,
@@ -175,6 +175,38 @@ impl MetadataValidationReport {
     }
 }
 
+#[derive(Debug, derive_more::Display, derive_more::Error)]
+#[display("Could not look up entities sharing the value of a unique property of {entity_type_id}")]
+#[must_use]
+pub struct UniquePropertyRetrieval {
+    pub entity_type_id: VersionedUrl,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicatePropertyValue {
+    /// The entity type which declares the property as unique.
+    pub entity_type_id: VersionedUrl,
+    /// Entities in the same web which already hold the same value.
+    pub conflicting_entities: Vec<EntityId>,
+    /// The number of further conflicting entities the actor is not allowed to view.
+    pub hidden_conflicting_entities: usize,
+}
+
+#[derive(Debug, serde::Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(tag = "type", rename_all = "camelCase")]
+#[must_use]
+pub enum UniquePropertyError {
+    Duplicate {
+        data: DuplicatePropertyValue,
+    },
+    Retrieval {
+        error: Report<UniquePropertyRetrieval>,
+    },
+}
+
 #[derive(Debug, Default, serde::Serialize)]
 #[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
 #[serde(rename_all = "camelCase")]
@@ -186,11 +218,16 @@ pub struct EntityValidationReport {
     pub link: LinkValidationReport,
     #[serde(skip_serializing_if = "MetadataValidationReport::is_valid")]
     pub metadata: MetadataValidationReport,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub unique_properties: HashMap<BaseUrl, UniquePropertyError>,
 }
 
 impl EntityValidationReport {
     #[must_use]
     pub fn is_valid(&self) -> bool {
-        self.properties.is_empty() && self.link.is_valid() && self.metadata.is_valid()
+        self.properties.is_empty()
+            && self.link.is_valid()
+            && self.metadata.is_valid()
+            && self.unique_properties.is_empty()
     }
 }
@@ -125,11 +125,26 @@ pub enum Filter<'p, R: QueryRecord> {
         FilterExpression<'p, R>,
         FilterExpression<'p, R>,
     ),
-    #[serde(skip)]
     In(FilterExpression<'p, R>, ParameterList<'p>),
     StartsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     EndsWith(FilterExpression<'p, R>, FilterExpression<'p, R>),
     ContainsSegment(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches a text against a case-insensitive `LIKE` pattern.
+    ///
+    /// Without the `%` and `_` wildcards this is a case-insensitive equality check.
+    ILike(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Matches a text against a POSIX regular expression.
+    ///
+    /// Stores may reject long patterns as matching them can be expensive.
+    Regex(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Checks if a list, e.g. an array property or the type base URLs, contains a value.
+    Contains(FilterExpression<'p, R>, FilterExpression<'p, R>),
+    /// Checks if a value is present at the path.
+    ///
+    /// As opposed to comparing with `null`, a property explicitly set to `null` exists.
+    Exists(FilterExpression<'p, R>),
+    /// Matches a full-text search vector against a web search query.
+    FullTextMatch(FilterExpression<'p, R>, FilterExpression<'p, R>),
 }
 
 impl<'p, R> Filter<'p, R>
@@ -434,24 +449,59 @@ where
             Self::In(lhs, rhs) => {
                 lhs.apply_parameter_conversion(data_type_provider).await?;
 
-                if let FilterExpression::Parameter {
-                    parameter,
-                    convert: _,
-                } = lhs
-                {
-                    match rhs {
+                match (lhs, rhs) {
+                    (
+                        FilterExpression::Parameter {
+                            parameter,
+                            convert: _,
+                        },
                         ParameterList::DataTypeIds(_)
                         | ParameterList::PropertyTypeIds(_)
                         | ParameterList::EntityTypeIds(_)
-                        | ParameterList::EntityEditionIds(_) => {
-                            parameter.convert_to_parameter_type(&ParameterType::Uuid)?;
+                        | ParameterList::EntityEditionIds(_),
+                    ) => {
+                        parameter.convert_to_parameter_type(&ParameterType::Uuid)?;
+                    }
+                    (FilterExpression::Path { path }, ParameterList::Parameters(parameters)) => {
+                        let expected_type = path.expected_type();
+                        for parameter in parameters {
+                            parameter.convert_to_parameter_type(&expected_type)?;
+                        }
+                    }
+                    (..) => {}
+                }
+            }
+            Self::Contains(lhs, rhs) => {
+                lhs.apply_parameter_conversion(data_type_provider).await?;
+                rhs.apply_parameter_conversion(data_type_provider).await?;
+
+                if let (
+                    FilterExpression::Path { path },
+                    FilterExpression::Parameter {
+                        parameter,
+                        convert: _,
+                    },
+                ) = (lhs, rhs)
+                {
+                    match path.expected_type() {
+                        ParameterType::Vector(element_type) => {
+                            parameter.convert_to_parameter_type(&element_type)?;
                         }
+                        expected_type => parameter.convert_to_parameter_type(&expected_type)?,
                     }
                 }
             }
+            Self::Exists(expression) => {
+                expression
+                    .apply_parameter_conversion(data_type_provider)
+                    .await?;
+            }
             Self::StartsWith(lhs, rhs)
             | Self::EndsWith(lhs, rhs)
-            | Self::ContainsSegment(lhs, rhs) => {
+            | Self::ContainsSegment(lhs, rhs)
+            | Self::ILike(lhs, rhs)
+            | Self::Regex(lhs, rhs)
+            | Self::FullTextMatch(lhs, rhs) => {
                 lhs.apply_parameter_conversion(data_type_provider).await?;
                 rhs.apply_parameter_conversion(data_type_provider).await?;
 
@@ -849,4 +899,27 @@ mod tests {
         )
         .await;
     }
+
+    #[tokio::test]
+    async fn in_parameter_list() {
+        let web_ids = [Uuid::new_v4(), Uuid::new_v4()];
+
+        let expected = json!({
+          "in": [
+            { "path": ["ownedById"] },
+            web_ids
+          ]
+        });
+
+        test_filter_representation(
+            &Filter::<Entity>::In(
+                FilterExpression::Path {
+                    path: EntityQueryPath::OwnedById,
+                },
+                ParameterList::Parameters(web_ids.map(Parameter::Uuid).to_vec()),
+            ),
+            &expected,
+        )
+        .await;
+    }
 }
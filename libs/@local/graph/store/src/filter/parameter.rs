@@ -5,7 +5,7 @@ use error_stack::{Report, ResultExt as _, bail};
 use hash_codec::numeric::Real;
 use hash_graph_temporal_versioning::Timestamp;
 use hash_graph_types::Embedding;
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
 use type_system::{
     knowledge::{PropertyValue, entity::id::EntityEditionId},
     ontology::{
@@ -70,12 +70,23 @@ impl fmt::Display for ParameterType {
     }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ParameterList<'p> {
     DataTypeIds(&'p [DataTypeUuid]),
     PropertyTypeIds(&'p [PropertyTypeUuid]),
     EntityTypeIds(&'p [EntityTypeUuid]),
     EntityEditionIds(&'p [EntityEditionId]),
+    /// A list of arbitrary parameters, e.g. as passed by an API caller.
+    Parameters(Vec<Parameter<'p>>),
+}
+
+impl<'de: 'p, 'p> Deserialize<'de> for ParameterList<'p> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Vec::deserialize(deserializer).map(Self::Parameters)
+    }
 }
 
 impl Parameter<'_> {
@@ -0,0 +1,32 @@
+/// A function to aggregate the values of a group of records.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, serde::Deserialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum AggregationFunction {
+    /// Counts the records in the group.
+    ///
+    /// If a path is provided, the values at the path are counted instead. A record with multiple
+    /// values at the path, e.g. multiple types, is counted once per value.
+    Count,
+    /// Sums up the numeric values at the path.
+    Sum,
+    /// Returns the smallest numeric value at the path.
+    Min,
+    /// Returns the largest numeric value at the path.
+    Max,
+    /// Returns the average of the numeric values at the path.
+    Avg,
+}
+
+impl AggregationFunction {
+    /// Returns if the function only takes numeric values into account.
+    ///
+    /// Values which are not numeric are ignored by these functions.
+    #[must_use]
+    pub const fn is_numeric(self) -> bool {
+        match self {
+            Self::Count => false,
+            Self::Sum | Self::Min | Self::Max | Self::Avg => true,
+        }
+    }
+}
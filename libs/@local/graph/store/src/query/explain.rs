@@ -0,0 +1,61 @@
+use error_stack::Report;
+use serde::Serialize;
+use type_system::provenance::ActorEntityUuid;
+
+use crate::{
+    error::QueryError,
+    filter::{Filter, QueryRecord},
+    query::Sorting,
+    subgraph::temporal_axes::QueryTemporalAxes,
+};
+
+/// A table which is joined in a compiled query.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct JoinedTable {
+    pub table: String,
+    pub alias: String,
+}
+
+/// The compiled statement of a query together with its execution plan.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "utoipa", derive(utoipa::ToSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct QueryExplanation {
+    /// The statement which is sent to the database.
+    pub statement: String,
+    /// The values bound to the placeholders of the statement, in order.
+    pub parameters: Vec<String>,
+    /// The tables joined to the base table of the query.
+    pub joined_tables: Vec<JoinedTable>,
+    /// The plan of the statement as reported by the database.
+    #[cfg_attr(feature = "utoipa", schema(value_type = Object))]
+    pub plan: serde_json::Value,
+}
+
+/// Explains how a query is executed by a store.
+pub trait ExplainQuery<R: QueryRecord, S: Sorting + Sync>: Sync {
+    /// Compiles the paginated query for the given `filter` and returns it together with its
+    /// execution plan.
+    ///
+    /// The statement is the same as the one used to read a page of records with the given
+    /// `sorting` and `limit`. It is executed to collect timing information, but no records are
+    /// returned. The parameters of the filter are converted on behalf of the actor before the query
+    /// is compiled.
+    ///
+    /// # Errors
+    ///
+    /// - if the parameters of the filter cannot be converted
+    /// - if the filter or the sorting cannot be compiled
+    /// - if the statement cannot be explained by the database
+    fn explain_query(
+        &self,
+        actor_id: ActorEntityUuid,
+        filter: Filter<'_, R>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        sorting: &S,
+        limit: Option<usize>,
+        include_drafts: bool,
+    ) -> impl Future<Output = Result<QueryExplanation, Report<QueryError>>> + Send;
+}
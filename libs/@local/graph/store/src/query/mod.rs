@@ -1,8 +1,12 @@
 pub use self::{
+    aggregation::AggregationFunction,
+    explain::{ExplainQuery, JoinedTable, QueryExplanation},
     ordering::{NullOrdering, Ordering, Sorting, VersionedUrlSorting},
     pagination::CursorField,
 };
 
+mod aggregation;
+mod explain;
 mod ordering;
 mod pagination;
 
@@ -272,6 +272,48 @@ impl QueryTemporalAxesUnresolved {
     }
 }
 
+impl<A> From<PinnedTemporalAxis<A>> for PinnedTemporalAxisUnresolved<A> {
+    fn from(axis: PinnedTemporalAxis<A>) -> Self {
+        Self {
+            axis: axis.axis,
+            timestamp: Some(axis.timestamp),
+        }
+    }
+}
+
+impl<A> From<VariableTemporalAxis<A>> for VariableTemporalAxisUnresolved<A> {
+    fn from(axis: VariableTemporalAxis<A>) -> Self {
+        let (start, end) = axis.interval.into_bounds();
+        Self {
+            axis: axis.axis,
+            interval: RightBoundedTemporalIntervalUnresolved {
+                start: Some(start),
+                end: Some(end),
+            },
+        }
+    }
+}
+
+/// Pins all bounds of the temporal axes, so they resolve to the same axes regardless of when they
+/// are resolved.
+///
+/// This allows a query which is read page by page to see the graph at the same point in time for
+/// every page.
+impl From<QueryTemporalAxes> for QueryTemporalAxesUnresolved {
+    fn from(axes: QueryTemporalAxes) -> Self {
+        match axes {
+            QueryTemporalAxes::DecisionTime { pinned, variable } => Self::DecisionTime {
+                pinned: pinned.into(),
+                variable: variable.into(),
+            },
+            QueryTemporalAxes::TransactionTime { pinned, variable } => Self::TransactionTime {
+                pinned: pinned.into(),
+                variable: variable.into(),
+            },
+        }
+    }
+}
+
 /// A representation of a "pinned" temporal axis, used to project another temporal axis along the
 /// given [`Timestamp`].
 ///
@@ -101,6 +101,17 @@ impl<T> Sub for Timestamp<T> {
     }
 }
 
+impl<T> Sub<time::Duration> for Timestamp<T> {
+    type Output = Self;
+
+    fn sub(self, rhs: time::Duration) -> Self::Output {
+        Self {
+            axis: PhantomData,
+            time: self.time - rhs,
+        }
+    }
+}
+
 impl<A> FromStr for Timestamp<A> {
     type Err = time::error::Parse;
 
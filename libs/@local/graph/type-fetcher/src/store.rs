@@ -16,8 +16,9 @@ use hash_graph_store::{
     account::{
         AccountGroupInsertionError, AccountInsertionError, AccountStore,
         InsertAccountGroupIdParams, InsertAccountIdParams, InsertWebIdParams, QueryWebError,
-        WebInsertionError,
+        UpdateWebTextSearchConfigParams, WebInsertionError, WebUpdateError,
     },
+    change_feed::{ChangeFeedStore, ReadChangesParams, ReadChangesResponse},
     data_type::{
         ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams, DataTypeStore,
         GetDataTypeConversionTargetsParams, GetDataTypeConversionTargetsResponse,
@@ -26,9 +27,14 @@ use hash_graph_store::{
         UpdateDataTypesParams,
     },
     entity::{
-        CountEntitiesParams, CreateEntityParams, EntityStore, EntityValidationReport,
-        GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
-        PatchEntityParams, UpdateEntityEmbeddingsParams, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EntityBatchOperation, EntityBatchOperationResult, EntityStore,
+        EntityTombstone, EntityValidationReport, EraseEntityParams, FindEntityPathsParams,
+        FindEntityPathsResponse, GetEntitiesParams, GetEntitiesResponse, GetEntityHistoryParams,
+        GetEntityHistoryResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
+        MergeEntitiesParams, MergeEntitiesResponse, PatchEntityParams,
+        UpdateEntityEmbeddingsParams, UpgradeEntityTypeParams, UpgradeEntityTypeResponse,
+        ValidateEntityParams,
     },
     entity_type::{
         ArchiveEntityTypeParams, CountEntityTypesParams, CreateEntityTypeParams, EntityTypeStore,
@@ -37,7 +43,7 @@ use hash_graph_store::{
         IncludeResolvedEntityTypeOption, UnarchiveEntityTypeParams,
         UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
     },
-    error::{InsertionError, QueryError, UpdateError},
+    error::{DeletionError, InsertionError, QueryError, UpdateError},
     filter::{Filter, QueryRecord},
     pool::StorePool,
     property_type::{
@@ -46,7 +52,9 @@ use hash_graph_store::{
         GetPropertyTypesResponse, PropertyTypeStore, UnarchivePropertyTypeParams,
         UpdatePropertyTypeEmbeddingParams, UpdatePropertyTypesParams,
     },
-    query::{ConflictBehavior, QueryResult, Read, ReadPaginated, Sorting},
+    query::{
+        ConflictBehavior, ExplainQuery, QueryExplanation, QueryResult, Read, ReadPaginated, Sorting,
+    },
     subgraph::temporal_axes::{
         PinnedTemporalAxisUnresolved, QueryTemporalAxes, QueryTemporalAxesUnresolved,
         VariableTemporalAxisUnresolved,
@@ -795,6 +803,35 @@ where
     }
 }
 
+impl<I, A, R, S> ExplainQuery<R, S> for FetchingStore<I, A>
+where
+    A: Send + Sync,
+    I: ExplainQuery<R, S> + Send,
+    R: QueryRecord,
+    S: Sorting + Sync,
+{
+    async fn explain_query(
+        &self,
+        actor_id: ActorEntityUuid,
+        filter: Filter<'_, R>,
+        temporal_axes: Option<&QueryTemporalAxes>,
+        sorting: &S,
+        limit: Option<usize>,
+        include_drafts: bool,
+    ) -> Result<QueryExplanation, Report<QueryError>> {
+        self.store
+            .explain_query(
+                actor_id,
+                filter,
+                temporal_axes,
+                sorting,
+                limit,
+                include_drafts,
+            )
+            .await
+    }
+}
+
 impl<S, A> AccountStore for FetchingStore<S, A>
 where
     S: AccountStore + Send + Sync,
@@ -824,6 +861,16 @@ where
         self.store.insert_web_id(actor_id, params).await
     }
 
+    async fn update_web_text_search_config(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: UpdateWebTextSearchConfigParams,
+    ) -> Result<(), Report<WebUpdateError>> {
+        self.store
+            .update_web_text_search_config(actor_id, params)
+            .await
+    }
+
     async fn identify_owned_by_id(
         &self,
         owned_by_id: OwnedById,
@@ -832,6 +879,20 @@ where
     }
 }
 
+impl<S, A> ChangeFeedStore for FetchingStore<S, A>
+where
+    S: ChangeFeedStore + Send + Sync,
+    A: Send + Sync,
+{
+    async fn read_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, Report<QueryError>> {
+        self.store.read_changes(actor_id, params).await
+    }
+}
+
 impl<S, A> DataTypeStore for FetchingStore<S, A>
 where
     S: DataTypeStore + PropertyTypeStore + EntityTypeStore + Send + Sync,
@@ -1348,6 +1409,54 @@ where
         self.store.count_entities(actor_id, params).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
+    async fn find_entity_paths(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: FindEntityPathsParams,
+    ) -> Result<FindEntityPathsResponse, Report<QueryError>> {
+        self.store.find_entity_paths(actor_id, params).await
+    }
+
+    async fn get_entity_history(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse<'static>, Report<QueryError>> {
+        self.store.get_entity_history(actor_id, params).await
+    }
+
+    async fn erase_entity(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: EraseEntityParams,
+    ) -> Result<Vec<EntityTombstone>, Report<DeletionError>> {
+        self.store.erase_entity(actor_id, params).await
+    }
+
+    async fn merge_entities(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: MergeEntitiesParams,
+    ) -> Result<MergeEntitiesResponse, Report<UpdateError>> {
+        self.store.merge_entities(actor_id, params).await
+    }
+
+    async fn upgrade_entity_type(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: UpgradeEntityTypeParams,
+    ) -> Result<UpgradeEntityTypeResponse, Report<UpdateError>> {
+        self.store.upgrade_entity_type(actor_id, params).await
+    }
+
     async fn patch_entity(
         &mut self,
         actor_id: ActorEntityUuid,
@@ -1370,6 +1479,39 @@ where
         self.store.patch_entity(actor_id, params).await
     }
 
+    async fn execute_entity_batch(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        operations: Vec<EntityBatchOperation>,
+    ) -> Result<Vec<EntityBatchOperationResult>, Report<UpdateError>> {
+        let type_ids = operations
+            .iter()
+            .filter_map(|operation| match operation {
+                EntityBatchOperation::CreateEntity(operation) => Some(&operation.entity_type_ids),
+                EntityBatchOperation::PatchEntity(operation) => Some(&operation.entity_type_ids),
+                EntityBatchOperation::ArchiveEntity(_)
+                | EntityBatchOperation::ModifyRelationship(_) => None,
+            })
+            .flatten()
+            .collect::<HashSet<_>>();
+
+        for entity_type_id in type_ids {
+            self.insert_external_types_by_reference(
+                actor_id,
+                OntologyTypeReference::EntityTypeReference(&EntityTypeReference {
+                    url: entity_type_id.clone(),
+                }),
+                ConflictBehavior::Skip,
+                FetchBehavior::ExcludeProvidedReferences,
+                &HashSet::new(),
+            )
+            .await
+            .change_context(UpdateError)?;
+        }
+
+        self.store.execute_entity_batch(actor_id, operations).await
+    }
+
     async fn update_entity_embeddings(
         &mut self,
         actor_id: ActorEntityUuid,
@@ -1,16 +1,16 @@
 use alloc::collections::BTreeSet;
 use core::borrow::Borrow as _;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use error_stack::{
     FutureExt as _, Report, ResultExt as _, TryReportIteratorExt as _, TryReportStreamExt as _,
 };
 use futures::{StreamExt as _, TryStreamExt as _, stream};
 use hash_graph_store::entity::{
-    EntityRetrieval, EntityTypeRetrieval, LinkDataStateError, LinkDataValidationReport, LinkError,
-    LinkTargetError, LinkValidationReport, LinkedEntityError, MissingLinkData,
-    PropertyMetadataValidationReport, UnexpectedEntityType, UnexpectedLinkData,
-    ValidateEntityComponents,
+    DuplicatePropertyValue, EntityRetrieval, EntityTypeRetrieval, LinkDataStateError,
+    LinkDataValidationReport, LinkError, LinkTargetError, LinkValidationReport, LinkedEntityError,
+    MissingLinkData, PropertyMetadataValidationReport, UnexpectedEntityType, UnexpectedLinkData,
+    UniquePropertyError, UniquePropertyRetrieval, ValidateEntityComponents,
 };
 use hash_graph_types::{
     knowledge::property::visitor::{
@@ -34,7 +34,7 @@ use type_system::{
         value::{PropertyValue, ValueMetadata},
     },
     ontology::{
-        VersionedUrl,
+        BaseUrl, VersionedUrl,
         data_type::schema::{ClosedDataType, DataTypeReference},
         entity_type::schema::{ClosedEntityType, ClosedMultiEntityType},
         json_schema::{ConstraintValidator as _, JsonSchemaValueType},
@@ -109,15 +109,79 @@ where
 pub struct PostInsertionEntityValidationReport {
     pub link: LinkValidationReport,
     pub property_metadata: PropertyMetadataValidationReport,
+    pub unique_properties: HashMap<BaseUrl, UniquePropertyError>,
 }
 
 impl PostInsertionEntityValidationReport {
     #[must_use]
-    pub const fn is_valid(&self) -> bool {
-        self.link.is_valid() && self.property_metadata.is_valid()
+    pub fn is_valid(&self) -> bool {
+        self.link.is_valid()
+            && self.property_metadata.is_valid()
+            && self.unique_properties.is_empty()
     }
 }
 
+/// Checks that no other entity in the web shares a value of a property marked as unique by any
+/// of the entity's types.
+///
+/// Drafts and archived entities are neither checked nor considered as conflicting.
+async fn validate_unique_properties<P>(
+    entity: &Entity,
+    schema: &ClosedMultiEntityType,
+    provider: &P,
+) -> HashMap<BaseUrl, UniquePropertyError>
+where
+    P: EntityProvider + Sync,
+{
+    let mut errors = HashMap::new();
+
+    let entity_id = entity.metadata.record_id.entity_id;
+    if entity_id.draft_id.is_some() || entity.metadata.archived {
+        return errors;
+    }
+
+    for (property, entity_type_ids) in &schema.unique_properties {
+        let Some(value) = entity.properties.properties().get(property) else {
+            continue;
+        };
+
+        for entity_type_id in entity_type_ids {
+            match provider
+                .provide_entities_with_property_value(entity_id, entity_type_id, property, value)
+                .await
+            {
+                Ok(conflicting_entities) if conflicting_entities.is_empty() => {}
+                Ok(conflicting_entities) => {
+                    errors.insert(
+                        property.clone(),
+                        UniquePropertyError::Duplicate {
+                            data: DuplicatePropertyValue {
+                                entity_type_id: entity_type_id.clone(),
+                                conflicting_entities: conflicting_entities.visible,
+                                hidden_conflicting_entities: conflicting_entities.hidden,
+                            },
+                        },
+                    );
+                    break;
+                }
+                Err(error) => {
+                    errors.insert(
+                        property.clone(),
+                        UniquePropertyError::Retrieval {
+                            error: error.change_context(UniquePropertyRetrieval {
+                                entity_type_id: entity_type_id.clone(),
+                            }),
+                        },
+                    );
+                    break;
+                }
+            }
+        }
+    }
+
+    errors
+}
+
 impl<P> Validate<ClosedMultiEntityType, P> for Entity
 where
     P: EntityProvider
@@ -145,6 +209,11 @@ where
                 .properties
                 .validate(&self.properties, components, context)
                 .await,
+            unique_properties: if components.unique_properties {
+                validate_unique_properties(self, schema, context).await
+            } else {
+                HashMap::new()
+            },
         }
     }
 }
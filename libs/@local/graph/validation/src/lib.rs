@@ -17,7 +17,10 @@ use core::{borrow::Borrow, error::Error};
 
 use error_stack::Report;
 use hash_graph_store::entity::ValidateEntityComponents;
-use type_system::knowledge::{Entity, entity::EntityId};
+use type_system::{
+    knowledge::{Entity, entity::EntityId, property::Property},
+    ontology::{BaseUrl, VersionedUrl},
+};
 
 pub trait Validate<S, C> {
     type Report: Send + Sync;
@@ -40,6 +43,38 @@ pub trait EntityProvider {
             Report<impl Error + Send + Sync + 'static>,
         >,
     > + Send;
+
+    /// Returns the entities in the web of `entity_id` which are of any version of the given
+    /// entity type, or of a type inheriting from it, and have `value` set for `property`.
+    ///
+    /// The entity itself, drafts, and archived entities are not returned. Entities the provider
+    /// is not allowed to view are only counted.
+    fn provide_entities_with_property_value(
+        &self,
+        entity_id: EntityId,
+        entity_type_id: &VersionedUrl,
+        property: &BaseUrl,
+        value: &Property,
+    ) -> impl Future<
+        Output = Result<ConflictingEntities, Report<impl Error + Send + Sync + 'static>>,
+    > + Send;
+}
+
+/// Entities sharing the value of a unique property, as returned by
+/// [`EntityProvider::provide_entities_with_property_value`].
+#[derive(Debug, Default)]
+pub struct ConflictingEntities {
+    /// The conflicting entities which may be viewed.
+    pub visible: Vec<EntityId>,
+    /// The number of conflicting entities which may not be viewed.
+    pub hidden: usize,
+}
+
+impl ConflictingEntities {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.visible.is_empty() && self.hidden == 0
+    }
 }
 
 #[cfg(test)]
@@ -195,6 +230,35 @@ mod tests {
                 .get(&entity_id)
                 .ok_or_else(|| Report::new(InvalidEntity { id: entity_id }))
         }
+
+        #[expect(refining_impl_trait)]
+        async fn provide_entities_with_property_value(
+            &self,
+            entity_id: EntityId,
+            entity_type_id: &VersionedUrl,
+            property: &BaseUrl,
+            value: &Property,
+        ) -> Result<ConflictingEntities, Report<InvalidEntity>> {
+            let visible = self
+                .entities
+                .values()
+                .filter(|entity| {
+                    let other_id = entity.metadata.record_id.entity_id;
+                    other_id.owned_by_id == entity_id.owned_by_id
+                        && other_id.entity_uuid != entity_id.entity_uuid
+                        && other_id.draft_id.is_none()
+                        && !entity.metadata.archived
+                        && entity
+                            .metadata
+                            .entity_type_ids
+                            .iter()
+                            .any(|id| id.base_url == entity_type_id.base_url)
+                        && entity.properties.properties().get(property) == Some(value)
+                })
+                .map(|entity| entity.metadata.record_id.entity_id)
+                .collect();
+            Ok(ConflictingEntities { visible, hidden: 0 })
+        }
     }
 
     impl OntologyTypeProvider<ClosedEntityType> for Provider {
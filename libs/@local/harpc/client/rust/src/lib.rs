@@ -27,7 +27,7 @@ use self::connection::{
     service::ConnectionService,
 };
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct ClientConfig {
     pub transport: TransportConfig,
     pub session: SessionConfig,
@@ -71,15 +71,50 @@ impl<C> Client<C> {
         let transport = TransportLayer::tcp(config.transport, token.clone())
             .change_context(ClientError::StartTransportLayer)?;
 
-        let session = SessionLayer::new(config.session, transport);
+        Ok(Self::from_transport(
+            config.session,
+            transport,
+            token,
+            codec,
+        ))
+    }
+
+    /// Creates a new `Client`, which is only able to connect to servers within the same process.
+    ///
+    /// Addresses to connect to are of the form `/memory/<port>`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ClientError::StartTransportLayer` if unable to start the transport layer.
+    pub fn memory(config: ClientConfig, codec: C) -> Result<Self, Report<ClientError>> {
+        let token = CancellationToken::new();
+
+        let transport = TransportLayer::memory(config.transport, token.clone())
+            .change_context(ClientError::StartTransportLayer)?;
+
+        Ok(Self::from_transport(
+            config.session,
+            transport,
+            token,
+            codec,
+        ))
+    }
+
+    fn from_transport(
+        config: SessionConfig,
+        transport: TransportLayer,
+        token: CancellationToken,
+        codec: C,
+    ) -> Self {
+        let session = SessionLayer::new(config, transport);
 
         let guard = Arc::new(token.drop_guard());
 
-        Ok(Self {
+        Self {
             session: Arc::new(session),
             codec,
             guard: TransportLayerGuard(guard),
-        })
+        }
     }
 
     /// Connects to a target address.
@@ -1,15 +1,21 @@
 //! Common utilities used to implement various traits as well as used in the proc-macro.
 
-use core::error::Error;
+use core::{
+    error::Error,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
 
+use bytes::Buf;
 use error_stack::{Report, ResultExt as _, TryReportStreamExt as _};
-use futures::{StreamExt as _, stream};
+use futures::{FutureExt as _, Stream, StreamExt as _, channel::oneshot, stream};
 use harpc_codec::encode::Encoder;
 use harpc_net::session::server::SessionId;
 use harpc_system::{Subsystem, procedure::ProcedureIdentifier};
 use harpc_tower::{
     Extensions,
     request::{self, Request},
+    response::Response,
 };
 use harpc_types::procedure::ProcedureDescriptor;
 use tower::ServiceExt as _;
@@ -98,3 +104,226 @@ where
     data.map_err(RemoteError::new)
         .change_context(RemoteInvocationError)
 }
+
+/// Delegates a call with a predetermined amount of inputs and a stream of outputs.
+///
+/// This is used for server-streaming procedures. The response is decoded as it arrives, so the
+/// stream may be unbounded.
+///
+/// # Errors
+///
+/// This function returns a `Report<RemoteInvocationError>` in the following cases:
+/// - If encoding the request fails
+/// - If the service call fails
+///
+/// Every item of the returned stream is an error in the following cases:
+/// - If decoding the response fails
+/// - If the remote server returns an error
+pub async fn invoke_call_server_stream<Svc, C, O>(
+    connection: Connection<Svc, C>,
+    procedure: impl ProcedureIdentifier + Send,
+    request: impl IntoIterator<Item: serde::Serialize, IntoIter: Send> + Send,
+) -> Result<
+    impl Stream<Item = Result<O, Report<RemoteInvocationError>>> + Send,
+    Report<RemoteInvocationError>,
+>
+where
+    Svc: ConnectionService<C>,
+    C: ConnectionCodec,
+    O: serde::de::DeserializeOwned + Send,
+{
+    let (service, codec) = connection.into_parts();
+
+    let request = encode_request_iter(codec.clone(), procedure, request)
+        .await
+        .change_context(RemoteInvocationError)?;
+
+    let response = service
+        .oneshot(request)
+        .await
+        .change_context(RemoteInvocationError)?;
+
+    let (_, body) = response.into_parts();
+
+    Ok(codec.decode(body).map(|item| {
+        let item: Result<_, _> = item.change_context(RemoteInvocationError)?;
+
+        item.map_err(RemoteError::new)
+            .change_context(RemoteInvocationError)
+    }))
+}
+
+pin_project_lite::pin_project! {
+    /// Request body of a streaming call, which encodes items as they are sent.
+    ///
+    /// The stream ends at the first item that cannot be encoded, the error is reported through
+    /// the response of the call.
+    #[must_use = "streams do nothing unless polled"]
+    pub struct EncodeRequestStream<S, C> {
+        #[pin]
+        inner: S,
+        error: Option<oneshot::Sender<Report<C>>>,
+    }
+}
+
+impl<S, B, C> Stream for EncodeRequestStream<S, C>
+where
+    S: Stream<Item = Result<B, Report<C>>>,
+{
+    type Item = B;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        // the sender is taken once an error has been encountered, which terminates the stream
+        let Some(error) = this.error.as_ref() else {
+            return Poll::Ready(None);
+        };
+
+        if error.is_canceled() {
+            // the call has been dropped, there's no point in continuing to encode items
+            return Poll::Ready(None);
+        }
+
+        match ready!(this.inner.poll_next(cx)) {
+            Some(Ok(item)) => Poll::Ready(Some(item)),
+            Some(Err(report)) => {
+                if let Some(error) = this.error.take() {
+                    // the receiver may have been dropped, in which case nobody is interested in
+                    // the error
+                    let _: Result<(), _> = error.send(report);
+                }
+
+                Poll::Ready(None)
+            }
+            None => Poll::Ready(None),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Response of a streaming call, which yields the error of the request once it occurs.
+    #[must_use = "streams do nothing unless polled"]
+    struct StreamResponse<S, C> {
+        #[pin]
+        inner: S,
+        error: Option<oneshot::Receiver<Report<C>>>,
+    }
+}
+
+impl<S, O, C> Stream for StreamResponse<S, C>
+where
+    S: Stream<Item = Result<O, Report<RemoteInvocationError>>>,
+    C: Error + Send + Sync + 'static,
+{
+    type Item = Result<O, Report<RemoteInvocationError>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+
+        // the request may fail at any point, we report the error as soon as it happens instead of
+        // waiting for the remote server to finish responding, which may never happen
+        if let Some(error) = this.error.as_mut() {
+            match error.poll_unpin(cx) {
+                Poll::Ready(Ok(report)) => {
+                    *this.error = None;
+
+                    return Poll::Ready(Some(Err(report.change_context(RemoteInvocationError))));
+                }
+                // the request has been sent completely
+                Poll::Ready(Err(oneshot::Canceled)) => *this.error = None,
+                Poll::Pending => {}
+            }
+        }
+
+        let item = ready!(this.inner.poll_next(cx));
+
+        if item.is_none() {
+            // the sender might not have been dropped yet, even though the request has failed
+            if let Some(Ok(Some(report))) = this.error.take().map(|mut error| error.try_recv()) {
+                return Poll::Ready(Some(Err(report.change_context(RemoteInvocationError))));
+            }
+        }
+
+        Poll::Ready(item)
+    }
+}
+
+/// Delegates a call with a stream of inputs and a stream of outputs.
+///
+/// Items are encoded and sent as they are produced by `request`, while the response is decoded as
+/// it arrives. The response may be consumed while the request is still being sent, allowing for
+/// client-streaming as well as bidirectional-streaming procedures.
+///
+/// If an item of the request cannot be encoded, the request is terminated and the response stream
+/// yields the error as soon as it occurs, followed by the remaining response of the remote
+/// server.
+///
+/// # Errors
+///
+/// This function returns a `Report<RemoteInvocationError>` if the service call fails. Every item of
+/// the returned stream is an error in the following cases:
+/// - If encoding the request fails
+/// - If decoding the response fails
+/// - If the remote server returns an error
+pub async fn invoke_call_stream<Svc, C, P, I, O, St, ResData, ResError, ServiceError>(
+    connection: Connection<Svc, C>,
+    procedure: P,
+    request: I,
+) -> Result<
+    impl Stream<Item = Result<O, Report<RemoteInvocationError>>> + Send,
+    Report<RemoteInvocationError>,
+>
+where
+    Svc: tower::Service<
+            Request<EncodeRequestStream<C::Output<I>, C::EncoderError>>,
+            Response = Response<St>,
+            Error = Report<ServiceError>,
+            Future: Send,
+        > + Send,
+    C: ConnectionCodec,
+    P: ProcedureIdentifier + Send,
+    I: Stream<Item: serde::Serialize> + Send,
+    O: serde::de::DeserializeOwned + Send,
+    St: Stream<Item = Result<ResData, ResError>> + Send,
+    ResData: Buf,
+    ServiceError: Error + Send + Sync + 'static,
+{
+    let (service, codec) = connection.into_parts();
+
+    let (error_tx, error_rx) = oneshot::channel();
+
+    let request = Request::from_parts(
+        request::Parts {
+            subsystem: <P::Subsystem as Subsystem>::descriptor(),
+            procedure: ProcedureDescriptor {
+                id: procedure.into_id(),
+            },
+            session: SessionId::CLIENT,
+            extensions: Extensions::new(),
+        },
+        EncodeRequestStream {
+            inner: codec.clone().encode(request),
+            error: Some(error_tx),
+        },
+    );
+
+    let response = service
+        .oneshot(request)
+        .await
+        .change_context(RemoteInvocationError)?;
+
+    let (_, body) = response.into_parts();
+
+    let items = codec.decode(body).map(|item| {
+        let item: Result<_, _> = item.change_context(RemoteInvocationError)?;
+
+        item.map_err(RemoteError::new)
+            .change_context(RemoteInvocationError)
+    });
+
+    Ok(StreamResponse {
+        inner: items,
+        error: Some(error_rx),
+    })
+}
@@ -14,6 +14,7 @@
 
 extern crate alloc;
 
+pub mod metrics;
 pub mod session;
 pub mod transport;
 
@@ -0,0 +1,65 @@
+use alloc::sync::Arc;
+use core::fmt;
+
+use prometheus_client::{encoding::text, registry::Registry};
+
+/// The content type of the rendered metrics, suitable for the `Content-Type` header of an HTTP
+/// response.
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+const EOF: &str = "# EOF\n";
+
+/// Handle to the metrics collected by one or more layers.
+///
+/// The handle is cheap to clone and can be moved into an HTTP handler, rendering always reflects
+/// the current state of the metrics.
+#[derive(Debug, Clone)]
+pub struct MetricsHandle {
+    registries: Vec<Arc<Registry>>,
+}
+
+impl MetricsHandle {
+    pub(crate) fn new(registry: Arc<Registry>) -> Self {
+        Self {
+            registries: vec![registry],
+        }
+    }
+
+    #[must_use]
+    pub(crate) fn with(mut self, registry: Arc<Registry>) -> Self {
+        self.registries.push(registry);
+        self
+    }
+
+    /// Encode the metrics in the Prometheus (OpenMetrics) text format into `writer`.
+    ///
+    /// # Errors
+    ///
+    /// If writing to `writer` fails.
+    pub fn encode(&self, writer: &mut impl fmt::Write) -> fmt::Result {
+        let mut output = String::new();
+        for registry in &self.registries {
+            // Every encoded registry is terminated by the EOF marker, which may only appear once
+            // at the end of the output.
+            output.clear();
+            text::encode(&mut output, registry)?;
+            writer.write_str(output.strip_suffix(EOF).unwrap_or(&output))?;
+        }
+
+        writer.write_str(EOF)
+    }
+
+    /// Render the metrics in the Prometheus (OpenMetrics) text format.
+    ///
+    /// The result should be served with the [`CONTENT_TYPE`] content type.
+    #[must_use]
+    pub fn render(&self) -> String {
+        let mut output = String::new();
+
+        // writing to a `String` is infallible
+        self.encode(&mut output)
+            .unwrap_or_else(|_| unreachable!("writing to a `String` is infallible"));
+
+        output
+    }
+}
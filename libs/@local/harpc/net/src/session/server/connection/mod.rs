@@ -25,6 +25,7 @@ use tokio_util::{either::Either, sync::CancellationToken, task::TaskTracker};
 use self::collection::TransactionCollection;
 use super::{
     SessionConfig, SessionEvent,
+    metrics::{ConnectionRecorder, RejectionReason, SessionMetrics},
     session_id::SessionId,
     transaction::{Transaction, TransactionParts},
 };
@@ -69,10 +70,12 @@ pub(crate) struct ConnectionTask {
     pub transactions: TransactionCollection,
     pub output: mpsc::Sender<Transaction>,
     pub events: broadcast::Sender<SessionEvent>,
+    pub metrics: SessionMetrics,
 
     pub config: SessionConfig,
 
     pub _permit: OwnedSemaphorePermit,
+    pub _recorder: ConnectionRecorder,
 }
 
 impl ConnectionTask {
@@ -122,6 +125,8 @@ impl ConnectionTask {
                         Ok((permit, tx, rx)) => (permit, tx, rx),
                         Err(error) => {
                             tracing::warn!("transaction limit reached, dropping transaction");
+                            self.metrics
+                                .transaction_rejected(RejectionReason::TransactionLimit);
 
                             self.respond_error(request_id, &error, &tx).await;
                             return;
@@ -148,6 +153,7 @@ impl ConnectionTask {
                         // any more transactions, existing transactions might still be in flight and
                         // be processed, this is also known as the "graceful shutdown" phase.
                         tracing::info!("supervisor has been dropped, dropping transaction");
+                        self.metrics.transaction_rejected(RejectionReason::Shutdown);
 
                         self.respond_error(request_id, &ConnectionGracefulShutdownError, &tx)
                             .await;
@@ -158,6 +164,8 @@ impl ConnectionTask {
                         // transaction, because we can assume that the upper layer is unable to keep
                         // up with the incoming requests, it also helps us to prevent a DoS attack.
                         tracing::warn!("transaction delivery timed out, dropping transaction");
+                        self.metrics
+                            .transaction_rejected(RejectionReason::DeliveryTimeout);
 
                         self.transactions.release(request_id).await;
 
@@ -176,6 +184,9 @@ impl ConnectionTask {
                         rx: request_rx,
                         tx: tx.clone(),
                         permit,
+                        recorder: self
+                            .metrics
+                            .transaction_started(begin.subsystem, begin.procedure),
                     },
                 );
 
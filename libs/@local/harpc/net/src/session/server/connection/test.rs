@@ -44,6 +44,7 @@ use super::{
 use crate::session::server::{
     SessionConfig, SessionEvent, SessionId, Transaction,
     connection::{ConnectionDelegateTask, TransactionCollection},
+    metrics::SessionMetrics,
     session_id::test_utils::mock_session_id,
     test::{make_request_begin, make_request_frame},
     transaction::ServerTransactionPermit as _,
@@ -100,9 +101,11 @@ impl Setup {
             transactions: TransactionCollection::new(config, cancel.clone()),
             output: output_tx,
             events: events_tx,
+            metrics: SessionMetrics::new(),
             config,
 
             _permit: permit,
+            _recorder: SessionMetrics::new().connection_accepted(),
         };
 
         let storage = Arc::clone(task.transactions.storage());
@@ -0,0 +1,237 @@
+use harpc_types::{procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor};
+use prometheus_client::{
+    encoding::{EncodeLabelSet, EncodeLabelValue, LabelValueEncoder},
+    metrics::{
+        counter::Counter,
+        family::Family,
+        gauge::Gauge,
+        histogram::{Histogram, exponential_buckets},
+    },
+    registry::{Registry, Unit},
+};
+use tokio::time::Instant;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+struct ProcedureLabels {
+    subsystem: u16,
+    version: String,
+    procedure: u16,
+}
+
+impl ProcedureLabels {
+    fn new(subsystem: SubsystemDescriptor, procedure: ProcedureDescriptor) -> Self {
+        Self {
+            subsystem: subsystem.id.value(),
+            version: subsystem.version.to_string(),
+            procedure: procedure.id.value(),
+        }
+    }
+}
+
+/// The reason a transaction has been rejected before it has been delivered to the upper layer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub(crate) enum RejectionReason {
+    /// The per-connection transaction limit has been reached.
+    TransactionLimit,
+    /// The transaction buffer was full and didn't make space within the delivery deadline.
+    DeliveryTimeout,
+    /// The session layer is shutting down and no longer accepts transactions.
+    Shutdown,
+}
+
+impl RejectionReason {
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::TransactionLimit => "transaction_limit",
+            Self::DeliveryTimeout => "delivery_timeout",
+            Self::Shutdown => "shutdown",
+        }
+    }
+}
+
+impl EncodeLabelValue for RejectionReason {
+    fn encode(&self, encoder: &mut LabelValueEncoder) -> core::fmt::Result {
+        EncodeLabelValue::encode(&self.as_str(), encoder)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, EncodeLabelSet)]
+struct RejectionLabels {
+    reason: RejectionReason,
+}
+
+fn duration_histogram() -> Histogram {
+    // 500µs up to ~16s
+    Histogram::new(exponential_buckets(0.0005, 2.0, 16))
+}
+
+/// Metrics recorded by the server session layer.
+///
+/// All metrics are reference counted, cloning is cheap and every clone records into the same
+/// metrics.
+#[derive(Debug, Clone)]
+pub(crate) struct SessionMetrics {
+    connections: Counter,
+    connections_active: Gauge,
+    connections_rejected: Counter,
+
+    transactions: Family<ProcedureLabels, Counter>,
+    transactions_active: Gauge,
+    transactions_rejected: Family<RejectionLabels, Counter>,
+    transaction_duration: Family<ProcedureLabels, Histogram, fn() -> Histogram>,
+
+    request_bytes: Family<ProcedureLabels, Counter>,
+    response_bytes: Family<ProcedureLabels, Counter>,
+}
+
+impl SessionMetrics {
+    pub(crate) fn new() -> Self {
+        Self {
+            connections: Counter::default(),
+            connections_active: Gauge::default(),
+            connections_rejected: Counter::default(),
+
+            transactions: Family::default(),
+            transactions_active: Gauge::default(),
+            transactions_rejected: Family::default(),
+            transaction_duration: Family::new_with_constructor(duration_histogram),
+
+            request_bytes: Family::default(),
+            response_bytes: Family::default(),
+        }
+    }
+
+    pub(crate) fn register(&self, registry: &mut Registry) {
+        registry.register(
+            "connections",
+            "Number of accepted connections",
+            self.connections.clone(),
+        );
+        registry.register(
+            "connections_active",
+            "Number of currently open connections",
+            self.connections_active.clone(),
+        );
+        registry.register(
+            "connections_rejected",
+            "Number of connections rejected, because the concurrent connection limit has been \
+             reached",
+            self.connections_rejected.clone(),
+        );
+
+        registry.register(
+            "transactions",
+            "Number of transactions started, by procedure",
+            self.transactions.clone(),
+        );
+        registry.register(
+            "transactions_active",
+            "Number of transactions currently in flight",
+            self.transactions_active.clone(),
+        );
+        registry.register(
+            "transactions_rejected",
+            "Number of transactions rejected before being delivered, by reason",
+            self.transactions_rejected.clone(),
+        );
+        registry.register_with_unit(
+            "transaction_duration",
+            "Time from the start of a transaction until its response has been sent, by procedure",
+            Unit::Seconds,
+            self.transaction_duration.clone(),
+        );
+
+        registry.register_with_unit(
+            "request",
+            "Number of request payload bytes received, by procedure",
+            Unit::Bytes,
+            self.request_bytes.clone(),
+        );
+        registry.register_with_unit(
+            "response",
+            "Number of response payload bytes sent, by procedure",
+            Unit::Bytes,
+            self.response_bytes.clone(),
+        );
+    }
+
+    pub(crate) fn connection_accepted(&self) -> ConnectionRecorder {
+        self.connections.inc();
+        self.connections_active.inc();
+
+        ConnectionRecorder {
+            active: self.connections_active.clone(),
+        }
+    }
+
+    pub(crate) fn connection_rejected(&self) {
+        self.connections_rejected.inc();
+    }
+
+    pub(crate) fn transaction_rejected(&self, reason: RejectionReason) {
+        self.transactions_rejected
+            .get_or_create(&RejectionLabels { reason })
+            .inc();
+    }
+
+    pub(crate) fn transaction_started(
+        &self,
+        subsystem: SubsystemDescriptor,
+        procedure: ProcedureDescriptor,
+    ) -> TransactionRecorder {
+        let labels = ProcedureLabels::new(subsystem, procedure);
+
+        self.transactions.get_or_create(&labels).inc();
+        self.transactions_active.inc();
+
+        TransactionRecorder {
+            start: Instant::now(),
+            active: self.transactions_active.clone(),
+            duration: self.transaction_duration.get_or_create(&labels).clone(),
+            request_bytes: self.request_bytes.get_or_create(&labels).clone(),
+            response_bytes: self.response_bytes.get_or_create(&labels).clone(),
+        }
+    }
+}
+
+/// Tracks the lifetime of a connection, once dropped the connection is no longer considered
+/// active.
+#[derive(Debug)]
+pub(crate) struct ConnectionRecorder {
+    active: Gauge,
+}
+
+impl Drop for ConnectionRecorder {
+    fn drop(&mut self) {
+        self.active.dec();
+    }
+}
+
+/// Tracks the lifetime of a transaction, once dropped the transaction is no longer considered in
+/// flight and its duration is recorded.
+#[derive(Debug)]
+pub(crate) struct TransactionRecorder {
+    start: Instant,
+    active: Gauge,
+    duration: Histogram,
+
+    request_bytes: Counter,
+    response_bytes: Counter,
+}
+
+impl TransactionRecorder {
+    pub(crate) fn request_bytes(&self) -> Counter {
+        self.request_bytes.clone()
+    }
+
+    pub(crate) fn record_response(&self, bytes: usize) {
+        self.response_bytes.inc_by(bytes as u64);
+    }
+}
+
+impl Drop for TransactionRecorder {
+    fn drop(&mut self) {
+        self.active.dec();
+        self.duration.observe(self.start.elapsed().as_secs_f64());
+    }
+}
@@ -1,5 +1,6 @@
 mod config;
 mod connection;
+mod metrics;
 pub(crate) mod session_id;
 mod task;
 #[cfg(test)]
@@ -15,13 +16,14 @@ use core::{
 use error_stack::{Report, ResultExt as _};
 use futures::{Stream, stream::FusedStream};
 use libp2p::Multiaddr;
+use prometheus_client::registry::Registry;
 use tokio::sync::{Semaphore, broadcast, mpsc};
 use tokio_util::task::TaskTracker;
 
 pub use self::{config::SessionConfig, session_id::SessionId, transaction::Transaction};
-use self::{session_id::SessionIdProducer, task::Task};
+use self::{metrics::SessionMetrics, session_id::SessionIdProducer, task::Task};
 use super::error::SessionError;
-use crate::transport::TransportLayer;
+use crate::{metrics::MetricsHandle, transport::TransportLayer};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum SessionEvent {
@@ -144,6 +146,9 @@ pub struct SessionLayer {
 
     events: broadcast::Sender<SessionEvent>,
 
+    metrics: SessionMetrics,
+    registry: Arc<Registry>,
+
     transport: TransportLayer,
 
     tasks: TaskTracker,
@@ -156,11 +161,18 @@ impl SessionLayer {
 
         let (events, _) = broadcast::channel(config.event_buffer_size.get());
 
+        let metrics = SessionMetrics::new();
+        let mut registry = Registry::with_prefix("harpc_session");
+        metrics.register(&mut registry);
+
         Self {
             config,
 
             events,
 
+            metrics,
+            registry: Arc::new(registry),
+
             transport,
 
             tasks,
@@ -187,6 +199,15 @@ impl SessionLayer {
         &self.transport
     }
 
+    /// Handle to the metrics of the session layer and its underlying transport layer.
+    ///
+    /// The handle stays valid after the session layer has started listening, and can be used to
+    /// expose the metrics, e.g. over HTTP.
+    #[must_use]
+    pub fn metrics(&self) -> MetricsHandle {
+        self.transport.metrics().with(Arc::clone(&self.registry))
+    }
+
     /// Listen for incoming connections on the given address.
     ///
     /// # Errors
@@ -211,6 +232,7 @@ impl SessionLayer {
             )),
             output,
             events: self.events.clone(),
+            metrics: self.metrics,
 
             _transport: self.transport,
         };
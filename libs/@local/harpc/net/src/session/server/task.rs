@@ -9,7 +9,7 @@ use tokio_util::{sync::CancellationToken, task::TaskTracker};
 
 use super::{
     SessionConfig, SessionEvent, connection::collection::TransactionCollection,
-    session_id::SessionIdProducer, transaction::Transaction,
+    metrics::SessionMetrics, session_id::SessionIdProducer, transaction::Transaction,
 };
 use crate::{
     session::server::connection::ConnectionTask,
@@ -28,6 +28,7 @@ pub(crate) struct Task {
 
     pub output: mpsc::Sender<Transaction>,
     pub events: broadcast::Sender<SessionEvent>,
+    pub metrics: SessionMetrics,
 
     // significant because of the Drop, if dropped this will stop the task automatically
     pub _transport: TransportLayer,
@@ -88,6 +89,7 @@ impl Task {
                 Ok(permit) => permit,
                 Err(TryAcquireError::NoPermits) => {
                     // we have reached the connection limit, we can't accept new connections
+                    self.metrics.connection_rejected();
                     continue;
                 }
                 Err(TryAcquireError::Closed) => {
@@ -105,8 +107,10 @@ impl Task {
                 transactions: TransactionCollection::new(self.config, cancel.clone()),
                 output: self.output.clone(),
                 events: self.events.clone(),
+                metrics: self.metrics.clone(),
 
                 _permit: permit,
+                _recorder: self.metrics.connection_accepted(),
             };
 
             tasks.spawn(task.run(sink, stream, tasks.clone(), cancel));
@@ -677,3 +677,60 @@ async fn connection_reclaim() {
     assert_response(stream, "hello").await;
     drop(sink);
 }
+
+#[tokio::test]
+async fn metrics() {
+    let address = memory_address();
+
+    let (server, (), metrics, _server_guard) = session_map(
+        SessionConfig::default(),
+        address.clone(),
+        |_| (),
+        SessionLayer::metrics,
+    )
+    .await;
+    let (client, _client_guard) = layer();
+
+    let system = EchoSystem::new();
+    system.spawn(server);
+
+    let OutgoingConnection {
+        mut sink, stream, ..
+    } = connect(&client, address).await;
+
+    sink.send(make_request_begin(
+        RequestFlag::EndOfRequest,
+        b"hello" as &[_],
+    ))
+    .await
+    .expect("should be able to send");
+
+    assert_response(stream, "hello").await;
+
+    let output = metrics.render();
+
+    let labels = r#"{subsystem="1",version="v0.1",procedure="1"}"#;
+    for expected in [
+        "harpc_session_connections_total 1".to_owned(),
+        "harpc_session_connections_active 1".to_owned(),
+        format!("harpc_session_transactions_total{labels} 1"),
+        format!("harpc_session_request_bytes_total{labels} 5"),
+        format!("harpc_session_response_bytes_total{labels} 5"),
+    ] {
+        assert!(
+            output.lines().any(|line| line == expected),
+            "expected `{expected}` in:\n{output}"
+        );
+    }
+
+    // The metrics of the transport layer are rendered together with the session metrics.
+    assert!(
+        output
+            .lines()
+            .any(|line| line.starts_with("# TYPE libp2p_")),
+        "expected transport metrics in:\n{output}"
+    );
+
+    assert_eq!(output.matches("# EOF").count(), 1);
+    assert!(output.ends_with("# EOF\n"));
+}
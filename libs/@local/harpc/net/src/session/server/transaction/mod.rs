@@ -19,13 +19,17 @@ use harpc_wire_protocol::{
     response::Response,
 };
 use libp2p::PeerId;
+use prometheus_client::metrics::counter::Counter;
 use tokio::{select, sync::mpsc};
 use tokio_util::{
     sync::{CancellationToken, PollSendError, PollSender},
     task::TaskTracker,
 };
 
-use super::{SessionConfig, connection::collection::TransactionPermit, session_id::SessionId};
+use super::{
+    SessionConfig, connection::collection::TransactionPermit, metrics::TransactionRecorder,
+    session_id::SessionId,
+};
 use crate::session::writer::{ResponseContext, ResponseWriter, WriterOptions};
 
 pub(crate) trait ServerTransactionPermit: Send + Sync + 'static {
@@ -43,6 +47,7 @@ struct TransactionSendDelegateTask<P> {
     tx: mpsc::Sender<Response>,
 
     permit: Arc<P>,
+    recorder: TransactionRecorder,
 }
 
 impl<P> TransactionSendDelegateTask<P>
@@ -97,6 +102,7 @@ where
                     // we had an error previously, so just ignore the rest of the stream
                 }
                 Ok(bytes) => {
+                    self.recorder.record_response(bytes.len());
                     writer.push(bytes);
 
                     if let Err(error) = writer.write().await {
@@ -137,6 +143,7 @@ pub(crate) struct TransactionTask<P> {
     response_tx: mpsc::Sender<Response>,
 
     permit: Arc<P>,
+    recorder: TransactionRecorder,
 }
 
 impl<P> TransactionTask<P>
@@ -151,6 +158,7 @@ where
             tx: self.response_tx,
 
             permit: self.permit,
+            recorder: self.recorder,
         };
 
         tasks.spawn(send.run());
@@ -167,6 +175,7 @@ pub(crate) struct TransactionParts<P> {
     pub tx: mpsc::Sender<Response>,
 
     pub permit: P,
+    pub recorder: TransactionRecorder,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -216,6 +225,7 @@ pub struct Transaction {
     response: mpsc::Sender<Result<Bytes, NetworkError>>,
 
     permit: Arc<TransactionPermit>,
+    request_bytes: Counter,
 }
 
 impl Transaction {
@@ -232,6 +242,7 @@ impl Transaction {
             rx,
             tx,
             permit,
+            recorder,
         }: TransactionParts<TransactionPermit>,
     ) -> (Self, TransactionTask<TransactionPermit>) {
         let permit = Arc::new(permit);
@@ -255,6 +266,7 @@ impl Transaction {
             response: response_tx,
 
             permit: Arc::clone(&permit),
+            request_bytes: recorder.request_bytes(),
         };
 
         let task = TransactionTask {
@@ -264,6 +276,7 @@ impl Transaction {
             response_tx: tx,
 
             permit,
+            recorder,
         };
 
         (transaction, task)
@@ -281,7 +294,7 @@ impl Transaction {
             inner: PollSender::new(self.response),
         };
 
-        let stream = TransactionStream::new(self.request, self.permit);
+        let stream = TransactionStream::new(self.request, self.permit, self.request_bytes);
 
         (context, sink, stream)
     }
@@ -298,7 +311,7 @@ impl Transaction {
     }
 
     pub fn into_stream(self) -> TransactionStream {
-        TransactionStream::new(self.request, self.permit)
+        TransactionStream::new(self.request, self.permit, self.request_bytes)
     }
 }
 
@@ -317,15 +330,21 @@ enum TransactionStreamState {
 #[derive(Debug)]
 pub struct TransactionStream {
     state: TransactionStreamState,
+    bytes: Counter,
 }
 
 impl TransactionStream {
-    const fn new(sender: tachyonix::Receiver<Request>, permit: Arc<TransactionPermit>) -> Self {
+    const fn new(
+        sender: tachyonix::Receiver<Request>,
+        permit: Arc<TransactionPermit>,
+        bytes: Counter,
+    ) -> Self {
         Self {
             state: TransactionStreamState::Open {
                 sender,
                 _permit: permit,
             },
+            bytes,
         }
     }
 
@@ -362,6 +381,7 @@ impl Stream for TransactionStream {
         }
 
         let bytes = value.body.into_payload().into_bytes();
+        self.bytes.inc_by(bytes.len() as u64);
 
         Poll::Ready(Some(bytes))
     }
@@ -37,14 +37,18 @@ use harpc_wire_protocol::{
     },
     test_utils::mock_request_id,
 };
+use prometheus_client::metrics::counter::Counter;
 use tokio::{sync::mpsc, task::JoinHandle};
 use tokio_stream::StreamExt as _;
 use tokio_util::sync::CancellationToken;
 
 use super::{ServerTransactionPermit, TransactionStream};
-use crate::session::server::{
-    SessionConfig, connection::test::make_transaction_permit,
-    transaction::TransactionSendDelegateTask,
+use crate::session::{
+    server::{
+        SessionConfig, connection::test::make_transaction_permit, metrics::SessionMetrics,
+        transaction::TransactionSendDelegateTask,
+    },
+    test::Descriptor,
 };
 
 fn config_delay() -> SessionConfig {
@@ -99,6 +103,10 @@ fn setup_send(
             id: mock_request_id(0),
             cancel: CancellationToken::new(),
         }),
+        recorder: SessionMetrics::new().transaction_started(
+            Descriptor::default().subsystem,
+            Descriptor::default().procedure,
+        ),
     };
 
     let handle = tokio::spawn(task.run());
@@ -1412,7 +1420,7 @@ async fn setup_recv() -> (tachyonix::Sender<Request>, TransactionStream) {
     let (permit, tx, rx) =
         make_transaction_permit(SessionConfig::default(), mock_request_id(0x00)).await;
 
-    let stream = TransactionStream::new(rx, Arc::new(permit));
+    let stream = TransactionStream::new(rx, Arc::new(permit), Counter::default());
 
     (tx, stream)
 }
@@ -22,6 +22,8 @@ use super::{
     },
 };
 use crate::transport::{Transport, TransportConfig, TransportLayer, test::memory_address};
+#[cfg(unix)]
+use crate::transport::{UnixTransport, test::unix_address};
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub(crate) struct Descriptor {
@@ -234,6 +236,14 @@ async fn echo_tcp() {
     echo(libp2p::tcp::tokio::Transport::default, address).await;
 }
 
+#[cfg(unix)]
+#[test_log::test(tokio::test(flavor = "multi_thread", worker_threads = 8))]
+async fn echo_unix() {
+    let address = unix_address();
+
+    echo(UnixTransport::new, address).await;
+}
+
 struct ClientOptions {
     length: usize,
     index: u8,
@@ -386,3 +396,11 @@ async fn echo_tcp_concurrent() {
 
     echo_concurrent(libp2p::tcp::tokio::Transport::default, address, 4).await;
 }
+
+#[cfg(unix)]
+#[test_log::test(tokio::test(flavor = "multi_thread", worker_threads = 8))]
+async fn echo_unix_concurrent() {
+    let address = unix_address();
+
+    echo_concurrent(UnixTransport::new, address, 4).await;
+}
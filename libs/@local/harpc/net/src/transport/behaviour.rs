@@ -1,4 +1,9 @@
-use libp2p::{Swarm, identify, ping, swarm::NetworkBehaviour};
+use libp2p::{
+    Swarm,
+    allow_block_list::{self, AllowedPeers},
+    identify, ping,
+    swarm::{NetworkBehaviour, behaviour::toggle::Toggle},
+};
 use libp2p_stream as stream;
 
 // in a separate file because `NetworkBehaviour` macro expects that `error-stack::Result` isn't
@@ -8,6 +13,8 @@ pub(crate) struct TransportBehaviour {
     pub stream: stream::Behaviour,
     pub identify: identify::Behaviour,
     pub ping: ping::Behaviour,
+    /// Only enabled if an allowlist has been configured.
+    pub allowlist: Toggle<allow_block_list::Behaviour<AllowedPeers>>,
 }
 
 pub(crate) type TransportSwarm = Swarm<TransportBehaviour>;
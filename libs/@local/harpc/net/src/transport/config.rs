@@ -6,6 +6,7 @@ use libp2p::{
     yamux::{self, WindowUpdateMode},
 };
 
+use super::IdentityConfig;
 use crate::macros::non_zero;
 
 /// The configuration for outbound pings.
@@ -191,8 +192,11 @@ impl SwarmConfig {
 }
 
 /// Configuration for the transport layer.
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct TransportConfig {
+    /// Identity of the transport layer and the peers it is allowed to communicate with.
+    pub identity: IdentityConfig,
+
     /// Configuration for the ping protocol.
     pub ping: PingConfig,
 
@@ -209,9 +213,18 @@ pub struct TransportConfig {
     pub ipc_buffer_size: NonZero<usize>,
 }
 
+impl TransportConfig {
+    #[must_use]
+    pub fn with_identity(mut self, identity: IdentityConfig) -> Self {
+        self.identity = identity;
+        self
+    }
+}
+
 impl Default for TransportConfig {
     fn default() -> Self {
         Self {
+            identity: IdentityConfig::default(),
             ping: PingConfig::default(),
             swarm: SwarmConfig::default(),
             yamux: YamuxConfig::default(),
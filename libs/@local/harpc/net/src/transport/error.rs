@@ -25,3 +25,15 @@ pub enum TransportError {
     #[error("Unable to initialize underlying transport layer of swarm")]
     SetupSwarmTransport,
 }
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+pub enum IdentityError {
+    #[error("Unable to read the key file")]
+    Read,
+    #[error("Unable to write the key file")]
+    Write,
+    #[error("The key file does not contain a valid keypair")]
+    Decode,
+    #[error("Unable to encode the keypair")]
+    Encode,
+}
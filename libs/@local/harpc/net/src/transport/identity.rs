@@ -0,0 +1,176 @@
+use std::{
+    collections::HashSet,
+    fs,
+    io::{self, Write as _},
+    path::Path,
+};
+
+use error_stack::{Report, ResultExt as _};
+use libp2p::{PeerId, identity::Keypair};
+
+use super::error::IdentityError;
+
+/// Identity of the transport layer and the peers it is willing to communicate with.
+///
+/// Connections are authenticated using the noise protocol, the authenticated [`PeerId`] is derived
+/// from the public key of the remote peer.
+#[derive(Debug, Clone, Default)]
+pub struct IdentityConfig {
+    /// Keypair used to authenticate the local peer.
+    ///
+    /// If `None`, a fresh keypair is generated every time the transport layer is started.
+    ///
+    /// **Default:** `None`
+    pub keypair: Option<Keypair>,
+
+    /// Peers that are allowed to connect or be connected to.
+    ///
+    /// If `None`, connections to and from any peer are permitted. Connections to or from peers
+    /// not on the allowlist are closed as soon as the peer has been authenticated.
+    ///
+    /// **Default:** `None`
+    pub allowlist: Option<HashSet<PeerId>>,
+}
+
+impl IdentityConfig {
+    #[must_use]
+    pub fn with_keypair(mut self, keypair: Keypair) -> Self {
+        self.keypair = Some(keypair);
+        self
+    }
+
+    #[must_use]
+    pub fn with_allowlist(mut self, peers: impl IntoIterator<Item = PeerId>) -> Self {
+        self.allowlist = Some(peers.into_iter().collect());
+        self
+    }
+}
+
+/// Load a keypair from a key file.
+///
+/// The key file contains the protobuf encoding of the keypair, as created by
+/// [`generate_keypair`].
+///
+/// # Errors
+///
+/// - [`IdentityError::Read`] if the key file cannot be read
+/// - [`IdentityError::Decode`] if the key file does not contain a valid keypair
+pub fn load_keypair(path: impl AsRef<Path>) -> Result<Keypair, Report<IdentityError>> {
+    let path = path.as_ref();
+
+    let bytes = fs::read(path)
+        .change_context(IdentityError::Read)
+        .attach_printable_lazy(|| path.display().to_string())?;
+
+    Keypair::from_protobuf_encoding(&bytes)
+        .change_context(IdentityError::Decode)
+        .attach_printable_lazy(|| path.display().to_string())
+}
+
+/// Generate a new ed25519 keypair and persist it to a new key file.
+///
+/// On unix systems the key file is only readable by the current user. Fails if the key file
+/// already exists, to never overwrite an existing identity.
+///
+/// # Errors
+///
+/// - [`IdentityError::Encode`] if the keypair cannot be encoded
+/// - [`IdentityError::Write`] if the key file cannot be created or written to
+pub fn generate_keypair(path: impl AsRef<Path>) -> Result<Keypair, Report<IdentityError>> {
+    let path = path.as_ref();
+
+    let keypair = Keypair::generate_ed25519();
+    let bytes = keypair
+        .to_protobuf_encoding()
+        .change_context(IdentityError::Encode)?;
+
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt as _;
+
+        options.mode(0o600);
+    }
+
+    options
+        .open(path)
+        .and_then(|mut file| file.write_all(&bytes))
+        .change_context(IdentityError::Write)
+        .attach_printable_lazy(|| path.display().to_string())?;
+
+    Ok(keypair)
+}
+
+/// Load a keypair from a key file, generating a new one if the key file does not exist.
+///
+/// # Errors
+///
+/// See [`load_keypair`] and [`generate_keypair`].
+pub fn load_or_generate_keypair(path: impl AsRef<Path>) -> Result<Keypair, Report<IdentityError>> {
+    let path = path.as_ref();
+
+    match load_keypair(path) {
+        Ok(keypair) => Ok(keypair),
+        Err(error)
+            if error
+                .downcast_ref::<io::Error>()
+                .is_some_and(|error| error.kind() == io::ErrorKind::NotFound) =>
+        {
+            tracing::info!(path = %path.display(), "key file not found, generating a new keypair");
+
+            generate_keypair(path)
+        }
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::sync::atomic::{AtomicU64, Ordering};
+    use std::path::PathBuf;
+
+    use super::{generate_keypair, load_keypair, load_or_generate_keypair};
+
+    fn key_file() -> PathBuf {
+        static KEY: AtomicU64 = AtomicU64::new(0);
+
+        let id = KEY.fetch_add(1, Ordering::SeqCst);
+        std::env::temp_dir().join(format!("harpc-{}-{id}.key", std::process::id()))
+    }
+
+    #[test]
+    fn roundtrip() {
+        let path = key_file();
+
+        let generated = generate_keypair(&path).expect("should be able to generate keypair");
+        let loaded = load_keypair(&path).expect("should be able to load keypair");
+
+        assert_eq!(generated.public(), loaded.public());
+
+        std::fs::remove_file(path).expect("should be able to remove key file");
+    }
+
+    #[test]
+    fn never_overwrite() {
+        let path = key_file();
+
+        generate_keypair(&path).expect("should be able to generate keypair");
+        generate_keypair(&path).expect_err("should not overwrite existing key file");
+
+        std::fs::remove_file(path).expect("should be able to remove key file");
+    }
+
+    #[test]
+    fn load_or_generate_is_stable() {
+        let path = key_file();
+
+        let first = load_or_generate_keypair(&path).expect("should be able to generate keypair");
+        let second = load_or_generate_keypair(&path).expect("should be able to load keypair");
+
+        assert_eq!(first.public(), second.public());
+
+        std::fs::remove_file(path).expect("should be able to remove key file");
+    }
+}
@@ -3,11 +3,14 @@ pub mod client;
 mod config;
 pub mod connection;
 pub mod error;
+mod identity;
 mod ipc;
 mod server;
 mod task;
 #[cfg(test)]
 pub(crate) mod test;
+#[cfg(unix)]
+mod unix;
 
 use alloc::sync::Arc;
 
@@ -23,6 +26,8 @@ use tokio_util::{
     task::TaskTracker,
 };
 
+#[cfg(unix)]
+pub use self::unix::UnixTransport;
 use self::{
     client::ClientCodec,
     connection::{IncomingConnections, OutgoingConnection},
@@ -31,8 +36,10 @@ use self::{
 };
 pub use self::{
     config::{SwarmConfig, TransportConfig, YamuxConfig},
+    identity::{IdentityConfig, generate_keypair, load_keypair, load_or_generate_keypair},
     ipc::TransportLayerIpc,
 };
+use crate::metrics::MetricsHandle;
 
 const PROTOCOL_NAME: StreamProtocol = StreamProtocol::new("/harpc/1.0.0");
 
@@ -122,6 +129,24 @@ impl TransportLayer {
         Self::start(config, transport, cancel)
     }
 
+    /// Create a new Unix domain socket transport layer.
+    ///
+    /// This is a convenience method that creates a Unix domain socket transport and starts the
+    /// transport layer. Addresses to listen on or dial are of the form `/unix/<path>`, see
+    /// [`UnixTransport::multiaddr`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the task fails to start.
+    #[cfg(unix)]
+    pub fn unix(
+        config: TransportConfig,
+        cancel: CancellationToken,
+    ) -> Result<Self, Report<TransportError>> {
+        let transport = UnixTransport::new();
+        Self::start(config, transport, cancel)
+    }
+
     pub(crate) fn cancellation_token(&self) -> CancellationToken {
         self.cancel.clone()
     }
@@ -180,6 +205,15 @@ impl TransportLayer {
         &self.registry
     }
 
+    /// Handle to the metrics of the transport layer.
+    ///
+    /// This includes the metrics of the underlying swarm, such as connections, bandwidth and the
+    /// ping and identify protocols.
+    #[must_use]
+    pub fn metrics(&self) -> MetricsHandle {
+        MetricsHandle::new(Arc::clone(&self.registry))
+    }
+
     /// Listen on an address.
     ///
     /// # Errors
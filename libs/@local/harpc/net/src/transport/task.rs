@@ -8,12 +8,13 @@ use std::{
 use error_stack::{Report, ResultExt as _};
 use futures::prelude::stream::StreamExt as _;
 use libp2p::{
-    Multiaddr, PeerId, SwarmBuilder,
+    Multiaddr, PeerId, SwarmBuilder, allow_block_list,
     core::{transport::ListenerId, upgrade},
     identify,
+    identity::Keypair,
     metrics::{self, Metrics, Recorder as _},
     noise, ping,
-    swarm::{ConnectionId, DialError, SwarmEvent, dial_opts::DialOpts},
+    swarm::{ConnectionId, DialError, SwarmEvent, behaviour::toggle::Toggle, dial_opts::DialOpts},
     yamux,
 };
 use libp2p_stream as stream;
@@ -80,7 +81,12 @@ impl TransportTask {
         let (ipx_tx, rx) = mpsc::channel(config.ipc_buffer_size.get());
         let ipc = TransportLayerIpc::new(ipx_tx);
 
-        let swarm = SwarmBuilder::with_new_identity()
+        let keypair = config
+            .identity
+            .keypair
+            .unwrap_or_else(Keypair::generate_ed25519);
+
+        let swarm = SwarmBuilder::with_existing_identity(keypair)
             .with_tokio()
             .with_other_transport(|keypair| {
                 let noise = noise::Config::new(keypair)?;
@@ -136,6 +142,15 @@ impl TransportTask {
                     keys.public(),
                 )),
                 ping: ping::Behaviour::new(config.ping.into()),
+                allowlist: Toggle::from(config.identity.allowlist.map(|peers| {
+                    let mut allowlist = allow_block_list::Behaviour::default();
+
+                    for peer in peers {
+                        allowlist.allow_peer(peer);
+                    }
+
+                    allowlist
+                })),
             });
 
         let swarm = swarm
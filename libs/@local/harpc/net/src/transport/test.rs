@@ -21,14 +21,16 @@ use harpc_wire_protocol::{
     test_utils::mock_request_id,
 };
 use libp2p::{
-    Multiaddr, TransportError, core::transport::MemoryTransport, multiaddr, swarm::DialError,
-    tcp::tokio::Transport,
+    Multiaddr, PeerId, TransportError, core::transport::MemoryTransport, identity::Keypair,
+    multiaddr, swarm::DialError, tcp::tokio::Transport,
 };
 use libp2p_stream::OpenStreamError;
 use multiaddr::multiaddr;
 use tokio_util::sync::CancellationToken;
 
-use super::{TransportConfig, TransportLayer};
+#[cfg(unix)]
+use super::UnixTransport;
+use super::{IdentityConfig, TransportConfig, TransportLayer};
 use crate::transport::connection::{IncomingConnection, OutgoingConnection};
 
 static EXAMPLE_REQUEST: Request = Request {
@@ -71,9 +73,24 @@ pub(crate) fn memory_address() -> libp2p::Multiaddr {
     iter::once(multiaddr::Protocol::Memory(id)).collect()
 }
 
+#[cfg(unix)]
+pub(crate) fn unix_address() -> libp2p::Multiaddr {
+    // socket files share a single namespace (the filesystem), to allow for tests to be run
+    // concurrently, even across processes, we include the process id as well as a counter.
+    static SOCKET: AtomicU64 = AtomicU64::new(0);
+
+    let id = SOCKET.fetch_add(1, Ordering::SeqCst);
+    let path = std::env::temp_dir().join(format!("harpc-{}-{id}.sock", std::process::id()));
+
+    UnixTransport::multiaddr(path).expect("temporary directory should be valid UTF-8")
+}
+
 pub(crate) fn layer() -> (TransportLayer, impl Drop) {
+    layer_with(TransportConfig::default())
+}
+
+pub(crate) fn layer_with(config: TransportConfig) -> (TransportLayer, impl Drop) {
     let transport = MemoryTransport::default();
-    let config = TransportConfig::default();
     let cancel = CancellationToken::new();
 
     let layer = TransportLayer::start(config, transport, cancel.clone())
@@ -93,6 +110,17 @@ pub(crate) fn layer_tcp() -> (TransportLayer, impl Drop) {
     (layer, cancel.drop_guard())
 }
 
+#[cfg(unix)]
+pub(crate) fn layer_unix() -> (TransportLayer, impl Drop) {
+    let config = TransportConfig::default();
+    let cancel = CancellationToken::new();
+
+    let layer =
+        TransportLayer::unix(config, cancel.clone()).expect("should be able to create swarm");
+
+    (layer, cancel.drop_guard())
+}
+
 #[tokio::test]
 async fn lookup_peer() {
     let (server, _guard_server) = layer();
@@ -483,6 +511,161 @@ async fn establish_connection_server_offline() {
         .expect("underlying error should be OpenStreamError");
 }
 
+#[tokio::test]
+async fn existing_identity() {
+    let keypair = Keypair::generate_ed25519();
+
+    let (layer, _guard) = layer_with(
+        TransportConfig::default()
+            .with_identity(IdentityConfig::default().with_keypair(keypair.clone())),
+    );
+
+    assert_eq!(layer.peer_id(), keypair.public().to_peer_id());
+}
+
+#[tokio::test]
+async fn dialer_allowlist_permits_peer() {
+    let (server, _guard_server) = layer();
+
+    let (client, _guard_client) = layer_with(
+        TransportConfig::default()
+            .with_identity(IdentityConfig::default().with_allowlist([server.peer_id()])),
+    );
+
+    let address = memory_address();
+
+    server
+        .listen_on(address.clone())
+        .await
+        .expect("memory transport should be able to listen on memory address");
+
+    // wait for `DEFAULT_DELAY` to make sure the server is ready
+    // this is more than strictly necessary, but it's better to be safe
+    tokio::time::sleep(DEFAULT_DELAY).await;
+
+    let peer_id = client
+        .lookup_peer(address)
+        .await
+        .expect("should be able to lookup peer");
+
+    assert_eq!(peer_id, server.peer_id());
+}
+
+#[tokio::test]
+async fn dialer_allowlist_denies_unknown_peer() {
+    let (server, _guard_server) = layer();
+
+    let (client, _guard_client) = layer_with(
+        TransportConfig::default()
+            .with_identity(IdentityConfig::default().with_allowlist([PeerId::random()])),
+    );
+
+    let address = memory_address();
+
+    server
+        .listen_on(address.clone())
+        .await
+        .expect("memory transport should be able to listen on memory address");
+
+    // wait for `DEFAULT_DELAY` to make sure the server is ready
+    // this is more than strictly necessary, but it's better to be safe
+    tokio::time::sleep(DEFAULT_DELAY).await;
+
+    let error = client
+        .lookup_peer(address)
+        .await
+        .expect_err("peer is not on the allowlist");
+
+    let dial = error
+        .downcast_ref::<DialError>()
+        .expect("should be dial error");
+
+    assert_matches!(dial, DialError::Denied { .. });
+}
+
+#[tokio::test]
+async fn listener_allowlist_permits_peer() {
+    let (client, _guard_client) = layer();
+    let (server, _guard_server) = layer_with(
+        TransportConfig::default()
+            .with_identity(IdentityConfig::default().with_allowlist([client.peer_id()])),
+    );
+
+    let address = memory_address();
+
+    server
+        .listen_on(address.clone())
+        .await
+        .expect("memory transport should be able to listen on memory address");
+
+    let server_id = server.peer_id();
+
+    let mut incoming = server.listen().await.expect("should be able to listen");
+
+    // wait for `DEFAULT_DELAY` to make sure the server is ready
+    // this is more than strictly necessary, but it's better to be safe
+    tokio::time::sleep(DEFAULT_DELAY).await;
+
+    client
+        .lookup_peer(address)
+        .await
+        .expect("should be able to lookup peer");
+
+    let _connection = client
+        .dial(server_id)
+        .await
+        .expect("client is on the allowlist of the server");
+
+    let IncomingConnection { peer_id, .. } = incoming
+        .next()
+        .await
+        .expect("should receive the connection");
+    assert_eq!(peer_id, client.peer_id());
+}
+
+#[tokio::test]
+async fn listener_allowlist_denies_unknown_peer() {
+    let (client, _guard_client) = layer();
+    let (server, _guard_server) = layer_with(
+        TransportConfig::default()
+            .with_identity(IdentityConfig::default().with_allowlist([PeerId::random()])),
+    );
+
+    let address = memory_address();
+
+    server
+        .listen_on(address.clone())
+        .await
+        .expect("memory transport should be able to listen on memory address");
+
+    let server_id = server.peer_id();
+
+    let mut incoming = server.listen().await.expect("should be able to listen");
+
+    // wait for `DEFAULT_DELAY` to make sure the server is ready
+    // this is more than strictly necessary, but it's better to be safe
+    tokio::time::sleep(DEFAULT_DELAY).await;
+
+    // The server closes the connection as soon as the client has been authenticated, depending on
+    // the timing either the lookup or opening the stream fails, or the stream is never accepted.
+    let connection = tokio::time::timeout(Duration::from_secs(1), async {
+        client.lookup_peer(address).await?;
+        client.dial(server_id).await
+    })
+    .await;
+    assert!(
+        !matches!(connection, Ok(Ok(_))),
+        "client is not on the allowlist of the server"
+    );
+
+    assert!(
+        tokio::time::timeout(DEFAULT_DELAY, incoming.next())
+            .await
+            .is_err(),
+        "the server should not accept the connection"
+    );
+}
+
 #[tokio::test]
 async fn listen_on() {
     let (layer, _guard) = layer();
@@ -525,3 +708,112 @@ async fn listen_on_tcp_unspecified() {
     assert_matches!(protocol[0], multiaddr::Protocol::Ip4(addr) if addr != Ipv4Addr::UNSPECIFIED);
     assert_matches!(protocol[1], multiaddr::Protocol::Tcp(port) if port != 0);
 }
+
+#[cfg(unix)]
+#[tokio::test]
+async fn listen_on_unix() {
+    let (layer, _guard) = layer_unix();
+
+    let address = unix_address();
+
+    let chosen = layer
+        .listen_on(address.clone())
+        .await
+        .expect("unix transport should be able to listen on unix address");
+
+    assert_eq!(chosen, address);
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn listen_on_unix_duplicate_address() {
+    let (layer, _guard) = layer_unix();
+
+    let address = unix_address();
+
+    layer
+        .listen_on(address.clone())
+        .await
+        .expect("unix transport should be able to listen on unix address");
+
+    let _error = layer
+        .listen_on(address)
+        .await
+        .expect_err("should not be able to listen on the same address twice");
+}
+
+#[cfg(unix)]
+#[tokio::test]
+async fn send_request_response_unix() {
+    let (server, _guard_server) = layer_unix();
+    let (client, _guard_client) = layer_unix();
+
+    let address = unix_address();
+
+    server
+        .listen_on(address.clone())
+        .await
+        .expect("unix transport should be able to listen on unix address");
+
+    let server_id = server.peer_id();
+
+    let mut stream = server.listen().await.expect("should be able to listen");
+
+    let handle = tokio::spawn(async move {
+        let Some(IncomingConnection {
+            mut sink,
+            mut stream,
+            ..
+        }) = stream.next().await
+        else {
+            panic!("should receive connection");
+        };
+
+        let request = stream
+            .next()
+            .await
+            .expect("should receive another request")
+            .expect("should be well-formed request");
+        assert_eq!(request, EXAMPLE_REQUEST);
+
+        sink.send(EXAMPLE_RESPONSE.clone())
+            .await
+            .expect("should be able to send response");
+    });
+
+    // wait for `DEFAULT_DELAY` to make sure the server is ready
+    // this is more than strictly necessary, but it's better to be safe
+    tokio::time::sleep(DEFAULT_DELAY).await;
+
+    let peer_id = client
+        .lookup_peer(address)
+        .await
+        .expect("should be able to lookup peer");
+    assert_eq!(peer_id, server_id);
+
+    let OutgoingConnection {
+        mut sink,
+        mut stream,
+        ..
+    } = client
+        .dial(server_id)
+        .await
+        .expect("should be able to dial");
+
+    sink.send(EXAMPLE_REQUEST.clone())
+        .await
+        .expect("should be able to send request");
+
+    let response = stream
+        .next()
+        .await
+        .expect("should receive response")
+        .expect("should be well-formed response");
+
+    assert_eq!(response, EXAMPLE_RESPONSE);
+
+    tokio::time::timeout(Duration::from_secs(1), handle)
+        .await
+        .expect("should have finished before deadline")
+        .expect("should not have panicked during handling");
+}
@@ -0,0 +1,252 @@
+use alloc::{borrow::Cow, collections::VecDeque};
+use core::{
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use futures::future::{self, BoxFuture, FutureExt as _};
+use libp2p::{
+    Multiaddr, TransportError,
+    core::transport::{DialOpts, ListenerId, TransportEvent},
+    multiaddr::Protocol,
+};
+use tokio::net::{UnixListener, UnixStream};
+use tokio_util::compat::{Compat, TokioAsyncReadCompatExt as _};
+
+/// Convert a multiaddr of the form `/unix/<path>` (optionally followed by `/p2p/<peer>`) into
+/// the path of the socket.
+fn multiaddr_to_path(address: &Multiaddr) -> Option<PathBuf> {
+    let mut iter = address.iter();
+
+    let Some(Protocol::Unix(path)) = iter.next() else {
+        return None;
+    };
+
+    match iter.next() {
+        None | Some(Protocol::P2p(_)) => {}
+        Some(_) => return None,
+    }
+
+    if iter.next().is_some() {
+        return None;
+    }
+
+    Some(PathBuf::from(path.as_ref()))
+}
+
+#[derive(Debug)]
+struct Listener {
+    id: ListenerId,
+    address: Multiaddr,
+    path: PathBuf,
+    inner: UnixListener,
+
+    announced: bool,
+}
+
+impl Drop for Listener {
+    fn drop(&mut self) {
+        // `bind` creates the socket file, but it isn't removed once the listener is closed, if it
+        // were to stay subsequent binds to the same path would fail with `AddrInUse`.
+        if let Err(error) = std::fs::remove_file(&self.path) {
+            tracing::warn!(path = %self.path.display(), %error, "unable to remove socket file");
+        }
+    }
+}
+
+/// A transport over Unix domain sockets.
+///
+/// Addresses are of the form `/unix/<path>`, use [`UnixTransport::multiaddr`] to create one from
+/// a path, as the textual representation of a multiaddr is unable to represent paths containing
+/// `/`.
+///
+/// The socket file is created once a listener has been bound and removed once the listener is
+/// removed or the transport is dropped. Access to the socket is governed by the filesystem
+/// permissions of the socket file and its parent directory.
+#[derive(Debug, Default)]
+pub struct UnixTransport {
+    listeners: Vec<Listener>,
+    pending: VecDeque<TransportEvent<<Self as libp2p::Transport>::ListenerUpgrade, io::Error>>,
+
+    waker: Option<Waker>,
+}
+
+impl UnixTransport {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the multiaddr of a Unix domain socket located at `path`.
+    ///
+    /// Returns `None` if the path is not valid UTF-8.
+    pub fn multiaddr(path: impl AsRef<Path>) -> Option<Multiaddr> {
+        let path = path.as_ref().to_str()?;
+
+        Some(Multiaddr::empty().with(Protocol::Unix(Cow::Owned(path.to_owned()))))
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl libp2p::Transport for UnixTransport {
+    type Dial = BoxFuture<'static, Result<Self::Output, Self::Error>>;
+    type Error = io::Error;
+    type ListenerUpgrade = future::Ready<Result<Self::Output, Self::Error>>;
+    type Output = Compat<UnixStream>;
+
+    fn listen_on(
+        &mut self,
+        id: ListenerId,
+        addr: Multiaddr,
+    ) -> Result<(), TransportError<Self::Error>> {
+        let Some(path) = multiaddr_to_path(&addr) else {
+            return Err(TransportError::MultiaddrNotSupported(addr));
+        };
+
+        let inner = UnixListener::bind(&path).map_err(TransportError::Other)?;
+
+        self.listeners.push(Listener {
+            id,
+            address: addr,
+            path,
+            inner,
+            announced: false,
+        });
+        self.wake();
+
+        Ok(())
+    }
+
+    fn remove_listener(&mut self, id: ListenerId) -> bool {
+        let Some(index) = self.listeners.iter().position(|listener| listener.id == id) else {
+            return false;
+        };
+
+        drop(self.listeners.swap_remove(index));
+
+        self.pending.push_back(TransportEvent::ListenerClosed {
+            listener_id: id,
+            reason: Ok(()),
+        });
+        self.wake();
+
+        true
+    }
+
+    fn dial(
+        &mut self,
+        addr: Multiaddr,
+        _: DialOpts,
+    ) -> Result<Self::Dial, TransportError<Self::Error>> {
+        let Some(path) = multiaddr_to_path(&addr) else {
+            return Err(TransportError::MultiaddrNotSupported(addr));
+        };
+
+        // `connect` is only called once the future is polled, as required by the `Transport`
+        // contract.
+        Ok(async move {
+            let stream = UnixStream::connect(path).await?;
+
+            Ok(stream.compat())
+        }
+        .boxed())
+    }
+
+    fn poll(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<TransportEvent<Self::ListenerUpgrade, Self::Error>> {
+        let this = self.get_mut();
+
+        if let Some(event) = this.pending.pop_front() {
+            return Poll::Ready(event);
+        }
+
+        for listener in &mut this.listeners {
+            if !listener.announced {
+                listener.announced = true;
+
+                return Poll::Ready(TransportEvent::NewAddress {
+                    listener_id: listener.id,
+                    listen_addr: listener.address.clone(),
+                });
+            }
+
+            match listener.inner.poll_accept(cx) {
+                Poll::Ready(Ok((stream, remote))) => {
+                    // The dialing side is usually unnamed, in that case the best address we can
+                    // give is the one of the listener.
+                    let send_back_addr = remote
+                        .as_pathname()
+                        .and_then(UnixTransport::multiaddr)
+                        .unwrap_or_else(|| listener.address.clone());
+
+                    return Poll::Ready(TransportEvent::Incoming {
+                        listener_id: listener.id,
+                        upgrade: future::ready(Ok(stream.compat())),
+                        local_addr: listener.address.clone(),
+                        send_back_addr,
+                    });
+                }
+                Poll::Ready(Err(error)) => {
+                    return Poll::Ready(TransportEvent::ListenerError {
+                        listener_id: listener.id,
+                        error,
+                    });
+                }
+                Poll::Pending => {}
+            }
+        }
+
+        this.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::Path;
+
+    use libp2p::{PeerId, multiaddr::multiaddr};
+
+    use super::{UnixTransport, multiaddr_to_path};
+
+    #[test]
+    fn path_roundtrip() {
+        let address = UnixTransport::multiaddr("/tmp/harpc.sock").expect("path is valid UTF-8");
+
+        assert_eq!(
+            multiaddr_to_path(&address).as_deref(),
+            Some(Path::new("/tmp/harpc.sock"))
+        );
+    }
+
+    #[test]
+    fn path_with_peer() {
+        let address = UnixTransport::multiaddr("/tmp/harpc.sock")
+            .expect("path is valid UTF-8")
+            .with_p2p(PeerId::random())
+            .expect("should not contain a peer id yet");
+
+        assert_eq!(
+            multiaddr_to_path(&address).as_deref(),
+            Some(Path::new("/tmp/harpc.sock"))
+        );
+    }
+
+    #[test]
+    fn path_unsupported() {
+        let address = multiaddr![Memory(1_u64)];
+
+        assert_eq!(multiaddr_to_path(&address), None);
+    }
+}
@@ -0,0 +1,64 @@
+//! Replay recorded traffic against a running server.
+//!
+//! Reads the recordings written by the [`RecordLayer`] and replays them, in order, against the
+//! server listening on the given address. The report is written to stdout as JSON, the process
+//! exits with a non-zero exit code if any replayed response differs from the recorded one.
+//!
+//! # Usage
+//!
+//! ```text
+//! replay <address> <recordings>
+//! ```
+//!
+//! Where `<address>` is the multiaddr of the server, e.g. `/ip4/127.0.0.1/tcp/4002`, and
+//! `<recordings>` is the path to a file containing newline delimited recordings.
+//!
+//! [`RecordLayer`]: harpc_tower::layer::record::RecordLayer
+#![allow(clippy::print_stdout)]
+
+use std::process::ExitCode;
+
+use harpc_server::replay::replay_remote;
+use harpc_tower::recording::read_recordings;
+use multiaddr::Multiaddr;
+use tokio::{fs::File, io::BufReader};
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let mut args = std::env::args().collect::<Vec<_>>();
+    args.reverse();
+
+    args.pop()
+        .expect("the first argument should be the command invoked");
+
+    let address: Multiaddr = args
+        .pop()
+        .expect("the second argument should be the address of the server")
+        .parse()
+        .expect("address should be a valid multiaddr");
+
+    let path = args
+        .pop()
+        .expect("the third argument should be the path to the recordings");
+
+    let file = File::open(&path)
+        .await
+        .expect("should be able to open recordings");
+    let recordings = read_recordings(BufReader::new(file))
+        .await
+        .expect("should be able to read recordings");
+
+    let report = replay_remote(address, recordings)
+        .await
+        .expect("should be able to replay recordings");
+
+    serde_json::to_writer_pretty(std::io::stdout(), &report)
+        .expect("should be able to write json to stdout");
+    println!();
+
+    if report.is_match() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
@@ -73,6 +73,7 @@ where
                     RequestInfo {
                         subsystem: req.subsystem(),
                         procedure: req.procedure(),
+                        peer: req.peer(),
                     },
                 )
                 .await;
@@ -10,6 +10,8 @@ use harpc_types::{
     subsystem::SubsystemDescriptor,
 };
 
+use crate::limit::LimitKey;
+
 #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
 #[display("subsystem {subsystem} not found")]
 pub struct SubsystemNotFound {
@@ -131,3 +133,22 @@ impl Error for RequestExpectedItemCountMismatch {
 #[derive(Debug, Copy, Clone, PartialEq, Eq, derive_more::Display, derive_more::Error)]
 #[display("unable to delegate request to subsystem implementation")]
 pub struct DelegationError;
+
+/// The caller has exceeded one of its quotas.
+///
+/// See [`LimitLayer`] for details.
+///
+/// [`LimitLayer`]: crate::limit::LimitLayer
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub enum QuotaExceeded {
+    #[display("rate limit per {key} exceeded")]
+    Rate { key: LimitKey },
+    #[display("too many requests per {key} in flight")]
+    InFlight { key: LimitKey },
+}
+
+impl Error for QuotaExceeded {
+    fn provide<'a>(&'a self, request: &mut core::error::Request<'a>) {
+        request.provide_value(ErrorCode::TOO_MANY_REQUESTS);
+    }
+}
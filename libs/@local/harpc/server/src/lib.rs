@@ -8,6 +8,8 @@ extern crate alloc;
 
 pub mod delegate;
 pub mod error;
+pub mod limit;
+pub mod replay;
 pub mod route;
 pub mod router;
 pub mod serve;
@@ -23,11 +25,16 @@ use core::{
 
 use error_stack::{Report, ResultExt as _};
 use futures::{Stream, StreamExt as _, stream::FusedStream};
-pub use harpc_net::{session::server::SessionConfig, transport::TransportConfig};
+pub use harpc_net::{
+    metrics,
+    session::server::SessionConfig,
+    transport::{IdentityConfig, TransportConfig, load_or_generate_keypair},
+};
 use harpc_net::{
     session::server::{EventStream, ListenStream, SessionLayer, Transaction},
     transport::TransportLayer,
 };
+use libp2p_identity::PeerId;
 use multiaddr::Multiaddr;
 use tokio_util::sync::{CancellationToken, DropGuard};
 
@@ -50,7 +57,7 @@ pub enum ServerError {
     Listen,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct ServerConfig {
     pub transport: TransportConfig,
     pub session: SessionConfig,
@@ -93,12 +100,38 @@ impl Server {
         let transport = TransportLayer::tcp(config.transport, token.clone())
             .change_context(ServerError::StartTransportLayer)?;
 
-        let session = SessionLayer::new(config.session, transport);
+        Ok(Self::from_transport(config.session, transport, token))
+    }
+
+    /// Creates a new server instance, which only accepts connections from within the same
+    /// process.
+    ///
+    /// Addresses to listen on are of the form `/memory/<port>`.
+    ///
+    /// # Errors
+    ///
+    /// This function will return an error if:
+    /// - The transport layer fails to start.
+    pub fn memory(config: ServerConfig) -> Result<Self, Report<ServerError>> {
+        let token = CancellationToken::new();
+
+        let transport = TransportLayer::memory(config.transport, token.clone())
+            .change_context(ServerError::StartTransportLayer)?;
 
-        Ok(Self {
+        Ok(Self::from_transport(config.session, transport, token))
+    }
+
+    fn from_transport(
+        config: SessionConfig,
+        transport: TransportLayer,
+        token: CancellationToken,
+    ) -> Self {
+        let session = SessionLayer::new(config, transport);
+
+        Self {
             session,
             guard: token.drop_guard(),
-        })
+        }
     }
 
     /// Returns the event stream for this server.
@@ -107,6 +140,20 @@ impl Server {
         self.session.events()
     }
 
+    /// Returns the peer id of this server, which clients use to authenticate it.
+    #[must_use]
+    pub const fn peer_id(&self) -> PeerId {
+        self.session.transport().peer_id()
+    }
+
+    /// Returns a handle to the metrics of this server.
+    ///
+    /// The handle remains valid once the server has started listening.
+    #[must_use]
+    pub fn metrics(&self) -> metrics::MetricsHandle {
+        self.session.metrics()
+    }
+
     /// Starts listening for incoming connections on the specified address.
     ///
     /// # Errors
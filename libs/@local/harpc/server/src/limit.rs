@@ -0,0 +1,609 @@
+//! Rate limiting and concurrency quotas.
+//!
+//! The [`SessionConfig`] is only able to limit the total number of connections and the number of
+//! transactions per connection, it is unable to distinguish between callers. The [`LimitLayer`]
+//! enforces a [`Quota`] per [`LimitKey`], so that a single misbehaving caller cannot starve every
+//! other caller.
+//!
+//! Multiple layers can be stacked to enforce quotas on different keys at the same time.
+//!
+//! Sessions are bound to a connection, a caller that reconnects is assigned a new session. To
+//! enforce a quota per caller use [`LimitLayer::actor`], which groups requests by the actor that
+//! has been authenticated on the session.
+//!
+//! [`SessionConfig`]: harpc_net::session::server::SessionConfig
+use alloc::sync::Arc;
+use core::{
+    fmt::Debug,
+    hash::Hash,
+    num::NonZero,
+    pin::Pin,
+    sync::atomic::{AtomicUsize, Ordering},
+    task::{Context, Poll},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use harpc_codec::error::NetworkError;
+use harpc_net::session::server::SessionId;
+use harpc_tower::{
+    body::{Body, BodyState, Frame, SizeHint, controlled::Controlled, full::Full},
+    either::Either,
+    request::Request,
+    response::{Parts, Response},
+};
+use harpc_types::{
+    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+};
+use libp2p_identity::PeerId;
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore},
+    time::Instant,
+};
+use tower::{Layer, Service, ServiceExt as _};
+
+use crate::error::QuotaExceeded;
+
+/// The number of requests after which idle limiters are removed.
+const SWEEP_INTERVAL: usize = 1024;
+
+/// What requests are grouped by when enforcing a [`Quota`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, derive_more::Display)]
+pub enum LimitKey {
+    /// Group requests by the peer that sent them.
+    ///
+    /// Requests without a known peer are not limited.
+    #[display("peer")]
+    Peer,
+    /// Group requests by the actor authenticated on the session.
+    ///
+    /// The actor is resolved through the [`ResolveActor`] of the layer, requests on sessions
+    /// without an authenticated actor are grouped by session instead.
+    #[display("actor")]
+    Actor,
+    /// Group requests by the procedure that is called.
+    #[display("procedure")]
+    Procedure,
+}
+
+/// Token bucket rate, allowing for bursts of up to `burst` requests, which is refilled at a
+/// rate of `burst` requests per `period`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rate {
+    pub burst: NonZero<u32>,
+    pub period: Duration,
+}
+
+impl Rate {
+    #[must_use]
+    pub const fn new(burst: NonZero<u32>, period: Duration) -> Self {
+        Self { burst, period }
+    }
+
+    /// Time it takes to refill a single token.
+    fn interval(self) -> Duration {
+        self.period / self.burst.get()
+    }
+}
+
+/// Quota enforced for every distinct key.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Quota {
+    /// Rate at which requests are accepted, `None` disables rate limiting.
+    pub rate: Option<Rate>,
+    /// Maximum number of requests in flight, `None` disables the limit.
+    ///
+    /// A request is in flight until its response has been fully sent.
+    pub in_flight: Option<NonZero<usize>>,
+}
+
+impl Quota {
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            rate: None,
+            in_flight: None,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_rate(mut self, rate: Rate) -> Self {
+        self.rate = Some(rate);
+        self
+    }
+
+    #[must_use]
+    pub const fn with_in_flight(mut self, in_flight: NonZero<usize>) -> Self {
+        self.in_flight = Some(in_flight);
+        self
+    }
+}
+
+/// Resolves the actor that has been authenticated on a session.
+///
+/// Implemented for closures of the form `Fn(SessionId) -> Option<A>`, which usually look up the
+/// actor in the [`SessionStorage`] of the router.
+///
+/// [`SessionStorage`]: crate::session::SessionStorage
+pub trait ResolveActor: Send + Sync + 'static {
+    type Actor: Debug + Clone + Eq + Hash + Send + Sync;
+
+    /// Returns the actor authenticated on `session`, `None` if the session is unauthenticated.
+    fn resolve(&self, session: SessionId) -> Option<Self::Actor>;
+}
+
+impl<F, A> ResolveActor for F
+where
+    F: Fn(SessionId) -> Option<A> + Send + Sync + 'static,
+    A: Debug + Clone + Eq + Hash + Send + Sync,
+{
+    type Actor = A;
+
+    fn resolve(&self, session: SessionId) -> Option<Self::Actor> {
+        self(session)
+    }
+}
+
+/// Resolver used if no actors are known, every session is treated as unauthenticated.
+#[derive(Debug, Copy, Clone, Default)]
+pub struct NoActor;
+
+impl ResolveActor for NoActor {
+    type Actor = !;
+
+    fn resolve(&self, _: SessionId) -> Option<Self::Actor> {
+        None
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Key<A> {
+    Peer(PeerId),
+    Actor(A),
+    Session(SessionId),
+    Procedure(SubsystemDescriptor, ProcedureDescriptor),
+}
+
+impl<A> Key<A> {
+    fn from_request<B, R>(kind: LimitKey, resolver: &R, request: &Request<B>) -> Option<Self>
+    where
+        R: ResolveActor<Actor = A>,
+    {
+        match kind {
+            LimitKey::Peer => request.peer().map(Self::Peer),
+            LimitKey::Actor => Some(
+                resolver
+                    .resolve(request.session())
+                    .map_or_else(|| Self::Session(request.session()), Self::Actor),
+            ),
+            LimitKey::Procedure => Some(Self::Procedure(request.subsystem(), request.procedure())),
+        }
+    }
+}
+
+/// Limiter state of a single key.
+///
+/// The rate limit is implemented using the generic cell rate algorithm (GCRA), which is
+/// equivalent to a token bucket, but only requires a single timestamp as state: the theoretical
+/// arrival time of the next request.
+#[derive(Debug)]
+struct Limiter {
+    arrival: Instant,
+    in_flight: Option<Arc<Semaphore>>,
+}
+
+impl Limiter {
+    fn new(quota: Quota, now: Instant) -> Self {
+        Self {
+            arrival: now,
+            in_flight: quota
+                .in_flight
+                .map(|limit| Arc::new(Semaphore::new(limit.get()))),
+        }
+    }
+
+    fn acquire_rate(&mut self, rate: Rate, now: Instant) -> bool {
+        let interval = rate.interval();
+        let arrival = self.arrival.max(now);
+
+        // the request is only admitted if the bucket has at least a single token left, which is
+        // the case if the arrival time is at most `period - interval` ahead of now.
+        if arrival.duration_since(now) > rate.period.saturating_sub(interval) {
+            return false;
+        }
+
+        self.arrival = arrival + interval;
+        true
+    }
+
+    /// Returns `true` if the limiter is in the same state as a freshly created one.
+    fn is_idle(&self, quota: Quota, now: Instant) -> bool {
+        let in_flight_idle = match (&self.in_flight, quota.in_flight) {
+            (Some(semaphore), Some(limit)) => semaphore.available_permits() == limit.get(),
+            _ => true,
+        };
+
+        self.arrival <= now && in_flight_idle
+    }
+}
+
+#[derive(derive_more::Debug)]
+struct State<R: ResolveActor> {
+    key: LimitKey,
+    quota: Quota,
+    #[debug(skip)]
+    resolver: R,
+
+    limiters: scc::HashMap<Key<R::Actor>, Limiter>,
+    requests: AtomicUsize,
+}
+
+impl<R> State<R>
+where
+    R: ResolveActor,
+{
+    async fn acquire(
+        &self,
+        key: Key<R::Actor>,
+    ) -> Result<Option<OwnedSemaphorePermit>, QuotaExceeded> {
+        let now = Instant::now();
+
+        if self.requests.fetch_add(1, Ordering::Relaxed) % SWEEP_INTERVAL == SWEEP_INTERVAL - 1 {
+            self.limiters
+                .retain_async(|_, limiter| !limiter.is_idle(self.quota, now))
+                .await;
+        }
+
+        let mut entry = self
+            .limiters
+            .entry_async(key)
+            .await
+            .or_insert_with(|| Limiter::new(self.quota, now));
+        let limiter = entry.get_mut();
+
+        // acquire the in-flight permit first, so that a rejected request does not consume a token
+        let permit = match &limiter.in_flight {
+            Some(semaphore) => Some(
+                Arc::clone(semaphore)
+                    .try_acquire_owned()
+                    .map_err(|_error| QuotaExceeded::InFlight { key: self.key })?,
+            ),
+            None => None,
+        };
+
+        if let Some(rate) = self.quota.rate {
+            if !limiter.acquire_rate(rate, now) {
+                return Err(QuotaExceeded::Rate { key: self.key });
+            }
+        }
+
+        Ok(permit)
+    }
+}
+
+/// Enforces a [`Quota`] for every distinct [`LimitKey`].
+///
+/// Requests that exceed the quota are rejected with [`ErrorCode::TOO_MANY_REQUESTS`], without
+/// calling the inner service.
+///
+/// The state is shared between all services created from the same layer.
+///
+/// [`ErrorCode::TOO_MANY_REQUESTS`]: harpc_types::error_code::ErrorCode::TOO_MANY_REQUESTS
+#[derive_where::derive_where(Debug, Clone)]
+pub struct LimitLayer<R: ResolveActor = NoActor> {
+    state: Arc<State<R>>,
+}
+
+impl LimitLayer {
+    /// Creates a layer, which enforces `quota` for every distinct `key`.
+    ///
+    /// The layer is unable to resolve actors, [`LimitKey::Actor`] therefore groups requests by
+    /// session, use [`LimitLayer::actor`] instead.
+    #[must_use]
+    pub fn new(key: LimitKey, quota: Quota) -> Self {
+        Self::with_resolver(key, quota, NoActor)
+    }
+}
+
+impl<R> LimitLayer<R>
+where
+    R: ResolveActor,
+{
+    /// Creates a layer, which enforces `quota` for every actor resolved by `resolver`.
+    #[must_use]
+    pub fn actor(resolver: R, quota: Quota) -> Self {
+        Self::with_resolver(LimitKey::Actor, quota, resolver)
+    }
+
+    fn with_resolver(key: LimitKey, quota: Quota, resolver: R) -> Self {
+        Self {
+            state: Arc::new(State {
+                key,
+                quota,
+                resolver,
+                limiters: scc::HashMap::new(),
+                requests: AtomicUsize::new(0),
+            }),
+        }
+    }
+}
+
+impl<S, R> Layer<S> for LimitLayer<R>
+where
+    R: ResolveActor,
+{
+    type Service = LimitService<S, R>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LimitService {
+            inner,
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+#[derive_where::derive_where(Debug, Clone; S)]
+pub struct LimitService<S, R: ResolveActor = NoActor> {
+    inner: S,
+    state: Arc<State<R>>,
+}
+
+impl<S, R, ReqBody, ResBody> Service<Request<ReqBody>> for LimitService<S, R>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send,
+    R: ResolveActor,
+    ReqBody: Body<Control = !>,
+    ResBody: Body<Control: AsRef<ResponseKind>>,
+{
+    type Error = S::Error;
+    type Response = Response<Either<InFlightBody<ResBody>, Controlled<ResponseKind, Full<Bytes>>>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        // we're always ready because we clone the inner service, therefore it is unused and always
+        // ready
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        let clone = self.inner.clone();
+        let inner = core::mem::replace(&mut self.inner, clone);
+
+        let state = Arc::clone(&self.state);
+
+        async move {
+            let permit = match Key::from_request(state.key, &state.resolver, &req) {
+                Some(key) => state.acquire(key).await,
+                None => Ok(None),
+            };
+
+            match permit {
+                Ok(permit) => {
+                    let response = inner.oneshot(req).await?;
+
+                    Ok(response.map_body(|body| {
+                        Either::Left(InFlightBody {
+                            inner: body,
+                            _permit: permit,
+                        })
+                    }))
+                }
+                Err(error) => {
+                    tracing::debug!(%error, "request rejected");
+
+                    let error = NetworkError::capture_error(&error);
+
+                    Ok(Response::from_error(Parts::new(req.session()), error)
+                        .map_body(Either::Right))
+                }
+            }
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Response body that keeps the request in flight until it has been dropped.
+    #[derive(Debug)]
+    pub struct InFlightBody<B> {
+        #[pin]
+        inner: B,
+        _permit: Option<OwnedSemaphorePermit>,
+    }
+}
+
+impl<B> Body for InFlightBody<B>
+where
+    B: Body,
+{
+    type Control = B::Control;
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<Frame<Self::Data, Self::Control>, Self::Error>>> {
+        self.project().inner.poll_frame(cx)
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use core::{future::ready, num::NonZero, time::Duration};
+
+    use bytes::Bytes;
+    use harpc_net::{session::server::SessionId, test_utils::mock_session_id};
+    use harpc_tower::{
+        Extensions,
+        body::{controlled::Controlled, full::Full},
+        either::Either,
+        request::{self, Request},
+        response::{self, Response},
+    };
+    use harpc_types::{
+        procedure::{ProcedureDescriptor, ProcedureId},
+        response_kind::ResponseKind,
+        subsystem::{SubsystemDescriptor, SubsystemId},
+        version::Version,
+    };
+    use tokio::time::Instant;
+    use tower::{Layer as _, Service, ServiceExt as _, service_fn};
+
+    use super::{LimitKey, LimitLayer, Limiter, Quota, Rate};
+
+    fn rate(burst: u32, period: Duration) -> Rate {
+        Rate::new(
+            NonZero::new(burst).expect("burst should be non-zero"),
+            period,
+        )
+    }
+
+    fn request(session: usize) -> Request<Full<Bytes>> {
+        Request::from_parts(
+            request::Parts {
+                subsystem: SubsystemDescriptor {
+                    id: SubsystemId::new(0x00),
+                    version: Version {
+                        major: 0x00,
+                        minor: 0x00,
+                    },
+                },
+                procedure: ProcedureDescriptor {
+                    id: ProcedureId::new(0x00),
+                },
+                session: mock_session_id(session),
+                extensions: Extensions::new(),
+            },
+            Full::new(Bytes::new()),
+        )
+    }
+
+    fn service() -> impl Service<
+        Request<Full<Bytes>>,
+        Response = Response<Controlled<ResponseKind, Full<Bytes>>>,
+        Error = !,
+        Future: Send,
+    > + Clone
+    + Send {
+        service_fn(|request: Request<Full<Bytes>>| {
+            ready(Ok(Response::from_parts(
+                response::Parts::new(request.session()),
+                Controlled::new(ResponseKind::Ok, Full::new(Bytes::new())),
+            )))
+        })
+    }
+
+    /// Returns `true` if the request has been passed to the inner service.
+    async fn accepted<S, L, R>(service: &S, session: usize) -> bool
+    where
+        S: Service<Request<Full<Bytes>>, Response = Response<Either<L, R>>, Error = !> + Clone,
+    {
+        let Ok(response) = service.clone().oneshot(request(session)).await;
+
+        matches!(response.body(), Either::Left(_))
+    }
+
+    #[test]
+    fn rate_allows_burst() {
+        let rate = rate(3, Duration::from_secs(3));
+        let now = Instant::now();
+        let mut limiter = Limiter::new(Quota::new().with_rate(rate), now);
+
+        for _ in 0..3 {
+            assert!(limiter.acquire_rate(rate, now));
+        }
+
+        assert!(!limiter.acquire_rate(rate, now));
+    }
+
+    #[test]
+    fn rate_refills() {
+        let rate = rate(3, Duration::from_secs(3));
+        let now = Instant::now();
+        let mut limiter = Limiter::new(Quota::new().with_rate(rate), now);
+
+        for _ in 0..3 {
+            assert!(limiter.acquire_rate(rate, now));
+        }
+
+        // rejected requests do not consume a token, a single token is refilled every second
+        assert!(!limiter.acquire_rate(rate, now));
+        assert!(!limiter.acquire_rate(rate, now + Duration::from_millis(999)));
+        assert!(limiter.acquire_rate(rate, now + Duration::from_secs(1)));
+        assert!(!limiter.acquire_rate(rate, now + Duration::from_secs(1)));
+
+        // once the bucket is full again, the whole burst is available, but not more
+        let later = now + Duration::from_secs(10);
+        for _ in 0..3 {
+            assert!(limiter.acquire_rate(rate, later));
+        }
+
+        assert!(!limiter.acquire_rate(rate, later));
+    }
+
+    #[test]
+    fn limiter_becomes_idle() {
+        let rate = rate(3, Duration::from_secs(3));
+        let quota = Quota::new().with_rate(rate);
+        let now = Instant::now();
+        let mut limiter = Limiter::new(quota, now);
+
+        assert!(limiter.is_idle(quota, now));
+
+        assert!(limiter.acquire_rate(rate, now));
+        assert!(!limiter.is_idle(quota, now));
+        assert!(limiter.is_idle(quota, now + Duration::from_secs(1)));
+    }
+
+    #[tokio::test]
+    async fn actor_is_shared_across_sessions() {
+        let quota = Quota::new().with_rate(rate(2, Duration::from_secs(3600)));
+
+        // sessions `0` and `1` are authenticated as the same actor, every other session is
+        // unauthenticated
+        let layer = LimitLayer::actor(
+            |session: SessionId| {
+                (session == mock_session_id(0) || session == mock_session_id(1)).then_some(0x01_u8)
+            },
+            quota,
+        );
+        let service = layer.layer(service());
+
+        assert!(accepted(&service, 0).await);
+        assert!(accepted(&service, 1).await);
+
+        // reconnecting as the same actor does not reset the quota
+        assert!(!accepted(&service, 0).await);
+        assert!(!accepted(&service, 1).await);
+
+        // unauthenticated sessions are limited per session
+        assert!(accepted(&service, 2).await);
+        assert!(accepted(&service, 2).await);
+        assert!(!accepted(&service, 2).await);
+        assert!(accepted(&service, 3).await);
+    }
+
+    #[tokio::test]
+    async fn in_flight_is_released_on_drop() {
+        let quota = Quota::new().with_in_flight(NonZero::new(1).expect("limit should be non-zero"));
+        let service = LimitLayer::new(LimitKey::Procedure, quota).layer(service());
+
+        let Ok(first) = service.clone().oneshot(request(0)).await;
+        assert!(matches!(first.body(), Either::Left(_)));
+
+        // the procedure is shared across sessions
+        assert!(!accepted(&service, 1).await);
+
+        drop(first);
+        assert!(accepted(&service, 1).await);
+    }
+}
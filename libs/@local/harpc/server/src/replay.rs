@@ -0,0 +1,392 @@
+//! Replay recorded traffic against a server.
+//!
+//! Recordings are captured using the [`RecordLayer`] and replayed either against a freshly built
+//! router, which is served over an in-memory transport ([`replay`]), or against a running server
+//! reachable over TCP ([`replay_remote`], also available through the `replay` binary). Every
+//! recorded request is sent in order, one after another, and the response is compared against the
+//! recorded one. Timing information is not replayed, making the replay deterministic.
+//!
+//! [`RecordLayer`]: harpc_tower::layer::record::RecordLayer
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use bytes::{Bytes, BytesMut};
+use error_stack::{Report, ResultExt as _};
+use futures::{Stream, StreamExt as _, stream};
+use harpc_codec::error::NetworkError;
+use harpc_net::{
+    session::client,
+    transport::{TransportConfig, TransportLayer},
+};
+use harpc_tower::{
+    body::server::request::RequestBody,
+    recording::{Recording, ResponseSegment},
+    request::Request,
+};
+use harpc_types::{
+    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+};
+use multiaddr::{Multiaddr, Protocol};
+use tokio_util::sync::CancellationToken;
+use tower::MakeService;
+
+use crate::{Server, ServerConfig, serve::serve};
+
+#[derive(
+    Debug,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    derive_more::Display,
+    derive_more::Error,
+)]
+pub enum ReplayError {
+    #[display("unable to start the server")]
+    StartServer,
+    #[display("unable to start the client")]
+    StartClient,
+    #[display("unable to connect to the server")]
+    Connect,
+    #[display("unable to replay recording {index}")]
+    Call { index: usize },
+}
+
+/// A recording whose replayed response differs from the recorded one.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ReplayMismatch {
+    /// Position of the recording in the replayed sequence.
+    pub index: usize,
+
+    pub subsystem: SubsystemDescriptor,
+    pub procedure: ProcedureDescriptor,
+
+    pub expected: Vec<ResponseSegment>,
+    pub actual: Vec<ResponseSegment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, serde::Serialize)]
+pub struct ReplayReport {
+    /// Number of recordings that have been replayed.
+    pub replayed: usize,
+    pub mismatches: Vec<ReplayMismatch>,
+}
+
+impl ReplayReport {
+    /// Returns `true` if every replayed response matched the recorded one.
+    #[must_use]
+    pub const fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+pub(crate) fn memory_address() -> Multiaddr {
+    // Memory ports are shared across the process, we start at a high port to not collide with
+    // any manually chosen ports. `0` cannot be used, as we need to know the port to dial.
+    static PORT: AtomicU64 = AtomicU64::new(1 << 63);
+
+    Multiaddr::empty().with(Protocol::Memory(PORT.fetch_add(1, Ordering::Relaxed)))
+}
+
+async fn collect(stream: impl Stream<Item = Bytes>) -> Bytes {
+    stream
+        .fold(BytesMut::new(), |mut buffer, bytes| async move {
+            buffer.extend_from_slice(&bytes);
+            buffer
+        })
+        .await
+        .freeze()
+}
+
+/// Replay `recordings` against the service created by `make_service` and compare the responses.
+///
+/// The service is served over an in-memory transport for the duration of the replay.
+///
+/// # Errors
+///
+/// - [`ReplayError::StartServer`] if the server cannot be started
+/// - [`ReplayError::StartClient`] if the client cannot be started
+/// - [`ReplayError::Connect`] if the client is unable to connect to the server
+/// - [`ReplayError::Call`] if a recording cannot be sent, because the connection has been closed
+pub async fn replay<M>(
+    make_service: M,
+    recordings: impl IntoIterator<Item = Recording>,
+) -> Result<ReplayReport, Report<ReplayError>>
+where
+    M: MakeService<
+            (),
+            Request<RequestBody>,
+            Response: Stream<Item = Result<Bytes, NetworkError>> + Send,
+            Error = !,
+            Service: tower::Service<Request<RequestBody>, Future: Send> + Send + 'static,
+            MakeError = !,
+            Future: Send,
+        > + Send
+        + 'static,
+{
+    let address = memory_address();
+
+    let server =
+        Server::memory(ServerConfig::default()).change_context(ReplayError::StartServer)?;
+    let transactions = server
+        .listen(address.clone())
+        .await
+        .change_context(ReplayError::StartServer)?;
+
+    // aborting the task drops the transaction stream, which in turn shuts down the server
+    let server = tokio::spawn(serve(transactions, make_service));
+
+    let cancel = CancellationToken::new();
+    let _guard = cancel.clone().drop_guard();
+
+    let transport = TransportLayer::memory(TransportConfig::default(), cancel)
+        .change_context(ReplayError::StartClient)?;
+    let session = client::SessionLayer::new(client::SessionConfig::default(), transport);
+
+    let result = replay_all(&session, address, recordings).await;
+
+    server.abort();
+
+    result
+}
+
+/// Replay `recordings` against the server listening on `address` and compare the responses.
+///
+/// The server is dialed over TCP, `address` is therefore expected to be a TCP address, e.g.
+/// `/ip4/127.0.0.1/tcp/4002`.
+///
+/// # Errors
+///
+/// - [`ReplayError::StartClient`] if the client cannot be started
+/// - [`ReplayError::Connect`] if the client is unable to connect to the server
+/// - [`ReplayError::Call`] if a recording cannot be sent, because the connection has been closed
+pub async fn replay_remote(
+    address: Multiaddr,
+    recordings: impl IntoIterator<Item = Recording>,
+) -> Result<ReplayReport, Report<ReplayError>> {
+    let cancel = CancellationToken::new();
+    let _guard = cancel.clone().drop_guard();
+
+    let transport = TransportLayer::tcp(TransportConfig::default(), cancel)
+        .change_context(ReplayError::StartClient)?;
+    let session = client::SessionLayer::new(client::SessionConfig::default(), transport);
+
+    replay_all(&session, address, recordings).await
+}
+
+async fn replay_all(
+    session: &client::SessionLayer,
+    address: Multiaddr,
+    recordings: impl IntoIterator<Item = Recording>,
+) -> Result<ReplayReport, Report<ReplayError>> {
+    let connection = session
+        .dial(address)
+        .await
+        .change_context(ReplayError::Connect)?;
+
+    let mut report = ReplayReport::default();
+
+    for (index, recording) in recordings.into_iter().enumerate() {
+        let payload: Vec<_> = recording.request_payload().collect();
+
+        let mut responses = connection
+            .call(
+                recording.subsystem,
+                recording.procedure,
+                stream::iter(payload),
+            )
+            .await
+            .change_context(ReplayError::Call { index })?;
+
+        let mut segments = Vec::new();
+        while let Some(response) = responses.next().await {
+            let segment = match response {
+                Ok(value) => ResponseSegment {
+                    kind: ResponseKind::Ok,
+                    payload: collect(value).await,
+                },
+                Err(error) => ResponseSegment {
+                    kind: ResponseKind::Err(error.code()),
+                    payload: collect(error).await,
+                },
+            };
+
+            segments.push(segment);
+        }
+
+        let expected = recording.response_segments();
+        let actual = ResponseSegment::normalize(segments);
+
+        report.replayed += 1;
+
+        if expected != actual {
+            report.mismatches.push(ReplayMismatch {
+                index,
+                subsystem: recording.subsystem,
+                procedure: recording.procedure,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    Ok(report)
+}
+
+#[cfg(test)]
+mod test {
+    use core::{future::ready, pin::pin, time::Duration};
+
+    use bytes::{Bytes, BytesMut};
+    use harpc_tower::{
+        body::{
+            BodyExt as _, Frame, controlled::Controlled, full::Full, record::RecordRequestBody,
+            server::request::RequestBody,
+        },
+        layer::record::RecordLayer,
+        net::pack::PackLayer,
+        recording::{RecordedFrame, Recording},
+        request::Request,
+        response::{self, Response},
+    };
+    use harpc_types::{
+        procedure::{ProcedureDescriptor, ProcedureId},
+        response_kind::ResponseKind,
+        subsystem::{SubsystemDescriptor, SubsystemId},
+        version::Version,
+    };
+    use tokio::sync::mpsc;
+    use tower::{Layer as _, service_fn};
+
+    use super::replay;
+
+    const SUBSYSTEM: SubsystemDescriptor = SubsystemDescriptor {
+        id: SubsystemId::new(0x01),
+        version: Version { major: 1, minor: 0 },
+    };
+    const PROCEDURE: ProcedureDescriptor = ProcedureDescriptor {
+        id: ProcedureId::new(0x01),
+    };
+
+    /// Creates a service, which responds with the request payload transformed by `transform`.
+    ///
+    /// Every transaction is recorded and sent through `sender`.
+    fn make_service(
+        transform: fn(&[u8]) -> Vec<u8>,
+        sender: mpsc::Sender<Recording>,
+    ) -> impl tower::MakeService<
+        (),
+        Request<RequestBody>,
+        Response: futures::Stream<Item = Result<Bytes, harpc_codec::error::NetworkError>> + Send,
+        Error = !,
+        Service: tower::Service<Request<RequestBody>, Future: Send> + Send + 'static,
+        MakeError = !,
+        Future: Send,
+    > + Send
+    + 'static {
+        let service = service_fn(move |request: Request<RecordRequestBody<RequestBody>>| {
+            let session = request.session();
+
+            async move {
+                let mut body = pin!(request.into_body());
+
+                let mut payload = BytesMut::new();
+                while let Some(Ok(Frame::Data(data))) = body.frame().await {
+                    payload.extend_from_slice(&data);
+                }
+
+                Ok::<_, !>(Response::from_parts(
+                    response::Parts::new(session),
+                    Controlled::new(
+                        ResponseKind::Ok,
+                        Full::new(Bytes::from(transform(&payload))),
+                    ),
+                ))
+            }
+        });
+
+        service_fn(move |()| {
+            let service = RecordLayer::new(sender.clone()).layer(service.clone());
+
+            ready(Ok::<_, !>(PackLayer::new().layer(service)))
+        })
+    }
+
+    fn recording(request: &'static [u8], response: &'static [u8]) -> Recording {
+        Recording {
+            subsystem: SUBSYSTEM,
+            procedure: PROCEDURE,
+            request: vec![RecordedFrame::Data {
+                offset: Duration::ZERO,
+                payload: Bytes::from_static(request),
+            }],
+            response: vec![
+                RecordedFrame::Control {
+                    offset: Duration::ZERO,
+                    kind: ResponseKind::Ok,
+                },
+                RecordedFrame::Data {
+                    offset: Duration::ZERO,
+                    payload: Bytes::from_static(response),
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn record_replay_roundtrip() {
+        let inputs = [recording(b"hello", b"hello"), recording(b"world", b"world")];
+
+        let (tx, mut rx) = mpsc::channel(inputs.len());
+        let report = replay(make_service(<[u8]>::to_vec, tx), inputs.clone())
+            .await
+            .expect("should be able to replay recordings");
+        assert!(report.is_match(), "{report:?}");
+
+        let mut recorded = Vec::with_capacity(inputs.len());
+        for _ in 0..inputs.len() {
+            recorded.push(
+                rx.recv()
+                    .await
+                    .expect("transaction should have been recorded"),
+            );
+        }
+
+        for recording in &recorded {
+            assert_eq!(recording.subsystem, SUBSYSTEM);
+            assert_eq!(recording.procedure, PROCEDURE);
+            assert_eq!(
+                recording.request_payload().collect::<Vec<_>>(),
+                [recording.response_segments()[0].payload.clone()]
+            );
+        }
+
+        let (tx, _rx) = mpsc::channel(recorded.len());
+        let report = replay(make_service(<[u8]>::to_vec, tx), recorded)
+            .await
+            .expect("should be able to replay recorded traffic");
+
+        assert_eq!(report.replayed, inputs.len());
+        assert!(report.is_match(), "{report:?}");
+    }
+
+    #[tokio::test]
+    async fn replay_reports_mismatch() {
+        let (tx, _rx) = mpsc::channel(2);
+        let report = replay(
+            make_service(<[u8]>::to_ascii_uppercase, tx),
+            [recording(b"hello", b"HELLO"), recording(b"world", b"world")],
+        )
+        .await
+        .expect("should be able to replay recordings");
+
+        assert_eq!(report.replayed, 2);
+        assert_eq!(report.mismatches.len(), 1);
+
+        let mismatch = &report.mismatches[0];
+        assert_eq!(mismatch.index, 1);
+        assert_eq!(mismatch.actual[0].payload, Bytes::from_static(b"WORLD"));
+    }
+}
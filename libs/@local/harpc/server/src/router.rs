@@ -110,6 +110,17 @@ impl<R, L, S, C> RouterBuilder<R, L, S, C> {
 }
 
 impl<R, L, S, C> RouterBuilder<R, L, S, C> {
+    /// The storage holding the session values of the router.
+    ///
+    /// Useful for layers that need to know about the session, e.g. to resolve the authenticated
+    /// actor in a [`LimitLayer`].
+    ///
+    /// [`LimitLayer`]: crate::limit::LimitLayer
+    #[must_use]
+    pub fn session_storage(&self) -> Arc<SessionStorage<S>> {
+        Arc::clone(&self.session)
+    }
+
     /// Creates a background task for session storage management.
     ///
     /// The returned [`session::Task`] implements `IntoFuture`, allowing it to be
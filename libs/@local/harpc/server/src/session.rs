@@ -5,6 +5,7 @@ use std::{collections::HashSet, sync::Mutex};
 use futures::{Stream, StreamExt as _};
 pub use harpc_net::session::server::{SessionEvent, SessionEventError, SessionId};
 use harpc_types::{procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor};
+use libp2p_identity::PeerId;
 use scc::{ebr::Guard, hash_index::Entry};
 use tokio::pin;
 use tokio_util::sync::CancellationToken;
@@ -13,6 +14,11 @@ use tokio_util::sync::CancellationToken;
 pub struct RequestInfo {
     pub subsystem: SubsystemDescriptor,
     pub procedure: ProcedureDescriptor,
+    /// The authenticated peer that sent the request.
+    ///
+    /// The peer is authenticated during the transport handshake and therefore cannot be spoofed,
+    /// it is `None` if the request did not originate from the network.
+    pub peer: Option<PeerId>,
 }
 
 #[derive(derive_more::Debug)]
@@ -194,6 +200,15 @@ impl<T> SessionStorage<T> {
     pub(crate) fn task<S>(self: Arc<Self>, stream: S) -> Task<T, S> {
         Task::new(self, stream)
     }
+
+    /// Read the value of a session without creating it.
+    ///
+    /// Returns `None` if no value has been stored for the session yet. This is lock-free and does
+    /// not count as an access of the session.
+    pub fn peek_with<R>(&self, session_id: SessionId, reader: impl FnOnce(&T) -> R) -> Option<R> {
+        self.storage
+            .peek_with(&session_id, |_, value| reader(value))
+    }
 }
 
 impl<T> SessionStorage<T>
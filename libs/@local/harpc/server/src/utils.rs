@@ -1,13 +1,20 @@
 //! Common utilities used to implement various traits as well as used in the proc-macro.
 
-use core::{array, pin::pin};
+use core::{array, error::Error, pin::pin};
 
 use error_stack::{Report, ResultExt as _};
-use futures::{StreamExt as _, stream};
-use harpc_codec::{decode::ReportDecoder, encode::Encoder};
+use futures::{Stream, StreamExt as _, TryStreamExt as _, stream};
+use harpc_codec::{
+    decode::{Decoder, ReportDecoder},
+    encode::Encoder,
+};
 use harpc_system::{Subsystem, procedure::ProcedureIdentifier};
 use harpc_tower::{
-    body::{Body, BodyExt as _, Frame, controlled::Controlled, stream::StreamBody},
+    body::{
+        Body, BodyExt as _, Frame,
+        controlled::Controlled,
+        stream::{BodyDataStream, StreamBody},
+    },
     request::Request,
     response::{self, Response},
 };
@@ -94,3 +101,390 @@ where
     // In theory we could also box this, or use `Either` if we have multiple responses
     Ok(Response::from_ok(response::Parts::new(session_id), data))
 }
+
+/// Delegates a call to a closure with a single input and a stream of outputs.
+///
+/// This is used for server-streaming procedures. The response is sent as soon as the returned
+/// stream yields items, so the stream may be unbounded.
+///
+/// # Errors
+///
+/// This function returns a `Report<DelegationError>` in the following cases:
+/// - If decoding the request fails
+/// - If the request does not contain exactly one item
+pub async fn delegate_call_server_stream<B, I, O, C, St, Fut>(
+    request: Request<B>,
+    codec: C,
+    closure: impl FnOnce(I) -> Fut + Send,
+) -> Result<
+    Response<
+        Controlled<
+            ResponseKind,
+            StreamBody<
+                stream::MapOk<
+                    <C as Encoder>::Output<St>,
+                    fn(<C as Encoder>::Buf) -> Frame<<C as Encoder>::Buf, !>,
+                >,
+            >,
+        >,
+    >,
+    Report<DelegationError>,
+>
+where
+    B: Body<Control = !, Error: Send + Sync> + Send,
+    I: serde::de::DeserializeOwned,
+    O: serde::Serialize + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+    St: Stream<Item = O> + Send,
+    Fut: Future<Output = St> + Send,
+{
+    let session_id = request.session();
+
+    let body = request.into_body();
+    let data = body.into_stream().into_data_stream();
+
+    let stream = codec.clone().decode(data);
+    let mut stream = pin!(stream);
+
+    let payload = stream
+        .next()
+        .await
+        .ok_or_else(|| RequestExpectedItemCountMismatch::exactly(1))
+        .change_context(DelegationError)?
+        .change_context(DelegationError)?;
+
+    let response = closure(payload).await;
+
+    let data = codec.encode(response);
+
+    Ok(Response::from_ok(response::Parts::new(session_id), data))
+}
+
+/// Items of a request, decoded incrementally as the request body arrives.
+///
+/// Passed to the closure of [`delegate_call_stream`].
+pub type RequestStream<B, C, I> = stream::MapErr<
+    <C as Decoder>::Output<I, BodyDataStream<B>>,
+    fn(<C as Decoder>::Error) -> Report<DelegationError>,
+>;
+
+fn into_delegation_error<C>(report: Report<C>) -> Report<DelegationError>
+where
+    C: Error + Send + Sync + 'static,
+{
+    report.change_context(DelegationError)
+}
+
+/// Delegates a call to a closure with a stream of inputs and a stream of outputs.
+///
+/// Unlike [`delegate_call_discrete`], the request is never buffered. Items are decoded once they
+/// have been received and the response is sent as soon as the returned stream yields items, which
+/// may be while the request is still being received. This allows for client-streaming as well as
+/// bidirectional-streaming procedures.
+///
+/// If an item of the request cannot be decoded, the input stream yields an error, it is up to the
+/// closure to decide whether to skip the item or to abort the call.
+///
+/// # Errors
+///
+/// This function is currently infallible, the `Result` is kept for symmetry with
+/// [`delegate_call_discrete`].
+pub async fn delegate_call_stream<B, I, O, C, St, Fut>(
+    request: Request<B>,
+    codec: C,
+    closure: impl FnOnce(RequestStream<B, C, I>) -> Fut + Send,
+) -> Result<
+    Response<
+        Controlled<
+            ResponseKind,
+            StreamBody<
+                stream::MapOk<
+                    <C as Encoder>::Output<St>,
+                    fn(<C as Encoder>::Buf) -> Frame<<C as Encoder>::Buf, !>,
+                >,
+            >,
+        >,
+    >,
+    Report<DelegationError>,
+>
+where
+    B: Body<Control = !, Error: Send + Sync> + Send,
+    I: serde::de::DeserializeOwned,
+    O: serde::Serialize + Send,
+    C: Encoder + ReportDecoder + Clone + Send,
+    St: Stream<Item = O> + Send,
+    Fut: Future<Output = St> + Send,
+{
+    let session_id = request.session();
+
+    let body = request.into_body();
+    let data = body.into_stream().into_data_stream();
+
+    let items = codec
+        .clone()
+        .decode(data)
+        .map_err(into_delegation_error::<C::Context> as fn(_) -> _);
+
+    let response = closure(items).await;
+
+    let data = codec.encode(response);
+
+    Ok(Response::from_ok(response::Parts::new(session_id), data))
+}
+
+#[cfg(test)]
+mod test {
+    use core::{future::ready, pin::pin, time::Duration};
+
+    use error_stack::Report;
+    use frunk::HNil;
+    use futures::{StreamExt as _, channel::mpsc, future::Either, stream};
+    use harpc_client::{
+        Client, ClientConfig,
+        connection::{Connection, default},
+        utils::invoke_call_stream,
+    };
+    use harpc_codec::{encode::Encoder, json::JsonCodec};
+    use harpc_system::{
+        Subsystem, SubsystemIdentifier, delegate::SubsystemDelegate, procedure::ProcedureIdentifier,
+    };
+    use harpc_tower::{
+        body::Body,
+        layer::{body_report::HandleBodyReportLayer, report::HandleReportLayer},
+        request::Request,
+        response::Response,
+    };
+    use harpc_types::{
+        procedure::ProcedureId, response_kind::ResponseKind, subsystem::SubsystemId,
+        version::Version,
+    };
+    use serde::ser::Error as _;
+    use tokio::time::timeout;
+
+    use super::{RequestStream, delegate_call_stream, parse_procedure_id};
+    use crate::{
+        Server, ServerConfig, error::DelegationError, replay::memory_address,
+        router::RouterBuilder, serve::serve, session::Session,
+    };
+
+    #[derive(Debug, Copy, Clone)]
+    enum System {
+        Numbers,
+    }
+
+    impl SubsystemIdentifier for System {
+        fn from_id(id: SubsystemId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::Numbers),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> SubsystemId {
+            match self {
+                Self::Numbers => SubsystemId::new(0x00),
+            }
+        }
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    enum NumbersProcedureId {
+        Sum,
+        Double,
+    }
+
+    impl ProcedureIdentifier for NumbersProcedureId {
+        type Subsystem = Numbers;
+
+        fn from_id(id: ProcedureId) -> Option<Self> {
+            match id.value() {
+                0x00 => Some(Self::Sum),
+                0x01 => Some(Self::Double),
+                _ => None,
+            }
+        }
+
+        fn into_id(self) -> ProcedureId {
+            match self {
+                Self::Sum => ProcedureId::new(0x00),
+                Self::Double => ProcedureId::new(0x01),
+            }
+        }
+    }
+
+    struct Numbers;
+
+    impl Subsystem for Numbers {
+        type ProcedureId = NumbersProcedureId;
+        type Procedures = HNil;
+        type SubsystemId = System;
+
+        const ID: System = System::Numbers;
+        const VERSION: Version = Version {
+            major: 0x00,
+            minor: 0x00,
+        };
+    }
+
+    #[derive(Debug, Copy, Clone)]
+    struct NumbersDelegate;
+
+    impl SubsystemDelegate<JsonCodec> for NumbersDelegate {
+        type Error = Report<DelegationError>;
+        type ExecutionScope = Session<()>;
+        type Subsystem = Numbers;
+
+        type Body<Source>
+            = impl Body<Control: AsRef<ResponseKind>, Error = <JsonCodec as Encoder>::Error>
+        where
+            Source: Body<Control = !, Error: Send + Sync> + Send;
+
+        async fn call<B>(
+            self,
+            request: Request<B>,
+            _: Session<()>,
+            codec: JsonCodec,
+        ) -> Result<Response<Self::Body<B>>, Self::Error>
+        where
+            B: Body<Control = !, Error: Send + Sync> + Send,
+        {
+            let id: NumbersProcedureId = parse_procedure_id(&request)?;
+
+            delegate_call_stream(
+                request,
+                codec,
+                |items: RequestStream<B, JsonCodec, u32>| async move {
+                    let items = items.filter_map(|item| ready(item.ok()));
+
+                    match id {
+                        // client-streaming: a single response once the request has ended
+                        NumbersProcedureId::Sum => {
+                            let sum = items.fold(0, |sum, item| ready(sum + item)).await;
+
+                            Either::Left(stream::once(ready(sum)))
+                        }
+                        // bidirectional-streaming: every item is answered as soon as it arrives
+                        NumbersProcedureId::Double => Either::Right(items.map(|item| item * 2)),
+                    }
+                },
+            )
+            .await
+        }
+    }
+
+    /// Item of a request, which cannot be serialized if it is missing.
+    struct Item(Option<u32>);
+
+    impl serde::Serialize for Item {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: serde::Serializer,
+        {
+            match self.0 {
+                Some(value) => serializer.serialize_u32(value),
+                None => Err(S::Error::custom("item is missing")),
+            }
+        }
+    }
+
+    async fn connect() -> Connection<default::Default, JsonCodec> {
+        let address = memory_address();
+
+        let server =
+            Server::memory(ServerConfig::default()).expect("should be able to start server");
+
+        let router = RouterBuilder::new::<()>(JsonCodec)
+            .with_builder(|builder| {
+                builder
+                    .layer(HandleReportLayer::new())
+                    .layer(HandleBodyReportLayer::new())
+            })
+            .register(NumbersDelegate);
+
+        tokio::spawn(router.background_task(server.events()).into_future());
+        let router = router.build();
+
+        let transactions = server
+            .listen(address.clone())
+            .await
+            .expect("should be able to listen");
+        tokio::spawn(serve(transactions, router));
+
+        let client =
+            Client::memory(ClientConfig::default(), JsonCodec).expect("should be able to start");
+
+        client
+            .connect(address)
+            .await
+            .expect("should be able to connect")
+    }
+
+    #[tokio::test]
+    async fn client_streaming() {
+        let connection = connect().await;
+
+        let response =
+            invoke_call_stream(connection, NumbersProcedureId::Sum, stream::iter(1..=4_u32))
+                .await
+                .expect("should be able to call procedure");
+
+        let response: Vec<_> = response
+            .map(|item: Result<u32, _>| item.expect("should be able to decode response"))
+            .collect()
+            .await;
+
+        assert_eq!(response, [10]);
+    }
+
+    #[tokio::test]
+    async fn bidirectional_streaming() {
+        let connection = connect().await;
+
+        let (tx, rx) = mpsc::unbounded::<u32>();
+
+        let response = invoke_call_stream(connection, NumbersProcedureId::Double, rx)
+            .await
+            .expect("should be able to call procedure");
+        let mut response = pin!(response);
+
+        for item in 1..=4 {
+            tx.unbounded_send(item)
+                .expect("request should still be open");
+
+            // the response needs to arrive while the request is still open
+            let doubled: u32 = timeout(Duration::from_secs(5), response.next())
+                .await
+                .expect("response should arrive before the request has ended")
+                .expect("response should not have ended")
+                .expect("should be able to decode response");
+
+            assert_eq!(doubled, item * 2);
+        }
+
+        drop(tx);
+        assert!(response.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn encode_error_is_reported() {
+        let connection = connect().await;
+
+        let response = invoke_call_stream(
+            connection,
+            NumbersProcedureId::Double,
+            stream::iter([Item(Some(1)), Item(None), Item(Some(2))]),
+        )
+        .await
+        .expect("should be able to call procedure");
+
+        let response: Vec<Result<u32, _>> = response.collect().await;
+
+        // the request is terminated at the first item that cannot be encoded
+        let items: Vec<_> = response
+            .iter()
+            .filter_map(|item| item.as_ref().ok())
+            .collect();
+        assert_eq!(items, [&2]);
+        assert_eq!(response.iter().filter(|item| item.is_err()).count(), 1);
+    }
+}
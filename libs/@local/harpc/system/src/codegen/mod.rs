@@ -0,0 +1,275 @@
+//! Language-agnostic description of subsystems, used to generate client bindings.
+//!
+//! A [`ClientSchema`] is a collection of subsystems, their procedures and the types of the
+//! requests and responses of every procedure. Types are described using `OpenAPI` schemas, so that
+//! the same definitions used to document the REST API can be re-used. Named types are referenced
+//! and resolved against the components of the [`ClientSchema`].
+//!
+//! Subsystems and procedures describe themselves by implementing [`DescribeSubsystem`] and
+//! [`DescribeProcedure`], the procedures of a [`SubsystemSchema`] are taken from the
+//! [`Subsystem::Procedures`] of the subsystem.
+//!
+//! Bindings are generated from a [`ClientSchema`], see [`typescript`] for the `TypeScript`
+//! generator.
+use alloc::{borrow::Cow, collections::BTreeMap};
+
+use frunk::{HCons, HNil};
+use utoipa::{
+    ToSchema,
+    openapi::{ObjectBuilder, Ref, RefOr, Schema, SchemaType},
+};
+
+use crate::{
+    Subsystem, SubsystemInformation,
+    procedure::{Procedure, ProcedureInformation},
+};
+
+pub mod typescript;
+
+/// Type of a request or response.
+///
+/// Requests and responses are serialized using `serde`, the variants mirror how `serde` serializes
+/// types which cannot be expressed using `OpenAPI` schemas.
+#[derive(Debug, Clone)]
+pub enum Type {
+    /// A type described by a schema.
+    ///
+    /// References are resolved against the components of the [`ClientSchema`].
+    Schema(RefOr<Schema>),
+    /// A tuple, which is serialized as an array of fixed length.
+    Tuple(Vec<Self>),
+    /// A sequence, such as a `Vec`, which is serialized as an array of any length.
+    List(Box<Self>),
+    /// A map, such as a `HashMap`, which is serialized as an object with the keys as strings.
+    Map(Box<Self>),
+    /// A `Result`, which is serialized as either `{"Ok": ...}` or `{"Err": ...}`.
+    Result { ok: Box<Self>, err: Box<Self> },
+    /// An `Option`, which is serialized as either the value or `null`.
+    Option(Box<Self>),
+    /// A stream of items.
+    ///
+    /// Only valid as the request or the response of a procedure, the items are sent as they become
+    /// available.
+    Stream(Box<Self>),
+    /// The unit type `()`, which is serialized as `null`.
+    Unit,
+}
+
+impl Type {
+    /// Reference to a named type, the type must be part of the components of the
+    /// [`ClientSchema`].
+    #[must_use]
+    pub fn of<'s, T>() -> Self
+    where
+        T: ToSchema<'s>,
+    {
+        let (name, _) = T::schema();
+
+        Self::named(name)
+    }
+
+    /// Reference to a named type by the name of its component.
+    ///
+    /// Useful for types which are part of the components of the [`ClientSchema`], but whose Rust
+    /// type is not accessible.
+    #[must_use]
+    pub fn named(name: impl Into<String>) -> Self {
+        Self::Schema(RefOr::Ref(Ref::from_schema_name(name)))
+    }
+
+    #[must_use]
+    pub fn schema(schema: impl Into<RefOr<Schema>>) -> Self {
+        Self::Schema(schema.into())
+    }
+
+    fn primitive(schema_type: SchemaType) -> Self {
+        Self::Schema(RefOr::T(Schema::Object(
+            ObjectBuilder::new().schema_type(schema_type).build(),
+        )))
+    }
+
+    #[must_use]
+    pub fn string() -> Self {
+        Self::primitive(SchemaType::String)
+    }
+
+    #[must_use]
+    pub fn boolean() -> Self {
+        Self::primitive(SchemaType::Boolean)
+    }
+
+    #[must_use]
+    pub fn number() -> Self {
+        Self::primitive(SchemaType::Number)
+    }
+
+    /// A type without any further restrictions, such as an error report.
+    #[must_use]
+    pub fn unknown() -> Self {
+        Self::primitive(SchemaType::Value)
+    }
+
+    #[must_use]
+    pub fn tuple(items: impl IntoIterator<Item = Self>) -> Self {
+        Self::Tuple(items.into_iter().collect())
+    }
+
+    #[must_use]
+    pub fn list(item: Self) -> Self {
+        Self::List(Box::new(item))
+    }
+
+    /// A map from keys serialized as strings to values of the given type.
+    #[must_use]
+    pub fn map(value: Self) -> Self {
+        Self::Map(Box::new(value))
+    }
+
+    #[must_use]
+    pub fn result(ok: Self, err: Self) -> Self {
+        Self::Result {
+            ok: Box::new(ok),
+            err: Box::new(err),
+        }
+    }
+
+    #[must_use]
+    pub fn option(item: Self) -> Self {
+        Self::Option(Box::new(item))
+    }
+
+    #[must_use]
+    pub fn stream(item: Self) -> Self {
+        Self::Stream(Box::new(item))
+    }
+}
+
+/// A procedure which describes its request and response.
+pub trait DescribeProcedure: Procedure {
+    /// Name of the procedure, in `snake_case`.
+    const NAME: &'static str;
+
+    fn request() -> Type;
+
+    fn response() -> Type;
+}
+
+/// A subsystem whose procedures are described.
+pub trait DescribeSubsystem: Subsystem<Procedures: DescribeProcedures<Self>> + Sized {
+    /// Name of the subsystem, in `PascalCase`.
+    const NAME: &'static str;
+}
+
+/// Heterogeneous list of procedures of the subsystem `S`, which are all described.
+pub trait DescribeProcedures<S> {
+    /// Appends the schema of every procedure in the order they are listed.
+    fn describe(procedures: &mut Vec<ProcedureSchema>);
+}
+
+impl<S> DescribeProcedures<S> for HNil {
+    fn describe(_: &mut Vec<ProcedureSchema>) {}
+}
+
+impl<S, Head, Tail> DescribeProcedures<S> for HCons<Head, Tail>
+where
+    S: Subsystem,
+    Head: DescribeProcedure<Subsystem = S>,
+    Tail: DescribeProcedures<S>,
+{
+    fn describe(procedures: &mut Vec<ProcedureSchema>) {
+        procedures.push(ProcedureSchema::of::<Head>());
+
+        Tail::describe(procedures);
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcedureSchema {
+    /// Name of the procedure, in `snake_case`.
+    pub name: Cow<'static, str>,
+    pub information: ProcedureInformation,
+
+    pub request: Type,
+    pub response: Type,
+}
+
+impl ProcedureSchema {
+    #[must_use]
+    pub fn of<P>() -> Self
+    where
+        P: DescribeProcedure,
+    {
+        Self {
+            name: Cow::Borrowed(P::NAME),
+            information: P::information(),
+            request: P::request(),
+            response: P::response(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubsystemSchema {
+    /// Name of the subsystem, in `PascalCase`.
+    pub name: Cow<'static, str>,
+    pub information: SubsystemInformation,
+
+    pub procedures: Vec<ProcedureSchema>,
+}
+
+impl SubsystemSchema {
+    /// Describes the subsystem and all of its procedures.
+    #[must_use]
+    pub fn of<S>() -> Self
+    where
+        S: DescribeSubsystem,
+    {
+        let mut procedures = Vec::new();
+        <S::Procedures as DescribeProcedures<S>>::describe(&mut procedures);
+
+        Self {
+            name: Cow::Borrowed(S::NAME),
+            information: S::information(),
+            procedures,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ClientSchema {
+    pub subsystems: Vec<SubsystemSchema>,
+    /// Named types, which are referenced by the requests and responses.
+    pub components: BTreeMap<String, RefOr<Schema>>,
+}
+
+impl ClientSchema {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn with_subsystem(mut self, subsystem: SubsystemSchema) -> Self {
+        self.subsystems.push(subsystem);
+        self
+    }
+
+    /// Adds the description of the subsystem `S`.
+    #[must_use]
+    pub fn subsystem<S>(self) -> Self
+    where
+        S: DescribeSubsystem,
+    {
+        self.with_subsystem(SubsystemSchema::of::<S>())
+    }
+
+    /// Add named types, such as the component schemas of an `OpenAPI` document.
+    #[must_use]
+    pub fn with_components(
+        mut self,
+        components: impl IntoIterator<Item = (String, RefOr<Schema>)>,
+    ) -> Self {
+        self.components.extend(components);
+        self
+    }
+}
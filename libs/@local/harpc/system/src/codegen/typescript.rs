@@ -0,0 +1,671 @@
+//! `TypeScript` client generator.
+//!
+//! The generated module contains a type alias for every named type referenced by a procedure, a
+//! descriptor for every subsystem and a client class for every subsystem, which exposes every
+//! procedure as a method.
+//!
+//! The module is independent of the underlying connection, every client is constructed from an
+//! `RpcTransport`, which is responsible for encoding the request, sending it to the procedure and
+//! decoding the response. Streamed requests and responses are exposed as `AsyncIterable`.
+use alloc::collections::BTreeSet;
+use core::fmt::{self, Display, Formatter, Write as _};
+
+use utoipa::openapi::{
+    RefOr, Schema, SchemaType,
+    schema::{AdditionalProperties, Object},
+};
+
+use super::{ClientSchema, ProcedureSchema, SubsystemSchema, Type};
+use crate::metadata::Deprecation;
+
+const COMPONENT_PREFIX: &str = "#/components/schemas/";
+
+const PRELUDE: &str = r"// This file has been generated by `harpc-system`, do not edit it manually.
+
+export interface RpcSubsystemDescriptor {
+  readonly id: number;
+  readonly version: { readonly major: number; readonly minor: number };
+}
+
+export interface RpcTransport {
+  /**
+   * Sends a request to a procedure and resolves with the decoded response.
+   */
+  call(
+    subsystem: RpcSubsystemDescriptor,
+    procedure: number,
+    request: unknown,
+  ): Promise<unknown>;
+
+  /**
+   * Sends a request to a procedure and yields every decoded item of the response as it arrives.
+   */
+  stream(
+    subsystem: RpcSubsystemDescriptor,
+    procedure: number,
+    request: unknown,
+  ): AsyncIterable<unknown>;
+
+  /**
+   * Sends every item of the request to a procedure as it becomes available and resolves with the
+   * decoded response.
+   */
+  send(
+    subsystem: RpcSubsystemDescriptor,
+    procedure: number,
+    request: AsyncIterable<unknown>,
+  ): Promise<unknown>;
+
+  /**
+   * Sends every item of the request to a procedure as it becomes available and yields every
+   * decoded item of the response as it arrives.
+   */
+  exchange(
+    subsystem: RpcSubsystemDescriptor,
+    procedure: number,
+    request: AsyncIterable<unknown>,
+  ): AsyncIterable<unknown>;
+}
+
+export type RpcResult<T, E> = { Ok: T } | { Err: E };
+";
+
+/// Renders a [`ClientSchema`] as a `TypeScript` module, using its [`Display`] implementation.
+#[derive(Debug, Copy, Clone)]
+pub struct TypeScriptClient<'schema> {
+    schema: &'schema ClientSchema,
+}
+
+impl<'schema> TypeScriptClient<'schema> {
+    #[must_use]
+    pub const fn new(schema: &'schema ClientSchema) -> Self {
+        Self { schema }
+    }
+
+    /// Names of all components which are referenced, directly or indirectly, by any procedure.
+    fn referenced_components(&self) -> BTreeSet<&'schema str> {
+        let mut visited = BTreeSet::new();
+
+        for subsystem in &self.schema.subsystems {
+            for procedure in &subsystem.procedures {
+                self.visit_type(&procedure.request, &mut visited);
+                self.visit_type(&procedure.response, &mut visited);
+            }
+        }
+
+        visited
+    }
+
+    fn visit_type(&self, r#type: &'schema Type, visited: &mut BTreeSet<&'schema str>) {
+        match r#type {
+            Type::Schema(schema) => self.visit_schema(schema, visited),
+            Type::Tuple(items) => {
+                for item in items {
+                    self.visit_type(item, visited);
+                }
+            }
+            Type::List(item) | Type::Map(item) | Type::Option(item) | Type::Stream(item) => {
+                self.visit_type(item, visited);
+            }
+            Type::Result { ok, err } => {
+                self.visit_type(ok, visited);
+                self.visit_type(err, visited);
+            }
+            Type::Unit => {}
+        }
+    }
+
+    fn visit_schema(&self, schema: &'schema RefOr<Schema>, visited: &mut BTreeSet<&'schema str>) {
+        let schema = match schema {
+            RefOr::Ref(reference) => {
+                let component = reference
+                    .ref_location
+                    .strip_prefix(COMPONENT_PREFIX)
+                    .and_then(|name| self.schema.components.get_key_value(name));
+
+                if let Some((name, component)) = component {
+                    if visited.insert(name.as_str()) {
+                        self.visit_schema(component, visited);
+                    }
+                }
+
+                return;
+            }
+            RefOr::T(schema) => schema,
+        };
+
+        match schema {
+            Schema::Object(object) => {
+                for property in object.properties.values() {
+                    self.visit_schema(property, visited);
+                }
+
+                if let Some(AdditionalProperties::RefOr(additional)) =
+                    object.additional_properties.as_deref()
+                {
+                    self.visit_schema(additional, visited);
+                }
+            }
+            Schema::Array(array) => self.visit_schema(&array.items, visited),
+            Schema::OneOf(one_of) => {
+                for item in &one_of.items {
+                    self.visit_schema(item, visited);
+                }
+            }
+            Schema::AllOf(all_of) => {
+                for item in &all_of.items {
+                    self.visit_schema(item, visited);
+                }
+            }
+            Schema::AnyOf(any_of) => {
+                for item in &any_of.items {
+                    self.visit_schema(item, visited);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Display for TypeScriptClient<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        fmt.write_str(PRELUDE)?;
+
+        for name in self.referenced_components() {
+            let component = &self.schema.components[name];
+
+            write!(
+                fmt,
+                "\nexport type {} = {};\n",
+                Identifier(name),
+                RenderSchema(component)
+            )?;
+        }
+
+        for subsystem in &self.schema.subsystems {
+            fmt.write_str("\n")?;
+            render_subsystem(fmt, subsystem)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn render_subsystem(fmt: &mut Formatter<'_>, subsystem: &SubsystemSchema) -> fmt::Result {
+    let name = &subsystem.name;
+    let descriptor = subsystem.information.descriptor;
+
+    write!(
+        fmt,
+        "export const {name}Subsystem: RpcSubsystemDescriptor = {{\n  id: {},\n  version: {{ \
+         major: {}, minor: {} }},\n}};\n\n",
+        descriptor.id.value(),
+        descriptor.version.major,
+        descriptor.version.minor,
+    )?;
+
+    if let Some(deprecation) = subsystem.information.deprecation {
+        render_deprecation(fmt, "", deprecation)?;
+    }
+
+    writeln!(fmt, "export class {name}Client {{")?;
+    fmt.write_str("  constructor(private readonly transport: RpcTransport) {}\n")?;
+
+    for procedure in &subsystem.procedures {
+        fmt.write_str("\n")?;
+        render_procedure(fmt, name, procedure)?;
+    }
+
+    fmt.write_str("}\n")
+}
+
+fn render_procedure(
+    fmt: &mut Formatter<'_>,
+    subsystem: &str,
+    procedure: &ProcedureSchema,
+) -> fmt::Result {
+    if let Some(deprecation) = procedure.information.deprecation {
+        render_deprecation(fmt, "  ", deprecation)?;
+    }
+
+    let name = CamelCase(&procedure.name);
+    let output = Output(&procedure.response);
+    let method = transport_method(&procedure.request, &procedure.response);
+    let id = procedure.information.descriptor.id.value();
+
+    if matches!(procedure.request, Type::Unit) {
+        writeln!(fmt, "  {name}(): {output} {{")?;
+        writeln!(
+            fmt,
+            "    return this.transport.{method}({subsystem}Subsystem, {id}, null) as {output};"
+        )?;
+    } else {
+        let request = RenderType(&procedure.request);
+
+        writeln!(fmt, "  {name}(request: {request}): {output} {{")?;
+        writeln!(
+            fmt,
+            "    return this.transport.{method}({subsystem}Subsystem, {id}, request) as {output};"
+        )?;
+    }
+
+    fmt.write_str("  }\n")
+}
+
+fn render_deprecation(
+    fmt: &mut Formatter<'_>,
+    indent: &str,
+    deprecation: Deprecation,
+) -> fmt::Result {
+    write!(fmt, "{indent}/** @deprecated since {}", deprecation.since)?;
+
+    if let Some(reason) = deprecation.reason {
+        write!(fmt, ": {reason}")?;
+    }
+
+    fmt.write_str(" */\n")
+}
+
+/// Name of a component, as a valid `TypeScript` identifier.
+struct Identifier<'a>(&'a str);
+
+impl Display for Identifier<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        for char in self.0.chars() {
+            if char.is_ascii_alphanumeric() || char == '_' || char == '$' {
+                fmt.write_char(char)?;
+            } else {
+                fmt.write_str("_")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts a `snake_case` name into `camelCase`.
+struct CamelCase<'a>(&'a str);
+
+impl Display for CamelCase<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        for (index, segment) in self
+            .0
+            .split('_')
+            .filter(|segment| !segment.is_empty())
+            .enumerate()
+        {
+            let mut chars = segment.chars();
+
+            if index > 0 {
+                if let Some(first) = chars.next() {
+                    write!(fmt, "{}", first.to_ascii_uppercase())?;
+                }
+            }
+
+            fmt.write_str(chars.as_str())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A property key, quoted if it is not a valid identifier.
+struct PropertyKey<'a>(&'a str);
+
+impl Display for PropertyKey<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let is_identifier = self
+            .0
+            .chars()
+            .next()
+            .is_some_and(|char| char.is_ascii_alphabetic() || char == '_' || char == '$')
+            && self
+                .0
+                .chars()
+                .all(|char| char.is_ascii_alphanumeric() || char == '_' || char == '$');
+
+        if is_identifier {
+            fmt.write_str(self.0)
+        } else {
+            write!(fmt, "\"{}\"", self.0.escape_default())
+        }
+    }
+}
+
+/// Method of the `RpcTransport` used to invoke a procedure with the given request and response.
+const fn transport_method(request: &Type, response: &Type) -> &'static str {
+    match (request, response) {
+        (Type::Stream(_), Type::Stream(_)) => "exchange",
+        (Type::Stream(_), _) => "send",
+        (_, Type::Stream(_)) => "stream",
+        _ => "call",
+    }
+}
+
+/// Return type of the method of a procedure.
+///
+/// Streamed responses are exposed as `AsyncIterable`, every other response as `Promise`.
+struct Output<'a>(&'a Type);
+
+impl Display for Output<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Type::Stream(_) => RenderType(self.0).fmt(fmt),
+            r#type => write!(fmt, "Promise<{}>", RenderType(r#type)),
+        }
+    }
+}
+
+struct RenderType<'a>(&'a Type);
+
+impl Display for RenderType<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Type::Schema(schema) => RenderSchema(schema).fmt(fmt),
+            Type::Tuple(items) => {
+                fmt.write_str("[")?;
+
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        fmt.write_str(", ")?;
+                    }
+
+                    RenderType(item).fmt(fmt)?;
+                }
+
+                fmt.write_str("]")
+            }
+            Type::List(item) => write!(fmt, "Array<{}>", RenderType(item)),
+            Type::Map(item) => write!(fmt, "Record<string, {}>", RenderType(item)),
+            Type::Option(item) => write!(fmt, "{} | null", RenderType(item)),
+            Type::Stream(item) => write!(fmt, "AsyncIterable<{}>", RenderType(item)),
+            Type::Result { ok, err } => {
+                write!(fmt, "RpcResult<{}, {}>", RenderType(ok), RenderType(err))
+            }
+            Type::Unit => fmt.write_str("null"),
+        }
+    }
+}
+
+struct RenderSchema<'a>(&'a RefOr<Schema>);
+
+impl RenderSchema<'_> {
+    fn join(fmt: &mut Formatter<'_>, items: &[RefOr<Schema>], separator: &str) -> fmt::Result {
+        if items.is_empty() {
+            return fmt.write_str("unknown");
+        }
+
+        for (index, item) in items.iter().enumerate() {
+            if index > 0 {
+                fmt.write_str(separator)?;
+            }
+
+            write!(fmt, "({})", RenderSchema(item))?;
+        }
+
+        Ok(())
+    }
+
+    fn object(fmt: &mut Formatter<'_>, object: &Object) -> fmt::Result {
+        if let Some(values) = object.enum_values.as_deref() {
+            if !values.is_empty() {
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        fmt.write_str(" | ")?;
+                    }
+
+                    write!(fmt, "{value}")?;
+                }
+
+                return Ok(());
+            }
+        }
+
+        match object.schema_type {
+            SchemaType::String => fmt.write_str("string"),
+            SchemaType::Integer | SchemaType::Number => fmt.write_str("number"),
+            SchemaType::Boolean => fmt.write_str("boolean"),
+            SchemaType::Value => fmt.write_str("unknown"),
+            SchemaType::Array => fmt.write_str("unknown[]"),
+            SchemaType::Object if object.properties.is_empty() => {
+                match object.additional_properties.as_deref() {
+                    Some(AdditionalProperties::RefOr(additional)) => {
+                        write!(fmt, "Record<string, {}>", RenderSchema(additional))
+                    }
+                    Some(AdditionalProperties::FreeForm(false)) => {
+                        fmt.write_str("Record<string, never>")
+                    }
+                    Some(AdditionalProperties::FreeForm(true)) | None => {
+                        fmt.write_str("Record<string, unknown>")
+                    }
+                }
+            }
+            SchemaType::Object => {
+                fmt.write_str("{ ")?;
+
+                for (key, property) in &object.properties {
+                    let optional = if object.required.contains(key) {
+                        ""
+                    } else {
+                        "?"
+                    };
+
+                    write!(
+                        fmt,
+                        "{}{optional}: {}; ",
+                        PropertyKey(key),
+                        RenderSchema(property)
+                    )?;
+                }
+
+                fmt.write_str("}")
+            }
+        }
+    }
+}
+
+impl Display for RenderSchema<'_> {
+    fn fmt(&self, fmt: &mut Formatter<'_>) -> fmt::Result {
+        let schema = match self.0 {
+            RefOr::Ref(reference) => {
+                return match reference.ref_location.strip_prefix(COMPONENT_PREFIX) {
+                    Some(name) => Identifier(name).fmt(fmt),
+                    // external references cannot be resolved
+                    None => fmt.write_str("unknown"),
+                };
+            }
+            RefOr::T(schema) => schema,
+        };
+
+        match schema {
+            Schema::Object(object) => {
+                Self::object(fmt, object)?;
+
+                if object.nullable {
+                    fmt.write_str(" | null")?;
+                }
+
+                Ok(())
+            }
+            Schema::Array(array) => write!(fmt, "Array<{}>", Self(&array.items)),
+            Schema::OneOf(one_of) => Self::join(fmt, &one_of.items, " | "),
+            Schema::AnyOf(any_of) => Self::join(fmt, &any_of.items, " | "),
+            Schema::AllOf(all_of) => Self::join(fmt, &all_of.items, " & "),
+            _ => fmt.write_str("unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::borrow::Cow;
+
+    use harpc_types::{
+        procedure::{ProcedureDescriptor, ProcedureId},
+        subsystem::{SubsystemDescriptor, SubsystemId},
+        version::Version,
+    };
+    use utoipa::openapi::{ObjectBuilder, Ref, RefOr, Schema, SchemaType};
+
+    use super::{CamelCase, TypeScriptClient};
+    use crate::{
+        SubsystemInformation,
+        codegen::{ClientSchema, ProcedureSchema, SubsystemSchema, Type},
+        procedure::ProcedureInformation,
+    };
+
+    fn schema() -> ClientSchema {
+        let version = Version { major: 1, minor: 0 };
+
+        ClientSchema::new()
+            .with_components([
+                (
+                    "Account".to_owned(),
+                    RefOr::T(Schema::Object(
+                        ObjectBuilder::new()
+                            .property("id", RefOr::Ref(Ref::from_schema_name("AccountId")))
+                            .property(
+                                "display-name",
+                                RefOr::T(Schema::Object(
+                                    ObjectBuilder::new().schema_type(SchemaType::String).build(),
+                                )),
+                            )
+                            .required("id")
+                            .build(),
+                    )),
+                ),
+                (
+                    "AccountId".to_owned(),
+                    RefOr::T(Schema::Object(
+                        ObjectBuilder::new().schema_type(SchemaType::String).build(),
+                    )),
+                ),
+                (
+                    "Unused".to_owned(),
+                    RefOr::T(Schema::Object(
+                        ObjectBuilder::new()
+                            .schema_type(SchemaType::Boolean)
+                            .build(),
+                    )),
+                ),
+            ])
+            .with_subsystem(SubsystemSchema {
+                name: Cow::Borrowed("Account"),
+                information: SubsystemInformation {
+                    descriptor: SubsystemDescriptor {
+                        id: SubsystemId::new(0x02),
+                        version,
+                    },
+                    deprecation: None,
+                    initial_version: version,
+                },
+                procedures: vec![
+                    ProcedureSchema {
+                        name: Cow::Borrowed("get_account"),
+                        information: ProcedureInformation {
+                            descriptor: ProcedureDescriptor {
+                                id: ProcedureId::new(0x03),
+                            },
+                            since: version,
+                            deprecation: None,
+                        },
+                        request: Type::tuple([
+                            Type::Schema(RefOr::Ref(Ref::from_schema_name("AccountId"))),
+                            Type::boolean(),
+                        ]),
+                        response: Type::result(
+                            Type::Schema(RefOr::Ref(Ref::from_schema_name("Account"))),
+                            Type::unknown(),
+                        ),
+                    },
+                    ProcedureSchema {
+                        name: Cow::Borrowed("watch_account"),
+                        information: ProcedureInformation {
+                            descriptor: ProcedureDescriptor {
+                                id: ProcedureId::new(0x04),
+                            },
+                            since: version,
+                            deprecation: None,
+                        },
+                        request: Type::option(Type::Schema(RefOr::Ref(Ref::from_schema_name(
+                            "AccountId",
+                        )))),
+                        response: Type::stream(Type::result(
+                            Type::Schema(RefOr::Ref(Ref::from_schema_name("Account"))),
+                            Type::unknown(),
+                        )),
+                    },
+                    ProcedureSchema {
+                        name: Cow::Borrowed("import_accounts"),
+                        information: ProcedureInformation {
+                            descriptor: ProcedureDescriptor {
+                                id: ProcedureId::new(0x05),
+                            },
+                            since: version,
+                            deprecation: None,
+                        },
+                        request: Type::stream(Type::Schema(RefOr::Ref(Ref::from_schema_name(
+                            "Account",
+                        )))),
+                        response: Type::Unit,
+                    },
+                    ProcedureSchema {
+                        name: Cow::Borrowed("resolve_accounts"),
+                        information: ProcedureInformation {
+                            descriptor: ProcedureDescriptor {
+                                id: ProcedureId::new(0x06),
+                            },
+                            since: version,
+                            deprecation: None,
+                        },
+                        request: Type::stream(Type::Schema(RefOr::Ref(Ref::from_schema_name(
+                            "AccountId",
+                        )))),
+                        response: Type::stream(Type::Schema(RefOr::Ref(Ref::from_schema_name(
+                            "Account",
+                        )))),
+                    },
+                ],
+            })
+    }
+
+    #[test]
+    fn camel_case() {
+        assert_eq!(CamelCase("get_account").to_string(), "getAccount");
+        assert_eq!(CamelCase("echo").to_string(), "echo");
+        assert_eq!(CamelCase("_leading__double").to_string(), "leadingDouble");
+    }
+
+    #[test]
+    fn client() {
+        let schema = schema();
+        let module = TypeScriptClient::new(&schema).to_string();
+
+        assert!(module.contains("export type AccountId = string;"));
+        assert!(
+            module
+                .contains(r#"export type Account = { "display-name"?: string; id: AccountId; };"#)
+        );
+        assert!(!module.contains("Unused"), "unreferenced types are skipped");
+
+        assert!(module.contains("id: 2,"));
+        assert!(module.contains("export class AccountClient {"));
+        assert!(module.contains(
+            "getAccount(request: [AccountId, boolean]): Promise<RpcResult<Account, unknown>>"
+        ));
+        assert!(module.contains("this.transport.call(AccountSubsystem, 3, request)"));
+
+        assert!(module.contains(
+            "watchAccount(request: AccountId | null): AsyncIterable<RpcResult<Account, unknown>>"
+        ));
+        assert!(module.contains("this.transport.stream(AccountSubsystem, 4, request)"));
+
+        assert!(module.contains("importAccounts(request: AsyncIterable<Account>): Promise<null>"));
+        assert!(module.contains("this.transport.send(AccountSubsystem, 5, request)"));
+
+        assert!(module.contains(
+            "resolveAccounts(request: AsyncIterable<AccountId>): AsyncIterable<Account>"
+        ));
+        assert!(module.contains("this.transport.exchange(AccountSubsystem, 6, request)"));
+    }
+}
@@ -4,6 +4,9 @@
 #![cfg_attr(doc, doc = simple_mermaid::mermaid!("../docs/dependency-diagram.mmd"))]
 #![feature(never_type, marker_trait_attr)]
 
+#[cfg(feature = "codegen")]
+extern crate alloc;
+
 use harpc_types::{
     subsystem::{SubsystemDescriptor, SubsystemId},
     version::Version,
@@ -11,6 +14,8 @@ use harpc_types::{
 
 use self::{metadata::Deprecation, procedure::ProcedureIdentifier};
 
+#[cfg(feature = "codegen")]
+pub mod codegen;
 pub mod delegate;
 pub mod metadata;
 pub mod procedure;
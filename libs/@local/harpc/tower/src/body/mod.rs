@@ -6,6 +6,7 @@ pub mod encode_report;
 pub mod full;
 pub mod limited;
 pub mod map;
+pub mod record;
 pub mod server;
 pub mod size_hint;
 pub mod stream;
@@ -0,0 +1,266 @@
+use alloc::sync::Arc;
+use core::{
+    mem,
+    pin::Pin,
+    task::{Context, Poll, ready},
+    time::Duration,
+};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use bytes::Bytes;
+use harpc_types::{
+    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+};
+use tokio::{sync::mpsc, time::Instant};
+
+use super::{Body, BodyState, Frame, SizeHint};
+use crate::recording::{RecordedFrame, Recording};
+
+/// Collects the frames of a single transaction.
+///
+/// The collector is shared between the request and response body, once both have been dropped the
+/// recording is complete and is sent to the recorder.
+#[derive(Debug)]
+pub(crate) struct Collector {
+    start: Instant,
+    recording: Mutex<Recording>,
+
+    sender: mpsc::Sender<Recording>,
+}
+
+impl Collector {
+    pub(crate) fn new(
+        subsystem: SubsystemDescriptor,
+        procedure: ProcedureDescriptor,
+        sender: mpsc::Sender<Recording>,
+    ) -> Self {
+        Self {
+            start: Instant::now(),
+            recording: Mutex::new(Recording {
+                subsystem,
+                procedure,
+                request: Vec::new(),
+                response: Vec::new(),
+            }),
+            sender,
+        }
+    }
+
+    fn offset(&self) -> Duration {
+        self.start.elapsed()
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Recording> {
+        self.recording
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn push_request(&self, frame: RecordedFrame) {
+        self.lock().request.push(frame);
+    }
+
+    fn push_response(&self, frame: RecordedFrame) {
+        self.lock().response.push(frame);
+    }
+}
+
+impl Drop for Collector {
+    fn drop(&mut self) {
+        let recording = self
+            .recording
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let recording = Recording {
+            subsystem: recording.subsystem,
+            procedure: recording.procedure,
+            request: mem::take(&mut recording.request),
+            response: mem::take(&mut recording.response),
+        };
+
+        // The recorder must never apply backpressure to the service, if it is unable to keep up we
+        // drop the recording instead.
+        if let Err(error) = self.sender.try_send(recording) {
+            tracing::warn!(%error, "unable to deliver recording, recording has been dropped");
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Request body that records every data frame.
+    #[derive(Debug)]
+    pub struct RecordRequestBody<B> {
+        #[pin]
+        inner: B,
+        collector: Arc<Collector>,
+    }
+}
+
+impl<B> RecordRequestBody<B> {
+    pub(crate) const fn new(inner: B, collector: Arc<Collector>) -> Self {
+        Self { inner, collector }
+    }
+}
+
+impl<B> Body for RecordRequestBody<B>
+where
+    B: Body<Control = !, Data = Bytes>,
+{
+    type Control = !;
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<Frame<Self::Data, Self::Control>, Self::Error>>> {
+        let this = self.project();
+
+        let frame = ready!(this.inner.poll_frame(cx));
+
+        if let Some(Ok(Frame::Data(payload))) = &frame {
+            this.collector.push_request(RecordedFrame::Data {
+                offset: this.collector.offset(),
+                payload: payload.clone(),
+            });
+        }
+
+        Poll::Ready(frame)
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// Response body that records every data and control frame.
+    #[derive(Debug)]
+    pub struct RecordResponseBody<B> {
+        #[pin]
+        inner: B,
+        collector: Arc<Collector>,
+    }
+}
+
+impl<B> RecordResponseBody<B> {
+    pub(crate) const fn new(inner: B, collector: Arc<Collector>) -> Self {
+        Self { inner, collector }
+    }
+}
+
+impl<B> Body for RecordResponseBody<B>
+where
+    B: Body<Control: AsRef<ResponseKind>, Data = Bytes>,
+{
+    type Control = B::Control;
+    type Data = Bytes;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Option<Result<Frame<Self::Data, Self::Control>, Self::Error>>> {
+        let this = self.project();
+
+        let frame = ready!(this.inner.poll_frame(cx));
+
+        match &frame {
+            Some(Ok(Frame::Data(payload))) => this.collector.push_response(RecordedFrame::Data {
+                offset: this.collector.offset(),
+                payload: payload.clone(),
+            }),
+            Some(Ok(Frame::Control(control))) => {
+                this.collector.push_response(RecordedFrame::Control {
+                    offset: this.collector.offset(),
+                    kind: *control.as_ref(),
+                });
+            }
+            Some(Err(_)) | None => {}
+        }
+
+        Poll::Ready(frame)
+    }
+
+    fn state(&self) -> Option<BodyState> {
+        self.inner.state()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use alloc::sync::Arc;
+
+    use bytes::Bytes;
+    use harpc_types::{
+        procedure::{ProcedureDescriptor, ProcedureId},
+        response_kind::ResponseKind,
+        subsystem::{SubsystemDescriptor, SubsystemId},
+        version::Version,
+    };
+    use tokio::sync::mpsc;
+
+    use super::{Collector, RecordRequestBody, RecordResponseBody};
+    use crate::{
+        body::{BodyExt as _, Frame, controlled::Controlled, full::Full},
+        recording::RecordedFrame,
+        test::StaticBody,
+    };
+
+    #[tokio::test]
+    async fn records_once_dropped() {
+        let (tx, mut rx) = mpsc::channel(1);
+        let collector = Arc::new(Collector::new(
+            SubsystemDescriptor {
+                id: SubsystemId::new(0x01),
+                version: Version { major: 1, minor: 0 },
+            },
+            ProcedureDescriptor {
+                id: ProcedureId::new(0x02),
+            },
+            tx,
+        ));
+
+        let mut request = RecordRequestBody::new(
+            StaticBody::<Bytes, !, !>::new([Ok(Frame::Data(Bytes::from_static(b"ping")))]),
+            Arc::clone(&collector),
+        );
+        let mut response = RecordResponseBody::new(
+            Controlled::new(ResponseKind::Ok, Full::new(Bytes::from_static(b"pong"))),
+            collector,
+        );
+
+        while request.frame().await.is_some() {}
+        while response.frame().await.is_some() {}
+
+        drop(request);
+        assert!(rx.try_recv().is_err(), "response is still in flight");
+
+        drop(response);
+        let recording = rx.try_recv().expect("recording should have been sent");
+
+        assert!(matches!(
+            recording.request.as_slice(),
+            [RecordedFrame::Data { payload, .. }] if payload == "ping"
+        ));
+        assert!(matches!(
+            recording.response.as_slice(),
+            [
+                RecordedFrame::Control {
+                    kind: ResponseKind::Ok,
+                    ..
+                },
+                RecordedFrame::Data { payload, .. }
+            ] if payload == "pong"
+        ));
+    }
+}
@@ -6,4 +6,5 @@ pub mod decode_error;
 pub mod encode;
 pub mod error;
 pub mod map_body;
+pub mod record;
 pub mod report;
@@ -0,0 +1,154 @@
+use alloc::sync::Arc;
+use core::{
+    mem,
+    task::{Context, Poll},
+};
+
+use bytes::Bytes;
+use futures::TryFutureExt as _;
+use harpc_types::response_kind::ResponseKind;
+use tokio::sync::mpsc;
+use tower::{Layer, Service};
+
+use crate::{
+    body::{
+        Body,
+        record::{Collector, RecordRequestBody, RecordResponseBody},
+    },
+    recording::Recording,
+    request::Request,
+    response::Response,
+};
+
+/// Records every request and response frame that passes through the service.
+///
+/// Once both the request and the response body of a transaction have been dropped, the
+/// [`Recording`] is sent through the channel, use [`write_recordings`] to persist them.
+///
+/// Recording never applies backpressure to the service, if the channel is full the recording is
+/// dropped and a warning is emitted.
+///
+/// The layer should be the outermost layer, so that the recorded frames are the ones that are sent
+/// over the wire.
+///
+/// [`write_recordings`]: crate::recording::write_recordings
+#[derive(Debug, Clone)]
+pub struct RecordLayer {
+    sender: mpsc::Sender<Recording>,
+}
+
+impl RecordLayer {
+    #[must_use]
+    pub const fn new(sender: mpsc::Sender<Recording>) -> Self {
+        Self { sender }
+    }
+}
+
+impl<S> Layer<S> for RecordLayer {
+    type Service = RecordService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RecordService {
+            inner,
+            sender: self.sender.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RecordService<S> {
+    inner: S,
+    sender: mpsc::Sender<Recording>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for RecordService<S>
+where
+    S: Service<Request<RecordRequestBody<ReqBody>>, Response = Response<ResBody>> + Clone,
+    ReqBody: Body<Control = !, Data = Bytes>,
+    ResBody: Body<Control: AsRef<ResponseKind>, Data = Bytes>,
+{
+    type Error = S::Error;
+    type Response = Response<RecordResponseBody<ResBody>>;
+
+    type Future = impl Future<Output = Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<ReqBody>) -> Self::Future {
+        // see: https://docs.rs/tower/latest/tower/trait.Service.html#be-careful-when-cloning-inner-services
+        let clone = self.inner.clone();
+        let mut inner = mem::replace(&mut self.inner, clone);
+
+        let collector = Arc::new(Collector::new(
+            req.subsystem(),
+            req.procedure(),
+            self.sender.clone(),
+        ));
+
+        let request = req.map_body(|body| RecordRequestBody::new(body, Arc::clone(&collector)));
+
+        inner.call(request).map_ok(move |response| {
+            response.map_body(|body| RecordResponseBody::new(body, collector))
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use bytes::Bytes;
+    use harpc_types::response_kind::ResponseKind;
+    use tokio::sync::mpsc;
+    use tower::{Layer as _, ServiceExt as _, service_fn};
+
+    use crate::{
+        body::{BodyExt as _, controlled::Controlled, full::Full, record::RecordRequestBody},
+        layer::{
+            error::test::{BODY, request},
+            record::RecordLayer,
+        },
+        recording::{RecordedFrame, ResponseSegment},
+        request::Request,
+        response::{self, Response},
+    };
+
+    #[tokio::test]
+    async fn record() {
+        let (tx, mut rx) = mpsc::channel(1);
+
+        let service = RecordLayer::new(tx).layer(service_fn(
+            |request: Request<RecordRequestBody<Full<Bytes>>>| {
+                let session = request.session();
+
+                async move {
+                    let mut body = request.into_body();
+                    while body.frame().await.is_some() {}
+
+                    Ok::<_, !>(Response::from_parts(
+                        response::Parts::new(session),
+                        Controlled::new(ResponseKind::Ok, Full::new(Bytes::from_static(b"pong"))),
+                    ))
+                }
+            },
+        ));
+
+        let Ok(mut response) = service.oneshot(request()).await;
+        while response.body_mut().frame().await.is_some() {}
+        drop(response);
+
+        let recording = rx.recv().await.expect("recording should have been sent");
+
+        assert!(matches!(
+            recording.request.as_slice(),
+            [RecordedFrame::Data { payload, .. }] if payload == BODY
+        ));
+        assert_eq!(
+            recording.response_segments(),
+            [ResponseSegment {
+                kind: ResponseKind::Ok,
+                payload: Bytes::from_static(b"pong"),
+            }]
+        );
+    }
+}
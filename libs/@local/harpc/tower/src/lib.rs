@@ -19,6 +19,7 @@ pub mod either;
 pub(crate) mod extensions;
 pub mod layer;
 pub mod net;
+pub mod recording;
 pub mod request;
 pub mod response;
 #[cfg(test)]
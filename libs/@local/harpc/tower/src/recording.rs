@@ -0,0 +1,350 @@
+//! Recordings of the traffic handled by a service.
+//!
+//! Recordings are captured by the [`RecordLayer`] and can be persisted as newline delimited JSON
+//! (one [`Recording`] per line) using [`write_recordings`] and read back using
+//! [`read_recordings`]. Payloads are encoded as standard base64 strings.
+//!
+//! [`RecordLayer`]: crate::layer::record::RecordLayer
+use core::time::Duration;
+
+use bytes::{Bytes, BytesMut};
+use error_stack::{Report, ResultExt as _};
+use harpc_types::{
+    procedure::ProcedureDescriptor, response_kind::ResponseKind, subsystem::SubsystemDescriptor,
+};
+use tokio::{
+    io::{AsyncBufRead, AsyncBufReadExt as _, AsyncWrite, AsyncWriteExt as _},
+    sync::mpsc,
+};
+
+mod payload {
+    use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64_STANDARD};
+    use bytes::Bytes;
+    use serde::{Deserialize as _, Deserializer, Serializer, de::Error as _};
+
+    pub(super) fn serialize<S>(payload: &Bytes, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&BASE64_STANDARD.encode(payload))
+    }
+
+    pub(super) fn deserialize<'de, D>(deserializer: D) -> Result<Bytes, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = String::deserialize(deserializer)?;
+
+        BASE64_STANDARD
+            .decode(value)
+            .map(Bytes::from)
+            .map_err(D::Error::custom)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, thiserror::Error)]
+pub enum RecordingError {
+    #[error("unable to encode recording")]
+    Encode,
+    #[error("unable to decode recording on line {line}")]
+    Decode { line: usize },
+    #[error("unable to write recording")]
+    Write,
+    #[error("unable to read recording")]
+    Read,
+}
+
+/// A single frame of a recorded request or response body.
+///
+/// The `offset` is the time elapsed since the start of the transaction until the frame has been
+/// observed.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum RecordedFrame {
+    Data {
+        offset: Duration,
+        #[serde(with = "payload")]
+        payload: Bytes,
+    },
+    Control {
+        offset: Duration,
+        kind: ResponseKind,
+    },
+}
+
+impl RecordedFrame {
+    #[must_use]
+    pub const fn offset(&self) -> Duration {
+        match self {
+            Self::Data { offset, .. } | Self::Control { offset, .. } => *offset,
+        }
+    }
+}
+
+/// Contiguous part of a response that shares the same [`ResponseKind`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ResponseSegment {
+    pub kind: ResponseKind,
+    #[serde(with = "payload")]
+    pub payload: Bytes,
+}
+
+impl ResponseSegment {
+    /// Normalize a sequence of segments into their canonical form.
+    ///
+    /// Adjacent segments of the same kind are merged and empty successful segments are removed,
+    /// as they are indistinguishable from a missing segment on the wire.
+    pub fn normalize(segments: impl IntoIterator<Item = Self>) -> Vec<Self> {
+        let mut output: Vec<(ResponseKind, BytesMut)> = Vec::new();
+
+        for segment in segments {
+            match output.last_mut() {
+                Some((kind, payload)) if *kind == segment.kind => {
+                    payload.extend_from_slice(&segment.payload);
+                }
+                _ => output.push((segment.kind, BytesMut::from(segment.payload))),
+            }
+        }
+
+        output
+            .into_iter()
+            .filter(|(kind, payload)| kind.is_err() || !payload.is_empty())
+            .map(|(kind, payload)| Self {
+                kind,
+                payload: payload.freeze(),
+            })
+            .collect()
+    }
+}
+
+/// A single recorded transaction.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Recording {
+    pub subsystem: SubsystemDescriptor,
+    pub procedure: ProcedureDescriptor,
+
+    pub request: Vec<RecordedFrame>,
+    pub response: Vec<RecordedFrame>,
+}
+
+impl Recording {
+    /// The payload of the request, in the order it has been received.
+    pub fn request_payload(&self) -> impl Iterator<Item = Bytes> {
+        self.request.iter().filter_map(|frame| match frame {
+            RecordedFrame::Data { payload, .. } => Some(payload.clone()),
+            RecordedFrame::Control { .. } => None,
+        })
+    }
+
+    /// The normalized response, split into segments of the same [`ResponseKind`].
+    ///
+    /// Data that has been sent before any control frame is considered successful.
+    #[must_use]
+    pub fn response_segments(&self) -> Vec<ResponseSegment> {
+        let mut kind = ResponseKind::Ok;
+
+        let segments = self.response.iter().map(|frame| match frame {
+            RecordedFrame::Data { payload, .. } => ResponseSegment {
+                kind,
+                payload: payload.clone(),
+            },
+            RecordedFrame::Control {
+                kind: next_kind, ..
+            } => {
+                kind = *next_kind;
+
+                ResponseSegment {
+                    kind,
+                    payload: Bytes::new(),
+                }
+            }
+        });
+
+        ResponseSegment::normalize(segments)
+    }
+}
+
+/// Write all recordings received through `rx` to `writer`, one recording per line.
+///
+/// Returns once all senders have been dropped and every recording has been written.
+///
+/// # Errors
+///
+/// - [`RecordingError::Encode`] if a recording cannot be serialized
+/// - [`RecordingError::Write`] if writing to `writer` fails
+pub async fn write_recordings<W>(
+    mut rx: mpsc::Receiver<Recording>,
+    writer: W,
+) -> Result<(), Report<RecordingError>>
+where
+    W: AsyncWrite + Unpin,
+{
+    let mut writer = tokio::io::BufWriter::new(writer);
+
+    while let Some(recording) = rx.recv().await {
+        let mut line = serde_json::to_vec(&recording).change_context(RecordingError::Encode)?;
+        line.push(b'\n');
+
+        writer
+            .write_all(&line)
+            .await
+            .change_context(RecordingError::Write)?;
+
+        // flush after every recording, so that a crash loses at most a single recording
+        writer.flush().await.change_context(RecordingError::Write)?;
+    }
+
+    Ok(())
+}
+
+/// Read all recordings from `reader`, which are expected to be one recording per line.
+///
+/// Empty lines are skipped.
+///
+/// # Errors
+///
+/// - [`RecordingError::Read`] if reading from `reader` fails
+/// - [`RecordingError::Decode`] if a line is not a valid recording
+pub async fn read_recordings<R>(reader: R) -> Result<Vec<Recording>, Report<RecordingError>>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let mut lines = reader.lines();
+    let mut recordings = Vec::new();
+    let mut line_number = 0;
+
+    while let Some(line) = lines
+        .next_line()
+        .await
+        .change_context(RecordingError::Read)?
+    {
+        line_number += 1;
+
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let recording = serde_json::from_str(&line)
+            .change_context(RecordingError::Decode { line: line_number })?;
+
+        recordings.push(recording);
+    }
+
+    Ok(recordings)
+}
+
+#[cfg(test)]
+mod test {
+    use core::time::Duration;
+
+    use bytes::Bytes;
+    use harpc_types::{
+        error_code::ErrorCode,
+        procedure::{ProcedureDescriptor, ProcedureId},
+        response_kind::ResponseKind,
+        subsystem::{SubsystemDescriptor, SubsystemId},
+        version::Version,
+    };
+    use tokio::{io::BufReader, sync::mpsc};
+
+    use super::{RecordedFrame, Recording, ResponseSegment, read_recordings, write_recordings};
+
+    fn recording() -> Recording {
+        Recording {
+            subsystem: SubsystemDescriptor {
+                id: SubsystemId::new(0x01),
+                version: Version { major: 1, minor: 2 },
+            },
+            procedure: ProcedureDescriptor {
+                id: ProcedureId::new(0x02),
+            },
+            request: vec![RecordedFrame::Data {
+                offset: Duration::from_millis(1),
+                payload: Bytes::from_static(b"request"),
+            }],
+            response: vec![
+                RecordedFrame::Control {
+                    offset: Duration::from_millis(2),
+                    kind: ResponseKind::Ok,
+                },
+                RecordedFrame::Data {
+                    offset: Duration::from_millis(3),
+                    payload: Bytes::from_static(b"hello "),
+                },
+                RecordedFrame::Data {
+                    offset: Duration::from_millis(4),
+                    payload: Bytes::from_static(b"world"),
+                },
+                RecordedFrame::Control {
+                    offset: Duration::from_millis(5),
+                    kind: ResponseKind::Err(ErrorCode::INTERNAL_SERVER_ERROR),
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn response_segments() {
+        let segments = recording().response_segments();
+
+        assert_eq!(
+            segments,
+            [
+                ResponseSegment {
+                    kind: ResponseKind::Ok,
+                    payload: Bytes::from_static(b"hello world"),
+                },
+                ResponseSegment {
+                    kind: ResponseKind::Err(ErrorCode::INTERNAL_SERVER_ERROR),
+                    payload: Bytes::new(),
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn normalize_removes_empty_ok() {
+        let segments = ResponseSegment::normalize([
+            ResponseSegment {
+                kind: ResponseKind::Ok,
+                payload: Bytes::new(),
+            },
+            ResponseSegment {
+                kind: ResponseKind::Ok,
+                payload: Bytes::new(),
+            },
+        ]);
+
+        assert_eq!(segments, []);
+    }
+
+    #[test]
+    fn payload_is_base64() {
+        let value = serde_json::to_value(recording()).expect("should be able to encode recording");
+
+        assert_eq!(value["request"][0]["payload"], "cmVxdWVzdA==");
+    }
+
+    #[tokio::test]
+    async fn roundtrip() {
+        let (tx, rx) = mpsc::channel(4);
+        tx.send(recording())
+            .await
+            .expect("receiver should be alive");
+        tx.send(recording())
+            .await
+            .expect("receiver should be alive");
+        drop(tx);
+
+        let mut buffer = Vec::new();
+        write_recordings(rx, &mut buffer)
+            .await
+            .expect("should be able to write recordings");
+
+        let recordings = read_recordings(BufReader::new(buffer.as_slice()))
+            .await
+            .expect("should be able to read recordings");
+
+        assert_eq!(recordings, [recording(), recording()]);
+    }
+}
@@ -1,5 +1,6 @@
 use harpc_net::session::server::{SessionId, transaction::TransactionContext};
 use harpc_types::{procedure::ProcedureDescriptor, subsystem::SubsystemDescriptor};
+use libp2p_identity::PeerId;
 
 use crate::extensions::Extensions;
 
@@ -15,13 +16,19 @@ pub struct Parts {
 }
 
 impl Parts {
+    /// Creates the parts of a request from the context of a transaction.
+    ///
+    /// The [`PeerId`] of the remote peer is inserted into the extensions.
     #[must_use]
     pub fn from_transaction(context: &TransactionContext) -> Self {
+        let mut extensions = Extensions::new();
+        extensions.insert(context.peer());
+
         Self {
             subsystem: context.subsystem(),
             procedure: context.procedure(),
             session: context.session(),
-            extensions: Extensions::new(),
+            extensions,
         }
     }
 }
@@ -55,6 +62,13 @@ impl<B> Request<B> {
         self.head.session
     }
 
+    /// The peer that sent the request.
+    ///
+    /// Returns `None` if the request did not originate from a transaction.
+    pub fn peer(&self) -> Option<PeerId> {
+        self.head.extensions.get::<PeerId>().copied()
+    }
+
     pub const fn body(&self) -> &B {
         &self.body
     }
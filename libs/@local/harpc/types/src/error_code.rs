@@ -99,7 +99,12 @@ define_error_code_consts! {
         /// The HTTP equivalent is 403 Forbidden.
         FORBIDDEN,
         /// The amount of items in the request stream does not match the expected amount.
-        REQUEST_EXPECTED_ITEM_COUNT_MISMATCH
+        REQUEST_EXPECTED_ITEM_COUNT_MISMATCH,
+        /// The client has exceeded its quota, either by sending requests at too high of a rate or
+        /// by having too many requests in flight.
+        ///
+        /// The HTTP equivalent is 429 Too Many Requests.
+        TOO_MANY_REQUESTS
     ],
     // 0xFF_xx = server errors
     /// Errors that occur in a session and are issued by the server.
@@ -0,0 +1,325 @@
+use alloc::borrow::Cow;
+use std::collections::HashSet;
+
+use hash_graph_store::{
+    entity::{
+        AggregateEntitiesParams, CreateEntityParams, EntityAggregation, EntityAggregationGroup,
+        EntityQueryPath, EntityStore as _,
+    },
+    entity_type::EntityTypeQueryPath,
+    filter::{Filter, JsonPath, PathToken},
+    query::AggregationFunction,
+    subgraph::{
+        edges::SharedEdgeKind,
+        temporal_axes::{
+            PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
+            VariableTemporalAxisUnresolved,
+        },
+    },
+};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::{
+    knowledge::{
+        entity::provenance::ProvidedEntityEditionProvenance,
+        property::{PropertyObject, PropertyObjectWithMetadata},
+    },
+    ontology::id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    provenance::{ActorType, OriginProvenance, OriginType},
+    web::OwnedById,
+};
+
+use crate::DatabaseTestWrapper;
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn organization_entity_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/organization/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn age_path() -> EntityQueryPath<'static> {
+    EntityQueryPath::Properties(Some(JsonPath::from_path_tokens(vec![PathToken::Field(
+        Cow::Borrowed("https://blockprotocol.org/@alice/types/property-type/age/"),
+    )])))
+}
+
+#[tokio::test]
+async fn aggregate_by_property() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    for person in [
+        entity::PERSON_ALICE_V1,
+        entity::PERSON_BOB_V1,
+        entity::PERSON_CHARLES_V1,
+    ] {
+        let properties: PropertyObject =
+            serde_json::from_str(person).expect("could not parse entity");
+        api.create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([person_entity_type_id()]),
+                properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    let person_entity_type_id = person_entity_type_id();
+    let params = |group_by: Vec<EntityQueryPath<'static>>| AggregateEntitiesParams {
+        filter: Filter::for_entity_by_type_id(&person_entity_type_id),
+        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        },
+        include_drafts: false,
+        group_by,
+        aggregations: vec![
+            EntityAggregation {
+                function: AggregationFunction::Count,
+                path: None,
+            },
+            EntityAggregation {
+                function: AggregationFunction::Count,
+                path: Some(age_path()),
+            },
+            EntityAggregation {
+                function: AggregationFunction::Sum,
+                path: Some(age_path()),
+            },
+            EntityAggregation {
+                function: AggregationFunction::Min,
+                path: Some(age_path()),
+            },
+            EntityAggregation {
+                function: AggregationFunction::Max,
+                path: Some(age_path()),
+            },
+            EntityAggregation {
+                function: AggregationFunction::Avg,
+                path: Some(age_path()),
+            },
+        ],
+    };
+
+    let response = api
+        .aggregate_entities(api.account_id, params(Vec::new()))
+        .await
+        .expect("could not aggregate entities");
+    assert_eq!(
+        response.groups,
+        [EntityAggregationGroup {
+            key: Vec::new(),
+            values: vec![
+                Some(3.0),
+                Some(2.0),
+                Some(84.0),
+                Some(42.0),
+                Some(42.0),
+                Some(42.0)
+            ],
+        }]
+    );
+
+    let response = api
+        .aggregate_entities(api.account_id, params(vec![age_path()]))
+        .await
+        .expect("could not aggregate entities");
+    assert_eq!(
+        response.groups,
+        [
+            EntityAggregationGroup {
+                key: vec![json!(42)],
+                values: vec![
+                    Some(2.0),
+                    Some(2.0),
+                    Some(84.0),
+                    Some(42.0),
+                    Some(42.0),
+                    Some(42.0)
+                ],
+            },
+            EntityAggregationGroup {
+                key: vec![json!(null)],
+                values: vec![Some(1.0), Some(0.0), None, None, None, None],
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn count_entities_joined_with_multiple_types() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::TEXT_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::ORGANIZATION_V1,
+                entity_type::PERSON_V1,
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let properties: PropertyObject =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([
+                person_entity_type_id(),
+                organization_entity_type_id(),
+            ]),
+            properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity");
+
+    // Aggregating the type versions joins every edition with both of its types.
+    let response = api
+        .aggregate_entities(
+            api.account_id,
+            AggregateEntitiesParams {
+                filter: Filter::All(Vec::new()),
+                temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                    pinned: PinnedTemporalAxisUnresolved::new(None),
+                    variable: VariableTemporalAxisUnresolved::new(None, None),
+                },
+                include_drafts: false,
+                group_by: Vec::new(),
+                aggregations: vec![
+                    EntityAggregation {
+                        function: AggregationFunction::Count,
+                        path: None,
+                    },
+                    EntityAggregation {
+                        function: AggregationFunction::Count,
+                        path: Some(EntityQueryPath::EntityTypeEdge {
+                            edge_kind: SharedEdgeKind::IsOfType,
+                            path: EntityTypeQueryPath::Version,
+                            inheritance_depth: Some(0),
+                        }),
+                    },
+                ],
+            },
+        )
+        .await
+        .expect("could not aggregate entities");
+    assert_eq!(
+        response.groups,
+        [EntityAggregationGroup {
+            key: Vec::new(),
+            values: vec![Some(1.0), Some(2.0)],
+        }]
+    );
+}
+
+#[tokio::test]
+async fn aggregation_requires_path() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let api = database
+        .seed([], [], [])
+        .await
+        .expect("could not seed database");
+
+    api.aggregate_entities(
+        api.account_id,
+        AggregateEntitiesParams {
+            filter: Filter::All(Vec::new()),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            include_drafts: false,
+            group_by: Vec::new(),
+            aggregations: vec![EntityAggregation {
+                function: AggregationFunction::Sum,
+                path: None,
+            }],
+        },
+    )
+    .await
+    .expect_err("summing up without a path should fail");
+}
@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+
+use hash_graph_authorization::NoAuthorization;
+use hash_graph_store::entity::{
+    ArchiveEntityBatchOperation, CreateEntityBatchOperation, EntityBatchLinkData,
+    EntityBatchOperation, EntityBatchOperationResult, EntityBatchReference, EntityStore as _,
+    InvalidEntityBatchReference,
+};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use type_system::{
+    knowledge::{
+        entity::{
+            id::{EntityId, EntityUuid},
+            provenance::ProvidedEntityEditionProvenance,
+        },
+        property::{PropertyObject, PropertyObjectWithMetadata, metadata::PropertyProvenance},
+    },
+    ontology::id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    provenance::{ActorType, OriginProvenance, OriginType},
+    web::OwnedById,
+};
+use uuid::Uuid;
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+async fn seed(
+    database: &mut DatabaseTestWrapper<NoAuthorization>,
+) -> DatabaseApi<'_, &mut NoAuthorization> {
+    database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+fn person_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn friend_of_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn provenance() -> ProvidedEntityEditionProvenance {
+    ProvidedEntityEditionProvenance {
+        actor_type: ActorType::User,
+        origin: OriginProvenance::from_empty_type(OriginType::Api),
+        sources: Vec::new(),
+    }
+}
+
+fn create_entity(
+    owned_by_id: OwnedById,
+    entity_uuid: Option<EntityUuid>,
+    entity_type_id: VersionedUrl,
+    properties: PropertyObject,
+    link_data: Option<EntityBatchLinkData>,
+) -> EntityBatchOperation {
+    EntityBatchOperation::CreateEntity(Box::new(CreateEntityBatchOperation {
+        owned_by_id,
+        entity_uuid,
+        decision_time: None,
+        entity_type_ids: HashSet::from([entity_type_id]),
+        properties: PropertyObjectWithMetadata::from_parts(properties, None)
+            .expect("could not create property with metadata object"),
+        confidence: None,
+        link_data,
+        draft: false,
+        relationships: Vec::new(),
+        provenance: provenance(),
+    }))
+}
+
+fn link_data(left: EntityBatchReference, right: EntityBatchReference) -> EntityBatchLinkData {
+    EntityBatchLinkData {
+        left_entity_id: left,
+        right_entity_id: right,
+        left_entity_confidence: None,
+        left_entity_provenance: PropertyProvenance::default(),
+        right_entity_confidence: None,
+        right_entity_provenance: PropertyProvenance::default(),
+    }
+}
+
+#[tokio::test]
+async fn create_and_link() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+    let owned_by_id = OwnedById::new(api.account_id.into_uuid());
+
+    let results = api
+        .execute_entity_batch(
+            api.account_id,
+            vec![
+                create_entity(owned_by_id, None, person_type_id(), alice, None),
+                create_entity(owned_by_id, None, person_type_id(), bob, None),
+                create_entity(
+                    owned_by_id,
+                    None,
+                    friend_of_type_id(),
+                    PropertyObject::empty(),
+                    Some(link_data(
+                        EntityBatchReference::Operation { operation: 0 },
+                        EntityBatchReference::Operation { operation: 1 },
+                    )),
+                ),
+                EntityBatchOperation::ArchiveEntity(ArchiveEntityBatchOperation {
+                    entity_id: EntityBatchReference::Operation { operation: 2 },
+                    decision_time: None,
+                    provenance: provenance(),
+                }),
+            ],
+        )
+        .await
+        .expect("could not execute batch");
+
+    let [
+        EntityBatchOperationResult::CreateEntity { entity: alice },
+        EntityBatchOperationResult::CreateEntity { entity: bob },
+        EntityBatchOperationResult::CreateEntity { entity: link },
+        EntityBatchOperationResult::ArchiveEntity {
+            entity: archived_link,
+        },
+    ] = results.as_slice()
+    else {
+        panic!("unexpected batch results: {results:?}");
+    };
+
+    let link_data = link.link_data.as_ref().expect("link should have link data");
+    assert_eq!(link_data.left_entity_id, alice.metadata.record_id.entity_id);
+    assert_eq!(link_data.right_entity_id, bob.metadata.record_id.entity_id);
+    assert_eq!(
+        archived_link.metadata.record_id.entity_id,
+        link.metadata.record_id.entity_id
+    );
+    assert!(archived_link.metadata.archived);
+}
+
+#[tokio::test]
+async fn rollback_on_invalid_reference() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+    let owned_by_id = OwnedById::new(api.account_id.into_uuid());
+    let alice_id = EntityId {
+        owned_by_id,
+        entity_uuid: EntityUuid::new(Uuid::new_v4()),
+        draft_id: None,
+    };
+
+    let report = api
+        .execute_entity_batch(
+            api.account_id,
+            vec![
+                create_entity(
+                    owned_by_id,
+                    Some(alice_id.entity_uuid),
+                    person_type_id(),
+                    alice,
+                    None,
+                ),
+                create_entity(
+                    owned_by_id,
+                    None,
+                    friend_of_type_id(),
+                    PropertyObject::empty(),
+                    Some(link_data(
+                        EntityBatchReference::Operation { operation: 0 },
+                        EntityBatchReference::Operation { operation: 2 },
+                    )),
+                ),
+            ],
+        )
+        .await
+        .expect_err("batch should fail");
+    assert!(report.contains::<InvalidEntityBatchReference>());
+
+    api.get_entity_by_id(api.account_id, alice_id, None, None)
+        .await
+        .expect_err("entity should not have been created");
+}
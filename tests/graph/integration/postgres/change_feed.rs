@@ -0,0 +1,190 @@
+use std::collections::HashSet;
+
+use hash_graph_store::{
+    change_feed::{Change, ChangeFeedRecord, ChangeFeedStore as _, ReadChangesParams},
+    entity::{CreateEntityParams, EntityStore as _, PatchEntityParams},
+};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use type_system::{
+    knowledge::{
+        entity::provenance::ProvidedEntityEditionProvenance,
+        property::{PropertyObject, PropertyObjectWithMetadata},
+    },
+    ontology::id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    provenance::{ActorType, OriginProvenance, OriginType},
+    web::OwnedById,
+};
+
+use crate::DatabaseTestWrapper;
+
+fn provenance() -> ProvidedEntityEditionProvenance {
+    ProvidedEntityEditionProvenance {
+        actor_type: ActorType::User,
+        origin: OriginProvenance::from_empty_type(OriginType::Api),
+        sources: Vec::new(),
+    }
+}
+
+#[tokio::test]
+async fn entity_and_type_changes() {
+    let person: PropertyObject =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let created = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([person_type_id.clone()]),
+                properties: PropertyObjectWithMetadata::from_parts(person, None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: provenance(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = created.metadata.record_id.entity_id;
+
+    let archived = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id,
+                decision_time: None,
+                expected_edition_id: None,
+                archived: Some(true),
+                draft: None,
+                entity_type_ids: HashSet::new(),
+                properties: vec![],
+                confidence: None,
+                provenance: provenance(),
+            },
+        )
+        .await
+        .expect("could not archive entity");
+
+    // Read the feed in small pages to resume from the returned cursor.
+    let mut records = Vec::<ChangeFeedRecord>::new();
+    let mut after = None;
+    loop {
+        let response = api
+            .read_changes(
+                api.account_id,
+                ReadChangesParams {
+                    after,
+                    before: None,
+                    limit: 2,
+                },
+            )
+            .await
+            .expect("could not read changes");
+        records.extend(response.changes);
+        after = response.cursor;
+        if !response.has_more {
+            break;
+        }
+    }
+
+    assert!(
+        records
+            .windows(2)
+            .all(|window| window[0].cursor < window[1].cursor),
+        "changes should be ordered by their cursor"
+    );
+
+    let person_type_position = records
+        .iter()
+        .position(|record| {
+            matches!(
+                &record.change,
+                Change::OntologyTypeCreated { ontology_type_id, .. }
+                    if *ontology_type_id == person_type_id
+            )
+        })
+        .expect("person type creation should be in the feed");
+
+    let entity_changes = records
+        .iter()
+        .enumerate()
+        .filter(|(_, record)| {
+            matches!(
+                record.change,
+                Change::EntityEditionCreated { entity_id: id, .. }
+                    | Change::EntityArchived { entity_id: id, .. } if id == entity_id
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let [(created_position, created_change), (_, archived_change)] = entity_changes.as_slice()
+    else {
+        panic!("unexpected entity changes: {entity_changes:?}");
+    };
+    assert!(person_type_position < *created_position);
+    assert_eq!(
+        created_change.change,
+        Change::EntityEditionCreated {
+            entity_id,
+            entity_edition_id: created.metadata.record_id.edition_id,
+        }
+    );
+    assert_eq!(
+        archived_change.change,
+        Change::EntityArchived {
+            entity_id,
+            entity_edition_id: archived.metadata.record_id.edition_id,
+        }
+    );
+
+    let tail = api
+        .read_changes(
+            api.account_id,
+            ReadChangesParams {
+                after: Some(archived_change.cursor),
+                before: None,
+                limit: 10,
+            },
+        )
+        .await
+        .expect("could not read changes");
+    assert!(tail.changes.is_empty());
+    assert_eq!(tail.cursor, Some(archived_change.cursor));
+}
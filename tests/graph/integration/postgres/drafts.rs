@@ -144,6 +144,7 @@ async fn initial_draft() {
                 archived: None,
                 draft: Some(true),
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: None,
                 provenance: ProvidedEntityEditionProvenance {
                     actor_type: ActorType::User,
@@ -199,6 +200,7 @@ async fn initial_draft() {
                 archived: None,
                 draft: Some(false),
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: None,
                 provenance: ProvidedEntityEditionProvenance {
                     actor_type: ActorType::User,
@@ -334,6 +336,7 @@ async fn no_initial_draft() {
                     archived: None,
                     draft: Some(true),
                     decision_time: None,
+                    expected_edition_id: None,
                     confidence: None,
                     provenance: ProvidedEntityEditionProvenance {
                         actor_type: ActorType::User,
@@ -403,6 +406,7 @@ async fn no_initial_draft() {
                     archived: None,
                     draft: Some(false),
                     decision_time: None,
+                    expected_edition_id: None,
                     confidence: None,
                     provenance: ProvidedEntityEditionProvenance {
                         actor_type: ActorType::User,
@@ -527,6 +531,7 @@ async fn multiple_drafts() {
                     archived: None,
                     draft: Some(true),
                     decision_time: None,
+                    expected_edition_id: None,
                     confidence: None,
                     provenance: ProvidedEntityEditionProvenance {
                         actor_type: ActorType::User,
@@ -599,6 +604,7 @@ async fn multiple_drafts() {
                     archived: None,
                     draft: Some(false),
                     decision_time: None,
+                    expected_edition_id: None,
                     confidence: None,
                     provenance: ProvidedEntityEditionProvenance {
                         actor_type: ActorType::User,
@@ -1,31 +1,50 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use hash_graph_authorization::{AuthorizationApi, schema::WebOwnerSubject};
+use hash_graph_postgres_store::store::error::EntityEditionMismatch;
 use hash_graph_store::{
+    account::{AccountStore as _, InsertAccountIdParams, InsertWebIdParams},
     entity::{
         CountEntitiesParams, CreateEntityParams, EntityQuerySorting, EntityStore as _,
-        GetEntitiesParams, PatchEntityParams,
+        EntityValidationReport, EraseEntityParams, GetEntitiesParams, GetEntityHistoryParams,
+        MergeEntitiesParams, PatchEntityParams, PropertyMergeStrategy, PropertyUpgradeOperation,
+        UniquePropertyError, UpgradeEntityTypeParams,
     },
+    entity_type::{CreateEntityTypeParams, EntityTypeStore as _},
     filter::Filter,
+    query::ConflictBehavior,
     subgraph::temporal_axes::{
         PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
     },
 };
-use hash_graph_temporal_versioning::{ClosedTemporalBound, LimitedTemporalBound, TemporalBound};
+use hash_graph_temporal_versioning::{
+    ClosedTemporalBound, LimitedTemporalBound, RightBoundedTemporalInterval, TemporalBound,
+    Timestamp,
+};
 use hash_graph_test_data::{data_type, entity, entity_type, property_type};
 use type_system::{
     knowledge::{
-        entity::provenance::ProvidedEntityEditionProvenance,
+        entity::{
+            EntityId, LinkData,
+            id::{EntityEditionId, EntityUuid},
+            provenance::ProvidedEntityEditionProvenance,
+        },
         property::{
             Property, PropertyObject, PropertyObjectWithMetadata, PropertyPatchOperation,
-            PropertyPath, PropertyWithMetadata,
+            PropertyPath, PropertyWithMetadata, metadata::PropertyProvenance,
         },
     },
-    ontology::id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
-    provenance::{ActorType, OriginProvenance, OriginType},
+    ontology::{
+        entity_type::EntityType,
+        id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+        provenance::{OntologyOwnership, ProvidedOntologyEditionProvenance},
+    },
+    provenance::{ActorEntityUuid, ActorType, OriginProvenance, OriginType},
     web::OwnedById,
 };
+use uuid::Uuid;
 
-use crate::DatabaseTestWrapper;
+use crate::{DatabaseApi, DatabaseTestWrapper, entity_type_relationships};
 
 #[tokio::test]
 async fn insert() {
@@ -271,6 +290,7 @@ async fn update() {
                 archived: None,
                 draft: None,
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: None,
                 provenance: ProvidedEntityEditionProvenance {
                     actor_type: ActorType::User,
@@ -407,3 +427,1277 @@ async fn update() {
     let entity_v2 = response_v2.entities.pop().expect("no entity found");
     assert_eq!(entity_v2.properties.properties(), page_v2.properties());
 }
+
+#[tokio::test]
+async fn update_with_expected_edition() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let provenance = ProvidedEntityEditionProvenance {
+        actor_type: ActorType::User,
+        origin: OriginProvenance::from_empty_type(OriginType::Api),
+        sources: Vec::new(),
+    };
+
+    let v1_entity = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([VersionedUrl {
+                    base_url: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    version: OntologyTypeVersion::new(1),
+                }]),
+                properties: PropertyObjectWithMetadata::from_parts(page_v1, None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: provenance.clone(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = v1_entity.metadata.record_id.entity_id;
+
+    let patch = |expected_edition_id| PatchEntityParams {
+        entity_id,
+        properties: vec![PropertyPatchOperation::Replace {
+            path: PropertyPath::default(),
+            property: PropertyWithMetadata::from_parts(Property::Object(page_v2.clone()), None)
+                .expect("could not create property with metadata"),
+        }],
+        entity_type_ids: HashSet::new(),
+        archived: None,
+        draft: None,
+        decision_time: None,
+        expected_edition_id: Some(expected_edition_id),
+        confidence: None,
+        provenance: provenance.clone(),
+    };
+
+    let report = api
+        .patch_entity(api.account_id, patch(EntityEditionId::new(Uuid::new_v4())))
+        .await
+        .expect_err("patching an unexpected edition should fail");
+    assert!(
+        report.contains::<EntityEditionMismatch>(),
+        "unexpected error: {report:?}"
+    );
+
+    let v2_entity = api
+        .patch_entity(
+            api.account_id,
+            patch(v1_entity.metadata.record_id.edition_id),
+        )
+        .await
+        .expect("could not update entity");
+    assert_ne!(
+        v2_entity.metadata.record_id.edition_id,
+        v1_entity.metadata.record_id.edition_id
+    );
+
+    // The first edition is not current anymore, so another patch based on it has to fail.
+    let report = api
+        .patch_entity(
+            api.account_id,
+            patch(v1_entity.metadata.record_id.edition_id),
+        )
+        .await
+        .expect_err("patching an outdated edition should fail");
+    assert!(
+        report.contains::<EntityEditionMismatch>(),
+        "unexpected error: {report:?}"
+    );
+}
+
+#[tokio::test]
+async fn history() {
+    let page_v1: PropertyObject =
+        serde_json::from_str(entity::PAGE_V1).expect("could not parse entity");
+    let page_v2: PropertyObject =
+        serde_json::from_str(entity::PAGE_V2).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::TEXT_V1],
+            [entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database:");
+
+    let provenance = ProvidedEntityEditionProvenance {
+        actor_type: ActorType::User,
+        origin: OriginProvenance::from_empty_type(OriginType::Api),
+        sources: Vec::new(),
+    };
+
+    let v1_entity = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([VersionedUrl {
+                    base_url: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    version: OntologyTypeVersion::new(1),
+                }]),
+                properties: PropertyObjectWithMetadata::from_parts(page_v1, None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: provenance.clone(),
+            },
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = v1_entity.metadata.record_id.entity_id;
+
+    let v2_entity = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id,
+                properties: vec![PropertyPatchOperation::Replace {
+                    path: PropertyPath::default(),
+                    property: PropertyWithMetadata::from_parts(Property::Object(page_v2), None)
+                        .expect("could not create property with metadata"),
+                }],
+                entity_type_ids: HashSet::new(),
+                archived: None,
+                draft: None,
+                decision_time: None,
+                expected_edition_id: None,
+                confidence: None,
+                provenance,
+            },
+        )
+        .await
+        .expect("could not update entity");
+
+    let history = api
+        .get_entity_history(
+            api.account_id,
+            GetEntityHistoryParams {
+                entity_id,
+                interval: None,
+            },
+        )
+        .await
+        .expect("could not get entity history");
+
+    let [first, second] = history.editions.as_slice() else {
+        panic!("expected two editions, got {:?}", history.editions);
+    };
+    assert_eq!(first.edition_id, v1_entity.metadata.record_id.edition_id);
+    assert_eq!(second.edition_id, v2_entity.metadata.record_id.edition_id);
+    // The transaction time of the first edition was closed by the update.
+    assert_eq!(first.temporal_versioning.len(), 1);
+    assert_eq!(
+        first.temporal_versioning[0].transaction_time.start(),
+        v1_entity
+            .metadata
+            .temporal_versioning
+            .transaction_time
+            .start()
+    );
+    assert_eq!(
+        second.temporal_versioning,
+        [v2_entity.metadata.temporal_versioning]
+    );
+    assert!(!first.properties.is_empty());
+    assert!(!second.properties.is_empty());
+
+    let ClosedTemporalBound::Inclusive(created_at) = *v1_entity
+        .metadata
+        .temporal_versioning
+        .transaction_time
+        .start();
+    let history = api
+        .get_entity_history(
+            api.account_id,
+            GetEntityHistoryParams {
+                entity_id,
+                interval: Some(RightBoundedTemporalInterval::new(
+                    TemporalBound::Unbounded,
+                    LimitedTemporalBound::Exclusive(created_at),
+                )),
+            },
+        )
+        .await
+        .expect("could not get entity history");
+    assert!(history.editions.is_empty());
+}
+
+#[tokio::test]
+async fn erase() {
+    let organization: PropertyObject =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [entity_type::ORGANIZATION_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let entity = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([VersionedUrl {
+                    base_url: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/entity-type/organization/"
+                            .to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    version: OntologyTypeVersion::new(1),
+                }]),
+                properties: PropertyObjectWithMetadata::from_parts(organization, None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.metadata.record_id.entity_id;
+
+    let tombstones = api
+        .erase_entity(
+            api.account_id,
+            EraseEntityParams {
+                entity_id,
+                reason: "Requested by the data subject".to_owned(),
+            },
+        )
+        .await
+        .expect("could not erase entity");
+    assert_eq!(tombstones.len(), 1);
+    assert_eq!(tombstones[0].owned_by_id, entity_id.owned_by_id);
+    assert_eq!(tombstones[0].entity_uuid, entity_id.entity_uuid);
+    assert_eq!(tombstones[0].erased_by_id, api.account_id);
+    assert_eq!(tombstones[0].reason, "Requested by the data subject");
+
+    api.get_entity_history(
+        api.account_id,
+        GetEntityHistoryParams {
+            entity_id,
+            interval: None,
+        },
+    )
+    .await
+    .expect_err("erased entity should not have a history");
+
+    api.erase_entity(
+        api.account_id,
+        EraseEntityParams {
+            entity_id,
+            reason: "Requested by the data subject".to_owned(),
+        },
+    )
+    .await
+    .expect_err("erased entity should not be erasable again");
+}
+
+#[tokio::test]
+async fn erase_recreated_entity() {
+    let organization: PropertyObject =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [entity_type::ORGANIZATION_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let entity_id = create_entity(&mut api, "organization", organization.clone(), None).await;
+    let first_tombstones = api
+        .erase_entity(
+            api.account_id,
+            EraseEntityParams {
+                entity_id,
+                reason: "Requested by the data subject".to_owned(),
+            },
+        )
+        .await
+        .expect("could not erase entity");
+
+    let recreated_entity_id = api
+        .create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: entity_id.owned_by_id,
+                entity_uuid: Some(entity_id.entity_uuid),
+                decision_time: None,
+                entity_type_ids: HashSet::from([VersionedUrl {
+                    base_url: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/entity-type/organization/"
+                            .to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    version: OntologyTypeVersion::new(1),
+                }]),
+                properties: PropertyObjectWithMetadata::from_parts(organization, None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not create entity with the ID of an erased entity")
+        .metadata
+        .record_id
+        .entity_id;
+    assert_eq!(recreated_entity_id, entity_id);
+
+    let second_tombstones = api
+        .erase_entity(
+            api.account_id,
+            EraseEntityParams {
+                entity_id,
+                reason: "Requested again by the data subject".to_owned(),
+            },
+        )
+        .await
+        .expect("could not erase the entity a second time");
+    assert_eq!(second_tombstones.len(), 1);
+    assert_eq!(second_tombstones[0].entity_uuid, entity_id.entity_uuid);
+    assert_eq!(
+        second_tombstones[0].reason,
+        "Requested again by the data subject"
+    );
+    assert!(
+        second_tombstones[0].erased_at_transaction_time
+            > first_tombstones[0].erased_at_transaction_time
+    );
+}
+
+async fn create_entity<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    entity_type_id: &str,
+    properties: PropertyObject,
+    link_data: Option<LinkData>,
+) -> EntityId {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([VersionedUrl {
+                base_url: BaseUrl::new(format!(
+                    "https://blockprotocol.org/@alice/types/entity-type/{entity_type_id}/"
+                ))
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }]),
+            properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity")
+    .metadata
+    .record_id
+    .entity_id
+}
+
+fn link_data(left_entity_id: EntityId, right_entity_id: EntityId) -> LinkData {
+    LinkData {
+        left_entity_id,
+        right_entity_id,
+        left_entity_confidence: None,
+        left_entity_provenance: PropertyProvenance::default(),
+        right_entity_confidence: None,
+        right_entity_provenance: PropertyProvenance::default(),
+    }
+}
+
+#[tokio::test]
+async fn erase_links_of_links() {
+    let alice: PropertyObject =
+        serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob: PropertyObject =
+        serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let alice_id = create_entity(&mut api, "person", alice, None).await;
+    let bob_id = create_entity(&mut api, "person", bob, None).await;
+    let friendship_id = create_entity(
+        &mut api,
+        "friend-of",
+        PropertyObject::empty(),
+        Some(link_data(alice_id, bob_id)),
+    )
+    .await;
+    // The entity types don't allow links between links, so the link would be rejected otherwise.
+    api.store.settings.validate_links = false;
+    let acquaintance_id = create_entity(
+        &mut api,
+        "acquaintance-of",
+        PropertyObject::empty(),
+        Some(link_data(bob_id, friendship_id)),
+    )
+    .await;
+
+    let tombstones = api
+        .erase_entity(
+            api.account_id,
+            EraseEntityParams {
+                entity_id: alice_id,
+                reason: "Requested by the data subject".to_owned(),
+            },
+        )
+        .await
+        .expect("could not erase entity");
+    assert_eq!(
+        tombstones
+            .iter()
+            .map(|tombstone| tombstone.entity_uuid)
+            .collect::<HashSet<_>>(),
+        HashSet::from([
+            alice_id.entity_uuid,
+            friendship_id.entity_uuid,
+            acquaintance_id.entity_uuid
+        ])
+    );
+
+    api.get_entity_by_id(api.account_id, bob_id, None, None)
+        .await
+        .expect("the other endpoint should not be erased");
+}
+
+#[tokio::test]
+async fn merge() {
+    let target_properties: PropertyObject =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+    let source_properties: PropertyObject = serde_json::from_value(serde_json::json!({
+        "https://blockprotocol.org/@alice/types/property-type/name/": "HASH"
+    }))
+    .expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [entity_type::ORGANIZATION_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let entity_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/organization/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let provenance = ProvidedEntityEditionProvenance {
+        actor_type: ActorType::User,
+        origin: OriginProvenance::from_empty_type(OriginType::Api),
+        sources: Vec::new(),
+    };
+
+    let mut entity_ids = Vec::new();
+    for properties in [target_properties, source_properties.clone()] {
+        let entity = api
+            .create_entity(
+                api.account_id,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: HashSet::from([entity_type_id.clone()]),
+                    properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                        .expect("could not create property with metadata object"),
+                    confidence: None,
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    provenance: provenance.clone(),
+                },
+            )
+            .await
+            .expect("could not create entity");
+        entity_ids.push(entity.metadata.record_id.entity_id);
+    }
+    let [target_entity_id, source_entity_id] = entity_ids[..] else {
+        unreachable!("two entities were created");
+    };
+
+    let response = api
+        .merge_entities(
+            api.account_id,
+            MergeEntitiesParams {
+                target_entity_id,
+                source_entity_id,
+                default_strategy: PropertyMergeStrategy::KeepTarget,
+                property_strategies: HashMap::from([(
+                    BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/property-type/name/".to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    PropertyMergeStrategy::KeepSource,
+                )]),
+                provenance,
+            },
+        )
+        .await
+        .expect("could not merge entities");
+
+    assert_eq!(
+        response.entity.metadata.record_id.entity_id,
+        target_entity_id
+    );
+    assert_eq!(response.entity.properties, source_properties);
+    assert!(!response.entity.metadata.archived);
+
+    assert_eq!(
+        response.merged_entity.metadata.record_id.entity_id,
+        source_entity_id
+    );
+    assert!(response.merged_entity.metadata.archived);
+    assert_eq!(
+        response
+            .merged_entity
+            .metadata
+            .provenance
+            .edition
+            .merged_into,
+        Some(target_entity_id)
+    );
+
+    let merged_entity = api
+        .get_entity_by_id(api.account_id, source_entity_id, None, None)
+        .await
+        .expect("could not get merged entity");
+    assert!(merged_entity.metadata.archived);
+    assert_eq!(
+        merged_entity.metadata.provenance.edition.merged_into,
+        Some(target_entity_id)
+    );
+
+    api.merge_entities(
+        api.account_id,
+        MergeEntitiesParams {
+            target_entity_id,
+            source_entity_id: target_entity_id,
+            default_strategy: PropertyMergeStrategy::KeepTarget,
+            property_strategies: HashMap::new(),
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect_err("an entity should not be mergeable into itself");
+}
+
+#[tokio::test]
+async fn merge_replaces_links() {
+    let [alice, bob, charles] = [
+        entity::PERSON_ALICE_V1,
+        entity::PERSON_BOB_V1,
+        entity::PERSON_CHARLES_V1,
+    ]
+    .map(|person| serde_json::from_str::<PropertyObject>(person).expect("could not parse entity"));
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let alice_id = create_entity(&mut api, "person", alice, None).await;
+    let bob_id = create_entity(&mut api, "person", bob, None).await;
+    let charles_id = create_entity(&mut api, "person", charles, None).await;
+    let friendship_id = create_entity(
+        &mut api,
+        "friend-of",
+        PropertyObject::empty(),
+        Some(link_data(charles_id, bob_id)),
+    )
+    .await;
+
+    let before_merge = Timestamp::now();
+    let response = api
+        .merge_entities(
+            api.account_id,
+            MergeEntitiesParams {
+                target_entity_id: alice_id,
+                source_entity_id: bob_id,
+                default_strategy: PropertyMergeStrategy::KeepTarget,
+                property_strategies: HashMap::new(),
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not merge entities");
+
+    let [link] = response.links.as_slice() else {
+        panic!("the link of the merged entity should be replaced");
+    };
+    assert_ne!(link.metadata.record_id.entity_id, friendship_id);
+    let link_data = link
+        .link_data
+        .as_ref()
+        .expect("the entity should be a link");
+    assert_eq!(link_data.left_entity_id, charles_id);
+    assert_eq!(link_data.right_entity_id, alice_id);
+
+    let friendship = api
+        .get_entity_by_id(api.account_id, friendship_id, None, None)
+        .await
+        .expect("could not get replaced link");
+    assert!(friendship.metadata.archived);
+
+    let friendship = api
+        .get_entity_by_id(api.account_id, friendship_id, Some(before_merge), None)
+        .await
+        .expect("could not get link before the merge");
+    assert!(!friendship.metadata.archived);
+    assert_eq!(
+        friendship
+            .link_data
+            .expect("the entity should be a link")
+            .right_entity_id,
+        bob_id
+    );
+}
+
+#[tokio::test]
+async fn merge_replaces_draft_links() {
+    let [alice, bob, charles] = [
+        entity::PERSON_ALICE_V1,
+        entity::PERSON_BOB_V1,
+        entity::PERSON_CHARLES_V1,
+    ]
+    .map(|person| serde_json::from_str::<PropertyObject>(person).expect("could not parse entity"));
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let alice_id = create_entity(&mut api, "person", alice, None).await;
+    let bob_id = create_entity(&mut api, "person", bob, None).await;
+    let charles_id = create_entity(&mut api, "person", charles, None).await;
+    let friendship_id = create_entity(
+        &mut api,
+        "friend-of",
+        PropertyObject::empty(),
+        Some(link_data(charles_id, bob_id)),
+    )
+    .await;
+    let draft_friendship_id = api
+        .patch_entity(
+            api.account_id,
+            PatchEntityParams {
+                entity_id: friendship_id,
+                decision_time: None,
+                expected_edition_id: None,
+                entity_type_ids: HashSet::new(),
+                properties: Vec::new(),
+                draft: Some(true),
+                archived: None,
+                confidence: None,
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not create draft of link")
+        .metadata
+        .record_id
+        .entity_id;
+    assert!(draft_friendship_id.draft_id.is_some());
+
+    let response = api
+        .merge_entities(
+            api.account_id,
+            MergeEntitiesParams {
+                target_entity_id: alice_id,
+                source_entity_id: bob_id,
+                default_strategy: PropertyMergeStrategy::KeepTarget,
+                property_strategies: HashMap::new(),
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not merge entities");
+
+    let [link, draft_link] = response.links.as_slice() else {
+        panic!("the link and its draft should be replaced");
+    };
+    let link_id = link.metadata.record_id.entity_id;
+    let draft_link_id = draft_link.metadata.record_id.entity_id;
+    assert_ne!(link_id.entity_uuid, friendship_id.entity_uuid);
+    assert!(link_id.draft_id.is_none());
+    assert_eq!(draft_link_id.entity_uuid, link_id.entity_uuid);
+    assert!(draft_link_id.draft_id.is_some());
+    for link in [link, draft_link] {
+        let link_data = link
+            .link_data
+            .as_ref()
+            .expect("the entity should be a link");
+        assert_eq!(link_data.left_entity_id, charles_id);
+        assert_eq!(link_data.right_entity_id, alice_id);
+    }
+
+    let draft_friendship = api
+        .get_entity_by_id(api.account_id, draft_friendship_id, None, None)
+        .await
+        .expect("could not get replaced draft link");
+    assert!(draft_friendship.metadata.archived);
+}
+
+#[tokio::test]
+async fn merge_drops_links_between_merged_entities() {
+    let [alice, bob] = [entity::PERSON_ALICE_V1, entity::PERSON_BOB_V1].map(|person| {
+        serde_json::from_str::<PropertyObject>(person).expect("could not parse entity")
+    });
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let alice_id = create_entity(&mut api, "person", alice, None).await;
+    let bob_id = create_entity(&mut api, "person", bob, None).await;
+    let friendship_id = create_entity(
+        &mut api,
+        "friend-of",
+        PropertyObject::empty(),
+        Some(link_data(alice_id, bob_id)),
+    )
+    .await;
+
+    let response = api
+        .merge_entities(
+            api.account_id,
+            MergeEntitiesParams {
+                target_entity_id: alice_id,
+                source_entity_id: bob_id,
+                default_strategy: PropertyMergeStrategy::KeepTarget,
+                property_strategies: HashMap::new(),
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not merge entities");
+    assert!(
+        response.links.is_empty(),
+        "the link between the merged entities should not be replaced by a self-link"
+    );
+
+    let friendship = api
+        .get_entity_by_id(api.account_id, friendship_id, None, None)
+        .await
+        .expect("could not get link between the merged entities");
+    assert!(friendship.metadata.archived);
+}
+
+#[tokio::test]
+async fn upgrade_entity_type() {
+    let organization: PropertyObject =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::NAME_V1, property_type::TEXT_V1],
+            [entity_type::ORGANIZATION_V1, entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let organization_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/organization/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let page_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let provenance = ProvidedEntityEditionProvenance {
+        actor_type: ActorType::User,
+        origin: OriginProvenance::from_empty_type(OriginType::Api),
+        sources: Vec::new(),
+    };
+
+    let mut entity_ids = HashSet::new();
+    for _ in 0..3 {
+        let entity = api
+            .create_entity(
+                api.account_id,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: HashSet::from([organization_type_id.clone()]),
+                    properties: PropertyObjectWithMetadata::from_parts(organization.clone(), None)
+                        .expect("could not create property with metadata object"),
+                    confidence: None,
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    provenance: provenance.clone(),
+                },
+            )
+            .await
+            .expect("could not create entity");
+        entity_ids.insert(entity.metadata.record_id.entity_id);
+    }
+
+    // Without a mapping, the `name` property is not allowed on a page.
+    let response = api
+        .upgrade_entity_type(
+            api.account_id,
+            UpgradeEntityTypeParams {
+                source_entity_type_id: organization_type_id.clone(),
+                target_entity_type_id: page_type_id.clone(),
+                mapping: Vec::new(),
+                batch_size: Some(2),
+                provenance: provenance.clone(),
+            },
+        )
+        .await
+        .expect("could not upgrade entities");
+    assert!(response.upgraded.is_empty());
+    assert_eq!(
+        response
+            .failures
+            .iter()
+            .map(|failure| failure.entity_id)
+            .collect::<HashSet<_>>(),
+        entity_ids
+    );
+
+    let response = api
+        .upgrade_entity_type(
+            api.account_id,
+            UpgradeEntityTypeParams {
+                source_entity_type_id: organization_type_id,
+                target_entity_type_id: page_type_id.clone(),
+                mapping: vec![PropertyUpgradeOperation::Rename {
+                    from: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/property-type/name/".to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                    to: BaseUrl::new(
+                        "https://blockprotocol.org/@alice/types/property-type/text/".to_owned(),
+                    )
+                    .expect("couldn't construct Base URL"),
+                }],
+                batch_size: Some(2),
+                provenance,
+            },
+        )
+        .await
+        .expect("could not upgrade entities");
+    assert!(response.failures.is_empty());
+    assert_eq!(
+        response.upgraded.iter().copied().collect::<HashSet<_>>(),
+        entity_ids
+    );
+
+    for entity_id in entity_ids {
+        let entity = api
+            .get_entity_by_id(api.account_id, entity_id, None, None)
+            .await
+            .expect("could not get entity");
+        assert_eq!(
+            entity.metadata.entity_type_ids,
+            HashSet::from([page_type_id.clone()])
+        );
+        assert_eq!(
+            serde_json::to_value(&entity.properties).expect("could not serialize properties"),
+            serde_json::json!({
+                "https://blockprotocol.org/@alice/types/property-type/text/": "HASH, Ltd"
+            })
+        );
+    }
+}
+
+#[tokio::test]
+async fn upgrade_entity_type_skips_unauthorized_entities() {
+    let organization: PropertyObject =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::NAME_V1, property_type::TEXT_V1],
+            [entity_type::ORGANIZATION_V1, entity_type::PAGE_V1],
+        )
+        .await
+        .expect("could not seed database");
+
+    let organization_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/organization/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let page_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+    let provenance = ProvidedEntityEditionProvenance {
+        actor_type: ActorType::User,
+        origin: OriginProvenance::from_empty_type(OriginType::Api),
+        sources: Vec::new(),
+    };
+
+    let entity_id = create_entity(&mut api, "organization", organization, None).await;
+
+    // An actor with its own web, which is not allowed to view the entities of the other web.
+    let other_account_id = ActorEntityUuid::new(EntityUuid::new(Uuid::new_v4()));
+    api.store
+        .insert_account_id(
+            other_account_id,
+            InsertAccountIdParams {
+                account_id: other_account_id,
+                account_type: ActorType::User,
+            },
+        )
+        .await
+        .expect("could not insert account id");
+    api.store
+        .insert_web_id(
+            other_account_id,
+            InsertWebIdParams {
+                owned_by_id: OwnedById::new(other_account_id.into_uuid()),
+                owner: WebOwnerSubject::Account {
+                    id: other_account_id,
+                },
+            },
+        )
+        .await
+        .expect("could not insert web id");
+    api.get_entity_by_id(other_account_id, entity_id, None, None)
+        .await
+        .expect_err("the other actor should not be able to view the entity");
+
+    // Without a mapping, the upgrade would fail for the entity, which must not be reported to an
+    // actor who cannot view it.
+    let response = api
+        .upgrade_entity_type(
+            other_account_id,
+            UpgradeEntityTypeParams {
+                source_entity_type_id: organization_type_id.clone(),
+                target_entity_type_id: page_type_id,
+                mapping: Vec::new(),
+                batch_size: None,
+                provenance,
+            },
+        )
+        .await
+        .expect("could not upgrade entities");
+    assert!(response.upgraded.is_empty());
+    assert!(
+        response.failures.is_empty(),
+        "entities of other webs should not be reported"
+    );
+
+    let entity = api
+        .get_entity_by_id(api.account_id, entity_id, None, None)
+        .await
+        .expect("could not get entity");
+    assert_eq!(
+        entity.metadata.entity_type_ids,
+        HashSet::from([organization_type_id])
+    );
+}
+
+#[tokio::test]
+async fn unique_properties() {
+    let mut organization_type: EntityType = serde_json::from_str(entity_type::ORGANIZATION_V1)
+        .expect("could not parse entity type representation");
+    let name_property =
+        BaseUrl::new("https://blockprotocol.org/@alice/types/property-type/name/".to_owned())
+            .expect("couldn't construct Base URL");
+    organization_type
+        .unique_properties
+        .insert(name_property.clone());
+    let organization_type_id = organization_type.id.clone();
+    let organization: PropertyObject =
+        serde_json::from_str(entity::ORGANIZATION_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [data_type::VALUE_V1, data_type::TEXT_V1],
+            [property_type::NAME_V1],
+            [],
+        )
+        .await
+        .expect("could not seed database");
+
+    api.create_entity_type(
+        api.account_id,
+        CreateEntityTypeParams {
+            schema: organization_type,
+            ownership: OntologyOwnership::Local {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            },
+            relationships: entity_type_relationships(),
+            conflict_behavior: ConflictBehavior::Fail,
+            provenance: ProvidedOntologyEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity type");
+
+    let owned_by_id = OwnedById::new(api.account_id.into_uuid());
+    let create_params = |draft| CreateEntityParams {
+        owned_by_id,
+        entity_uuid: None,
+        decision_time: None,
+        entity_type_ids: HashSet::from([organization_type_id.clone()]),
+        properties: PropertyObjectWithMetadata::from_parts(organization.clone(), None)
+            .expect("could not create property with metadata object"),
+        confidence: None,
+        link_data: None,
+        draft,
+        relationships: [],
+        provenance: ProvidedEntityEditionProvenance {
+            actor_type: ActorType::User,
+            origin: OriginProvenance::from_empty_type(OriginType::Api),
+            sources: Vec::new(),
+        },
+    };
+
+    let entity = api
+        .create_entity(api.account_id, create_params(false))
+        .await
+        .expect("could not create entity");
+    let entity_id = entity.metadata.record_id.entity_id;
+
+    let report = api
+        .create_entity(api.account_id, create_params(false))
+        .await
+        .expect_err("duplicate value of a unique property should be rejected");
+    let validation_reports = report
+        .downcast_ref::<HashMap<usize, EntityValidationReport>>()
+        .expect("validation report should be attached");
+    match &validation_reports[&0].unique_properties[&name_property] {
+        UniquePropertyError::Duplicate { data } => {
+            assert_eq!(data.entity_type_id, organization_type_id);
+            assert_eq!(data.conflicting_entities, [entity_id]);
+            assert_eq!(data.hidden_conflicting_entities, 0);
+        }
+        UniquePropertyError::Retrieval { error } => panic!("unexpected error: {error:?}"),
+    }
+
+    // Drafts are not subject to uniqueness
+    api.create_entity(api.account_id, create_params(true))
+        .await
+        .expect("could not create draft entity");
+
+    // Archived entities do not conflict anymore
+    api.patch_entity(
+        api.account_id,
+        PatchEntityParams {
+            entity_id,
+            properties: Vec::new(),
+            entity_type_ids: HashSet::new(),
+            archived: Some(true),
+            draft: None,
+            decision_time: None,
+            expected_edition_id: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not archive entity");
+    api.create_entity(api.account_id, create_params(false))
+        .await
+        .expect("archived entity should not conflict");
+}
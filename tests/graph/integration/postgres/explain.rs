@@ -0,0 +1,166 @@
+use std::collections::HashSet;
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    entity::{
+        CreateEntityParams, EntityQueryCursor, EntityQueryPath, EntityQuerySorting,
+        EntityQuerySortingRecord, EntityStore as _, GetEntitiesParams,
+    },
+    filter::Filter,
+    query::{ExplainQuery as _, Ordering, QueryExplanation},
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use type_system::{
+    knowledge::{
+        Entity,
+        entity::provenance::ProvidedEntityEditionProvenance,
+        property::{PropertyObject, PropertyObjectWithMetadata},
+    },
+    ontology::id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    provenance::{ActorType, OriginProvenance, OriginType},
+    web::OwnedById,
+};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn temporal_axes() -> QueryTemporalAxesUnresolved {
+    QueryTemporalAxesUnresolved::DecisionTime {
+        pinned: PinnedTemporalAxisUnresolved::new(None),
+        variable: VariableTemporalAxisUnresolved::new(None, None),
+    }
+}
+
+fn sorting(cursor: Option<EntityQueryCursor<'static>>) -> EntityQuerySorting<'static> {
+    EntityQuerySorting {
+        paths: vec![EntityQuerySortingRecord {
+            path: EntityQueryPath::Uuid,
+            ordering: Ordering::Ascending,
+            nulls: None,
+        }],
+        cursor,
+    }
+}
+
+async fn explain<A: AuthorizationApi>(
+    api: &DatabaseApi<'_, A>,
+    sorting: &EntityQuerySorting<'static>,
+) -> QueryExplanation {
+    api.store
+        .explain_query(
+            api.account_id,
+            Filter::<Entity>::All(Vec::new()),
+            Some(&temporal_axes().resolve()),
+            sorting,
+            Some(1),
+            false,
+        )
+        .await
+        .expect("could not explain query")
+}
+
+#[tokio::test]
+async fn explained_statement_is_paginated() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    for person in [entity::PERSON_ALICE_V1, entity::PERSON_BOB_V1] {
+        let properties: PropertyObject =
+            serde_json::from_str(person).expect("could not parse entity");
+        api.create_entity(
+            api.account_id,
+            CreateEntityParams {
+                owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                entity_uuid: None,
+                decision_time: None,
+                entity_type_ids: HashSet::from([person_entity_type_id()]),
+                properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                    .expect("could not create property with metadata object"),
+                confidence: None,
+                link_data: None,
+                draft: false,
+                relationships: [],
+                provenance: ProvidedEntityEditionProvenance {
+                    actor_type: ActorType::User,
+                    origin: OriginProvenance::from_empty_type(OriginType::Api),
+                    sources: Vec::new(),
+                },
+            },
+        )
+        .await
+        .expect("could not create entity");
+    }
+
+    let first_page = api
+        .get_entities(
+            api.account_id,
+            GetEntitiesParams {
+                filter: Filter::All(Vec::new()),
+                temporal_axes: temporal_axes(),
+                sorting: sorting(None),
+                limit: Some(1),
+                conversions: Vec::new(),
+                include_count: false,
+                include_entity_types: None,
+                include_drafts: false,
+                include_web_ids: false,
+                include_created_by_ids: false,
+                include_edition_created_by_ids: false,
+                include_type_ids: false,
+                include_type_titles: false,
+            },
+        )
+        .await
+        .expect("could not read first page");
+    let cursor = first_page
+        .cursor
+        .expect("the first page should have a cursor")
+        .into_owned();
+
+    let first_explanation = explain(&api, &sorting(None)).await;
+    let explanation = explain(&api, &sorting(Some(cursor))).await;
+
+    assert!(explanation.statement.contains("ORDER BY"));
+    assert!(explanation.statement.contains("LIMIT 1"));
+    assert!(
+        explanation.parameters.len() > first_explanation.parameters.len(),
+        "the cursor should be bound as parameter"
+    );
+    assert!(!explanation.joined_tables.is_empty());
+    assert_eq!(explanation.plan[0]["Plan"]["Node Type"], "Limit");
+}
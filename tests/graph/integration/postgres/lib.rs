@@ -11,17 +11,23 @@
 
 extern crate alloc;
 
+mod aggregation;
+mod batch;
+mod change_feed;
 mod data_type;
 mod drafts;
 mod entity;
 mod entity_type;
+mod explain;
 mod interconnected_graph;
 mod links;
 mod multi_type;
 mod partial_updates;
 mod property_metadata;
 mod property_type;
+mod snapshot;
 mod sorting;
+mod text_search;
 
 use alloc::borrow::Cow;
 use std::collections::HashMap;
@@ -47,6 +53,7 @@ use hash_graph_postgres_store::{
 };
 use hash_graph_store::{
     account::{AccountStore as _, InsertAccountIdParams, InsertWebIdParams},
+    change_feed::{ChangeFeedStore, ReadChangesParams, ReadChangesResponse},
     data_type::{
         ArchiveDataTypeParams, CountDataTypesParams, CreateDataTypeParams, DataTypeStore,
         GetDataTypeConversionTargetsParams, GetDataTypeConversionTargetsResponse,
@@ -55,9 +62,14 @@ use hash_graph_store::{
         UpdateDataTypesParams,
     },
     entity::{
-        CountEntitiesParams, CreateEntityParams, EntityStore, EntityValidationReport,
-        GetEntitiesParams, GetEntitiesResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
-        PatchEntityParams, UpdateEntityEmbeddingsParams, ValidateEntityParams,
+        AggregateEntitiesParams, AggregateEntitiesResponse, CountEntitiesParams,
+        CreateEntityParams, EntityBatchOperation, EntityBatchOperationResult, EntityStore,
+        EntityTombstone, EntityValidationReport, EraseEntityParams, FindEntityPathsParams,
+        FindEntityPathsResponse, GetEntitiesParams, GetEntitiesResponse, GetEntityHistoryParams,
+        GetEntityHistoryResponse, GetEntitySubgraphParams, GetEntitySubgraphResponse,
+        MergeEntitiesParams, MergeEntitiesResponse, PatchEntityParams,
+        UpdateEntityEmbeddingsParams, UpgradeEntityTypeParams, UpgradeEntityTypeResponse,
+        ValidateEntityParams,
     },
     entity_type::{
         ArchiveEntityTypeParams, CountEntityTypesParams, CreateEntityTypeParams, EntityTypeStore,
@@ -66,7 +78,7 @@ use hash_graph_store::{
         IncludeResolvedEntityTypeOption, UnarchiveEntityTypeParams,
         UpdateEntityTypeEmbeddingParams, UpdateEntityTypesParams,
     },
-    error::{InsertionError, QueryError, UpdateError},
+    error::{DeletionError, InsertionError, QueryError, UpdateError},
     pool::StorePool,
     property_type::{
         ArchivePropertyTypeParams, CountPropertyTypesParams, CreatePropertyTypeParams,
@@ -158,32 +170,30 @@ pub fn init_logging() {
         .try_init();
 }
 
+/// Returns the connection info of the test database as configured in the environment.
+pub fn database_connection_info() -> DatabaseConnectionInfo {
+    load_env(Environment::Test);
+    init_logging();
+
+    let user = std::env::var("HASH_GRAPH_PG_USER").unwrap_or_else(|_| "graph".to_owned());
+    let password = std::env::var("HASH_GRAPH_PG_PASSWORD").unwrap_or_else(|_| "graph".to_owned());
+    let host = std::env::var("HASH_GRAPH_PG_HOST").unwrap_or_else(|_| "localhost".to_owned());
+    let port = std::env::var("HASH_GRAPH_PG_PORT")
+        .map(|port| port.parse::<u16>().unwrap())
+        .unwrap_or(5432);
+    let database = std::env::var("HASH_GRAPH_PG_DATABASE").unwrap_or_else(|_| "graph".to_owned());
+
+    DatabaseConnectionInfo::new(DatabaseType::Postgres, user, password, host, port, database)
+}
+
 impl DatabaseTestWrapper<NoAuthorization> {
     pub async fn new() -> Self {
-        load_env(Environment::Test);
-        init_logging();
-
-        let user = std::env::var("HASH_GRAPH_PG_USER").unwrap_or_else(|_| "graph".to_owned());
-        let password =
-            std::env::var("HASH_GRAPH_PG_PASSWORD").unwrap_or_else(|_| "graph".to_owned());
-        let host = std::env::var("HASH_GRAPH_PG_HOST").unwrap_or_else(|_| "localhost".to_owned());
-        let port = std::env::var("HASH_GRAPH_PG_PORT")
-            .map(|port| port.parse::<u16>().unwrap())
-            .unwrap_or(5432);
-        let database =
-            std::env::var("HASH_GRAPH_PG_DATABASE").unwrap_or_else(|_| "graph".to_owned());
-
-        let connection_info = DatabaseConnectionInfo::new(
-            DatabaseType::Postgres,
-            user,
-            password,
-            host,
-            port,
-            database,
-        );
+        Self::connect(&database_connection_info()).await
+    }
 
+    pub async fn connect(connection_info: &DatabaseConnectionInfo) -> Self {
         let pool = PostgresStorePool::new(
-            &connection_info,
+            connection_info,
             &DatabasePoolConfig::default(),
             NoTls,
             PostgresStoreSettings::default(),
@@ -859,6 +869,54 @@ where
         self.store.count_entities(actor_id, params).await
     }
 
+    async fn aggregate_entities(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: AggregateEntitiesParams<'_>,
+    ) -> Result<AggregateEntitiesResponse, Report<QueryError>> {
+        self.store.aggregate_entities(actor_id, params).await
+    }
+
+    async fn find_entity_paths(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: FindEntityPathsParams,
+    ) -> Result<FindEntityPathsResponse, Report<QueryError>> {
+        self.store.find_entity_paths(actor_id, params).await
+    }
+
+    async fn get_entity_history(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: GetEntityHistoryParams,
+    ) -> Result<GetEntityHistoryResponse<'static>, Report<QueryError>> {
+        self.store.get_entity_history(actor_id, params).await
+    }
+
+    async fn erase_entity(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: EraseEntityParams,
+    ) -> Result<Vec<EntityTombstone>, Report<DeletionError>> {
+        self.store.erase_entity(actor_id, params).await
+    }
+
+    async fn merge_entities(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: MergeEntitiesParams,
+    ) -> Result<MergeEntitiesResponse, Report<UpdateError>> {
+        self.store.merge_entities(actor_id, params).await
+    }
+
+    async fn upgrade_entity_type(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        params: UpgradeEntityTypeParams,
+    ) -> Result<UpgradeEntityTypeResponse, Report<UpdateError>> {
+        self.store.upgrade_entity_type(actor_id, params).await
+    }
+
     async fn get_entity_by_id(
         &self,
         actor_id: ActorEntityUuid,
@@ -879,6 +937,14 @@ where
         self.store.patch_entity(actor_id, params).await
     }
 
+    async fn execute_entity_batch(
+        &mut self,
+        actor_id: ActorEntityUuid,
+        operations: Vec<EntityBatchOperation>,
+    ) -> Result<Vec<EntityBatchOperationResult>, Report<UpdateError>> {
+        self.store.execute_entity_batch(actor_id, operations).await
+    }
+
     async fn update_entity_embeddings(
         &mut self,
         actor_id: ActorEntityUuid,
@@ -892,6 +958,19 @@ where
     }
 }
 
+impl<A> ChangeFeedStore for DatabaseApi<'_, A>
+where
+    A: AuthorizationApi,
+{
+    async fn read_changes(
+        &self,
+        actor_id: ActorEntityUuid,
+        params: ReadChangesParams,
+    ) -> Result<ReadChangesResponse, Report<QueryError>> {
+        self.store.read_changes(actor_id, params).await
+    }
+}
+
 #[tokio::test]
 async fn can_connect() {
     DatabaseTestWrapper::new().await;
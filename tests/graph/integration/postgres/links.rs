@@ -3,13 +3,14 @@ use std::collections::HashSet;
 
 use hash_graph_store::{
     entity::{
-        CountEntitiesParams, CreateEntityParams, EntityQueryPath, EntityQuerySorting,
-        EntityStore as _, GetEntitiesParams, PatchEntityParams,
+        CountEntitiesParams, CreateEntityParams, EntityPath, EntityPathHop, EntityQueryPath,
+        EntityQuerySorting, EntityStore as _, FindEntityPathsParams, GetEntitiesParams,
+        LinkDirection, PatchEntityParams,
     },
     entity_type::EntityTypeQueryPath,
     filter::{Filter, FilterExpression, Parameter},
     subgraph::{
-        edges::{EdgeDirection, KnowledgeGraphEdgeKind, SharedEdgeKind},
+        edges::{EdgeDirection, GraphResolveDepths, KnowledgeGraphEdgeKind, SharedEdgeKind},
         temporal_axes::{
             PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved,
             VariableTemporalAxisUnresolved,
@@ -723,6 +724,7 @@ async fn remove_link() {
         PatchEntityParams {
             entity_id: link_entity_metadata.metadata.record_id.entity_id,
             decision_time: None,
+            expected_edition_id: None,
             archived: Some(true),
             draft: None,
             entity_type_ids: HashSet::new(),
@@ -785,3 +787,237 @@ async fn remove_link() {
         > 0;
     assert!(!has_link);
 }
+
+#[tokio::test]
+#[expect(clippy::too_many_lines)]
+async fn find_paths() {
+    let alice = serde_json::from_str(entity::PERSON_ALICE_V1).expect("could not parse entity");
+    let bob = serde_json::from_str(entity::PERSON_BOB_V1).expect("could not parse entity");
+    let charles = serde_json::from_str(entity::PERSON_CHARLES_V1).expect("could not parse entity");
+
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+
+    let person_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let friend_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/friend-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let acquaintance_link_type_id = VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/acquaintance-of/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    };
+
+    let mut person_ids = Vec::new();
+    for person in [alice, bob, charles] {
+        let entity = api
+            .create_entity(
+                api.account_id,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: HashSet::from([person_type_id.clone()]),
+                    properties: PropertyObjectWithMetadata::from_parts(person, None)
+                        .expect("could not create property with metadata object"),
+                    link_data: None,
+                    draft: false,
+                    relationships: [],
+                    confidence: None,
+                    provenance: ProvidedEntityEditionProvenance {
+                        actor_type: ActorType::User,
+                        origin: OriginProvenance::from_empty_type(OriginType::Api),
+                        sources: Vec::new(),
+                    },
+                },
+            )
+            .await
+            .expect("could not create entity");
+        person_ids.push(entity.metadata.record_id.entity_id);
+    }
+    let [alice_id, bob_id, charles_id] = person_ids[..] else {
+        unreachable!("three persons were created");
+    };
+
+    // alice -[friend of]-> bob -[acquaintance of]-> charles
+    let mut link_ids = Vec::new();
+    for (link_type_id, left_entity_id, right_entity_id) in [
+        (&friend_link_type_id, alice_id, bob_id),
+        (&acquaintance_link_type_id, bob_id, charles_id),
+    ] {
+        let link = api
+            .create_entity(
+                api.account_id,
+                CreateEntityParams {
+                    owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+                    entity_uuid: None,
+                    decision_time: None,
+                    entity_type_ids: HashSet::from([link_type_id.clone()]),
+                    properties: PropertyObjectWithMetadata::from_parts(
+                        PropertyObject::empty(),
+                        None,
+                    )
+                    .expect("could not create property with metadata object"),
+                    link_data: Some(LinkData {
+                        left_entity_id,
+                        right_entity_id,
+                        left_entity_confidence: None,
+                        left_entity_provenance: PropertyProvenance::default(),
+                        right_entity_confidence: None,
+                        right_entity_provenance: PropertyProvenance::default(),
+                    }),
+                    draft: false,
+                    relationships: [],
+                    confidence: None,
+                    provenance: ProvidedEntityEditionProvenance {
+                        actor_type: ActorType::User,
+                        origin: OriginProvenance::from_empty_type(OriginType::Api),
+                        sources: Vec::new(),
+                    },
+                },
+            )
+            .await
+            .expect("could not create link");
+        link_ids.push(link.metadata.record_id.entity_id);
+    }
+
+    let params = |source_entity_id, target_entity_id, max_depth| FindEntityPathsParams {
+        source_entity_id,
+        target_entity_id,
+        max_depth,
+        limit: 1,
+        link_entity_type_ids: None,
+        directions: None,
+        temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+            pinned: PinnedTemporalAxisUnresolved::new(None),
+            variable: VariableTemporalAxisUnresolved::new(None, None),
+        },
+        graph_resolve_depths: GraphResolveDepths::default(),
+        include_drafts: false,
+    };
+
+    let paths = api
+        .find_entity_paths(api.account_id, params(alice_id, charles_id, 2))
+        .await
+        .expect("could not find paths")
+        .paths;
+    assert_eq!(
+        paths,
+        [EntityPath {
+            hops: vec![
+                EntityPathHop {
+                    link_entity_id: link_ids[0],
+                    direction: LinkDirection::Outgoing,
+                    entity_id: bob_id,
+                },
+                EntityPathHop {
+                    link_entity_id: link_ids[1],
+                    direction: LinkDirection::Outgoing,
+                    entity_id: charles_id,
+                },
+            ],
+        }]
+    );
+
+    let paths = api
+        .find_entity_paths(api.account_id, params(alice_id, charles_id, 1))
+        .await
+        .expect("could not find paths")
+        .paths;
+    assert!(paths.is_empty(), "charles is two links away from alice");
+
+    let paths = api
+        .find_entity_paths(
+            api.account_id,
+            FindEntityPathsParams {
+                directions: Some(vec![LinkDirection::Outgoing]),
+                ..params(charles_id, alice_id, 2)
+            },
+        )
+        .await
+        .expect("could not find paths")
+        .paths;
+    assert!(paths.is_empty(), "links must not be followed backwards");
+
+    let paths = api
+        .find_entity_paths(
+            api.account_id,
+            FindEntityPathsParams {
+                link_entity_type_ids: Some(vec![friend_link_type_id.clone()]),
+                ..params(alice_id, charles_id, 2)
+            },
+        )
+        .await
+        .expect("could not find paths")
+        .paths;
+    assert!(paths.is_empty(), "only friend-of links may be followed");
+
+    let paths = api
+        .find_entity_paths(api.account_id, params(charles_id, bob_id, 2))
+        .await
+        .expect("could not find paths")
+        .paths;
+    assert_eq!(
+        paths,
+        [EntityPath {
+            hops: vec![EntityPathHop {
+                link_entity_id: link_ids[1],
+                direction: LinkDirection::Incoming,
+                entity_id: bob_id,
+            }],
+        }]
+    );
+
+    api.find_entity_paths(
+        api.account_id,
+        params(alice_id, charles_id, FindEntityPathsParams::MAX_DEPTH + 1),
+    )
+    .await
+    .expect_err("the depth must be bounded");
+    api.find_entity_paths(
+        api.account_id,
+        FindEntityPathsParams {
+            limit: FindEntityPathsParams::MAX_LIMIT + 1,
+            ..params(alice_id, charles_id, 2)
+        },
+    )
+    .await
+    .expect_err("the number of paths must be bounded");
+}
@@ -181,6 +181,7 @@ async fn initial_person() {
             PatchEntityParams {
                 entity_id: entity.metadata.record_id.entity_id,
                 decision_time: None,
+                expected_edition_id: None,
                 entity_type_ids: HashSet::from([person_entity_type_id(), org_entity_type_id()]),
                 properties: vec![],
                 draft: None,
@@ -388,6 +389,7 @@ async fn create_multi() {
             PatchEntityParams {
                 entity_id: entity.metadata.record_id.entity_id,
                 decision_time: None,
+                expected_edition_id: None,
                 entity_type_ids: HashSet::from([person_entity_type_id()]),
                 properties: vec![],
                 draft: None,
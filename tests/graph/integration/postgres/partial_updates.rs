@@ -121,6 +121,7 @@ async fn properties_add() {
         PatchEntityParams {
             entity_id,
             decision_time: None,
+            expected_edition_id: None,
             entity_type_ids: HashSet::new(),
             properties: vec![
                 PropertyPatchOperation::Add {
@@ -241,6 +242,7 @@ async fn properties_remove() {
         PatchEntityParams {
             entity_id,
             decision_time: None,
+            expected_edition_id: None,
             entity_type_ids: HashSet::new(),
             properties: vec![PropertyPatchOperation::Remove {
                 path: once(PropertyPathElement::from(name_property_type_id())).collect(),
@@ -328,6 +330,7 @@ async fn properties_replace() {
         PatchEntityParams {
             entity_id,
             decision_time: None,
+            expected_edition_id: None,
             entity_type_ids: HashSet::new(),
             properties: vec![PropertyPatchOperation::Replace {
                 path: once(PropertyPathElement::from(name_property_type_id())).collect(),
@@ -430,6 +433,7 @@ async fn type_ids() {
         PatchEntityParams {
             entity_id,
             decision_time: None,
+            expected_edition_id: None,
             entity_type_ids: HashSet::new(),
             properties: vec![],
             draft: None,
@@ -488,6 +492,7 @@ async fn type_ids() {
         PatchEntityParams {
             entity_id,
             decision_time: None,
+            expected_edition_id: None,
             entity_type_ids: HashSet::from([person_entity_type_id(), org_entity_type_id()]),
             properties: vec![],
             draft: None,
@@ -548,6 +553,7 @@ async fn type_ids() {
         PatchEntityParams {
             entity_id,
             decision_time: None,
+            expected_edition_id: None,
             entity_type_ids: HashSet::from([person_entity_type_id()]),
             properties: vec![],
             draft: None,
@@ -253,6 +253,7 @@ async fn initial_metadata() {
                 archived: None,
                 draft: None,
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: Confidence::new(0.5),
                 provenance: edition_provenance(),
             },
@@ -295,6 +296,7 @@ async fn initial_metadata() {
                 archived: None,
                 draft: None,
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: None,
                 provenance: edition_provenance(),
             },
@@ -372,6 +374,7 @@ async fn no_initial_metadata() {
                 archived: None,
                 draft: None,
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: None,
                 provenance: ProvidedEntityEditionProvenance {
                     actor_type: ActorType::User,
@@ -395,6 +398,7 @@ async fn no_initial_metadata() {
                 archived: None,
                 draft: None,
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: Confidence::new(0.5),
                 provenance: ProvidedEntityEditionProvenance {
                     actor_type: ActorType::User,
@@ -451,6 +455,7 @@ async fn no_initial_metadata() {
                 archived: None,
                 draft: None,
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: None,
                 provenance: ProvidedEntityEditionProvenance {
                     actor_type: ActorType::User,
@@ -495,6 +500,7 @@ async fn no_initial_metadata() {
                 archived: None,
                 draft: None,
                 decision_time: None,
+                expected_edition_id: None,
                 confidence: Confidence::new(0.5),
                 provenance: edition_provenance(),
             },
@@ -567,6 +573,7 @@ async fn properties_add() {
             PatchEntityParams {
                 entity_id,
                 decision_time: None,
+                expected_edition_id: None,
                 entity_type_ids: HashSet::new(),
                 properties: vec![PropertyPatchOperation::Add {
                     path: path.clone(),
@@ -680,6 +687,7 @@ async fn properties_remove() {
             PatchEntityParams {
                 entity_id,
                 decision_time: None,
+                expected_edition_id: None,
                 entity_type_ids: HashSet::new(),
                 properties: vec![
                     PropertyPatchOperation::Add {
@@ -774,6 +782,7 @@ async fn properties_remove() {
             PatchEntityParams {
                 entity_id,
                 decision_time: None,
+                expected_edition_id: None,
                 entity_type_ids: HashSet::new(),
                 properties: vec![PropertyPatchOperation::Remove {
                     path: interests_path,
@@ -0,0 +1,764 @@
+use core::hash::Hash;
+use std::collections::{HashMap, HashSet};
+
+use error_stack::Report;
+use futures::{SinkExt as _, StreamExt as _, channel::mpsc, stream};
+use hash_graph_authorization::{AuthorizationApi, NoAuthorization, schema::WebOwnerSubject};
+use hash_graph_postgres_store::{
+    snapshot::{
+        SnapshotDumpFilter, SnapshotDumpSettings, SnapshotEntry, SnapshotRestoreError,
+        SnapshotStore,
+    },
+    store::{
+        AsClient as _, DatabaseConnectionInfo, DatabasePoolConfig, DatabaseType, PostgresStorePool,
+        PostgresStoreSettings,
+    },
+};
+use hash_graph_store::{
+    account::{AccountStore as _, InsertAccountIdParams, InsertWebIdParams},
+    data_type::{CreateDataTypeParams, DataTypeStore as _},
+    entity::{CreateEntityParams, EntityStore as _, EraseEntityParams, PatchEntityParams},
+    entity_type::{CreateEntityTypeParams, EntityTypeStore as _},
+    migration::StoreMigration as _,
+    pool::StorePool as _,
+    property_type::{CreatePropertyTypeParams, PropertyTypeStore as _},
+    query::ConflictBehavior,
+};
+use hash_graph_temporal_versioning::{Timestamp, TransactionTime};
+use hash_graph_test_data::{data_type, entity, entity_type, property_type};
+use serde_json::json;
+use tokio_postgres::{NoTls, types::FromSqlOwned};
+use type_system::{
+    knowledge::{
+        entity::{EntityId, LinkData, id::EntityUuid, provenance::ProvidedEntityEditionProvenance},
+        property::{PropertyObject, PropertyObjectWithMetadata, metadata::PropertyProvenance},
+    },
+    ontology::{
+        data_type::{Conversions, DataType},
+        entity_type::EntityType,
+        id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+        provenance::{OntologyOwnership, ProvidedOntologyEditionProvenance},
+    },
+    provenance::{ActorEntityUuid, ActorType, OriginProvenance, OriginType},
+    web::OwnedById,
+};
+use uuid::Uuid;
+
+use crate::{
+    DatabaseApi, DatabaseTestWrapper, data_type_relationships, database_connection_info,
+    entity_type_relationships, property_type_relationships,
+};
+
+/// A database which only exists for a single test.
+///
+/// Snapshots are dumped from and restored into committed data, so they cannot be tested inside
+/// the transaction of a [`DatabaseTestWrapper`].
+struct ScratchDatabase {
+    connection_info: DatabaseConnectionInfo,
+    pool: PostgresStorePool,
+}
+
+/// Connects to `database` as super user, which is required to create databases and extensions.
+async fn super_user_pool(database: &str) -> PostgresStorePool {
+    let connection_info = database_connection_info();
+    PostgresStorePool::new(
+        &DatabaseConnectionInfo::new(
+            DatabaseType::Postgres,
+            std::env::var("POSTGRES_USER").unwrap_or_else(|_| "postgres".to_owned()),
+            std::env::var("POSTGRES_PASSWORD").unwrap_or_else(|_| "postgres".to_owned()),
+            connection_info.host().to_owned(),
+            connection_info.port(),
+            database.to_owned(),
+        ),
+        &DatabasePoolConfig::default(),
+        NoTls,
+        PostgresStoreSettings::default(),
+    )
+    .await
+    .expect("could not connect to database")
+}
+
+impl ScratchDatabase {
+    async fn create() -> Self {
+        let source = database_connection_info();
+        let name = format!("graph_snapshot_{}", Uuid::new_v4().simple());
+
+        super_user_pool(source.database())
+            .await
+            .acquire(NoAuthorization, None)
+            .await
+            .expect("could not acquire a database connection")
+            .as_client()
+            .batch_execute(&format!("CREATE DATABASE {name} OWNER {};", source.user()))
+            .await
+            .expect("could not create database");
+
+        // The migrations are run as super user, so the tables are made accessible to the graph
+        // user in the same way as for the main database.
+        let store = super_user_pool(&name)
+            .await
+            .acquire_owned(NoAuthorization, None)
+            .await
+            .expect("could not acquire a database connection");
+        store
+            .as_client()
+            .batch_execute(&format!(
+                "
+                ALTER DEFAULT PRIVILEGES GRANT USAGE ON SCHEMAS TO {user};
+                ALTER DEFAULT PRIVILEGES
+                    GRANT SELECT, INSERT, UPDATE, DELETE ON TABLES TO {user};
+                ",
+                user = source.user()
+            ))
+            .await
+            .expect("could not grant privileges");
+        store
+            .run_migrations()
+            .await
+            .expect("could not run migrations");
+
+        let connection_info = DatabaseConnectionInfo::new(
+            DatabaseType::Postgres,
+            source.user().to_owned(),
+            source.password().to_owned(),
+            source.host().to_owned(),
+            source.port(),
+            name,
+        );
+        let pool = PostgresStorePool::new(
+            &connection_info,
+            &DatabasePoolConfig::default(),
+            NoTls,
+            PostgresStoreSettings::default(),
+        )
+        .await
+        .expect("could not connect to database");
+
+        Self {
+            connection_info,
+            pool,
+        }
+    }
+
+    /// Dumps the database, or only the subset matching `filter` which changed after `since`.
+    ///
+    /// Dumping blocks the current thread, so this requires a multi-threaded runtime.
+    async fn dump(
+        &self,
+        filter: Option<SnapshotDumpFilter>,
+        since: Option<Timestamp<TransactionTime>>,
+    ) -> Vec<SnapshotEntry> {
+        let (entry_tx, entry_rx) = mpsc::unbounded();
+        self.pool
+            .dump_snapshot(
+                entry_tx.sink_map_err(Report::new),
+                &NoAuthorization,
+                SnapshotDumpSettings {
+                    chunk_size: 10_000,
+                    dump_webs: true,
+                    dump_accounts: true,
+                    dump_account_groups: true,
+                    dump_entities: true,
+                    dump_entity_types: true,
+                    dump_property_types: true,
+                    dump_data_types: true,
+                    dump_embeddings: true,
+                    dump_relations: true,
+                    filter,
+                    since,
+                },
+            )
+            .expect("could not dump snapshot");
+
+        entry_rx.collect().await
+    }
+
+    async fn restore(&self, entries: Vec<SnapshotEntry>) {
+        SnapshotStore::new(
+            self.pool
+                .acquire(NoAuthorization, None)
+                .await
+                .expect("could not acquire a database connection"),
+        )
+        .restore_snapshot(
+            stream::iter(
+                entries
+                    .into_iter()
+                    .map(Ok::<_, Report<SnapshotRestoreError>>),
+            ),
+            10_000,
+            false,
+        )
+        .await
+        .expect("could not restore snapshot");
+    }
+
+    async fn select<T>(&self, query: &str) -> HashSet<T>
+    where
+        T: FromSqlOwned + Eq + Hash,
+    {
+        self.pool
+            .acquire(NoAuthorization, None)
+            .await
+            .expect("could not acquire a database connection")
+            .as_client()
+            .query(query, &[])
+            .await
+            .expect("could not read rows")
+            .into_iter()
+            .map(|row| row.get(0))
+            .collect()
+    }
+
+    async fn delete(self) {
+        let name = self.connection_info.database().to_owned();
+        drop(self.pool);
+
+        super_user_pool(database_connection_info().database())
+            .await
+            .acquire(NoAuthorization, None)
+            .await
+            .expect("could not acquire a database connection")
+            .as_client()
+            .batch_execute(&format!("DROP DATABASE {name} WITH (FORCE);"))
+            .await
+            .expect("could not drop database");
+    }
+}
+
+fn entity_type_id(name: &str) -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(format!(
+            "https://blockprotocol.org/@alice/types/entity-type/{name}/"
+        ))
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+async fn create_entity<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    owned_by_id: OwnedById,
+    entity_type: &str,
+    properties: PropertyObject,
+    link_data: Option<LinkData>,
+) -> EntityId {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id,
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([entity_type_id(entity_type)]),
+            properties: PropertyObjectWithMetadata::from_parts(properties, None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity")
+    .metadata
+    .record_id
+    .entity_id
+}
+
+async fn create_person<A: AuthorizationApi>(
+    api: &mut DatabaseApi<'_, A>,
+    owned_by_id: OwnedById,
+    person: &str,
+) -> EntityId {
+    let properties = serde_json::from_str(person).expect("could not parse entity");
+    create_entity(api, owned_by_id, "person", properties, None).await
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn erased_entities_are_not_restored() {
+    let source = ScratchDatabase::create().await;
+
+    let mut database = DatabaseTestWrapper::connect(&source.connection_info).await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+    let account_id = api.account_id;
+    let web_id = OwnedById::new(account_id.into_uuid());
+    let alice = create_person(&mut api, web_id, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, web_id, entity::PERSON_BOB_V1).await;
+    api.store
+        .commit()
+        .await
+        .expect("could not commit seeded data");
+
+    let snapshot = source.dump(None, None).await;
+
+    source
+        .pool
+        .acquire(NoAuthorization, None)
+        .await
+        .expect("could not acquire a database connection")
+        .erase_entity(
+            account_id,
+            EraseEntityParams {
+                entity_id: bob,
+                reason: "test".to_owned(),
+            },
+        )
+        .await
+        .expect("could not erase entity");
+
+    let tombstones = source
+        .dump(None, None)
+        .await
+        .into_iter()
+        .filter(|entry| match entry {
+            SnapshotEntry::Entity(entity) => {
+                assert_ne!(
+                    entity.metadata.record_id.entity_id.entity_uuid, bob.entity_uuid,
+                    "erased entities should not be dumped"
+                );
+                false
+            }
+            SnapshotEntry::EntityTombstone(_) => true,
+            _ => false,
+        })
+        .collect::<Vec<_>>();
+    assert!(
+        matches!(
+            tombstones.as_slice(),
+            [SnapshotEntry::EntityTombstone(tombstone)] if tombstone.entity_uuid == bob.entity_uuid
+        ),
+        "the erased entity should be dumped as tombstone"
+    );
+
+    // The first snapshot still contains the erased entity, the tombstone removes it when
+    // restoring.
+    let target = ScratchDatabase::create().await;
+    target
+        .restore(snapshot.into_iter().chain(tombstones).collect())
+        .await;
+
+    let entity_uuids = target
+        .select::<EntityUuid>("SELECT entity_uuid FROM entity_ids;")
+        .await;
+    assert!(entity_uuids.contains(&alice.entity_uuid));
+    assert!(!entity_uuids.contains(&bob.entity_uuid));
+    assert_eq!(
+        target
+            .select::<EntityUuid>("SELECT entity_uuid FROM entity_tombstones;")
+            .await,
+        HashSet::from([bob.entity_uuid])
+    );
+
+    drop(database);
+    source.delete().await;
+    target.delete().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn filtered_dumps_are_closed_under_references() {
+    let source = ScratchDatabase::create().await;
+
+    let mut database = DatabaseTestWrapper::connect(&source.connection_info).await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+                data_type::LENGTH_V1,
+                data_type::CENTIMETER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+    let account_id = api.account_id;
+    let web_id = OwnedById::new(account_id.into_uuid());
+
+    // `meter` is only reachable from the `line` entity type and pulls in `centimeter` through its
+    // conversion.
+    let centimeter: DataType =
+        serde_json::from_str(data_type::CENTIMETER_V1).expect("could not parse data type");
+    let conversions: Conversions = serde_json::from_value(json!({
+        "from": { "expression": ["/", "self", { "const": 100, "type": "number" }] },
+        "to": { "expression": ["*", "self", { "const": 100, "type": "number" }] },
+    }))
+    .expect("could not parse conversions");
+    api.create_data_type(
+        account_id,
+        CreateDataTypeParams {
+            schema: serde_json::from_str(data_type::METER_V1).expect("could not parse data type"),
+            ownership: OntologyOwnership::Local {
+                owned_by_id: web_id,
+            },
+            relationships: data_type_relationships(),
+            conflict_behavior: ConflictBehavior::Fail,
+            provenance: ProvidedOntologyEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+            conversions: HashMap::from([(centimeter.id.base_url.clone(), conversions)]),
+        },
+    )
+    .await
+    .expect("could not create data type");
+    api.create_property_type(
+        account_id,
+        CreatePropertyTypeParams {
+            schema: serde_json::from_str(property_type::LENGTH_V1)
+                .expect("could not parse property type"),
+            ownership: OntologyOwnership::Local {
+                owned_by_id: web_id,
+            },
+            relationships: property_type_relationships(),
+            conflict_behavior: ConflictBehavior::Fail,
+            provenance: ProvidedOntologyEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create property type");
+    let link: EntityType =
+        serde_json::from_str(entity_type::LINK_V1).expect("could not parse entity type");
+    let line: EntityType =
+        serde_json::from_str(entity_type::LINE_V1).expect("could not parse entity type");
+    api.create_entity_type(
+        account_id,
+        CreateEntityTypeParams {
+            schema: line.clone(),
+            ownership: OntologyOwnership::Local {
+                owned_by_id: web_id,
+            },
+            relationships: entity_type_relationships(),
+            conflict_behavior: ConflictBehavior::Fail,
+            provenance: ProvidedOntologyEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity type");
+
+    // A second web with an entity which is only dumped as endpoint of a link, and an account
+    // which is not referenced by any dumped record.
+    let other_account_id = ActorEntityUuid::new(EntityUuid::new(Uuid::new_v4()));
+    let other_web_id = OwnedById::new(other_account_id.into_uuid());
+    let unrelated_account_id = ActorEntityUuid::new(EntityUuid::new(Uuid::new_v4()));
+    for id in [other_account_id, unrelated_account_id] {
+        api.store
+            .insert_account_id(
+                id,
+                InsertAccountIdParams {
+                    account_id: id,
+                    account_type: ActorType::User,
+                },
+            )
+            .await
+            .expect("could not insert account id");
+    }
+    api.store
+        .insert_web_id(
+            other_account_id,
+            InsertWebIdParams {
+                owned_by_id: other_web_id,
+                owner: WebOwnerSubject::Account {
+                    id: other_account_id,
+                },
+            },
+        )
+        .await
+        .expect("could not insert web id");
+
+    let alice = create_person(&mut api, web_id, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, other_web_id, entity::PERSON_BOB_V1).await;
+    let charles = create_person(&mut api, other_web_id, entity::PERSON_CHARLES_V1).await;
+    let friendship = create_entity(
+        &mut api,
+        web_id,
+        "friend-of",
+        PropertyObject::empty(),
+        Some(LinkData {
+            left_entity_id: alice,
+            right_entity_id: bob,
+            left_entity_confidence: None,
+            left_entity_provenance: PropertyProvenance::default(),
+            right_entity_confidence: None,
+            right_entity_provenance: PropertyProvenance::default(),
+        }),
+    )
+    .await;
+    api.store
+        .commit()
+        .await
+        .expect("could not commit seeded data");
+
+    source
+        .pool
+        .acquire(NoAuthorization, None)
+        .await
+        .expect("could not acquire a database connection")
+        .erase_entity(
+            account_id,
+            EraseEntityParams {
+                entity_id: charles,
+                reason: "test".to_owned(),
+            },
+        )
+        .await
+        .expect("could not erase entity");
+
+    let web_snapshot = source
+        .dump(
+            Some(SnapshotDumpFilter {
+                webs: vec![web_id],
+                entity_types: Vec::new(),
+            }),
+            None,
+        )
+        .await;
+    let type_snapshot = source
+        .dump(
+            Some(SnapshotDumpFilter {
+                webs: Vec::new(),
+                entity_types: vec![line.id.clone()],
+            }),
+            None,
+        )
+        .await;
+    for snapshot in [&web_snapshot, &type_snapshot] {
+        assert!(
+            !snapshot
+                .iter()
+                .any(|entry| matches!(entry, SnapshotEntry::EntityTombstone(_))),
+            "tombstones of entities not matching the filter should not be dumped"
+        );
+    }
+
+    let web_target = ScratchDatabase::create().await;
+    web_target.restore(web_snapshot).await;
+
+    // Links pull in their endpoints, even if they are owned by a different web.
+    assert_eq!(
+        web_target
+            .select::<EntityUuid>("SELECT entity_uuid FROM entity_ids;")
+            .await,
+        HashSet::from([alice.entity_uuid, bob.entity_uuid, friendship.entity_uuid])
+    );
+    assert_eq!(
+        web_target.select::<Uuid>("SELECT web_id FROM webs;").await,
+        HashSet::from([web_id.into_uuid(), other_web_id.into_uuid()])
+    );
+    assert_eq!(
+        web_target
+            .select::<Uuid>("SELECT account_id FROM accounts;")
+            .await,
+        HashSet::from([account_id.into_uuid(), other_account_id.into_uuid()])
+    );
+    let web_type_ids = web_target
+        .select::<VersionedUrl>("SELECT base_url || 'v/' || version FROM ontology_ids;")
+        .await;
+    assert!(web_type_ids.contains(&entity_type_id("friend-of")));
+    // `friend-of` inherits from `link`
+    assert!(web_type_ids.contains(&link.id));
+    assert!(!web_type_ids.contains(&line.id));
+    assert!(!web_type_ids.contains(&centimeter.id));
+
+    let type_target = ScratchDatabase::create().await;
+    type_target.restore(type_snapshot).await;
+
+    assert!(
+        type_target
+            .select::<EntityUuid>("SELECT entity_uuid FROM entity_ids;")
+            .await
+            .is_empty()
+    );
+    let type_ids = type_target
+        .select::<VersionedUrl>("SELECT base_url || 'v/' || version FROM ontology_ids;")
+        .await;
+    assert!(type_ids.contains(&line.id));
+    assert!(type_ids.contains(&centimeter.id));
+    assert!(!type_ids.contains(&entity_type_id("person")));
+
+    drop(database);
+    source.delete().await;
+    web_target.delete().await;
+    type_target.delete().await;
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn incremental_snapshots_are_restored_on_top_of_the_base() {
+    let source = ScratchDatabase::create().await;
+
+    let mut database = DatabaseTestWrapper::connect(&source.connection_info).await;
+    let mut api = database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database");
+    let account_id = api.account_id;
+    let web_id = OwnedById::new(account_id.into_uuid());
+    let alice = create_person(&mut api, web_id, entity::PERSON_ALICE_V1).await;
+    let bob = create_person(&mut api, web_id, entity::PERSON_BOB_V1).await;
+    api.store
+        .commit()
+        .await
+        .expect("could not commit seeded data");
+
+    let base = source.dump(None, None).await;
+    let taken_at = base
+        .iter()
+        .find_map(|entry| match entry {
+            SnapshotEntry::Snapshot(metadata) => metadata.taken_at,
+            _ => None,
+        })
+        .expect("the snapshot should record when it was taken");
+
+    // Adds, updates and erases an entity after the base snapshot was taken.
+    let mut api = database
+        .seed([], [], [])
+        .await
+        .expect("could not seed database");
+    let charles = create_person(&mut api, web_id, entity::PERSON_CHARLES_V1).await;
+    api.patch_entity(
+        account_id,
+        PatchEntityParams {
+            entity_id: alice,
+            properties: Vec::new(),
+            entity_type_ids: HashSet::new(),
+            archived: Some(true),
+            draft: None,
+            decision_time: None,
+            expected_edition_id: None,
+            confidence: None,
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not archive entity");
+    api.store.commit().await.expect("could not commit changes");
+    source
+        .pool
+        .acquire(NoAuthorization, None)
+        .await
+        .expect("could not acquire a database connection")
+        .erase_entity(
+            account_id,
+            EraseEntityParams {
+                entity_id: bob,
+                reason: "test".to_owned(),
+            },
+        )
+        .await
+        .expect("could not erase entity");
+
+    let delta = source.dump(None, Some(taken_at)).await;
+    assert!(
+        delta.iter().all(|entry| match entry {
+            SnapshotEntry::Entity(entity) =>
+                entity.metadata.record_id.entity_id.entity_uuid != bob.entity_uuid,
+            SnapshotEntry::Snapshot(metadata) => metadata.since == Some(taken_at),
+            _ => true,
+        }),
+        "the incremental snapshot should be taken since the base snapshot"
+    );
+
+    let target = ScratchDatabase::create().await;
+    target.restore(base).await;
+    target.restore(delta).await;
+
+    for query in [
+        "SELECT entity_uuid::text FROM entity_ids;",
+        "SELECT entity_uuid::text FROM entity_tombstones;",
+        "SELECT concat_ws(' ', entity_uuid, entity_edition_id, decision_time, transaction_time)
+           FROM entity_temporal_metadata;",
+        "SELECT concat_ws(' ', entity_edition_id, archived, properties)
+           FROM entity_editions;",
+        "SELECT concat_ws(' ', ontology_id, transaction_time) FROM ontology_temporal_metadata;",
+    ] {
+        assert_eq!(
+            target.select::<String>(query).await,
+            source.select::<String>(query).await,
+            "{query}"
+        );
+    }
+    assert_eq!(
+        target
+            .select::<EntityUuid>("SELECT entity_uuid FROM entity_ids;")
+            .await,
+        HashSet::from([alice.entity_uuid, charles.entity_uuid])
+    );
+
+    drop(database);
+    source.delete().await;
+    target.delete().await;
+}
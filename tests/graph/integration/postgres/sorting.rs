@@ -4,8 +4,9 @@ use std::collections::HashSet;
 use hash_graph_authorization::AuthorizationApi;
 use hash_graph_store::{
     entity::{
-        CreateEntityParams, EntityQueryPath, EntityQuerySorting, EntityQuerySortingRecord,
-        EntityStore as _, GetEntitySubgraphParams, GetEntitySubgraphResponse,
+        CreateEntityParams, EntityQueryCursor, EntityQueryPath, EntityQuerySorting,
+        EntityQuerySortingRecord, EntityStore as _, GetEntitiesParams, GetEntitySubgraphParams,
+        GetEntitySubgraphResponse,
     },
     filter::{Filter, JsonPath, PathToken},
     query::{NullOrdering, Ordering},
@@ -809,3 +810,95 @@ async fn age_descending_first_name_descending_first() {
     )
     .await;
 }
+
+#[tokio::test]
+async fn pinned_temporal_axes_across_pages() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = insert(&mut database).await;
+
+    // The axes are resolved once and pinned for every page, as done by a streamed query.
+    let temporal_axes = QueryTemporalAxesUnresolved::DecisionTime {
+        pinned: PinnedTemporalAxisUnresolved::new(None),
+        variable: VariableTemporalAxisUnresolved::new(None, None),
+    }
+    .resolve();
+    let params = |cursor| GetEntitiesParams {
+        filter: Filter::All(Vec::new()),
+        temporal_axes: temporal_axes.clone().into(),
+        sorting: EntityQuerySorting {
+            paths: vec![EntityQuerySortingRecord {
+                path: EntityQueryPath::Uuid,
+                ordering: Ordering::Ascending,
+                nulls: None,
+            }],
+            cursor,
+        },
+        conversions: Vec::new(),
+        limit: Some(3),
+        include_drafts: false,
+        include_count: false,
+        include_entity_types: None,
+        include_web_ids: false,
+        include_created_by_ids: false,
+        include_edition_created_by_ids: false,
+        include_type_ids: false,
+        include_type_titles: false,
+    };
+
+    let first_page = api
+        .get_entities(api.account_id, params(None))
+        .await
+        .expect("could not read first page");
+    assert_eq!(first_page.entities.len(), 3);
+
+    // Sorts after all entities read so far, so it would be part of the second page if the axes
+    // were resolved again.
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: Some(EntityUuid::new(Uuid::from_u128(u128::MAX))),
+            decision_time: None,
+            entity_type_ids: HashSet::from([VersionedUrl {
+                base_url: BaseUrl::new(
+                    "https://blockprotocol.org/@alice/types/entity-type/page/".to_owned(),
+                )
+                .expect("couldn't construct Base URL"),
+                version: OntologyTypeVersion::new(1),
+            }]),
+            properties: PropertyObjectWithMetadata::from_parts(page_v1(), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: ProvidedEntityEditionProvenance {
+                actor_type: ActorType::User,
+                origin: OriginProvenance::from_empty_type(OriginType::Api),
+                sources: Vec::new(),
+            },
+        },
+    )
+    .await
+    .expect("could not create entity");
+
+    let second_page = api
+        .get_entities(
+            api.account_id,
+            params(first_page.cursor.map(EntityQueryCursor::into_owned)),
+        )
+        .await
+        .expect("could not read second page");
+
+    assert_eq!(
+        first_page
+            .entities
+            .iter()
+            .chain(&second_page.entities)
+            .map(|entity| entity.metadata.record_id.entity_id.entity_uuid)
+            .collect::<Vec<_>>(),
+        (0..5)
+            .map(|idx| EntityUuid::new(Uuid::from_u128(idx)))
+            .collect::<Vec<_>>()
+    );
+}
@@ -0,0 +1,247 @@
+use alloc::borrow::Cow;
+use std::collections::HashSet;
+
+use hash_graph_authorization::AuthorizationApi;
+use hash_graph_store::{
+    account::{AccountStore as _, UpdateWebTextSearchConfigParams},
+    entity::{
+        CreateEntityParams, EntityQueryPath, EntityQuerySorting, EntityQuerySortingRecord,
+        EntityStore as _, GetEntitiesParams, PatchEntityParams,
+    },
+    filter::{Filter, FilterExpression, Parameter},
+    query::Ordering,
+    subgraph::temporal_axes::{
+        PinnedTemporalAxisUnresolved, QueryTemporalAxesUnresolved, VariableTemporalAxisUnresolved,
+    },
+};
+use hash_graph_test_data::{data_type, entity_type, property_type};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use type_system::{
+    knowledge::{
+        Entity,
+        entity::provenance::ProvidedEntityEditionProvenance,
+        property::{
+            Property, PropertyObject, PropertyObjectWithMetadata, PropertyPatchOperation,
+            PropertyPath, PropertyWithMetadata,
+        },
+    },
+    ontology::id::{BaseUrl, OntologyTypeVersion, VersionedUrl},
+    provenance::{ActorType, OriginProvenance, OriginType},
+    web::OwnedById,
+};
+
+use crate::{DatabaseApi, DatabaseTestWrapper};
+
+fn person_entity_type_id() -> VersionedUrl {
+    VersionedUrl {
+        base_url: BaseUrl::new(
+            "https://blockprotocol.org/@alice/types/entity-type/person/".to_owned(),
+        )
+        .expect("couldn't construct Base URL"),
+        version: OntologyTypeVersion::new(1),
+    }
+}
+
+fn person(name: &str) -> PropertyObject {
+    serde_json::from_value(json!({
+        "https://blockprotocol.org/@alice/types/property-type/name/": name
+    }))
+    .expect("could not parse entity")
+}
+
+const fn provenance() -> ProvidedEntityEditionProvenance {
+    ProvidedEntityEditionProvenance {
+        actor_type: ActorType::User,
+        origin: OriginProvenance::from_empty_type(OriginType::Api),
+        sources: Vec::new(),
+    }
+}
+
+async fn seed<A: AuthorizationApi>(
+    database: &mut DatabaseTestWrapper<A>,
+) -> DatabaseApi<'_, &mut A> {
+    database
+        .seed(
+            [
+                data_type::VALUE_V1,
+                data_type::TEXT_V1,
+                data_type::NUMBER_V1,
+            ],
+            [
+                property_type::NAME_V1,
+                property_type::AGE_V1,
+                property_type::FAVORITE_SONG_V1,
+                property_type::FAVORITE_FILM_V1,
+                property_type::HOBBY_V1,
+                property_type::INTERESTS_V1,
+            ],
+            [
+                entity_type::LINK_V1,
+                entity_type::link::FRIEND_OF_V1,
+                entity_type::link::ACQUAINTANCE_OF_V1,
+                entity_type::PERSON_V1,
+            ],
+        )
+        .await
+        .expect("could not seed database")
+}
+
+async fn create_person<A: AuthorizationApi>(api: &mut DatabaseApi<'_, A>, name: &str) -> Entity {
+    api.create_entity(
+        api.account_id,
+        CreateEntityParams {
+            owned_by_id: OwnedById::new(api.account_id.into_uuid()),
+            entity_uuid: None,
+            decision_time: None,
+            entity_type_ids: HashSet::from([person_entity_type_id()]),
+            properties: PropertyObjectWithMetadata::from_parts(person(name), None)
+                .expect("could not create property with metadata object"),
+            confidence: None,
+            link_data: None,
+            draft: false,
+            relationships: [],
+            provenance: provenance(),
+        },
+    )
+    .await
+    .expect("could not create entity")
+}
+
+/// Returns the properties of the entities matching `query`, ordered by their rank.
+async fn search<A: AuthorizationApi>(api: &DatabaseApi<'_, A>, query: &str) -> Vec<PropertyObject> {
+    api.get_entities(
+        api.account_id,
+        GetEntitiesParams {
+            filter: Filter::FullTextMatch(
+                FilterExpression::Path {
+                    path: EntityQueryPath::SearchVector,
+                },
+                FilterExpression::Parameter {
+                    parameter: Parameter::Text(Cow::Owned(query.to_owned())),
+                    convert: None,
+                },
+            ),
+            temporal_axes: QueryTemporalAxesUnresolved::DecisionTime {
+                pinned: PinnedTemporalAxisUnresolved::new(None),
+                variable: VariableTemporalAxisUnresolved::new(None, None),
+            },
+            sorting: EntityQuerySorting {
+                paths: vec![EntityQuerySortingRecord {
+                    path: EntityQueryPath::SearchRank(Cow::Owned(query.to_owned())),
+                    ordering: Ordering::Descending,
+                    nulls: None,
+                }],
+                cursor: None,
+            },
+            limit: None,
+            conversions: Vec::new(),
+            include_count: false,
+            include_entity_types: None,
+            include_drafts: false,
+            include_web_ids: false,
+            include_created_by_ids: false,
+            include_edition_created_by_ids: false,
+            include_type_ids: false,
+            include_type_titles: false,
+        },
+    )
+    .await
+    .expect("could not search entities")
+    .entities
+    .into_iter()
+    .map(|entity| entity.properties)
+    .collect()
+}
+
+#[tokio::test]
+async fn created_and_updated_entities_are_indexed() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    let entity = create_person(&mut api, "Alice").await;
+    create_person(&mut api, "Bob").await;
+    assert_eq!(search(&api, "alice").await, [person("Alice")]);
+
+    api.patch_entity(
+        api.account_id,
+        PatchEntityParams {
+            entity_id: entity.metadata.record_id.entity_id,
+            properties: vec![PropertyPatchOperation::Replace {
+                path: PropertyPath::default(),
+                property: PropertyWithMetadata::from_parts(
+                    Property::Object(person("Charles")),
+                    None,
+                )
+                .expect("could not create property with metadata"),
+            }],
+            entity_type_ids: HashSet::new(),
+            archived: None,
+            draft: None,
+            decision_time: None,
+            expected_edition_id: None,
+            confidence: None,
+            provenance: provenance(),
+        },
+    )
+    .await
+    .expect("could not update entity");
+
+    assert!(search(&api, "alice").await.is_empty());
+    assert_eq!(search(&api, "charles").await, [person("Charles")]);
+}
+
+#[tokio::test]
+async fn web_text_search_config_is_applied() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    create_person(&mut api, "Running").await;
+    // The `simple` configuration does not stem words.
+    assert!(search(&api, "runs").await.is_empty());
+
+    let owned_by_id = OwnedById::new(api.account_id.into_uuid());
+    api.store
+        .update_web_text_search_config(
+            api.account_id,
+            UpdateWebTextSearchConfigParams {
+                owned_by_id,
+                config: "english".to_owned(),
+            },
+        )
+        .await
+        .expect("could not update text search config");
+
+    // Existing entities are reindexed with the new configuration ...
+    assert_eq!(search(&api, "runs").await, [person("Running")]);
+
+    // ... and new entities are indexed with it.
+    create_person(&mut api, "Jumping").await;
+    assert_eq!(search(&api, "jumps").await, [person("Jumping")]);
+
+    api.store
+        .update_web_text_search_config(
+            api.account_id,
+            UpdateWebTextSearchConfigParams {
+                owned_by_id,
+                config: "does_not_exist".to_owned(),
+            },
+        )
+        .await
+        .expect_err("unknown text search configurations should be rejected");
+}
+
+#[tokio::test]
+async fn matches_are_ordered_by_rank() {
+    let mut database = DatabaseTestWrapper::new().await;
+    let mut api = seed(&mut database).await;
+
+    create_person(&mut api, "Alice Bob").await;
+    create_person(&mut api, "Alice Alice Alice").await;
+    create_person(&mut api, "Charles").await;
+
+    assert_eq!(
+        search(&api, "alice").await,
+        [person("Alice Alice Alice"), person("Alice Bob")]
+    );
+}